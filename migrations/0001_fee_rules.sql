@@ -0,0 +1,43 @@
+-- Fee schedule engine: rules keyed by (asset, posting kind) and the rule ids applied to each journal.
+
+ALTER TABLE ledger_accounts DROP CONSTRAINT IF EXISTS ledger_accounts_account_type_check;
+ALTER TABLE ledger_accounts ADD CONSTRAINT ledger_accounts_account_type_check CHECK (
+    account_type IN (
+        'USER_AVAILABLE', 'USER_LOCKED',
+        'PLATFORM_CLEARING', 'PLATFORM_REVENUE',
+        'TREASURY_AVAILABLE', 'TREASURY_LOCKED',
+        'INVENTORY_AVAILABLE', 'INVENTORY_LOCKED'
+    )
+);
+
+CREATE TABLE fee_rules (
+    id           BIGSERIAL PRIMARY KEY,
+    asset_id     SMALLINT    NOT NULL REFERENCES assets(id),
+    posting_kind TEXT        NOT NULL,
+    calculation  TEXT        NOT NULL CHECK (calculation IN ('FLAT', 'PERCENTAGE', 'TIERED')),
+    flat_amount  NUMERIC(38,0) CHECK (flat_amount >= 0),
+    rate_bps     INTEGER     CHECK (rate_bps >= 0),
+    min_fee      NUMERIC(38,0) CHECK (min_fee >= 0),
+    max_fee      NUMERIC(38,0) CHECK (max_fee >= 0),
+    rounding     TEXT        NOT NULL DEFAULT 'HALF_EVEN'
+                             CHECK (rounding IN ('DOWN', 'UP', 'HALF_UP', 'HALF_EVEN')),
+    is_active    BOOLEAN     NOT NULL DEFAULT true,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CHECK (calculation <> 'FLAT' OR flat_amount IS NOT NULL),
+    CHECK (calculation <> 'PERCENTAGE' OR rate_bps IS NOT NULL),
+    CHECK (min_fee IS NULL OR max_fee IS NULL OR min_fee <= max_fee)
+);
+
+CREATE INDEX fee_rules_lookup_idx ON fee_rules (asset_id, posting_kind) WHERE is_active;
+
+CREATE TABLE fee_rule_tiers (
+    fee_rule_id BIGINT        NOT NULL REFERENCES fee_rules(id),
+    position    SMALLINT      NOT NULL,
+    up_to       NUMERIC(38,0),
+    rate_bps    INTEGER       NOT NULL DEFAULT 0 CHECK (rate_bps >= 0),
+    flat_amount NUMERIC(38,0) NOT NULL DEFAULT 0 CHECK (flat_amount >= 0),
+    PRIMARY KEY (fee_rule_id, position)
+);
+
+ALTER TABLE journal_transactions
+    ADD COLUMN fee_rule_ids BIGINT[] NOT NULL DEFAULT '{}';
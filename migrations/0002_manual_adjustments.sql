@@ -0,0 +1,63 @@
+-- Maker-checker workflow: manual adjustments are stored as drafts until approved by other principals.
+-- Drafts keep their metadata, tags and line memos, so the journal posted on approval carries them.
+
+CREATE TABLE manual_adjustments (
+    id                   BIGSERIAL PRIMARY KEY,
+    public_id            UUID          NOT NULL UNIQUE,
+    external_ref         TEXT          NOT NULL UNIQUE,
+    description          TEXT,
+    created_by           TEXT          NOT NULL,
+    asset_id             SMALLINT      NOT NULL REFERENCES assets(id),
+    gross_amount         NUMERIC(38,0) NOT NULL CHECK (gross_amount > 0),
+    status               TEXT          NOT NULL DEFAULT 'PENDING_APPROVAL'
+                                       CHECK (status IN ('PENDING_APPROVAL', 'REJECTED', 'POSTED')),
+    required_approvals   SMALLINT      NOT NULL CHECK (required_approvals >= 1),
+    rejection_reason     TEXT,
+    posted_journal_tx_id BIGINT        REFERENCES journal_transactions(id),
+    metadata             JSONB         NOT NULL DEFAULT '{}'::jsonb CHECK (jsonb_typeof(metadata) = 'object'),
+    tags                 TEXT[]        NOT NULL DEFAULT '{}',
+    created_at           TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    updated_at           TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    CHECK (status <> 'REJECTED' OR rejection_reason IS NOT NULL),
+    CHECK (status <> 'POSTED' OR posted_journal_tx_id IS NOT NULL)
+);
+
+CREATE INDEX manual_adjustments_pending_idx ON manual_adjustments (created_at)
+    WHERE status = 'PENDING_APPROVAL';
+
+CREATE TABLE manual_adjustment_lines (
+    adjustment_id BIGINT        NOT NULL REFERENCES manual_adjustments(id),
+    position      SMALLINT      NOT NULL,
+    account_id    BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    amount        NUMERIC(38,0) NOT NULL CHECK (amount <> 0),
+    memo          TEXT          CHECK (memo IS NULL OR char_length(memo) <= 500),
+    PRIMARY KEY (adjustment_id, position)
+);
+
+CREATE TABLE manual_adjustment_approvals (
+    adjustment_id BIGINT      NOT NULL REFERENCES manual_adjustments(id),
+    approver      TEXT        NOT NULL,
+    approved_at   TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (adjustment_id, approver)
+);
+
+CREATE TABLE manual_adjustment_audit (
+    id            BIGSERIAL PRIMARY KEY,
+    adjustment_id BIGINT      NOT NULL REFERENCES manual_adjustments(id),
+    action        TEXT        NOT NULL CHECK (action IN ('SUBMITTED', 'APPROVED', 'REJECTED', 'POSTED')),
+    actor         TEXT        NOT NULL,
+    reason        TEXT,
+    at            TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX manual_adjustment_audit_adjustment_idx ON manual_adjustment_audit (adjustment_id, id);
+
+CREATE FUNCTION manual_adjustment_audit_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'manual_adjustment_audit is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER manual_adjustment_audit_no_update_delete
+    BEFORE UPDATE OR DELETE ON manual_adjustment_audit
+    FOR EACH ROW EXECUTE FUNCTION manual_adjustment_audit_append_only();
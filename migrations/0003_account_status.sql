@@ -0,0 +1,10 @@
+-- Account lifecycle: status beyond is_active, with the reason and principal of the last change.
+
+ALTER TABLE ledger_accounts
+    ADD COLUMN status            TEXT NOT NULL DEFAULT 'ACTIVE'
+        CHECK (status IN ('ACTIVE', 'FROZEN', 'CREDIT_ONLY', 'DEBIT_ONLY', 'CLOSED')),
+    ADD COLUMN status_reason     TEXT,
+    ADD COLUMN status_note       TEXT,
+    ADD COLUMN status_changed_by TEXT,
+    ADD COLUMN status_changed_at TIMESTAMPTZ,
+    ADD CONSTRAINT ledger_accounts_closed_inactive_check CHECK (status <> 'CLOSED' OR NOT is_active);
@@ -0,0 +1,34 @@
+-- Append-only history of account status and is_active changes, written in the same
+-- transaction as the change itself.
+
+CREATE TABLE account_status_history (
+    id          BIGSERIAL PRIMARY KEY,
+    account_id  BIGINT      NOT NULL REFERENCES ledger_accounts(id),
+    from_status TEXT        NOT NULL,
+    to_status   TEXT        NOT NULL,
+    was_active  BOOLEAN     NOT NULL,
+    is_active   BOOLEAN     NOT NULL,
+    reason      TEXT        NOT NULL,
+    note        TEXT,
+    actor       TEXT        NOT NULL,
+    changed_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX account_status_history_account_idx ON account_status_history (account_id, changed_at, id);
+
+CREATE FUNCTION account_status_history_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'account_status_history is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER account_status_history_no_update_delete
+    BEFORE UPDATE OR DELETE ON account_status_history
+    FOR EACH ROW EXECUTE FUNCTION account_status_history_append_only();
+
+-- Seed the current state of existing accounts so every account has a starting point.
+-- Earlier changes were not recorded, so backdated checks before this point fail closed.
+INSERT INTO account_status_history
+    (account_id, from_status, to_status, was_active, is_active, reason, actor, changed_at)
+SELECT id, status, status, is_active, is_active, 'ACCOUNT_OPENED', 'system:migration', now()
+FROM ledger_accounts;
@@ -0,0 +1,104 @@
+-- Hierarchical chart of accounts. Every ledger account maps to a node; nodes carry a
+-- GL class and a normal-balance side and roll up through their parents.
+
+CREATE TABLE coa_nodes (
+    id             BIGSERIAL PRIMARY KEY,
+    code           TEXT   NOT NULL UNIQUE,
+    name           TEXT   NOT NULL,
+    gl_class       TEXT   NOT NULL CHECK (gl_class IN ('ASSET', 'LIABILITY', 'EQUITY', 'REVENUE', 'EXPENSE')),
+    normal_balance TEXT   NOT NULL CHECK (normal_balance IN ('DEBIT', 'CREDIT')),
+    parent_id      BIGINT REFERENCES coa_nodes(id),
+    CHECK (parent_id IS NULL OR parent_id <> id)
+);
+
+CREATE INDEX coa_nodes_parent_idx ON coa_nodes (parent_id);
+
+-- Node assigned to new accounts of each type unless one is given explicitly.
+CREATE TABLE coa_account_type_defaults (
+    account_type TEXT   PRIMARY KEY,
+    coa_node_id  BIGINT NOT NULL REFERENCES coa_nodes(id)
+);
+
+INSERT INTO coa_nodes (code, name, gl_class, normal_balance) VALUES
+    ('1000', 'Assets',      'ASSET',     'DEBIT'),
+    ('2000', 'Liabilities', 'LIABILITY', 'CREDIT'),
+    ('3000', 'Equity',      'EQUITY',    'CREDIT'),
+    ('4000', 'Revenue',     'REVENUE',   'CREDIT'),
+    ('5000', 'Expenses',    'EXPENSE',   'DEBIT');
+
+-- Children inherit class and side from their parent; parents are listed before children.
+DO $$
+DECLARE
+    r RECORD;
+BEGIN
+    FOR r IN
+        SELECT * FROM (VALUES
+            (1,  '1100', 'Funds in clearing',        '1000'),
+            (2,  '2100', 'Customer balances',        '2000'),
+            (3,  '2110', 'Customer available funds', '2100'),
+            (4,  '2120', 'Customer locked funds',    '2100'),
+            (5,  '3100', 'Treasury funds',           '3000'),
+            (6,  '3110', 'Treasury available',       '3100'),
+            (7,  '3120', 'Treasury locked',          '3100'),
+            (8,  '3200', 'Inventory funds',          '3000'),
+            (9,  '3210', 'Inventory available',      '3200'),
+            (10, '3220', 'Inventory locked',         '3200'),
+            (11, '4100', 'Fee revenue',              '4000')
+        ) AS v(ord, code, name, parent_code)
+        ORDER BY ord
+    LOOP
+        INSERT INTO coa_nodes (code, name, gl_class, normal_balance, parent_id)
+        SELECT r.code, r.name, p.gl_class, p.normal_balance, p.id
+        FROM coa_nodes p
+        WHERE p.code = r.parent_code;
+    END LOOP;
+END $$;
+
+INSERT INTO coa_account_type_defaults (account_type, coa_node_id)
+SELECT v.account_type, n.id
+FROM (VALUES
+    ('USER_AVAILABLE',      '2110'),
+    ('USER_LOCKED',         '2120'),
+    ('PLATFORM_CLEARING',   '1100'),
+    ('PLATFORM_REVENUE',    '4100'),
+    ('TREASURY_AVAILABLE',  '3110'),
+    ('TREASURY_LOCKED',     '3120'),
+    ('INVENTORY_AVAILABLE', '3210'),
+    ('INVENTORY_LOCKED',    '3220')
+) AS v(account_type, code)
+JOIN coa_nodes n ON n.code = v.code;
+
+ALTER TABLE ledger_accounts ADD COLUMN coa_node_id BIGINT REFERENCES coa_nodes(id);
+
+UPDATE ledger_accounts a
+SET coa_node_id = d.coa_node_id
+FROM coa_account_type_defaults d
+WHERE d.account_type = a.account_type;
+
+ALTER TABLE ledger_accounts ALTER COLUMN coa_node_id SET NOT NULL;
+
+-- Writers that do not name a node get their account type's default, as create_account
+-- does; a type without a default is rejected by name rather than by the NOT NULL.
+CREATE FUNCTION ledger_accounts_default_coa_node() RETURNS trigger AS $$
+BEGIN
+    IF NEW.coa_node_id IS NULL THEN
+        SELECT coa_node_id INTO NEW.coa_node_id
+        FROM coa_account_type_defaults
+        WHERE account_type = NEW.account_type;
+        IF NEW.coa_node_id IS NULL THEN
+            RAISE EXCEPTION 'no default chart-of-accounts node for account type %', NEW.account_type;
+        END IF;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER ledger_accounts_default_coa_node
+    BEFORE INSERT ON ledger_accounts
+    FOR EACH ROW EXECUTE FUNCTION ledger_accounts_default_coa_node();
+
+CREATE INDEX ledger_accounts_coa_node_idx ON ledger_accounts (coa_node_id, asset_id);
+
+-- Posting time, used to bound income statement periods.
+ALTER TABLE journal_transactions ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ NOT NULL DEFAULT now();
+CREATE INDEX IF NOT EXISTS journal_transactions_created_at_idx ON journal_transactions (created_at);
@@ -0,0 +1,13 @@
+-- Structured metadata and tags on journals, and a memo per journal line. Metadata is a
+-- flat JSON object of string values; containment queries (metadata @> '{"order_id":"X"}')
+-- and tag lookups are served by GIN indexes.
+
+ALTER TABLE journal_transactions
+    ADD COLUMN metadata JSONB  NOT NULL DEFAULT '{}'::jsonb CHECK (jsonb_typeof(metadata) = 'object'),
+    ADD COLUMN tags     TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX journal_transactions_metadata_idx ON journal_transactions USING GIN (metadata jsonb_path_ops);
+CREATE INDEX journal_transactions_tags_idx ON journal_transactions USING GIN (tags);
+
+ALTER TABLE journal_lines
+    ADD COLUMN memo TEXT CHECK (memo IS NULL OR char_length(memo) <= 500);
@@ -0,0 +1,13 @@
+-- Value dating: a journal's effective date is kept apart from the time it was posted,
+-- so late settlements can be booked on the day they happened.
+
+ALTER TABLE journal_transactions ADD COLUMN effective_at TIMESTAMPTZ;
+UPDATE journal_transactions SET effective_at = created_at;
+ALTER TABLE journal_transactions
+    ALTER COLUMN effective_at SET NOT NULL,
+    ALTER COLUMN effective_at SET DEFAULT now();
+
+CREATE INDEX journal_transactions_effective_at_idx ON journal_transactions (effective_at);
+
+-- Adjustments carry the requested value date until they are approved and posted.
+ALTER TABLE manual_adjustments ADD COLUMN effective_at TIMESTAMPTZ;
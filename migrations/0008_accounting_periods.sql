@@ -0,0 +1,40 @@
+-- Accounting periods per asset. Journals are placed in a period by effective date;
+-- closed periods reject postings and keep a snapshot of every account's closing balance.
+
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+CREATE TABLE accounting_periods (
+    id                BIGSERIAL PRIMARY KEY,
+    asset_id          SMALLINT    NOT NULL REFERENCES assets(id),
+    name              TEXT        NOT NULL,
+    starts_at         TIMESTAMPTZ NOT NULL,
+    ends_at           TIMESTAMPTZ NOT NULL,
+    status            TEXT        NOT NULL DEFAULT 'OPEN'
+                                  CHECK (status IN ('OPEN', 'SOFT_CLOSED', 'CLOSED')),
+    status_changed_at TIMESTAMPTZ,
+    status_changed_by TEXT,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CHECK (starts_at < ends_at),
+    UNIQUE (asset_id, name),
+    -- periods of one asset never overlap
+    EXCLUDE USING gist (asset_id WITH =, tstzrange(starts_at, ends_at) WITH &&)
+);
+
+CREATE TABLE period_closing_balances (
+    period_id   BIGINT        NOT NULL REFERENCES accounting_periods(id),
+    account_id  BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    -- chart node at close, so later reassignments do not move historic figures
+    coa_node_id BIGINT        NOT NULL REFERENCES coa_nodes(id),
+    balance     NUMERIC(38,0) NOT NULL,
+    PRIMARY KEY (period_id, account_id)
+);
+
+CREATE FUNCTION period_closing_balances_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'period_closing_balances is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER period_closing_balances_no_update_delete
+    BEFORE UPDATE OR DELETE ON period_closing_balances
+    FOR EACH ROW EXECUTE FUNCTION period_closing_balances_append_only();
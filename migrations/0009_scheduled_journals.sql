@@ -0,0 +1,68 @@
+-- Journal templates posted at a future time or on a recurrence. The worker claims due
+-- schedules with SKIP LOCKED; occurrence n posts with external_ref '<external_ref>:<n>'.
+
+CREATE TABLE scheduled_journals (
+    id                   BIGSERIAL PRIMARY KEY,
+    public_id            UUID        NOT NULL UNIQUE,
+    external_ref_type    TEXT        NOT NULL,
+    external_ref         TEXT        NOT NULL,
+    description          TEXT,
+    created_by           TEXT        NOT NULL,
+    metadata             JSONB       NOT NULL DEFAULT '{}'::jsonb,
+    tags                 TEXT[]      NOT NULL DEFAULT '{}',
+    -- the template's lines may span assets, each asset balancing on its own
+    multi_asset          BOOLEAN     NOT NULL DEFAULT false,
+    recurrence           TEXT        NOT NULL CHECK (recurrence IN ('ONCE', 'INTERVAL', 'MONTHLY')),
+    interval_seconds     BIGINT      CHECK (interval_seconds >= 60),
+    starts_at            TIMESTAMPTZ NOT NULL,
+    ends_at              TIMESTAMPTZ,
+    max_occurrences      INTEGER     CHECK (max_occurrences >= 1),
+    status               TEXT        NOT NULL DEFAULT 'ACTIVE'
+                                     CHECK (status IN ('ACTIVE', 'PAUSED', 'COMPLETED', 'CANCELLED')),
+    next_occurrence      INTEGER     NOT NULL DEFAULT 0 CHECK (next_occurrence >= 0),
+    next_run_at          TIMESTAMPTZ,
+    -- when the worker may next claim it: the scheduled time, a retry backoff or a claim lease
+    due_at               TIMESTAMPTZ,
+    posted_count         INTEGER     NOT NULL DEFAULT 0,
+    consecutive_failures INTEGER     NOT NULL DEFAULT 0,
+    last_error           TEXT,
+    status_changed_at    TIMESTAMPTZ,
+    status_changed_by    TEXT,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (external_ref_type, external_ref),
+    CHECK ((recurrence = 'INTERVAL') = (interval_seconds IS NOT NULL)),
+    CHECK (ends_at IS NULL OR starts_at < ends_at)
+);
+
+CREATE INDEX scheduled_journals_due_idx ON scheduled_journals (due_at)
+    WHERE status = 'ACTIVE';
+
+CREATE TABLE scheduled_journal_lines (
+    schedule_id BIGINT        NOT NULL REFERENCES scheduled_journals(id),
+    position    SMALLINT      NOT NULL,
+    account_id  BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    amount      NUMERIC(38,0) NOT NULL CHECK (amount <> 0),
+    memo        TEXT,
+    PRIMARY KEY (schedule_id, position)
+);
+
+-- One row per posted occurrence; the primary key is a second guard against double posting.
+CREATE TABLE scheduled_journal_runs (
+    schedule_id    BIGINT      NOT NULL REFERENCES scheduled_journals(id),
+    occurrence     INTEGER     NOT NULL,
+    scheduled_for  TIMESTAMPTZ NOT NULL,
+    journal_tx_id  BIGINT      NOT NULL REFERENCES journal_transactions(id),
+    posted_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (schedule_id, occurrence)
+);
+
+CREATE FUNCTION scheduled_journal_runs_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'scheduled_journal_runs is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER scheduled_journal_runs_no_update_delete
+    BEFORE UPDATE OR DELETE ON scheduled_journal_runs
+    FOR EACH ROW EXECUTE FUNCTION scheduled_journal_runs_append_only();
@@ -0,0 +1,41 @@
+-- Two-phase journals: a pending journal reserves funds without touching the posted
+-- balance, then is committed (fully or partially), voided or expired.
+
+ALTER TABLE ledger_account_balances
+    ADD COLUMN pending_inflow  NUMERIC(38,0) NOT NULL DEFAULT 0 CHECK (pending_inflow >= 0),
+    ADD COLUMN pending_outflow NUMERIC(38,0) NOT NULL DEFAULT 0 CHECK (pending_outflow >= 0);
+
+CREATE TABLE pending_journals (
+    id                  BIGSERIAL PRIMARY KEY,
+    public_id           UUID          NOT NULL UNIQUE,
+    external_ref_type   TEXT          NOT NULL,
+    external_ref        TEXT          NOT NULL,
+    description         TEXT,
+    created_by          TEXT          NOT NULL,
+    metadata            JSONB         NOT NULL DEFAULT '{}'::jsonb,
+    tags                TEXT[]        NOT NULL DEFAULT '{}',
+    asset_id            SMALLINT      NOT NULL REFERENCES assets(id),
+    status              TEXT          NOT NULL DEFAULT 'PENDING'
+                                      CHECK (status IN ('PENDING', 'COMMITTED', 'VOIDED', 'EXPIRED')),
+    expires_at          TIMESTAMPTZ   NOT NULL,
+    committed_amount    NUMERIC(38,0) CHECK (committed_amount > 0),
+    posted_journal_tx_id BIGINT       REFERENCES journal_transactions(id),
+    status_changed_at   TIMESTAMPTZ,
+    status_changed_by   TEXT,
+    created_at          TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    -- the committed journal posts under the same reference
+    UNIQUE (external_ref_type, external_ref),
+    CHECK (status <> 'COMMITTED' OR (posted_journal_tx_id IS NOT NULL AND committed_amount IS NOT NULL))
+);
+
+CREATE INDEX pending_journals_expiry_idx ON pending_journals (expires_at)
+    WHERE status = 'PENDING';
+
+CREATE TABLE pending_journal_lines (
+    pending_id BIGINT        NOT NULL REFERENCES pending_journals(id),
+    position   SMALLINT      NOT NULL,
+    account_id BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    amount     NUMERIC(38,0) NOT NULL CHECK (amount <> 0),
+    memo       TEXT,
+    PRIMARY KEY (pending_id, position)
+);
@@ -0,0 +1,54 @@
+-- Holds: funds moved from a user's UserAvailable to their UserLocked bucket under an id,
+-- then captured (in full or in part), released or expired. Every step is a journal.
+
+CREATE TABLE holds (
+    id                   BIGSERIAL PRIMARY KEY,
+    public_id            UUID          NOT NULL UNIQUE,
+    owner_id             UUID          NOT NULL,
+    asset_id             SMALLINT      NOT NULL REFERENCES assets(id),
+    available_account_id BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    locked_account_id    BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    amount               NUMERIC(38,0) NOT NULL CHECK (amount > 0),
+    captured_amount      NUMERIC(38,0) NOT NULL DEFAULT 0 CHECK (captured_amount >= 0),
+    released_amount      NUMERIC(38,0) NOT NULL DEFAULT 0 CHECK (released_amount >= 0),
+    status               TEXT          NOT NULL DEFAULT 'ACTIVE'
+                                       CHECK (status IN ('ACTIVE', 'CAPTURED', 'RELEASED', 'EXPIRED')),
+    expires_at           TIMESTAMPTZ,
+    description          TEXT,
+    created_by           TEXT          NOT NULL,
+    journal_count        INTEGER       NOT NULL DEFAULT 0,
+    status_changed_at    TIMESTAMPTZ,
+    status_changed_by    TEXT,
+    created_at           TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    CHECK (captured_amount + released_amount <= amount),
+    CHECK (status = 'ACTIVE' OR captured_amount + released_amount = amount)
+);
+
+CREATE INDEX holds_owner_active_idx ON holds (owner_id, asset_id)
+    WHERE status = 'ACTIVE';
+
+CREATE INDEX holds_expiry_idx ON holds (expires_at)
+    WHERE status = 'ACTIVE' AND expires_at IS NOT NULL;
+
+-- One row per journal posted for a hold, numbered in order.
+CREATE TABLE hold_journals (
+    hold_id                 BIGINT        NOT NULL REFERENCES holds(id),
+    seq                     INTEGER       NOT NULL,
+    action                  TEXT          NOT NULL CHECK (action IN ('PLACE', 'CAPTURE', 'RELEASE', 'EXPIRE')),
+    amount                  NUMERIC(38,0) NOT NULL CHECK (amount > 0),
+    journal_tx_id           BIGINT        NOT NULL UNIQUE REFERENCES journal_transactions(id),
+    counterparty_account_id BIGINT        NOT NULL REFERENCES ledger_accounts(id),
+    actor                   TEXT          NOT NULL,
+    created_at              TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    PRIMARY KEY (hold_id, seq)
+);
+
+CREATE FUNCTION hold_journals_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'hold_journals is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER hold_journals_no_update_delete
+    BEFORE UPDATE OR DELETE ON hold_journals
+    FOR EACH ROW EXECUTE FUNCTION hold_journals_append_only();
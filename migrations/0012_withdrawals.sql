@@ -0,0 +1,27 @@
+-- Withdrawals: REQUESTED -> HELD -> SUBMITTED -> CONFIRMED, or FAILED from any unfinished
+-- state. Funds move through the hold sharing the withdrawal's public id.
+
+CREATE TABLE withdrawals (
+    id                BIGSERIAL PRIMARY KEY,
+    public_id         UUID          NOT NULL UNIQUE,
+    owner_id          UUID          NOT NULL,
+    asset_id          SMALLINT      NOT NULL REFERENCES assets(id),
+    amount            NUMERIC(38,0) NOT NULL CHECK (amount > 0),
+    destination       TEXT          NOT NULL,
+    status            TEXT          NOT NULL DEFAULT 'REQUESTED'
+                                    CHECK (status IN ('REQUESTED', 'HELD', 'SUBMITTED', 'CONFIRMED', 'FAILED')),
+    rail_reference    TEXT          UNIQUE,
+    failure_reason    TEXT,
+    created_by        TEXT          NOT NULL,
+    status_changed_at TIMESTAMPTZ,
+    status_changed_by TEXT,
+    created_at        TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    updated_at        TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    CHECK (status NOT IN ('SUBMITTED', 'CONFIRMED') OR rail_reference IS NOT NULL)
+);
+
+CREATE INDEX withdrawals_owner_idx ON withdrawals (owner_id, created_at);
+
+-- rail polling walks submitted withdrawals
+CREATE INDEX withdrawals_submitted_idx ON withdrawals (updated_at)
+    WHERE status = 'SUBMITTED';
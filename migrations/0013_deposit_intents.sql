@@ -0,0 +1,29 @@
+-- Deposit intents: on-chain deposits detected for a user, PENDING until they reach the
+-- asset's confirmation threshold, then CREDITED from the platform's clearing account or
+-- the treasury to the user's UserAvailable bucket.
+
+CREATE TABLE deposit_intents (
+    id                     BIGSERIAL PRIMARY KEY,
+    public_id              UUID          NOT NULL UNIQUE,
+    owner_id               UUID          NOT NULL,
+    asset_id               SMALLINT      NOT NULL REFERENCES assets(id),
+    amount                 NUMERIC(38,0) NOT NULL CHECK (amount > 0),
+    tx_reference           TEXT          NOT NULL,
+    required_confirmations INTEGER       NOT NULL CHECK (required_confirmations >= 0),
+    source                 TEXT          NOT NULL CHECK (source IN ('PLATFORM_CLEARING', 'TREASURY')),
+    confirmations          INTEGER       NOT NULL DEFAULT 0 CHECK (confirmations >= 0),
+    status                 TEXT          NOT NULL DEFAULT 'PENDING'
+                                         CHECK (status IN ('PENDING', 'CREDITED')),
+    journal_tx_id          BIGINT        UNIQUE REFERENCES journal_transactions(id),
+    created_by             TEXT          NOT NULL,
+    confirmed_at           TIMESTAMPTZ,
+    credited_at            TIMESTAMPTZ,
+    created_at             TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    -- a transfer is detected once per asset, whatever id the watcher sends
+    UNIQUE (asset_id, tx_reference),
+    CHECK ((status = 'CREDITED') = (journal_tx_id IS NOT NULL)),
+    CHECK (status = 'PENDING' OR confirmations >= required_confirmations)
+);
+
+CREATE INDEX deposit_intents_owner_pending_idx ON deposit_intents (owner_id, asset_id)
+    WHERE status = 'PENDING';
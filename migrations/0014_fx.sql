@@ -0,0 +1,47 @@
+-- FX: mid rates per asset pair with validity windows, and quotes that lock a rate less
+-- the pair's spread until they expire. Executing a quote posts two single-asset journals
+-- (sell and buy legs) through the treasury or inventory pool.
+
+CREATE TABLE fx_rates (
+    id             BIGSERIAL PRIMARY KEY,
+    base_asset_id  SMALLINT      NOT NULL REFERENCES assets(id),
+    quote_asset_id SMALLINT      NOT NULL REFERENCES assets(id),
+    rate           NUMERIC(38,18) NOT NULL CHECK (rate > 0),
+    valid_from     TIMESTAMPTZ   NOT NULL,
+    valid_to       TIMESTAMPTZ,
+    source         TEXT          NOT NULL,
+    created_by     TEXT          NOT NULL,
+    created_at     TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    CHECK (base_asset_id <> quote_asset_id),
+    CHECK (valid_to IS NULL OR valid_to > valid_from)
+);
+
+-- rate lookup takes the latest window started at a point in time
+CREATE INDEX fx_rates_pair_idx ON fx_rates (base_asset_id, quote_asset_id, valid_from DESC);
+
+CREATE TABLE fx_quotes (
+    id              BIGSERIAL PRIMARY KEY,
+    public_id       UUID           NOT NULL UNIQUE,
+    owner_id        UUID           NOT NULL,
+    from_asset_id   SMALLINT       NOT NULL REFERENCES assets(id),
+    to_asset_id     SMALLINT       NOT NULL REFERENCES assets(id),
+    rate_id         BIGINT         NOT NULL REFERENCES fx_rates(id),
+    mid_rate        NUMERIC(38,18) NOT NULL CHECK (mid_rate > 0),
+    spread_bps      INTEGER        NOT NULL CHECK (spread_bps >= 0 AND spread_bps < 10000),
+    applied_rate    NUMERIC(38,18) NOT NULL CHECK (applied_rate > 0),
+    from_amount     NUMERIC(38,0)  NOT NULL CHECK (from_amount > 0),
+    to_amount       NUMERIC(38,0)  NOT NULL CHECK (to_amount > 0),
+    liquidity       TEXT           NOT NULL CHECK (liquidity IN ('TREASURY', 'INVENTORY')),
+    expires_at      TIMESTAMPTZ    NOT NULL,
+    status          TEXT           NOT NULL DEFAULT 'OPEN' CHECK (status IN ('OPEN', 'EXECUTED')),
+    sell_journal_id BIGINT         UNIQUE REFERENCES journal_transactions(id),
+    buy_journal_id  BIGINT         UNIQUE REFERENCES journal_transactions(id),
+    created_by      TEXT           NOT NULL,
+    executed_at     TIMESTAMPTZ,
+    executed_by     TEXT,
+    created_at      TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK (from_asset_id <> to_asset_id),
+    CHECK ((status = 'EXECUTED') = (sell_journal_id IS NOT NULL AND buy_journal_id IS NOT NULL))
+);
+
+CREATE INDEX fx_quotes_owner_idx ON fx_quotes (owner_id, created_at);
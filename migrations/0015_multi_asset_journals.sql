@@ -0,0 +1,103 @@
+-- Multi-asset journals: a journal flagged multi_asset may post lines in several assets
+-- in one transaction (a swap's legs), provided each asset's lines balance on their own.
+-- Every line records its asset; the composite key ties it to its account's asset.
+
+ALTER TABLE journal_transactions ADD COLUMN multi_asset BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE ledger_accounts ADD CONSTRAINT ledger_accounts_id_asset_key UNIQUE (id, asset_id);
+
+ALTER TABLE journal_lines ADD COLUMN asset_id SMALLINT;
+
+-- journal_lines is append-only; the backfill is the one sanctioned rewrite.
+ALTER TABLE journal_lines DISABLE TRIGGER USER;
+UPDATE journal_lines l
+SET asset_id = a.asset_id
+FROM ledger_accounts a
+WHERE a.id = l.account_id;
+ALTER TABLE journal_lines ENABLE TRIGGER USER;
+
+ALTER TABLE journal_lines
+    ALTER COLUMN asset_id SET NOT NULL,
+    ADD CONSTRAINT journal_lines_account_asset_fkey
+        FOREIGN KEY (account_id, asset_id) REFERENCES ledger_accounts (id, asset_id);
+
+CREATE INDEX journal_lines_asset_idx ON journal_lines (asset_id, journal_tx_id);
+
+-- Writers that do not name the asset get their account's.
+CREATE FUNCTION journal_lines_default_asset() RETURNS trigger AS $$
+BEGIN
+    IF NEW.asset_id IS NULL THEN
+        SELECT asset_id INTO NEW.asset_id FROM ledger_accounts WHERE id = NEW.account_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER journal_lines_default_asset
+    BEFORE INSERT ON journal_lines
+    FOR EACH ROW EXECUTE FUNCTION journal_lines_default_asset();
+
+-- The commit-time check on each journal's lines rejected any journal spanning assets.
+-- It is replaced by one that allows it for journals flagged multi_asset, whose lines must
+-- then balance per asset.
+DO $$
+DECLARE
+    t record;
+BEGIN
+    FOR t IN
+        SELECT tg.tgname
+        FROM pg_trigger tg
+        JOIN pg_proc p ON p.oid = tg.tgfoid
+        WHERE tg.tgrelid = 'journal_lines'::regclass
+          AND NOT tg.tgisinternal
+          AND p.prosrc LIKE '%spans multiple assets%'
+    LOOP
+        EXECUTE format('DROP TRIGGER %I ON journal_lines', t.tgname);
+    END LOOP;
+END;
+$$;
+
+CREATE FUNCTION journal_lines_check_journal() RETURNS trigger AS $$
+DECLARE
+    v_multi_asset BOOLEAN;
+    v_lines       INTEGER;
+    v_sum         NUMERIC;
+    v_assets      INTEGER;
+    v_unbalanced  SMALLINT;
+BEGIN
+    SELECT multi_asset INTO v_multi_asset FROM journal_transactions WHERE id = NEW.journal_tx_id;
+
+    SELECT COUNT(*), COALESCE(SUM(amount), 0), COUNT(DISTINCT asset_id)
+    INTO v_lines, v_sum, v_assets
+    FROM journal_lines
+    WHERE journal_tx_id = NEW.journal_tx_id;
+
+    IF v_lines < 2 THEN
+        RAISE EXCEPTION 'journal_tx_id=% must have at least 2 lines', NEW.journal_tx_id;
+    END IF;
+    IF v_sum <> 0 THEN
+        RAISE EXCEPTION 'journal_tx_id=% is not balanced', NEW.journal_tx_id;
+    END IF;
+    IF v_assets > 1 AND NOT v_multi_asset THEN
+        RAISE EXCEPTION 'journal_tx_id=% spans multiple assets', NEW.journal_tx_id;
+    END IF;
+
+    SELECT asset_id INTO v_unbalanced
+    FROM journal_lines
+    WHERE journal_tx_id = NEW.journal_tx_id
+    GROUP BY asset_id
+    HAVING SUM(amount) <> 0
+    ORDER BY asset_id
+    LIMIT 1;
+    IF FOUND THEN
+        RAISE EXCEPTION 'journal_tx_id=% is not balanced in asset_id=%', NEW.journal_tx_id, v_unbalanced;
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE CONSTRAINT TRIGGER journal_lines_check_journal
+    AFTER INSERT ON journal_lines
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE FUNCTION journal_lines_check_journal();
@@ -0,0 +1,67 @@
+-- FX revaluation: at a period's end, foreign holdings in the treasury and inventory pools
+-- are valued in the reporting asset at the period's opening and closing rates. The
+-- difference is posted, per foreign asset, between a carrying-adjustment account and an
+-- unrealised FX account in the reporting asset. A period has at most one posted run; a
+-- reversed run frees it to be revalued again.
+
+ALTER TABLE ledger_accounts DROP CONSTRAINT IF EXISTS ledger_accounts_account_type_check;
+ALTER TABLE ledger_accounts ADD CONSTRAINT ledger_accounts_account_type_check CHECK (
+    account_type IN (
+        'USER_AVAILABLE', 'USER_LOCKED',
+        'PLATFORM_CLEARING', 'PLATFORM_REVENUE', 'PLATFORM_UNREALISED_FX',
+        'TREASURY_AVAILABLE', 'TREASURY_LOCKED', 'TREASURY_REVALUATION',
+        'INVENTORY_AVAILABLE', 'INVENTORY_LOCKED'
+    )
+);
+
+INSERT INTO coa_nodes (code, name, gl_class, normal_balance, parent_id)
+SELECT v.code, v.name, p.gl_class, p.normal_balance, p.id
+FROM (VALUES
+    ('3300', 'FX revaluation reserve',  '3000'),
+    ('4200', 'Unrealised FX gain/loss', '4000')
+) AS v(code, name, parent_code)
+JOIN coa_nodes p ON p.code = v.parent_code;
+
+INSERT INTO coa_account_type_defaults (account_type, coa_node_id)
+SELECT v.account_type, n.id
+FROM (VALUES
+    ('TREASURY_REVALUATION',   '3300'),
+    ('PLATFORM_UNREALISED_FX', '4200')
+) AS v(account_type, code)
+JOIN coa_nodes n ON n.code = v.code;
+
+CREATE TABLE fx_revaluations (
+    id                 BIGSERIAL PRIMARY KEY,
+    public_id          UUID        NOT NULL UNIQUE,
+    reporting_asset_id SMALLINT    NOT NULL REFERENCES assets(id),
+    period_id          BIGINT      NOT NULL REFERENCES accounting_periods(id),
+    valued_at          TIMESTAMPTZ NOT NULL,
+    status             TEXT        NOT NULL DEFAULT 'POSTED' CHECK (status IN ('POSTED', 'REVERSED')),
+    created_by         TEXT        NOT NULL,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    reversed_at        TIMESTAMPTZ,
+    reversed_by        TEXT,
+    CHECK ((status = 'REVERSED') = (reversed_at IS NOT NULL))
+);
+
+-- idempotent per period: one posted revaluation at a time
+CREATE UNIQUE INDEX fx_revaluations_posted_period_key ON fx_revaluations (period_id) WHERE status = 'POSTED';
+CREATE INDEX fx_revaluations_period_idx ON fx_revaluations (period_id, created_at);
+
+CREATE TABLE fx_revaluation_lines (
+    revaluation_id      BIGINT         NOT NULL REFERENCES fx_revaluations(id),
+    asset_id            SMALLINT       NOT NULL REFERENCES assets(id),
+    balance             NUMERIC(38,0)  NOT NULL,
+    opening_rate_id     BIGINT         NOT NULL REFERENCES fx_rates(id),
+    opening_rate        NUMERIC(38,18) NOT NULL CHECK (opening_rate > 0),
+    closing_rate_id     BIGINT         NOT NULL REFERENCES fx_rates(id),
+    closing_rate        NUMERIC(38,18) NOT NULL CHECK (closing_rate > 0),
+    opening_value       NUMERIC(38,0)  NOT NULL,
+    closing_value       NUMERIC(38,0)  NOT NULL,
+    gain                NUMERIC(38,0)  NOT NULL,
+    journal_id          BIGINT         UNIQUE REFERENCES journal_transactions(id),
+    reversal_journal_id BIGINT         UNIQUE REFERENCES journal_transactions(id),
+    PRIMARY KEY (revaluation_id, asset_id),
+    CHECK (gain = closing_value - opening_value),
+    CHECK ((gain = 0) = (journal_id IS NULL))
+);
@@ -0,0 +1,28 @@
+-- Proof of reserves: per-asset snapshots of every owner's liability (UserAvailable plus
+-- UserLocked) committed to by a Merkle sum tree over salted owner hashes. The root hash
+-- and total are published; leaves are kept so owners can be given inclusion proofs.
+
+CREATE TABLE reserve_snapshots (
+    id          BIGSERIAL PRIMARY KEY,
+    public_id   UUID          NOT NULL UNIQUE,
+    asset_id    SMALLINT      NOT NULL REFERENCES assets(id),
+    taken_at    TIMESTAMPTZ   NOT NULL,
+    salt        BYTEA         NOT NULL,
+    root_hash   BYTEA         NOT NULL CHECK (octet_length(root_hash) = 32),
+    total       NUMERIC(38,0) NOT NULL CHECK (total >= 0),
+    leaf_count  INTEGER       NOT NULL CHECK (leaf_count >= 0),
+    created_by  TEXT          NOT NULL,
+    created_at  TIMESTAMPTZ   NOT NULL DEFAULT now()
+);
+
+CREATE INDEX reserve_snapshots_asset_idx ON reserve_snapshots (asset_id, taken_at DESC);
+
+CREATE TABLE reserve_snapshot_leaves (
+    snapshot_id BIGINT        NOT NULL REFERENCES reserve_snapshots(id),
+    position    INTEGER       NOT NULL CHECK (position >= 0),
+    owner_id    UUID          NOT NULL,
+    owner_hash  BYTEA         NOT NULL CHECK (octet_length(owner_hash) = 32),
+    balance     NUMERIC(38,0) NOT NULL CHECK (balance >= 0),
+    PRIMARY KEY (snapshot_id, position),
+    UNIQUE (snapshot_id, owner_id)
+);
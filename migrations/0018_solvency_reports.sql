@@ -0,0 +1,35 @@
+-- Solvency reports: per-asset user liabilities (UserAvailable plus UserLocked) against
+-- TreasuryAvailable, TreasuryLocked and PlatformClearing holdings. Every run, on demand
+-- or scheduled, is kept for history.
+--
+-- Pool accounts are credited as money arrives (a deposit debits the user and credits its
+-- source), so they carry negative balances while they hold funds: holdings are the
+-- negated sum of the pool buckets. Both sides are read from the ledger, so a report is an
+-- internal consistency check, not an attestation of reserves held off the books.
+
+CREATE TABLE solvency_reports (
+    id                  BIGSERIAL PRIMARY KEY,
+    public_id           UUID          NOT NULL UNIQUE,
+    asset_id            SMALLINT      NOT NULL REFERENCES assets(id),
+    as_of               TIMESTAMPTZ   NOT NULL,
+    trigger             TEXT          NOT NULL CHECK (trigger IN ('ON_DEMAND', 'SCHEDULED')),
+    status              TEXT          NOT NULL CHECK (status IN ('COVERED', 'SHORTFALL')),
+    user_available      NUMERIC(38,0) NOT NULL,
+    user_locked         NUMERIC(38,0) NOT NULL,
+    treasury_available  NUMERIC(38,0) NOT NULL,
+    treasury_locked     NUMERIC(38,0) NOT NULL,
+    platform_clearing   NUMERIC(38,0) NOT NULL,
+    liabilities         NUMERIC(38,0) NOT NULL,
+    holdings            NUMERIC(38,0) NOT NULL,
+    coverage_bps        NUMERIC(38,0),
+    requested_by        TEXT          NOT NULL,
+    alerted_at          TIMESTAMPTZ,
+    created_at          TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    CHECK (liabilities = user_available + user_locked),
+    CONSTRAINT solvency_reports_holdings_check
+        CHECK (holdings = -(treasury_available + treasury_locked + platform_clearing)),
+    CHECK ((status = 'SHORTFALL') = (holdings < liabilities)),
+    CHECK (alerted_at IS NULL OR status = 'SHORTFALL')
+);
+
+CREATE INDEX solvency_reports_asset_idx ON solvency_reports (asset_id, as_of DESC);
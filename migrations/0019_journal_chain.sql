@@ -0,0 +1,39 @@
+-- Tamper-evident journal log: every posted journal gets a link in one ledger-wide hash
+-- chain. Each link hashes the journal's canonical content together with the previous
+-- link's hash, so altering, removing or reordering a journal breaks every later link.
+-- The head row is locked by each posting, which orders links by commit.
+--
+-- Journals posted before the chain existed are linked by the backfill, oldest first.
+-- Postings are refused until it has caught up, so history is chained ahead of anything
+-- new and chain order stays posting order.
+
+CREATE TABLE journal_chain (
+    position       BIGINT      PRIMARY KEY CHECK (position >= 1),
+    journal_tx_id  BIGINT      NOT NULL UNIQUE REFERENCES journal_transactions(id),
+    prev_hash      BYTEA       NOT NULL CHECK (octet_length(prev_hash) = 32),
+    entry_hash     BYTEA       NOT NULL CHECK (octet_length(entry_hash) = 32),
+    chained_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE journal_chain_head (
+    id          BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    position    BIGINT  NOT NULL CHECK (position >= 0),
+    entry_hash  BYTEA   NOT NULL CHECK (octet_length(entry_hash) = 32),
+    -- false while journals posted before the chain are still unlinked
+    backfilled  BOOLEAN NOT NULL
+);
+
+-- position 0 is the genesis: nothing chained yet, all-zero hash
+INSERT INTO journal_chain_head (id, position, entry_hash, backfilled)
+SELECT TRUE, 0, decode(repeat('00', 32), 'hex'),
+       NOT EXISTS (SELECT 1 FROM journal_lines);
+
+CREATE FUNCTION journal_chain_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'journal_chain is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER journal_chain_no_update_delete
+    BEFORE UPDATE OR DELETE ON journal_chain
+    FOR EACH ROW EXECUTE FUNCTION journal_chain_append_only();
@@ -0,0 +1,38 @@
+-- Gapless ledger sequence: every posted journal takes the next number from a single
+-- counter row inside its posting transaction. A rolled-back posting rolls the counter
+-- back with it, and the row lock orders numbers by commit, so the committed journals
+-- are always exactly 1..N.
+
+ALTER TABLE journal_transactions ADD COLUMN ledger_seq BIGINT CHECK (ledger_seq >= 1);
+
+-- existing journals take their place in posting order
+UPDATE journal_transactions t
+SET ledger_seq = s.seq
+FROM (
+    SELECT j.id, ROW_NUMBER() OVER (ORDER BY j.created_at, j.id) AS seq
+    FROM journal_transactions j
+    WHERE EXISTS (SELECT 1 FROM journal_lines l WHERE l.journal_tx_id = j.id)
+) s
+WHERE t.id = s.id;
+
+CREATE UNIQUE INDEX journal_transactions_ledger_seq_idx ON journal_transactions (ledger_seq);
+
+CREATE TABLE ledger_sequence (
+    id        BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    last_seq  BIGINT  NOT NULL CHECK (last_seq >= 0)
+);
+
+INSERT INTO ledger_sequence (id, last_seq)
+SELECT TRUE, COALESCE(MAX(ledger_seq), 0) FROM journal_transactions;
+
+CREATE FUNCTION journal_ledger_seq_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'ledger_seq of a posted journal cannot change';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER journal_ledger_seq_no_change
+    BEFORE UPDATE OF ledger_seq ON journal_transactions
+    FOR EACH ROW
+    WHEN (OLD.ledger_seq IS NOT NULL AND NEW.ledger_seq IS DISTINCT FROM OLD.ledger_seq)
+    EXECUTE FUNCTION journal_ledger_seq_immutable();
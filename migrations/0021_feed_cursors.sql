@@ -0,0 +1,9 @@
+-- Change feed cursors: each named downstream consumer keeps the last ledger sequence it
+-- has acknowledged, so it resumes reading posted journals right after it.
+
+CREATE TABLE feed_cursors (
+    consumer    TEXT        PRIMARY KEY CHECK (consumer ~ '^[a-z0-9_.-]{1,64}$'),
+    position    BIGINT      NOT NULL DEFAULT 0 CHECK (position >= 0),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
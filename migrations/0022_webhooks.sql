@@ -0,0 +1,64 @@
+-- Outbound webhooks: partners subscribe per owner and event type. Deliveries are
+-- enqueued from the change feed, so only committed journals produce events, and are
+-- retried with backoff until delivered or dead-lettered. Every attempt is logged.
+
+CREATE TABLE webhook_subscriptions (
+    id           BIGSERIAL PRIMARY KEY,
+    public_id    UUID        NOT NULL UNIQUE,
+    owner_id     UUID        NOT NULL,
+    url          TEXT        NOT NULL CHECK (url ~ '^https?://'),
+    -- kept readable: every delivery is signed with it
+    secret       TEXT        NOT NULL CHECK (length(secret) >= 16),
+    event_types  TEXT[]      NOT NULL CHECK (
+        cardinality(event_types) > 0 AND event_types <@ ARRAY['journal.posted', 'balance.changed']
+    ),
+    active       BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_by   TEXT        NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX webhook_subscriptions_owner_idx ON webhook_subscriptions (owner_id);
+
+CREATE TABLE webhook_deliveries (
+    id               BIGSERIAL PRIMARY KEY,
+    public_id        UUID        NOT NULL UNIQUE,
+    subscription_id  BIGINT      NOT NULL REFERENCES webhook_subscriptions(id),
+    -- stable per event and subscription; sent to the receiver for deduplication
+    event_id         TEXT        NOT NULL,
+    event_type       TEXT        NOT NULL CHECK (event_type IN ('journal.posted', 'balance.changed')),
+    payload          TEXT        NOT NULL,
+    status           TEXT        NOT NULL CHECK (status IN ('PENDING', 'DELIVERED', 'DEAD_LETTERED')),
+    attempts         INT         NOT NULL DEFAULT 0 CHECK (attempts >= 0),
+    failures         INT         NOT NULL DEFAULT 0 CHECK (failures >= 0 AND failures <= attempts),
+    next_attempt_at  TIMESTAMPTZ NOT NULL,
+    last_error       TEXT,
+    delivered_at     TIMESTAMPTZ,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (subscription_id, event_id),
+    CHECK ((status = 'DELIVERED') = (delivered_at IS NOT NULL))
+);
+
+CREATE INDEX webhook_deliveries_due_idx ON webhook_deliveries (next_attempt_at) WHERE status = 'PENDING';
+CREATE INDEX webhook_deliveries_dead_idx ON webhook_deliveries (updated_at DESC) WHERE status = 'DEAD_LETTERED';
+
+CREATE TABLE webhook_delivery_attempts (
+    delivery_id   BIGINT      NOT NULL REFERENCES webhook_deliveries(id),
+    attempt       INT         NOT NULL CHECK (attempt >= 1),
+    attempted_at  TIMESTAMPTZ NOT NULL,
+    status_code   INT,
+    error         TEXT,
+    PRIMARY KEY (delivery_id, attempt),
+    CHECK (status_code IS NOT NULL OR error IS NOT NULL)
+);
+
+CREATE FUNCTION webhook_delivery_attempts_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'webhook_delivery_attempts is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER webhook_delivery_attempts_no_update_delete
+    BEFORE UPDATE OR DELETE ON webhook_delivery_attempts
+    FOR EACH ROW EXECUTE FUNCTION webhook_delivery_attempts_append_only();
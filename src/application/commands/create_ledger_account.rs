@@ -9,4 +9,5 @@ pub struct CreateLedgerAccountCommand {
     pub account_type: AccountType,
     pub asset_id: i16,
     pub is_active: bool,
+    pub coa_node_id: Option<i64>,
 }
@@ -1,5 +1,9 @@
+use crate::domain::entities::StatusReasonCode;
+
 #[derive(Debug, Clone)]
 pub struct SetLedgerAccountActiveCommand {
     pub account_id: i64,
     pub is_active: bool,
+    pub reason: StatusReasonCode,
+    pub actor: String,
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::application::dtos::{AccountStatusChangeDTO, ChangeAccountStatusDTO, LedgerAccountDTO};
+use crate::application::AppError;
+
+#[async_trait]
+pub trait AccountStatusService: Send + Sync {
+    async fn change_status(&self, req: ChangeAccountStatusDTO) -> Result<LedgerAccountDTO, AppError>;
+
+    /// Every recorded status and active-flag change for the account, oldest first.
+    async fn status_timeline(&self, account_id: i64) -> Result<Vec<AccountStatusChangeDTO>, AppError>;
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::application::dtos::{AccountingPeriodDTO, BalanceSheetDTO, ClosingBalanceDTO, DefinePeriodDTO};
+use crate::application::AppError;
+
+#[async_trait]
+pub trait AccountingPeriodService: Send + Sync {
+    /// Custom period; must not overlap another period of the asset.
+    async fn define_period(&self, req: DefinePeriodDTO) -> Result<AccountingPeriodDTO, AppError>;
+
+    /// Calendar month in UTC, named `YYYY-MM`.
+    async fn define_month(&self, asset_id: i16, year: i32, month: u32) -> Result<AccountingPeriodDTO, AppError>;
+
+    async fn list_periods(&self, asset_id: i16) -> Result<Vec<AccountingPeriodDTO>, AppError>;
+
+    /// Stops ordinary postings; adjusting journals are still accepted.
+    async fn soft_close(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError>;
+
+    async fn reopen(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError>;
+
+    /// Locks the period for good and snapshots every account's closing balance.
+    async fn close(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError>;
+
+    async fn closing_balances(&self, period_id: i64) -> Result<Vec<ClosingBalanceDTO>, AppError>;
+
+    /// Balance sheet at the period end, built from the closing snapshot.
+    async fn closing_balance_sheet(&self, period_id: i64) -> Result<BalanceSheetDTO, AppError>;
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Channel the ledger notifies on, in the posting transaction, after applying balance deltas.
+pub const BALANCE_CHANGED_CHANNEL: &str = "ledger_balance_changed";
+
+/// An account's balance after a posting moved it, published once the posting commits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub account_id: i64,
+    /// `USER`, `PLATFORM` or `TREASURY`.
+    pub owner_type: String,
+    /// Hyphenated owner uuid; absent for pool accounts.
+    pub owner_id: Option<String>,
+    pub asset_id: i16,
+    /// The running balance after the journal, not the amount it moved.
+    pub balance_minor: i128,
+    pub journal_id: i64,
+}
+
+impl BalanceChange {
+    pub fn is_owned_by(&self, owner_id: Uuid) -> bool {
+        self.owner_id.as_deref().and_then(|s| Uuid::parse_str(s).ok()) == Some(owner_id)
+    }
+}
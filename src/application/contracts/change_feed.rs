@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use crate::application::dtos::{CreateFeedConsumerDTO, FeedBatchDTO, FeedCursorDTO};
+use crate::application::AppError;
+
+/// Posted journals in ledger sequence order, for downstream systems to follow instead of
+/// polling the ledger tables. Reads long-poll: with nothing new they wait up to `wait`
+/// for the next posting before returning an empty batch.
+#[async_trait]
+pub trait ChangeFeedService: Send + Sync {
+    /// Journals after `after_sequence`, for consumers that keep their own cursor.
+    async fn read(&self, after_sequence: i64, limit: usize, wait: Duration) -> Result<FeedBatchDTO, AppError>;
+
+    /// Registers a named consumer whose cursor is kept in the ledger. Registering an
+    /// existing consumer returns its cursor unchanged.
+    async fn create_consumer(&self, req: CreateFeedConsumerDTO) -> Result<FeedCursorDTO, AppError>;
+
+    async fn find_consumer(&self, consumer: String) -> Result<Option<FeedCursorDTO>, AppError>;
+
+    async fn list_consumers(&self, limit: usize, offset: usize) -> Result<Vec<FeedCursorDTO>, AppError>;
+
+    /// Journals after the consumer's cursor. Reading does not move the cursor, so a
+    /// consumer that fails mid-batch reads the same journals again.
+    async fn read_consumer(&self, consumer: String, limit: usize, wait: Duration) -> Result<FeedBatchDTO, AppError>;
+
+    /// Moves the consumer's cursor forward to `sequence` once everything up to it is handled.
+    async fn acknowledge(&self, consumer: String, sequence: i64) -> Result<FeedCursorDTO, AppError>;
+}
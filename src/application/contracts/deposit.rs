@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use crate::application::dtos::{DepositDTO, DetectDepositDTO, PendingDepositsDTO};
+use crate::application::AppError;
+
+/// On-chain deposits are recorded as pending when detected and credited to the owner's
+/// `UserAvailable` bucket once they reach their asset's confirmation threshold. The
+/// credit is posted at most once per deposit, however often updates are replayed.
+#[async_trait]
+pub trait DepositService: Send + Sync {
+    /// Records a detected deposit, crediting it straight away if it already has enough
+    /// confirmations. Detecting a transfer already recorded returns that deposit; the same
+    /// id or transfer with a different owner, asset or amount is rejected.
+    async fn detect(&self, req: DetectDepositDTO) -> Result<DepositDTO, AppError>;
+
+    /// Records the chain's latest confirmation count for a deposit and credits it once
+    /// the count reaches the threshold.
+    async fn update_confirmations(&self, public_id: String, confirmations: u32, actor: String) -> Result<DepositDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<DepositDTO>, AppError>;
+
+    /// The owner's pending deposits in `asset_id` and their total.
+    async fn pending(&self, owner_id: String, asset_id: i16) -> Result<PendingDepositsDTO, AppError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use crate::application::dtos::{FxConversionDTO, FxQuoteDTO, FxRateDTO, RequestFxQuoteDTO, SetFxRateDTO};
+use crate::application::AppError;
+
+/// Exchange rates per asset pair and conversions between a user's assets. A conversion
+/// is priced by a quote that locks the rate for a short time; executing it posts two
+/// linked single-asset journals through the treasury or inventory pool, each balanced
+/// in its own asset and carrying the applied rate and spread.
+#[async_trait]
+pub trait FxRateService: Send + Sync {
+    /// Records a rate for the pair over its validity window. Where windows overlap, the
+    /// one that started last applies.
+    async fn set_rate(&self, req: SetFxRateDTO) -> Result<FxRateDTO, AppError>;
+
+    /// The rate in force for the pair now.
+    async fn current_rate(&self, base_asset_code: String, quote_asset_code: String) -> Result<Option<FxRateDTO>, AppError>;
+
+    /// The pair's most recently started rates, newest first.
+    async fn rates(&self, base_asset_code: String, quote_asset_code: String, limit: usize) -> Result<Vec<FxRateDTO>, AppError>;
+
+    /// Prices a conversion at the current rate less the pair's spread and locks it until
+    /// the quote expires. Requesting the same quote id again returns the original; the
+    /// same id with different terms is rejected.
+    async fn quote(&self, req: RequestFxQuoteDTO) -> Result<FxQuoteDTO, AppError>;
+
+    /// Executes an open quote before it expires. Executing it again returns the journals
+    /// already posted.
+    async fn execute(&self, quote_id: String, actor: String) -> Result<FxConversionDTO, AppError>;
+
+    async fn find_quote(&self, quote_id: String) -> Result<Option<FxQuoteDTO>, AppError>;
+}
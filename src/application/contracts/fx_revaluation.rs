@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use crate::application::dtos::{FxRevaluationDTO, FxRevaluationRunDTO, RunFxRevaluationDTO};
+use crate::application::AppError;
+
+/// Period-end revaluation of foreign holdings in the treasury and inventory pools. Each
+/// foreign asset's net pool balance at the period's end is valued in the reporting asset
+/// at the rates in force when the period opened and when it ended; the difference is
+/// posted as an unrealised gain or loss against a revaluation reserve, dated on the
+/// period's last instant. The rates used are kept with the run.
+#[async_trait]
+pub trait FxRevaluationService: Send + Sync {
+    /// Computes the revaluation without posting or storing anything.
+    async fn preview(&self, req: RunFxRevaluationDTO) -> Result<FxRevaluationDTO, AppError>;
+
+    /// Posts the period's revaluation. A period is revalued once: while a posted run
+    /// exists, running again returns it and its journals.
+    async fn run(&self, req: RunFxRevaluationDTO) -> Result<FxRevaluationRunDTO, AppError>;
+
+    /// Posts mirror journals for a posted run in the same period, after which the period
+    /// may be revalued again. Reversing again returns the reversal already posted.
+    async fn reverse(&self, revaluation_id: String, actor: String) -> Result<FxRevaluationRunDTO, AppError>;
+
+    async fn find(&self, revaluation_id: String) -> Result<Option<FxRevaluationDTO>, AppError>;
+
+    /// Every run for the period, newest first.
+    async fn history(&self, period_id: i64) -> Result<Vec<FxRevaluationDTO>, AppError>;
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use crate::application::dtos::{HoldDTO, HoldExpiryReportDTO, HoldJournalDTO, PlaceHoldDTO};
+use crate::application::AppError;
+
+/// Holds on a user's funds. Placing, capturing, releasing and expiring each post one
+/// journal between the user's `UserAvailable` and `UserLocked` buckets (or the capture
+/// destination), tagged with the hold id.
+#[async_trait]
+pub trait HoldService: Send + Sync {
+    async fn place(&self, req: PlaceHoldDTO) -> Result<HoldDTO, AppError>;
+
+    /// Captures `amount_minor` (the whole remainder when `None`) into `destination_account_id`.
+    /// The hold stays active until it is fully captured, released or expires.
+    async fn capture(
+        &self,
+        public_id: String,
+        amount_minor: Option<i128>,
+        destination_account_id: i64,
+        actor: String,
+    ) -> Result<HoldDTO, AppError>;
+
+    /// Returns the remainder to `UserAvailable`.
+    async fn release(&self, public_id: String, actor: String) -> Result<HoldDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<HoldDTO>, AppError>;
+
+    /// The journals posted for the hold, in order.
+    async fn journals(&self, public_id: String) -> Result<Vec<HoldJournalDTO>, AppError>;
+
+    async fn list_active(&self, owner_id: String, asset_id: Option<i16>) -> Result<Vec<HoldDTO>, AppError>;
+
+    /// Releases up to `batch_size` holds past their deadline.
+    async fn expire_due(&self, batch_size: usize) -> Result<HoldExpiryReportDTO, AppError>;
+}
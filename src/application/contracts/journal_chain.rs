@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use crate::application::dtos::{ChainHeadDTO, ChainVerificationDTO};
+use crate::application::AppError;
+
+/// The tamper-evident journal log: every posted journal is linked into one hash chain,
+/// each link hashing the journal's content with the previous link's hash.
+#[async_trait]
+pub trait JournalChainService: Send + Sync {
+    async fn head(&self) -> Result<ChainHeadDTO, AppError>;
+
+    /// Walks the chain from the first link to the current head, `batch_size` links at a
+    /// time, and reports the first broken link if any.
+    async fn verify(&self, batch_size: usize) -> Result<ChainVerificationDTO, AppError>;
+
+    /// Links up to `batch_size` journals posted before the chain existed. Returns how
+    /// many were linked; 0 once every journal is on the chain. Postings are refused
+    /// until the backfill has run to the end.
+    async fn backfill(&self, batch_size: usize) -> Result<usize, AppError>;
+}
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use crate::application::dtos::{CreateAccountDTO, LedgerAccountDTO, PostJournalRequestDTO, PostedJournalDTO};
+use chrono::{DateTime, Utc};
+use crate::application::dtos::{
+    AccountBalanceDTO, CreateAccountDTO, LedgerAccountDTO, ListJournalsFilterDTO, PostJournalRequestDTO, PostedJournalDTO,
+};
 use crate::application::AppError;
 #[async_trait]
 pub trait LedgerService: Send + Sync {
@@ -12,4 +15,29 @@ pub trait LedgerService: Send + Sync {
         external_ref_type: String,
         external_ref: String,
     ) -> Result<Option<PostedJournalDTO>, AppError>;
-}
\ No newline at end of file
+
+    /// Posted journals filtered by reference type, author, metadata pairs and tags.
+    async fn list_journals(&self, filter: ListJournalsFilterDTO) -> Result<Vec<PostedJournalDTO>, AppError>;
+
+    /// An account's balance just before `at`; `basis` is `"POSTED"` or `"EFFECTIVE"`.
+    async fn balance_as_of(
+        &self,
+        account_id: i64,
+        at: DateTime<Utc>,
+        basis: String,
+    ) -> Result<AccountBalanceDTO, AppError>;
+
+    /// The highest committed ledger sequence; every journal up to it is visible.
+    async fn latest_sequence(&self) -> Result<i64, AppError>;
+
+    /// Journals numbered after `after_sequence`, oldest first. Passing the last
+    /// sequence seen pages through the ledger without gaps or repeats.
+    async fn journals_after_sequence(
+        &self,
+        after_sequence: i64,
+        limit: usize,
+    ) -> Result<Vec<PostedJournalDTO>, AppError>;
+
+    /// An account's balance as it stood once journal `sequence` was posted.
+    async fn balance_as_of_sequence(&self, account_id: i64, sequence: i64) -> Result<AccountBalanceDTO, AppError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use crate::application::dtos::{AdjustmentAuditEntryDTO, ManualAdjustmentDTO, PostJournalRequestDTO};
+use crate::application::AppError;
+
+/// Maker-checker workflow for `MANUAL_ADJUSTMENT` journals: the maker submits a draft,
+/// distinct checkers approve or reject it, and it is posted once enough approvals exist.
+#[async_trait]
+pub trait ManualAdjustmentService: Send + Sync {
+    async fn submit(&self, req: PostJournalRequestDTO) -> Result<ManualAdjustmentDTO, AppError>;
+
+    async fn approve(&self, public_id: String, approver: String) -> Result<ManualAdjustmentDTO, AppError>;
+
+    async fn reject(
+        &self,
+        public_id: String,
+        approver: String,
+        reason: String,
+    ) -> Result<ManualAdjustmentDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<ManualAdjustmentDTO>, AppError>;
+
+    async fn audit_trail(&self, public_id: String) -> Result<Vec<AdjustmentAuditEntryDTO>, AppError>;
+}
@@ -1,3 +1,45 @@
 pub mod repository;
 mod ledger;
-pub use ledger::LedgerService;
\ No newline at end of file
+pub use ledger::LedgerService;
+mod manual_adjustment;
+pub use manual_adjustment::ManualAdjustmentService;
+mod account_status;
+pub use account_status::AccountStatusService;
+mod reporting;
+pub use reporting::ReportingService;
+mod accounting_period;
+pub use accounting_period::AccountingPeriodService;
+mod scheduler;
+pub use scheduler::JournalSchedulerService;
+mod pending_journal;
+pub use pending_journal::PendingJournalService;
+mod hold;
+pub use hold::HoldService;
+mod withdrawal;
+pub use withdrawal::WithdrawalService;
+mod deposit;
+pub use deposit::DepositService;
+mod transfer;
+pub use transfer::TransferService;
+mod fx_rate;
+pub use fx_rate::FxRateService;
+mod payout_rail;
+pub use payout_rail::{PayoutInstruction, PayoutRail, PayoutRailError, PayoutStatus};
+mod fx_revaluation;
+pub use fx_revaluation::FxRevaluationService;
+mod proof_of_reserves;
+pub use proof_of_reserves::ProofOfReservesService;
+mod solvency_alert;
+pub use solvency_alert::{SolvencyAlert, SolvencyAlertError, SolvencyAlertSink};
+mod solvency;
+pub use solvency::SolvencyService;
+mod journal_chain;
+pub use journal_chain::JournalChainService;
+mod balance_change;
+pub use balance_change::{BalanceChange, BALANCE_CHANGED_CHANNEL};
+mod change_feed;
+pub use change_feed::ChangeFeedService;
+mod webhook_transport;
+pub use webhook_transport::{WebhookRequest, WebhookTransport, WebhookTransportError};
+mod webhook;
+pub use webhook::WebhookService;
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::value_objects::PublicId;
+
+/// What the rail is asked to pay out.
+#[derive(Debug, Clone)]
+pub struct PayoutInstruction {
+    /// Idempotency key on the rail: resubmitting the same withdrawal never pays twice.
+    pub withdrawal_id: PublicId,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    pub destination: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutStatus {
+    Pending,
+    Confirmed,
+    Failed { reason: String },
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PayoutRailError {
+    /// The rail refused the payout; it will not go through.
+    #[error("payout rejected: {reason}")]
+    Rejected { reason: String },
+
+    /// The rail could not be reached or answered ambiguously; safe to retry.
+    #[error("payout rail unavailable: {message}")]
+    Unavailable { message: String },
+
+    #[error("unknown payout reference: {reference}")]
+    UnknownReference { reference: String },
+}
+
+/// External payout rail (bank transfer, on-chain broadcast, ...).
+#[async_trait]
+pub trait PayoutRail: Send + Sync {
+    /// Hands the payout to the rail and returns its reference. Submitting the same
+    /// withdrawal again returns the original reference.
+    async fn submit(&self, instruction: &PayoutInstruction) -> Result<String, PayoutRailError>;
+
+    async fn status(&self, rail_reference: &str) -> Result<PayoutStatus, PayoutRailError>;
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use crate::application::dtos::{AuthorizePendingDTO, AvailableBalanceDTO, PendingJournalDTO};
+use crate::application::AppError;
+
+/// Two-phase journals: authorizing reserves funds, committing posts the journal
+/// (in full or, for two-line journals, partially), voiding or expiry releases it.
+#[async_trait]
+pub trait PendingJournalService: Send + Sync {
+    /// Replaying the same external ref returns the stored pending journal.
+    async fn authorize(&self, req: AuthorizePendingDTO) -> Result<PendingJournalDTO, AppError>;
+
+    /// Posts `amount_minor`, or the full authorized amount when `None`, and releases
+    /// the rest. Committing again returns the committed journal unchanged.
+    async fn commit(
+        &self,
+        public_id: String,
+        amount_minor: Option<i128>,
+        actor: String,
+    ) -> Result<PendingJournalDTO, AppError>;
+
+    async fn void(&self, public_id: String, actor: String) -> Result<PendingJournalDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<PendingJournalDTO>, AppError>;
+
+    /// Expires up to `batch_size` pending journals past their deadline; returns how many.
+    async fn expire_due(&self, batch_size: usize) -> Result<usize, AppError>;
+
+    async fn available_balance(&self, account_id: i64) -> Result<AvailableBalanceDTO, AppError>;
+}
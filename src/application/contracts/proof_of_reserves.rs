@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use crate::application::dtos::{InclusionProofDTO, ReserveSnapshotDTO, TakeReserveSnapshotDTO};
+use crate::application::AppError;
+
+/// Proof of reserves for user liabilities. A snapshot takes every owner's available plus
+/// locked balance in an asset and commits to them with a Merkle sum tree over salted
+/// owner hashes; its root hash and total are what gets published. Owners fetch an
+/// inclusion proof for their own leaf and can check it offline with
+/// `domain::services::verify_owner_inclusion`, or through [`verify_proof`].
+///
+/// [`verify_proof`]: ProofOfReservesService::verify_proof
+#[async_trait]
+pub trait ProofOfReservesService: Send + Sync {
+    /// Snapshots the asset's user liabilities. Taking the same snapshot id again returns
+    /// the snapshot already taken.
+    async fn take_snapshot(&self, req: TakeReserveSnapshotDTO) -> Result<ReserveSnapshotDTO, AppError>;
+
+    async fn find_snapshot(&self, snapshot_id: String) -> Result<Option<ReserveSnapshotDTO>, AppError>;
+
+    /// The asset's most recent published snapshot.
+    async fn latest_snapshot(&self, asset_code: String) -> Result<Option<ReserveSnapshotDTO>, AppError>;
+
+    /// The owner's proof against the snapshot, or `None` when they had no balance in it.
+    async fn inclusion_proof(&self, snapshot_id: String, owner_id: String) -> Result<Option<InclusionProofDTO>, AppError>;
+
+    /// Checks a proof against the root and total it names; touches no stored state.
+    fn verify_proof(&self, proof: &InclusionProofDTO) -> Result<(), AppError>;
+}
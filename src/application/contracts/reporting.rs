@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::application::dtos::{
+    BalanceSheetDTO, CoaNodeDTO, FinancialStatementsDTO, IncomeStatementDTO, ReportExportDTO,
+};
+use crate::application::AppError;
+
+#[async_trait]
+pub trait ReportingService: Send + Sync {
+    async fn chart_of_accounts(&self) -> Result<Vec<CoaNodeDTO>, AppError>;
+
+    /// Moves an account to another chart node; the node must be in the GL class the
+    /// account type reports under.
+    async fn assign_account_node(&self, account_id: i64, coa_node_id: i64) -> Result<(), AppError>;
+
+    /// Balance sheet for one asset from current running balances.
+    async fn balance_sheet(&self, asset_id: i16) -> Result<BalanceSheetDTO, AppError>;
+
+    /// Balance sheet as it stood just before `at`; `basis` is `"POSTED"` or `"EFFECTIVE"`.
+    async fn balance_sheet_as_of(
+        &self,
+        asset_id: i16,
+        at: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<BalanceSheetDTO, AppError>;
+
+    /// Income statement for one asset from journal lines dated in `[from, to)` on `basis`.
+    async fn income_statement(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<IncomeStatementDTO, AppError>;
+
+    /// Balance sheet as of `to`, income statement and movement summary over `[from, to)`.
+    async fn financial_statements(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<FinancialStatementsDTO, AppError>;
+
+    /// `financial_statements` rendered as `"json"` or `"csv"`.
+    async fn export_financial_statements(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+        format: &str,
+    ) -> Result<ReportExportDTO, AppError>;
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::entities::{AccountStatusChange, AccountStatusTimeline, LedgerAccount};
+use crate::domain::repository::RepoError;
+
+#[async_trait]
+pub trait AccountStatusRepository: Send + Sync {
+    /// Locks the account and its running balance row; returns the account with its balance.
+    async fn lock_account_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        account_id: i64,
+    ) -> Result<Option<(LedgerAccount, i128)>, RepoError>;
+
+    /// Updates the account and appends the change to its status history.
+    async fn save_status_change_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        change: &AccountStatusChange,
+    ) -> Result<(), RepoError>;
+
+    async fn status_timeline(&self, account_id: i64) -> Result<AccountStatusTimeline, RepoError>;
+
+    async fn status_timelines_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        account_ids: &[i64],
+    ) -> Result<HashMap<i64, AccountStatusTimeline>, RepoError>;
+}
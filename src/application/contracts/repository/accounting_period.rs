@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::entities::{AccountingPeriod, NewAccountingPeriod};
+use crate::domain::repository::RepoError;
+
+#[async_trait]
+pub trait AccountingPeriodRepository: Send + Sync {
+    /// Fails with `Conflict` if the period overlaps another of the same asset.
+    async fn insert(&self, spec: &NewAccountingPeriod) -> Result<AccountingPeriod, RepoError>;
+
+    async fn find(&self, period_id: i64) -> Result<Option<AccountingPeriod>, RepoError>;
+
+    /// Periods of one asset, earliest first.
+    async fn list(&self, asset_id: i16) -> Result<Vec<AccountingPeriod>, RepoError>;
+
+    /// Loads the period and holds a row lock until the transaction ends. Postings dated
+    /// into the period hold a share lock on the same row, so a close waits for them.
+    async fn lock_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period_id: i64,
+    ) -> Result<Option<AccountingPeriod>, RepoError>;
+
+    async fn save_status_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period: &AccountingPeriod,
+    ) -> Result<(), RepoError>;
+
+    /// Records every account's balance from journals effective before the period end.
+    async fn snapshot_closing_balances_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period: &AccountingPeriod,
+    ) -> Result<u64, RepoError>;
+
+    /// Snapshotted closing balances by account id; empty until the period is closed.
+    async fn closing_balances(&self, period_id: i64) -> Result<HashMap<i64, i128>, RepoError>;
+
+    /// Snapshotted closing balances summed per chart-of-accounts node.
+    async fn closing_balances_by_node(&self, period_id: i64) -> Result<HashMap<i64, i128>, RepoError>;
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::ChartOfAccounts;
+use crate::domain::services::{BalanceBasis, NodeFlows};
+use crate::domain::repository::RepoError;
+
+#[async_trait]
+pub trait ChartOfAccountsRepository: Send + Sync {
+    async fn load_chart(&self) -> Result<ChartOfAccounts, RepoError>;
+
+    /// Running balances of the asset's ledger accounts, summed per chart node.
+    async fn balances_by_node(&self, asset_id: i16) -> Result<HashMap<i64, i128>, RepoError>;
+
+    /// Balances as they stood just before `at` on the given basis, rebuilt from journal
+    /// lines, per chart node.
+    async fn balances_by_node_as_of(
+        &self,
+        asset_id: i16,
+        at: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, i128>, RepoError>;
+
+    /// Net journal line amounts of the asset's ledger accounts dated in `[from, to)` on the
+    /// given basis, per chart node.
+    async fn movements_by_node(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, i128>, RepoError>;
+
+    /// Like `movements_by_node`, with positive and negative lines summed separately.
+    async fn flows_by_node(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, NodeFlows>, RepoError>;
+
+    async fn assign_account(&self, account_id: i64, coa_node_id: i64) -> Result<(), RepoError>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::aggregate::{DepositIntent, NewDepositIntent};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait DepositRepository: Send + Sync {
+    /// Stores the detected deposit. A deposit already recorded for the same transfer
+    /// (asset and tx reference) is returned instead, whatever its public id.
+    async fn insert(&self, spec: &NewDepositIntent) -> Result<DepositIntent, RepoError>;
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<DepositIntent>, RepoError>;
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<DepositIntent>, RepoError>;
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, deposit: &DepositIntent) -> Result<(), RepoError>;
+
+    /// The owner's pending deposits, oldest first, optionally for one asset.
+    async fn list_pending(&self, owner_id: Uuid, asset_id: Option<i16>) -> Result<Vec<DepositIntent>, RepoError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::repository::RepoError;
+use crate::domain::services::FeeSchedule;
+use crate::domain::value_objects::ExternalRefType;
+
+#[async_trait]
+pub trait FeeScheduleRepository: Send + Sync {
+    /// Active fee rules for the asset and posting kind; empty schedule when none are configured.
+    async fn load_schedule(
+        &self,
+        asset_id: i16,
+        posting_kind: ExternalRefType,
+    ) -> Result<FeeSchedule, RepoError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::aggregate::FeedCursor;
+use crate::domain::repository::RepoError;
+
+#[async_trait]
+pub trait FeedCursorRepository: Send + Sync {
+    /// Stores a new cursor; an existing cursor for the same consumer is returned instead.
+    async fn insert(&self, cursor: &FeedCursor) -> Result<FeedCursor, RepoError>;
+
+    async fn find(&self, consumer: &str) -> Result<Option<FeedCursor>, RepoError>;
+
+    /// Consumers by name.
+    async fn list(&self, limit: usize, offset: usize) -> Result<Vec<FeedCursor>, RepoError>;
+
+    /// Saves an advanced cursor. The stored position only moves forward, so two
+    /// acknowledgements racing each other cannot move it back.
+    async fn save_position(&self, cursor: &FeedCursor) -> Result<FeedCursor, RepoError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{FxQuote, FxRate, NewFxQuote, NewFxRate};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait FxRepository: Send + Sync {
+    async fn insert_rate(&self, spec: &NewFxRate) -> Result<FxRate, RepoError>;
+
+    /// The rate for the pair valid at `at`; of overlapping windows, the one started last.
+    async fn rate_at(&self, base_asset_id: i16, quote_asset_id: i16, at: DateTime<Utc>) -> Result<Option<FxRate>, RepoError>;
+
+    /// The pair's most recently started rates, newest first.
+    async fn list_rates(&self, base_asset_id: i16, quote_asset_id: i16, limit: usize) -> Result<Vec<FxRate>, RepoError>;
+
+    /// Stores the quote; an existing quote with the same public id is returned instead.
+    async fn insert_quote(&self, spec: &NewFxQuote) -> Result<FxQuote, RepoError>;
+
+    async fn find_quote(&self, public_id: PublicId) -> Result<Option<FxQuote>, RepoError>;
+
+    async fn lock_quote_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<FxQuote>, RepoError>;
+
+    async fn save_quote_tx(&self, tx: &mut Transaction<'_, Postgres>, quote: &FxQuote) -> Result<(), RepoError>;
+}
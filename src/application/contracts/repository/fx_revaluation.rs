@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{FxRevaluation, NewFxRevaluation, RevaluationPosition};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait FxRevaluationRepository: Send + Sync {
+    /// Non-zero net balances per foreign asset across the revalued pool accounts, from
+    /// journals effective before `at`.
+    async fn positions_at(&self, reporting_asset_id: i16, at: DateTime<Utc>) -> Result<Vec<RevaluationPosition>, RepoError>;
+
+    /// Stores the revaluation with its lines. Returns `None` when the period already has
+    /// a posted revaluation.
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewFxRevaluation,
+    ) -> Result<Option<FxRevaluation>, RepoError>;
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<FxRevaluation>, RepoError>;
+
+    async fn find_posted_for_period(&self, period_id: i64) -> Result<Option<FxRevaluation>, RepoError>;
+
+    /// Every revaluation of the period, newest first.
+    async fn list_for_period(&self, period_id: i64) -> Result<Vec<FxRevaluation>, RepoError>;
+
+    async fn lock_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<FxRevaluation>, RepoError>;
+
+    /// Persists the status and the reversal journals of each line.
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, run: &FxRevaluation) -> Result<(), RepoError>;
+}
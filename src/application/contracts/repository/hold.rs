@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::aggregate::{Hold, HoldJournal, NewHold};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait HoldRepository: Send + Sync {
+    /// Stores a new hold counting its placing journal; `None` when a hold with this
+    /// public id already exists.
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewHold,
+    ) -> Result<Option<Hold>, RepoError>;
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<Hold>, RepoError>;
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<Hold>, RepoError>;
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, hold: &Hold) -> Result<(), RepoError>;
+
+    /// Links a posted journal to the hold.
+    async fn record_journal_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        hold: &Hold,
+        journal: &HoldJournal,
+    ) -> Result<(), RepoError>;
+
+    /// A user's active holds, oldest first, optionally for one asset.
+    async fn list_active(&self, owner_id: Uuid, asset_id: Option<i16>) -> Result<Vec<Hold>, RepoError>;
+
+    /// Up to `limit` active holds whose deadline is at or before `now`.
+    async fn find_expired(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<PublicId>, RepoError>;
+
+    async fn journals(&self, public_id: PublicId) -> Result<Vec<HoldJournal>, RepoError>;
+}
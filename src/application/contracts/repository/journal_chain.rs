@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::aggregate::PostedJournal;
+use crate::domain::repository::RepoError;
+use crate::domain::services::{ChainHead, ChainLink};
+
+/// Reads the ledger-wide journal hash chain. Links are appended by the posting itself,
+/// inside `insert_posting_atomic_tx`.
+#[async_trait]
+pub trait JournalChainRepository: Send + Sync {
+    async fn chain_head(&self) -> Result<ChainHead, RepoError>;
+
+    /// Up to `limit` links in `(after_position, up_to_position]`, in position order, each
+    /// with its journal (`None` when the journal row is gone).
+    async fn chain_links(
+        &self,
+        after_position: i64,
+        up_to_position: i64,
+        limit: usize,
+    ) -> Result<Vec<(ChainLink, Option<PostedJournal>)>, RepoError>;
+
+    /// Appends up to `limit` journals posted before the chain existed, in ledger sequence
+    /// order. Postings are refused until a call finds fewer than `limit` left. Returns how
+    /// many were chained.
+    async fn chain_unlinked(&self, limit: usize) -> Result<usize, RepoError>;
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregate::PostedJournal;
+use crate::domain::repository::RepoError;
+use crate::domain::services::BalanceBasis;
+use crate::domain::value_objects::{ExternalRefType, JournalMetadata, JournalTag};
+
+/// Conditions a posted journal must all meet; unset/empty fields match everything.
+#[derive(Debug, Clone)]
+pub struct JournalFilter {
+    pub external_ref_type: Option<ExternalRefType>,
+    pub created_by: Option<String>,
+    /// Journals whose metadata contains every one of these pairs.
+    pub metadata: JournalMetadata,
+    /// Journals carrying all of these tags.
+    pub tags: Vec<JournalTag>,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+#[async_trait]
+pub trait JournalQueryRepository: Send + Sync {
+    /// Posted journals matching the filter, newest first.
+    async fn list_journals(&self, filter: &JournalFilter) -> Result<Vec<PostedJournal>, RepoError>;
+
+    /// An account's balance from journals dated strictly before `at` on the given basis.
+    async fn balance_as_of(
+        &self,
+        account_id: i64,
+        at: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<i128, RepoError>;
+
+    /// The highest ledger sequence committed so far; 0 before anything is posted.
+    /// Every sequence up to it is committed too, so it is a consistent cursor.
+    async fn latest_sequence(&self) -> Result<i64, RepoError>;
+
+    /// Up to `limit` journals numbered after `after`, in sequence order.
+    async fn journals_after_sequence(&self, after: i64, limit: usize) -> Result<Vec<PostedJournal>, RepoError>;
+
+    /// An account's balance from the journals numbered up to and including `sequence`.
+    async fn balance_as_of_sequence(&self, account_id: i64, sequence: i64) -> Result<i128, RepoError>;
+}
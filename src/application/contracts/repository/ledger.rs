@@ -2,13 +2,17 @@ use async_trait::async_trait;
 use sqlx::{Postgres, Transaction};
 
 use crate::domain::aggregate::{PostedJournal, ValidatedJournal};
+use crate::domain::error::DomainError;
 use crate::domain::repository::RepoError;
 
 #[async_trait]
 pub trait LedgerRepositoryTx: Send + Sync {
+    /// Posts `posting` inside `tx`. A journal the ledger rules reject under lock (a closed
+    /// accounting period, an account status on a backdated value date) comes back as the
+    /// inner `DomainError` and leaves nothing written.
      async fn insert_posting_atomic_tx(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         posting: ValidatedJournal,
-    ) -> Result<PostedJournal, RepoError>;
+    ) -> Result<Result<PostedJournal, DomainError>, RepoError>;
 }
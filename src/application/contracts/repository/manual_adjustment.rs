@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{AdjustmentAuditEntry, ManualAdjustment, NewManualAdjustment};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait ManualAdjustmentRepository: Send + Sync {
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<ManualAdjustment>, RepoError>;
+
+    /// Every recorded transition, oldest first.
+    async fn audit_trail(&self, public_id: PublicId) -> Result<Vec<AdjustmentAuditEntry>, RepoError>;
+
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewManualAdjustment,
+    ) -> Result<ManualAdjustment, RepoError>;
+
+    /// Loads the adjustment and holds a row lock until the transaction ends.
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<ManualAdjustment>, RepoError>;
+
+    /// Persists status, approvals and any uncommitted audit entries.
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        adjustment: &mut ManualAdjustment,
+    ) -> Result<(), RepoError>;
+}
@@ -3,3 +3,39 @@ mod uow;
 pub use uow::{UnitOfWork, BoxFut};
 mod ledger;
 pub use ledger::LedgerRepositoryTx;
+mod fee_schedule;
+pub use fee_schedule::FeeScheduleRepository;
+mod manual_adjustment;
+pub use manual_adjustment::ManualAdjustmentRepository;
+mod account_status;
+pub use account_status::AccountStatusRepository;
+mod chart_of_accounts;
+pub use chart_of_accounts::ChartOfAccountsRepository;
+mod journal_query;
+pub use journal_query::{JournalFilter, JournalQueryRepository};
+mod accounting_period;
+pub use accounting_period::AccountingPeriodRepository;
+mod scheduled_journal;
+pub use scheduled_journal::ScheduledJournalRepository;
+mod pending_journal;
+pub use pending_journal::PendingJournalRepository;
+mod hold;
+pub use hold::HoldRepository;
+mod withdrawal;
+pub use withdrawal::WithdrawalRepository;
+mod deposit;
+pub use deposit::DepositRepository;
+mod fx;
+pub use fx::FxRepository;
+mod fx_revaluation;
+pub use fx_revaluation::FxRevaluationRepository;
+mod reserve_snapshot;
+pub use reserve_snapshot::ReserveSnapshotRepository;
+mod solvency_report;
+pub use solvency_report::SolvencyReportRepository;
+mod journal_chain;
+pub use journal_chain::JournalChainRepository;
+mod feed_cursor;
+pub use feed_cursor::FeedCursorRepository;
+mod webhook;
+pub use webhook::WebhookRepository;
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{AvailableBalance, NewPendingJournal, PendingJournal};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait PendingJournalRepository: Send + Sync {
+    /// Stores the pending journal and reserves its lines on the account balances, under the
+    /// same account and balance locks as a posting. Replaying an external ref returns the
+    /// stored pending journal without reserving again; a ref that is already posted conflicts.
+    async fn authorize_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewPendingJournal,
+    ) -> Result<PendingJournal, RepoError>;
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<PendingJournal>, RepoError>;
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<PendingJournal>, RepoError>;
+
+    /// Locks up to `limit` pending journals whose deadline is at or before `now`,
+    /// skipping rows held elsewhere.
+    async fn lock_expired_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingJournal>, RepoError>;
+
+    /// Gives back what the pending journal reserves on its accounts.
+    async fn release_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pending: &PendingJournal,
+    ) -> Result<(), RepoError>;
+
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pending: &PendingJournal,
+    ) -> Result<(), RepoError>;
+
+    async fn available_balance(&self, account_id: i64) -> Result<Option<AvailableBalance>, RepoError>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::aggregate::{NewReserveSnapshot, OwnerLiability, ReserveSnapshot};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait ReserveSnapshotRepository: Send + Sync {
+    /// Every user owner's available plus locked balance in the asset, read in one
+    /// statement so the figures are mutually consistent.
+    async fn owner_liabilities(&self, asset_id: i16) -> Result<Vec<OwnerLiability>, RepoError>;
+
+    /// Stores the snapshot with its leaves; an existing snapshot with the same public id
+    /// is returned instead.
+    async fn insert(&self, spec: &NewReserveSnapshot) -> Result<ReserveSnapshot, RepoError>;
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<ReserveSnapshot>, RepoError>;
+
+    /// The asset's most recent snapshot.
+    async fn latest(&self, asset_id: i16) -> Result<Option<ReserveSnapshot>, RepoError>;
+}
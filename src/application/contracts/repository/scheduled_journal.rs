@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{NewScheduledJournal, ScheduleStatus, ScheduledJournal, ScheduledJournalRun};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait ScheduledJournalRepository: Send + Sync {
+    async fn insert(&self, spec: &NewScheduledJournal) -> Result<ScheduledJournal, RepoError>;
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<ScheduledJournal>, RepoError>;
+
+    /// Schedules by status (all when `None`), next due first.
+    async fn list(
+        &self,
+        status: Option<ScheduleStatus>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<ScheduledJournal>, RepoError>;
+
+    /// Leases up to `limit` due active schedules by pushing their `due_at` out by `lease`.
+    /// Rows locked by another worker are skipped; a lease that expires before the
+    /// occurrence is posted makes the schedule claimable again.
+    async fn claim_due(&self, limit: usize, lease: Duration) -> Result<Vec<PublicId>, RepoError>;
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<ScheduledJournal>, RepoError>;
+
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        schedule: &ScheduledJournal,
+    ) -> Result<(), RepoError>;
+
+    /// Records the schedule's pending occurrence as posted by `journal_id`.
+    async fn record_run_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        schedule: &ScheduledJournal,
+        journal_id: i64,
+    ) -> Result<(), RepoError>;
+
+    /// Posted occurrences, oldest first.
+    async fn runs(&self, public_id: PublicId) -> Result<Vec<ScheduledJournalRun>, RepoError>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregate::{NewSolvencyReport, SolvencyBalances, SolvencyReport};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait SolvencyReportRepository: Send + Sync {
+    /// Totals of the user, treasury and clearing buckets in the asset, read in one
+    /// statement so liabilities and holdings are taken at the same instant.
+    async fn balances(&self, asset_id: i16) -> Result<SolvencyBalances, RepoError>;
+
+    /// Assets a scheduled run reports on.
+    async fn active_asset_ids(&self) -> Result<Vec<i16>, RepoError>;
+
+    /// Stores the report; an existing report with the same public id is returned instead.
+    async fn insert(&self, spec: &NewSolvencyReport) -> Result<SolvencyReport, RepoError>;
+
+    async fn mark_alerted(&self, report_id: i64, at: DateTime<Utc>) -> Result<(), RepoError>;
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<SolvencyReport>, RepoError>;
+
+    /// The asset's reports, newest first.
+    async fn history(&self, asset_id: i16, limit: usize, offset: usize) -> Result<Vec<SolvencyReport>, RepoError>;
+}
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::pin::Pin;
 use sqlx::{Postgres, Transaction};
+use crate::domain::error::DomainError;
 use crate::domain::repository::RepoError;
 
 pub type BoxFut<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
@@ -12,4 +13,13 @@ pub trait UnitOfWork: Send + Sync {
         + Send
         + 'u,
     ) -> BoxFut<'u, Result<T, RepoError>>;
+
+    /// Like `with_tx`, but a domain rejection rolls back too, so writes made before the
+    /// rejection (a reservation release, an earlier journal leg) do not commit without it.
+    fn with_domain_tx<'u, T: Send + 'u>(
+        &'u self,
+        f: impl for<'a> FnOnce(&'a mut Transaction<'_, Postgres>) -> BoxFut<'a, Result<Result<T, DomainError>, RepoError>>
+        + Send
+        + 'u,
+    ) -> BoxFut<'u, Result<Result<T, DomainError>, RepoError>>;
 }
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::aggregate::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookAttempt, WebhookDelivery, WebhookSubscription,
+};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Stores the subscription; an existing one with the same public id is returned instead.
+    async fn insert_subscription(&self, spec: &NewWebhookSubscription) -> Result<WebhookSubscription, RepoError>;
+
+    async fn find_subscription(&self, public_id: PublicId) -> Result<Option<WebhookSubscription>, RepoError>;
+
+    async fn subscription_by_id(&self, id: i64) -> Result<Option<WebhookSubscription>, RepoError>;
+
+    /// The owner's subscriptions, active or not, oldest first.
+    async fn subscriptions_for_owner(&self, owner_id: Uuid) -> Result<Vec<WebhookSubscription>, RepoError>;
+
+    /// Active subscriptions of any of the owners.
+    async fn active_subscriptions(&self, owner_ids: &[Uuid]) -> Result<Vec<WebhookSubscription>, RepoError>;
+
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), RepoError>;
+
+    /// Enqueues the deliveries, skipping events already enqueued for their subscription.
+    /// Returns how many were new.
+    async fn enqueue(&self, deliveries: &[NewWebhookDelivery]) -> Result<usize, RepoError>;
+
+    /// Leases up to `limit` pending deliveries that are due by pushing their next attempt
+    /// out by `lease`. Rows locked by another worker are skipped; a lease that runs out
+    /// before the attempt is recorded makes the delivery claimable again.
+    async fn claim_due(&self, limit: usize, lease: Duration) -> Result<Vec<WebhookDelivery>, RepoError>;
+
+    /// Saves a pending delivery's new state and logs `attempt`, if one was made, together.
+    /// Fails with `Conflict` if another worker recorded an attempt first.
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: Option<&WebhookAttempt>,
+    ) -> Result<(), RepoError>;
+
+    async fn find_delivery(&self, public_id: PublicId) -> Result<Option<WebhookDelivery>, RepoError>;
+
+    /// The delivery log, first attempt first.
+    async fn attempts(&self, delivery_id: i64) -> Result<Vec<WebhookAttempt>, RepoError>;
+
+    /// The dead-letter queue, most recently dead-lettered first.
+    async fn dead_letters(&self, limit: usize, offset: usize) -> Result<Vec<WebhookDelivery>, RepoError>;
+
+    /// Saves a redriven delivery; fails with `Conflict` unless it was still dead-lettered.
+    async fn requeue(&self, delivery: &WebhookDelivery) -> Result<(), RepoError>;
+}
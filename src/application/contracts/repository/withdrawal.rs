@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::domain::aggregate::{NewWithdrawal, Withdrawal, WithdrawalStatus};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+
+#[async_trait]
+pub trait WithdrawalRepository: Send + Sync {
+    /// Stores the request; an existing withdrawal with the same public id is returned instead.
+    async fn insert(&self, spec: &NewWithdrawal) -> Result<Withdrawal, RepoError>;
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<Withdrawal>, RepoError>;
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<Withdrawal>, RepoError>;
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, withdrawal: &Withdrawal) -> Result<(), RepoError>;
+
+    /// Up to `limit` withdrawals in `status`, least recently updated first.
+    async fn list_by_status(&self, status: WithdrawalStatus, limit: usize) -> Result<Vec<Withdrawal>, RepoError>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use crate::application::dtos::{
+    CreateScheduleDTO, ScheduledJournalDTO, ScheduledJournalRunDTO, SchedulerRunReportDTO,
+};
+use crate::application::AppError;
+
+/// Journals posted at a future time or on a recurrence. Each occurrence goes through
+/// the normal atomic posting path with a deterministic external ref.
+#[async_trait]
+pub trait JournalSchedulerService: Send + Sync {
+    async fn create_schedule(&self, req: CreateScheduleDTO) -> Result<ScheduledJournalDTO, AppError>;
+
+    async fn find_schedule(&self, public_id: String) -> Result<Option<ScheduledJournalDTO>, AppError>;
+
+    /// `status` filters by `ACTIVE`, `PAUSED`, `COMPLETED` or `CANCELLED`.
+    async fn list_schedules(
+        &self,
+        status: Option<String>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<ScheduledJournalDTO>, AppError>;
+
+    async fn pause(&self, public_id: String, actor: String) -> Result<ScheduledJournalDTO, AppError>;
+
+    /// Missed occurrences post in order unless `skip_missed` drops them.
+    async fn resume(
+        &self,
+        public_id: String,
+        actor: String,
+        skip_missed: bool,
+    ) -> Result<ScheduledJournalDTO, AppError>;
+
+    async fn cancel(&self, public_id: String, actor: String) -> Result<ScheduledJournalDTO, AppError>;
+
+    async fn runs(&self, public_id: String) -> Result<Vec<ScheduledJournalRunDTO>, AppError>;
+
+    /// Claims up to `batch_size` due schedules and posts one occurrence of each.
+    async fn run_due(&self, batch_size: usize) -> Result<SchedulerRunReportDTO, AppError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use crate::application::dtos::{RunSolvencyReportDTO, SolvencyReportDTO, SolvencyRunReportDTO};
+use crate::application::AppError;
+
+/// Per-asset solvency: user liabilities (`UserAvailable` plus `UserLocked`) against the
+/// `TreasuryAvailable`, `TreasuryLocked` and `PlatformClearing` holdings backing them.
+/// Those pools are credited as money arrives, so holdings are their negated balance.
+/// Both sides are ledger balances, so this checks the books against themselves rather than
+/// against reserves held outside them. Every run is stored; a shortfall raises an alert.
+#[async_trait]
+pub trait SolvencyService: Send + Sync {
+    /// Runs the report for one asset now. Running it again with the same public id
+    /// returns the stored report.
+    async fn run(&self, req: RunSolvencyReportDTO) -> Result<SolvencyReportDTO, AppError>;
+
+    /// Runs the report for every active asset, as the scheduled job does.
+    async fn run_scheduled(&self) -> Result<SolvencyRunReportDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<SolvencyReportDTO>, AppError>;
+
+    /// The asset's stored reports, newest first.
+    async fn history(&self, asset_code: String, limit: usize, offset: usize) -> Result<Vec<SolvencyReportDTO>, AppError>;
+}
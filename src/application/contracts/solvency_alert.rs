@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::domain::value_objects::PublicId;
+
+/// Raised when a solvency report finds holdings short of user liabilities.
+#[derive(Debug, Clone)]
+pub struct SolvencyAlert {
+    /// The report that found the shortfall; raising it again is a no-op on the sink.
+    pub report_id: PublicId,
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    pub liabilities_minor: i128,
+    pub holdings_minor: i128,
+    pub shortfall_minor: i128,
+    pub coverage_bps: Option<i128>,
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum SolvencyAlertError {
+    /// The alert could not be delivered; the report stays unalerted and can be retried.
+    #[error("solvency alert not delivered: {message}")]
+    Undelivered { message: String },
+}
+
+/// Where shortfall alerts go (pager, chat channel, ...).
+#[async_trait]
+pub trait SolvencyAlertSink: Send + Sync {
+    async fn raise(&self, alert: &SolvencyAlert) -> Result<(), SolvencyAlertError>;
+}
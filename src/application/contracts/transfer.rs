@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::application::dtos::{PostedJournalDTO, TransferRequestDTO};
+use crate::application::AppError;
+
+/// User-to-user transfers addressed by owner and asset code; the `UserAvailable`
+/// accounts on both sides are resolved here.
+#[async_trait]
+pub trait TransferService: Send + Sync {
+    /// Posts one balanced `TRANSFER_INTENT` journal, with the sender's fee lines when
+    /// requested. A replayed idempotency key returns the original journal unchanged.
+    async fn transfer(&self, req: TransferRequestDTO) -> Result<PostedJournalDTO, AppError>;
+
+    async fn find(&self, idempotency_key: String) -> Result<Option<PostedJournalDTO>, AppError>;
+}
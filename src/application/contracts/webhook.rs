@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use crate::application::dtos::{
+    CreateWebhookSubscriptionDTO, WebhookDeliveryDTO, WebhookDeliveryRunDTO, WebhookDispatchReportDTO,
+    WebhookSubscriptionDTO,
+};
+use crate::application::AppError;
+
+/// Signed HTTP callbacks to partners when money moves on accounts of an owner they
+/// subscribed for. Events come from the change feed, so only committed journals fire.
+/// Delivery is at least once: receivers deduplicate on the event id.
+#[async_trait]
+pub trait WebhookService: Send + Sync {
+    async fn create_subscription(&self, req: CreateWebhookSubscriptionDTO) -> Result<WebhookSubscriptionDTO, AppError>;
+
+    async fn find_subscription(&self, public_id: String) -> Result<Option<WebhookSubscriptionDTO>, AppError>;
+
+    async fn list_subscriptions(&self, owner_id: String) -> Result<Vec<WebhookSubscriptionDTO>, AppError>;
+
+    /// Stops new deliveries; pending ones are dead-lettered when they come due.
+    async fn deactivate_subscription(&self, public_id: String) -> Result<WebhookSubscriptionDTO, AppError>;
+
+    /// Enqueues deliveries for up to `batch_size` journals committed since the last call.
+    async fn dispatch_committed(&self, batch_size: usize) -> Result<WebhookDispatchReportDTO, AppError>;
+
+    /// Attempts up to `limit` due deliveries, retrying failures with backoff.
+    async fn deliver_due(&self, limit: usize) -> Result<WebhookDeliveryRunDTO, AppError>;
+
+    /// The delivery with its log of attempts.
+    async fn find_delivery(&self, public_id: String) -> Result<Option<WebhookDeliveryDTO>, AppError>;
+
+    /// The dead-letter queue, most recent first.
+    async fn dead_letters(&self, limit: usize, offset: usize) -> Result<Vec<WebhookDeliveryDTO>, AppError>;
+
+    /// Puts a dead-lettered delivery back for a fresh round of retries.
+    async fn redrive(&self, public_id: String) -> Result<WebhookDeliveryDTO, AppError>;
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// A signed delivery, ready to be posted.
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    /// Same on every retry of the event; receivers deduplicate on it.
+    pub event_id: String,
+    pub event_type: String,
+    /// Unix seconds, covered by the signature.
+    pub timestamp: i64,
+    /// `v1=<hex HMAC-SHA256>` over `<timestamp>.<body>`.
+    pub signature: String,
+    pub body: String,
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum WebhookTransportError {
+    /// No HTTP answer (connection refused, timeout, ...); the attempt counts as failed.
+    #[error("webhook endpoint unreachable: {message}")]
+    Unreachable { message: String },
+}
+
+/// Posts deliveries to partner endpoints.
+#[async_trait]
+pub trait WebhookTransport: Send + Sync {
+    /// Returns the endpoint's HTTP status, whatever it is.
+    async fn post(&self, request: &WebhookRequest) -> Result<u16, WebhookTransportError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use crate::application::dtos::{RequestWithdrawalDTO, WithdrawalDTO, WithdrawalSyncReportDTO};
+use crate::application::AppError;
+
+/// Withdrawals move REQUESTED -> HELD -> SUBMITTED -> CONFIRMED, or to FAILED. Each step
+/// posts its journal through the withdrawal's hold in the same transaction, and
+/// repeating a step the withdrawal already took returns it unchanged.
+#[async_trait]
+pub trait WithdrawalService: Send + Sync {
+    /// Records the request and holds its funds. A hold refused for lack of funds or an
+    /// account restriction fails the withdrawal; other errors leave it requested and the
+    /// same request can be replayed.
+    async fn request(&self, req: RequestWithdrawalDTO) -> Result<WithdrawalDTO, AppError>;
+
+    /// Hands a held withdrawal to the payout rail. A rejection fails it and releases the hold.
+    async fn submit(&self, public_id: String, actor: String) -> Result<WithdrawalDTO, AppError>;
+
+    /// Captures the hold to `PlatformClearing`.
+    async fn confirm(&self, public_id: String, actor: String) -> Result<WithdrawalDTO, AppError>;
+
+    /// Releases any hold back to `UserAvailable`.
+    async fn fail(&self, public_id: String, reason: String, actor: String) -> Result<WithdrawalDTO, AppError>;
+
+    /// Applies the rail's answer for a submitted withdrawal.
+    async fn refresh(&self, public_id: String) -> Result<WithdrawalDTO, AppError>;
+
+    async fn find(&self, public_id: String) -> Result<Option<WithdrawalDTO>, AppError>;
+
+    /// Refreshes up to `batch_size` submitted withdrawals.
+    async fn sync_submitted(&self, batch_size: usize) -> Result<WithdrawalSyncReportDTO, AppError>;
+}
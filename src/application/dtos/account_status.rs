@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeAccountStatusDTO {
+    pub account_id: i64,
+    pub status: String,
+    pub reason_code: String,
+    pub note: Option<String>,
+    pub actor: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStatusChangeDTO {
+    pub account_id: i64,
+    pub from_status: String,
+    pub to_status: String,
+    pub was_active: bool,
+    pub is_active: bool,
+    pub reason_code: String,
+    pub note: Option<String>,
+    pub actor: String,
+    pub changed_at: DateTime<Utc>,
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefinePeriodDTO {
+    pub asset_id: i16,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountingPeriodDTO {
+    pub id: i64,
+    pub asset_id: i16,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: String,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosingBalanceDTO {
+    pub period_id: i64,
+    pub account_id: i64,
+    pub balance_minor: i128,
+}
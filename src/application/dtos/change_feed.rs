@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::application::dtos::PostedJournalDTO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateFeedConsumerDTO {
+    /// `a-z`, `0-9`, `_`, `-` and `.`, up to 64 characters.
+    pub consumer: String,
+    /// Where the consumer starts reading: 0 for the first journal ever posted, or the
+    /// latest sequence to take only what is posted from now on.
+    pub start_after_sequence: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedCursorDTO {
+    pub consumer: String,
+    /// Last acknowledged ledger sequence.
+    pub position: i64,
+    /// Journals posted but not yet acknowledged, as of `latest_sequence`.
+    pub lag: i64,
+    pub latest_sequence: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Journals read from the feed, oldest first. Empty when the wait ran out before
+/// anything was posted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedBatchDTO {
+    pub after_sequence: i64,
+    pub journals: Vec<PostedJournalDTO>,
+    /// Sequence of the last journal in the batch (`after_sequence` when empty): read
+    /// after it next, or acknowledge it once the batch is handled.
+    pub next_cursor: i64,
+    pub latest_sequence: i64,
+}
+
+impl FeedBatchDTO {
+    /// The batch as server-sent events: one `journal` event per journal with its sequence
+    /// as the event id, so a reconnecting client's `Last-Event-ID` is its cursor. An empty
+    /// batch is a keep-alive comment.
+    pub fn to_sse(&self) -> Result<String, serde_json::Error> {
+        if self.journals.is_empty() {
+            return Ok(": keep-alive\n\n".to_string());
+        }
+        let mut out = String::new();
+        for j in &self.journals {
+            out.push_str(&format!("id: {}\nevent: journal\ndata: {}\n\n", j.sequence, serde_json::to_string(j)?));
+        }
+        Ok(out)
+    }
+}
@@ -6,4 +6,6 @@ pub struct CreateAccountDTO {
     pub account_type: String,
     pub asset_id: i16,
     pub is_active: bool,
+    #[serde(default)]
+    pub coa_node_id: Option<i64>,
 }
\ No newline at end of file
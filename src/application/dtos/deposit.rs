@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectDepositDTO {
+    /// Deposit id chosen by the chain watcher. Detecting the same transfer again returns
+    /// the deposit already recorded for it.
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    /// The on-chain transfer, for example `<tx hash>:<output index>`.
+    pub tx_reference: String,
+    #[serde(default)]
+    pub confirmations: u32,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepositDTO {
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    pub tx_reference: String,
+    pub confirmations: u32,
+    pub required_confirmations: u32,
+    /// `PLATFORM_CLEARING` or `TREASURY`.
+    pub source: String,
+    pub status: String,
+    pub journal_id: Option<i64>,
+    pub created_by: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub credited_at: Option<DateTime<Utc>>,
+}
+
+/// What an owner has on the way in for one asset: shown, not yet spendable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingDepositsDTO {
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub pending_minor: i128,
+    pub deposits: Vec<DepositDTO>,
+}
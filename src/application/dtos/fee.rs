@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeRequestDTO {
+    pub payer_account_id: i64,
+    pub base_amount_minor: i128,
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::dtos::PostedJournalDTO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetFxRateDTO {
+    pub base_asset_code: String,
+    pub quote_asset_code: String,
+    /// Units of the quote asset per unit of the base asset, as a plain decimal string.
+    pub rate: String,
+    pub valid_from: DateTime<Utc>,
+    /// Open-ended when absent.
+    #[serde(default)]
+    pub valid_to: Option<DateTime<Utc>>,
+    pub source: String,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRateDTO {
+    pub id: i64,
+    pub base_asset_id: i16,
+    pub quote_asset_id: i16,
+    pub rate: String,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+    pub source: String,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestFxQuoteDTO {
+    /// Quote id chosen by the caller; requesting it again returns the quote already issued.
+    pub public_id: String,
+    pub owner_id: String,
+    pub from_asset_code: String,
+    pub to_asset_code: String,
+    /// Amount of the `from` asset the owner sells.
+    pub from_amount_minor: i128,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxQuoteDTO {
+    pub public_id: String,
+    pub owner_id: String,
+    pub from_asset_id: i16,
+    pub to_asset_id: i16,
+    pub rate_id: i64,
+    pub mid_rate: String,
+    pub spread_bps: u32,
+    /// The mid rate less the spread; what the conversion is priced at.
+    pub applied_rate: String,
+    pub from_amount_minor: i128,
+    pub to_amount_minor: i128,
+    /// `TREASURY` or `INVENTORY`.
+    pub liquidity: String,
+    pub expires_at: DateTime<Utc>,
+    /// `OPEN`, `EXECUTED` or `EXPIRED`.
+    pub status: String,
+    pub sell_journal_id: Option<i64>,
+    pub buy_journal_id: Option<i64>,
+    pub created_by: String,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub executed_by: Option<String>,
+}
+
+/// An executed quote with its two legs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxConversionDTO {
+    pub quote: FxQuoteDTO,
+    pub sell_journal: PostedJournalDTO,
+    pub buy_journal: PostedJournalDTO,
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::dtos::PostedJournalDTO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunFxRevaluationDTO {
+    /// Run id chosen by the caller; ignored by previews.
+    pub public_id: String,
+    pub reporting_asset_code: String,
+    /// The reporting asset's period to revalue; it must have ended.
+    pub period_id: i64,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRevaluationLineDTO {
+    pub asset_id: i16,
+    pub balance_minor: i128,
+    pub opening_rate_id: i64,
+    pub opening_rate: String,
+    pub closing_rate_id: i64,
+    pub closing_rate: String,
+    pub opening_value_minor: i128,
+    pub closing_value_minor: i128,
+    /// Positive is a gain, in the reporting asset.
+    pub gain_minor: i128,
+    pub journal_id: Option<i64>,
+    pub reversal_journal_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRevaluationDTO {
+    pub public_id: String,
+    pub reporting_asset_id: i16,
+    pub period_id: i64,
+    pub valued_at: DateTime<Utc>,
+    /// `POSTED` or `REVERSED`; `PREVIEW` for a dry run, which posts nothing.
+    pub status: String,
+    pub total_gain_minor: i128,
+    pub lines: Vec<FxRevaluationLineDTO>,
+    pub created_by: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub reversed_at: Option<DateTime<Utc>>,
+    pub reversed_by: Option<String>,
+}
+
+/// A revaluation with the journals it posted or, once reversed, the reversing ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRevaluationRunDTO {
+    pub revaluation: FxRevaluationDTO,
+    pub journals: Vec<PostedJournalDTO>,
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceHoldDTO {
+    /// Identifies the hold; placing the same id again returns the existing hold.
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    /// Remainder goes back to `UserAvailable` at this time; omitted means no deadline.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldDTO {
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub available_account_id: i64,
+    pub locked_account_id: i64,
+    pub amount_minor: i128,
+    pub captured_minor: i128,
+    pub released_minor: i128,
+    pub remaining_minor: i128,
+    pub status: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldJournalDTO {
+    pub seq: u32,
+    /// `PLACE`, `CAPTURE`, `RELEASE` or `EXPIRE`.
+    pub action: String,
+    pub amount_minor: i128,
+    pub journal_id: i64,
+    pub counterparty_account_id: i64,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Outcome of one pass over expired holds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HoldExpiryReportDTO {
+    pub expired: usize,
+    pub failed: usize,
+    /// `<hold public_id>: <error>` for each hold that could not be released.
+    pub errors: Vec<String>,
+}
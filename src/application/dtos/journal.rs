@@ -1,14 +1,26 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::application::dtos::JournalLineDTO;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostedJournalDTO {
     pub db_id: i64,
+    /// Gapless position in the ledger's commit order; usable as a feed cursor.
+    pub sequence: i64,
     pub public_id: String,
     pub external_ref_type: String,
     pub external_ref: String,
     pub description: Option<String>,
     pub created_by: String,
-    pub asset_id: i16,
+    pub metadata: BTreeMap<String, String>,
+    pub tags: Vec<String>,
+    pub effective_at: DateTime<Utc>,
+    pub posted_at: DateTime<Utc>,
+    pub multi_asset: bool,
+    /// The asset shared by every line; absent when the lines span assets.
+    pub asset_id: Option<i16>,
     pub lines: Vec<JournalLineDTO>,
+    pub fee_rule_ids: Vec<i64>,
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainHeadDTO {
+    /// Links so far; 0 before anything is chained.
+    pub position: i64,
+    /// Hex-encoded SHA-256 of the last link.
+    pub entry_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainBreakDTO {
+    pub position: i64,
+    pub journal_id: Option<i64>,
+    /// `GAP`, `PREV_HASH_MISMATCH`, `CONTENT_MISMATCH`, `MISSING_JOURNAL` or `HEAD_MISMATCH`.
+    pub kind: String,
+    /// For a `GAP`, the position the link should have had.
+    pub expected_position: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainVerificationDTO {
+    pub intact: bool,
+    /// Links verified before the first break (all of them when intact).
+    pub checked: u64,
+    pub head: ChainHeadDTO,
+    pub first_break: Option<ChainBreakDTO>,
+}
@@ -2,5 +2,10 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]pub struct JournalLineDTO {
     pub account_id: i64,
+    /// The account's asset; filled in on posted journals and ignored on requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<i16>,
     pub amount_minor: i128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
 }
\ No newline at end of file
@@ -10,4 +10,5 @@ pub struct LedgerAccountDTO {
     pub account_type: String,
     pub asset_id: i16,
     pub is_active: bool,
+    pub status: String,
 }
\ No newline at end of file
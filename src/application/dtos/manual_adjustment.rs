@@ -0,0 +1,32 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::application::dtos::JournalLineDTO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualAdjustmentDTO {
+    pub public_id: String,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub effective_at: Option<DateTime<Utc>>,
+    pub metadata: BTreeMap<String, String>,
+    pub tags: Vec<String>,
+    pub asset_id: i16,
+    pub gross_amount_minor: i128,
+    pub status: String,
+    pub required_approvals: u16,
+    pub approvals: Vec<String>,
+    pub rejection_reason: Option<String>,
+    pub posted_journal_id: Option<i64>,
+    pub lines: Vec<JournalLineDTO>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustmentAuditEntryDTO {
+    pub action: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub at: DateTime<Utc>,
+}
@@ -0,0 +1,16 @@
+use crate::application::dtos::AccountStatusChangeDTO;
+use crate::domain::entities::AccountStatusChange;
+
+pub fn status_change_to_dto(c: &AccountStatusChange) -> AccountStatusChangeDTO {
+    AccountStatusChangeDTO {
+        account_id: c.account_id,
+        from_status: c.from.as_code().to_string(),
+        to_status: c.to.as_code().to_string(),
+        was_active: c.was_active,
+        is_active: c.is_active,
+        reason_code: c.reason.as_code().to_string(),
+        note: c.note.clone(),
+        actor: c.actor.clone(),
+        changed_at: c.at,
+    }
+}
@@ -0,0 +1,15 @@
+use crate::application::dtos::AccountingPeriodDTO;
+use crate::domain::entities::AccountingPeriod;
+
+pub fn period_to_dto(p: &AccountingPeriod) -> AccountingPeriodDTO {
+    AccountingPeriodDTO {
+        id: p.id(),
+        asset_id: p.asset_id(),
+        name: p.name().to_string(),
+        starts_at: p.starts_at(),
+        ends_at: p.ends_at(),
+        status: p.status().as_code().to_string(),
+        status_changed_at: p.status_changed_at(),
+        status_changed_by: p.status_changed_by().map(str::to_string),
+    }
+}
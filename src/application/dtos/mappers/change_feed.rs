@@ -0,0 +1,13 @@
+use crate::application::dtos::FeedCursorDTO;
+use crate::domain::aggregate::FeedCursor;
+
+pub fn feed_cursor_to_dto(c: &FeedCursor, latest_sequence: i64) -> FeedCursorDTO {
+    FeedCursorDTO {
+        consumer: c.consumer().to_string(),
+        position: c.position(),
+        lag: (latest_sequence - c.position()).max(0),
+        latest_sequence,
+        created_at: c.created_at(),
+        updated_at: c.updated_at(),
+    }
+}
@@ -20,10 +20,13 @@ pub fn map_create_account_to_spec(dto: CreateAccountDTO) -> Result<NewLedgerAcco
     let account_type = match dto.account_type.as_str() {
         "USER_AVAILABLE" => AccountType::UserAvailable,
         "USER_LOCKED" => AccountType::UserLocked,
+        "PLATFORM_REVENUE" => AccountType::PlatformRevenue,
         "TREASURY_AVAILABLE" => AccountType::TreasuryAvailable,
         "TREASURY_LOCKED" => AccountType::TreasuryLocked,
         "INVENTORY_AVAILABLE" => AccountType::InventoryAvailable,
         "INVENTORY_LOCKED" => AccountType::InventoryLocked,
+        "TREASURY_REVALUATION" => AccountType::TreasuryRevaluation,
+        "PLATFORM_UNREALISED_FX" => AccountType::PlatformUnrealisedFx,
         _ => {
             return Err(AppError::InvalidRequest {
                 message: "Unknown owner type".to_string(),
@@ -38,6 +41,7 @@ pub fn map_create_account_to_spec(dto: CreateAccountDTO) -> Result<NewLedgerAcco
         account_type,
         asset_id: dto.asset_id,
         is_active: dto.is_active,
+        coa_node_id: dto.coa_node_id,
     })
 }
 
@@ -50,5 +54,6 @@ pub fn map_account_to_dto(a: &LedgerAccount) -> LedgerAccountDTO {
         account_type: a.account_type().as_str().to_string(),
         asset_id: a.asset_id(),
         is_active: a.is_active(),
+        status: a.status().as_code().to_string(),
     }
 }
@@ -0,0 +1,20 @@
+use crate::application::dtos::DepositDTO;
+use crate::domain::aggregate::DepositIntent;
+
+pub fn deposit_to_dto(d: &DepositIntent) -> DepositDTO {
+    DepositDTO {
+        public_id: d.public_id().value().to_string(),
+        owner_id: d.owner_id().to_string(),
+        asset_id: d.asset_id(),
+        amount_minor: d.amount_minor(),
+        tx_reference: d.tx_reference().to_string(),
+        confirmations: d.confirmations(),
+        required_confirmations: d.required_confirmations(),
+        source: d.source().as_code().to_string(),
+        status: d.status().as_code().to_string(),
+        journal_id: d.journal_id(),
+        created_by: d.created_by().to_string(),
+        confirmed_at: d.confirmed_at(),
+        credited_at: d.credited_at(),
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::dtos::{FxQuoteDTO, FxRateDTO};
+use crate::domain::aggregate::{FxQuote, FxRate};
+
+pub fn fx_rate_to_dto(r: &FxRate) -> FxRateDTO {
+    FxRateDTO {
+        id: r.id(),
+        base_asset_id: r.base_asset_id(),
+        quote_asset_id: r.quote_asset_id(),
+        rate: r.rate().to_string(),
+        valid_from: r.valid_from(),
+        valid_to: r.valid_to(),
+        source: r.source().to_string(),
+        created_by: r.created_by().to_string(),
+    }
+}
+
+/// `now` decides whether an open quote is reported as expired.
+pub fn fx_quote_to_dto(q: &FxQuote, now: DateTime<Utc>) -> FxQuoteDTO {
+    FxQuoteDTO {
+        public_id: q.public_id().value().to_string(),
+        owner_id: q.owner_id().to_string(),
+        from_asset_id: q.from_asset_id(),
+        to_asset_id: q.to_asset_id(),
+        rate_id: q.rate_id(),
+        mid_rate: q.mid_rate().to_string(),
+        spread_bps: q.spread_bps(),
+        applied_rate: q.applied_rate().to_string(),
+        from_amount_minor: q.from_amount_minor(),
+        to_amount_minor: q.to_amount_minor(),
+        liquidity: q.liquidity().as_code().to_string(),
+        expires_at: q.expires_at(),
+        status: q.status_at(now).as_code().to_string(),
+        sell_journal_id: q.sell_journal_id(),
+        buy_journal_id: q.buy_journal_id(),
+        created_by: q.created_by().to_string(),
+        executed_at: q.executed_at(),
+        executed_by: q.executed_by().map(str::to_string),
+    }
+}
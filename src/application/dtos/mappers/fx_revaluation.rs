@@ -0,0 +1,50 @@
+use crate::application::dtos::{FxRevaluationDTO, FxRevaluationLineDTO};
+use crate::domain::aggregate::{FxRevaluation, NewFxRevaluation, RevaluationLine};
+
+fn line_to_dto(l: &RevaluationLine) -> FxRevaluationLineDTO {
+    FxRevaluationLineDTO {
+        asset_id: l.asset_id,
+        balance_minor: l.balance_minor,
+        opening_rate_id: l.opening_rate_id,
+        opening_rate: l.opening_rate.to_string(),
+        closing_rate_id: l.closing_rate_id,
+        closing_rate: l.closing_rate.to_string(),
+        opening_value_minor: l.opening_value_minor,
+        closing_value_minor: l.closing_value_minor,
+        gain_minor: l.gain_minor,
+        journal_id: l.journal_id,
+        reversal_journal_id: l.reversal_journal_id,
+    }
+}
+
+pub fn fx_revaluation_to_dto(r: &FxRevaluation) -> FxRevaluationDTO {
+    FxRevaluationDTO {
+        public_id: r.public_id().value().to_string(),
+        reporting_asset_id: r.reporting_asset_id(),
+        period_id: r.period_id(),
+        valued_at: r.valued_at(),
+        status: r.status().as_code().to_string(),
+        total_gain_minor: r.total_gain_minor(),
+        lines: r.lines().iter().map(line_to_dto).collect(),
+        created_by: r.created_by().to_string(),
+        created_at: Some(r.created_at()),
+        reversed_at: r.reversed_at(),
+        reversed_by: r.reversed_by().map(str::to_string),
+    }
+}
+
+pub fn fx_revaluation_preview_to_dto(spec: &NewFxRevaluation) -> FxRevaluationDTO {
+    FxRevaluationDTO {
+        public_id: spec.public_id.value().to_string(),
+        reporting_asset_id: spec.reporting_asset_id,
+        period_id: spec.period_id,
+        valued_at: spec.valued_at,
+        status: "PREVIEW".to_string(),
+        total_gain_minor: spec.total_gain_minor(),
+        lines: spec.lines.iter().map(line_to_dto).collect(),
+        created_by: spec.created_by.clone(),
+        created_at: None,
+        reversed_at: None,
+        reversed_by: None,
+    }
+}
@@ -0,0 +1,34 @@
+use crate::application::dtos::{HoldDTO, HoldJournalDTO};
+use crate::domain::aggregate::{Hold, HoldJournal};
+
+pub fn hold_to_dto(h: &Hold) -> HoldDTO {
+    HoldDTO {
+        public_id: h.public_id().value().to_string(),
+        owner_id: h.owner_id().to_string(),
+        asset_id: h.asset_id(),
+        available_account_id: h.available_account_id(),
+        locked_account_id: h.locked_account_id(),
+        amount_minor: h.amount_minor(),
+        captured_minor: h.captured_minor(),
+        released_minor: h.released_minor(),
+        remaining_minor: h.remaining_minor(),
+        status: h.status().as_code().to_string(),
+        expires_at: h.expires_at(),
+        description: h.description().map(str::to_string),
+        created_by: h.created_by().to_string(),
+        status_changed_at: h.status_changed_at(),
+        status_changed_by: h.status_changed_by().map(str::to_string),
+    }
+}
+
+pub fn hold_journal_to_dto(j: &HoldJournal) -> HoldJournalDTO {
+    HoldJournalDTO {
+        seq: j.seq,
+        action: j.action.as_code().to_string(),
+        amount_minor: j.amount_minor,
+        journal_id: j.journal_id,
+        counterparty_account_id: j.counterparty_account_id,
+        actor: j.actor.clone(),
+        created_at: j.created_at,
+    }
+}
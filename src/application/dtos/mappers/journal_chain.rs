@@ -0,0 +1,27 @@
+use crate::application::dtos::{ChainBreakDTO, ChainHeadDTO, ChainVerificationDTO};
+use crate::domain::services::{ChainBreak, ChainBreakKind, ChainHead, ChainVerification};
+
+pub fn chain_head_to_dto(head: &ChainHead) -> ChainHeadDTO {
+    ChainHeadDTO { position: head.position, entry_hash: hex::encode(head.entry_hash) }
+}
+
+fn chain_break_to_dto(b: &ChainBreak) -> ChainBreakDTO {
+    ChainBreakDTO {
+        position: b.position,
+        journal_id: b.journal_id,
+        kind: b.kind.as_code().to_string(),
+        expected_position: match b.kind {
+            ChainBreakKind::Gap { expected_position } => Some(expected_position),
+            _ => None,
+        },
+    }
+}
+
+pub fn chain_verification_to_dto(head: &ChainHead, v: &ChainVerification) -> ChainVerificationDTO {
+    ChainVerificationDTO {
+        intact: v.is_intact(),
+        checked: v.checked,
+        head: chain_head_to_dto(head),
+        first_break: v.first_break.as_ref().map(chain_break_to_dto),
+    }
+}
@@ -0,0 +1,37 @@
+use crate::application::dtos::{AdjustmentAuditEntryDTO, JournalLineDTO, ManualAdjustmentDTO};
+use crate::domain::aggregate::{AdjustmentAuditEntry, ManualAdjustment};
+
+pub fn adjustment_to_dto(a: &ManualAdjustment) -> ManualAdjustmentDTO {
+    let draft = a.draft();
+    ManualAdjustmentDTO {
+        public_id: a.public_id().value().to_string(),
+        external_ref: draft.external_ref.as_str().to_string(),
+        description: draft.description.clone(),
+        created_by: draft.created_by.clone(),
+        effective_at: draft.effective_at,
+        metadata: draft.metadata.as_map().clone(),
+        tags: draft.tags().iter().map(|t| t.as_str().to_string()).collect(),
+        asset_id: a.asset_id(),
+        gross_amount_minor: a.gross_amount_minor(),
+        status: a.status().as_code().to_string(),
+        required_approvals: a.required_approvals(),
+        approvals: a.approvals().to_vec(),
+        rejection_reason: a.rejection_reason().map(str::to_string),
+        posted_journal_id: a.posted_journal_id(),
+        lines: draft.lines().iter().map(|l| JournalLineDTO {
+            account_id: l.account_id,
+            asset_id: None,
+            amount_minor: l.amount.minor(),
+            memo: l.memo.clone(),
+        }).collect(),
+    }
+}
+
+pub fn audit_entry_to_dto(e: &AdjustmentAuditEntry) -> AdjustmentAuditEntryDTO {
+    AdjustmentAuditEntryDTO {
+        action: e.action.as_code().to_string(),
+        actor: e.actor.clone(),
+        reason: e.reason.clone(),
+        at: e.at,
+    }
+}
@@ -6,4 +6,36 @@ pub use create_account::{
     map_account_to_dto
 };
 mod posted_to_dto;
-pub use posted_to_dto::posted_to_dto;
\ No newline at end of file
+pub use posted_to_dto::posted_to_dto;
+mod manual_adjustment;
+pub use manual_adjustment::{adjustment_to_dto, audit_entry_to_dto};
+mod account_status;
+pub use account_status::status_change_to_dto;
+mod statements;
+pub use statements::{balance_sheet_to_dto, coa_node_to_dto, income_statement_to_dto, movement_summary_to_dto};
+mod accounting_period;
+pub use accounting_period::period_to_dto;
+mod scheduled_journal;
+pub use scheduled_journal::{schedule_run_to_dto, schedule_to_dto};
+mod pending_journal;
+pub use pending_journal::{available_balance_to_dto, pending_to_dto};
+mod hold;
+pub use hold::{hold_journal_to_dto, hold_to_dto};
+mod withdrawal;
+pub use withdrawal::withdrawal_to_dto;
+mod deposit;
+pub use deposit::deposit_to_dto;
+mod fx_rate;
+pub use fx_rate::{fx_quote_to_dto, fx_rate_to_dto};
+mod fx_revaluation;
+pub use fx_revaluation::{fx_revaluation_preview_to_dto, fx_revaluation_to_dto};
+mod proof_of_reserves;
+pub use proof_of_reserves::{inclusion_proof_from_dto, inclusion_proof_to_dto, reserve_snapshot_to_dto};
+mod solvency;
+pub use solvency::solvency_report_to_dto;
+mod journal_chain;
+pub use journal_chain::{chain_head_to_dto, chain_verification_to_dto};
+mod change_feed;
+pub use change_feed::feed_cursor_to_dto;
+mod webhook;
+pub use webhook::{webhook_delivery_to_dto, webhook_subscription_to_dto};
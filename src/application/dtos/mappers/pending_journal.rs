@@ -0,0 +1,41 @@
+use crate::application::dtos::{AvailableBalanceDTO, JournalLineDTO, PendingJournalDTO};
+use crate::domain::aggregate::{AvailableBalance, PendingJournal};
+
+pub fn pending_to_dto(p: &PendingJournal) -> PendingJournalDTO {
+    let d = p.draft();
+    PendingJournalDTO {
+        public_id: p.public_id().value().to_string(),
+        external_ref_type: d.external_ref_type.as_code().to_string(),
+        external_ref: d.external_ref.as_str().to_string(),
+        description: d.description.clone(),
+        created_by: d.created_by.clone(),
+        asset_id: p.asset_id(),
+        lines: d.lines().iter().map(|l| JournalLineDTO {
+            account_id: l.account_id,
+            asset_id: None,
+            amount_minor: l.amount.minor(),
+            memo: l.memo.clone(),
+        }).collect(),
+        metadata: d.metadata.as_map().clone(),
+        tags: d.tags().iter().map(|g| g.as_str().to_string()).collect(),
+        authorized_amount_minor: p.authorized_amount_minor(),
+        status: p.status().as_code().to_string(),
+        expires_at: p.expires_at(),
+        committed_amount_minor: p.committed_amount_minor(),
+        posted_journal_id: p.posted_journal_id(),
+        status_changed_at: p.status_changed_at(),
+        status_changed_by: p.status_changed_by().map(str::to_string),
+    }
+}
+
+pub fn available_balance_to_dto(b: &AvailableBalance) -> AvailableBalanceDTO {
+    AvailableBalanceDTO {
+        account_id: b.account_id,
+        asset_id: b.asset_id,
+        posted_minor: b.posted_minor,
+        pending_inflow_minor: b.pending_inflow_minor,
+        pending_outflow_minor: b.pending_outflow_minor,
+        available_minor: b.available_minor(),
+        pending_minor: b.pending_minor(),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::application::dtos::{PostJournalRequestDTO, JournalLineDTO};
 use crate::domain::aggregate::JournalDraft;
-use crate::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
 use crate::application::AppError;
 
 pub fn map_post_journal_request(dto: PostJournalRequestDTO) -> Result<JournalDraft, AppError> {
@@ -21,6 +21,13 @@ pub fn map_post_journal_request(dto: PostJournalRequestDTO) -> Result<JournalDra
         dto.description,
     ).map_err(AppError::from)?;
 
+    draft.metadata = JournalMetadata::new(dto.metadata)?;
+    draft.effective_at = dto.effective_at;
+    draft.multi_asset = dto.multi_asset;
+    for tag in dto.tags {
+        draft.add_tag(JournalTag::new(tag)?);
+    }
+
     for l in dto.lines {
         let money = if l.amount_minor > 0 {
             Money::debit(l.amount_minor)
@@ -28,7 +35,7 @@ pub fn map_post_journal_request(dto: PostJournalRequestDTO) -> Result<JournalDra
             Money::credit(l.amount_minor.abs())
         };
 
-        draft.add_line(l.account_id, money.map_err(AppError::from)?);
+        draft.add_line_with_memo(l.account_id, money.map_err(AppError::from)?, l.memo);
     }
 
     Ok(draft)
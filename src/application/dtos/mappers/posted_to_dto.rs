@@ -4,15 +4,24 @@ use crate::domain::aggregate::PostedJournal;
 pub fn posted_to_dto(p: &PostedJournal) -> PostedJournalDTO {
     PostedJournalDTO {
         db_id: p.db_id,
+        sequence: p.sequence,
         public_id: p.public_id.value().to_string(),
         external_ref_type: p.external_ref_type.as_code().to_string(),
         external_ref: p.external_ref.as_str().to_string(),
         description: p.description.clone(),
         created_by: p.created_by.clone(),
+        metadata: p.metadata.as_map().clone(),
+        tags: p.tags.iter().map(|t| t.as_str().to_string()).collect(),
+        effective_at: p.effective_at,
+        posted_at: p.posted_at,
+        multi_asset: p.multi_asset,
         asset_id: p.asset_id,
         lines: p.lines.iter().map(|l| JournalLineDTO {
             account_id: l.account_id,
+            asset_id: Some(l.asset_id),
             amount_minor: l.amount.minor(),
+            memo: l.memo.clone(),
         }).collect(),
+        fee_rule_ids: p.fee_rule_ids.clone(),
     }
 }
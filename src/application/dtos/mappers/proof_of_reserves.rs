@@ -0,0 +1,57 @@
+use uuid::Uuid;
+
+use crate::application::dtos::{InclusionProofDTO, ProofStepDTO, ReserveSnapshotDTO};
+use crate::domain::aggregate::ReserveSnapshot;
+use crate::domain::error::DomainError;
+use crate::domain::services::{parse_digest, InclusionProof, MerkleNode, ProofStep, SiblingSide};
+
+pub fn reserve_snapshot_to_dto(s: &ReserveSnapshot) -> ReserveSnapshotDTO {
+    ReserveSnapshotDTO {
+        public_id: s.public_id().value().to_string(),
+        asset_id: s.asset_id(),
+        taken_at: s.taken_at(),
+        root_hash: hex::encode(s.root().hash),
+        total_minor: s.total_minor(),
+        leaf_count: s.leaf_count(),
+        created_by: s.created_by().to_string(),
+    }
+}
+
+pub fn inclusion_proof_to_dto(s: &ReserveSnapshot, owner_id: Uuid, proof: &InclusionProof) -> InclusionProofDTO {
+    InclusionProofDTO {
+        snapshot_id: s.public_id().value().to_string(),
+        asset_id: s.asset_id(),
+        root_hash: hex::encode(s.root().hash),
+        total_minor: s.total_minor(),
+        owner_id: owner_id.to_string(),
+        salt: hex::encode(s.salt()),
+        owner_hash: hex::encode(proof.owner_hash),
+        balance_minor: proof.balance_minor,
+        steps: proof
+            .steps
+            .iter()
+            .map(|step| ProofStepDTO {
+                side: step.side.as_code().to_string(),
+                hash: hex::encode(step.sibling.hash),
+                sum_minor: step.sibling.sum,
+            })
+            .collect(),
+    }
+}
+
+/// The published root and the proof, parsed back from their wire form.
+pub fn inclusion_proof_from_dto(dto: &InclusionProofDTO) -> Result<(MerkleNode, InclusionProof), DomainError> {
+    let root = MerkleNode { hash: parse_digest(&dto.root_hash)?, sum: dto.total_minor };
+    let steps = dto
+        .steps
+        .iter()
+        .map(|s| {
+            Ok(ProofStep {
+                side: SiblingSide::from_code(&s.side)?,
+                sibling: MerkleNode { hash: parse_digest(&s.hash)?, sum: s.sum_minor },
+            })
+        })
+        .collect::<Result<_, DomainError>>()?;
+    let proof = InclusionProof { owner_hash: parse_digest(&dto.owner_hash)?, balance_minor: dto.balance_minor, steps };
+    Ok((root, proof))
+}
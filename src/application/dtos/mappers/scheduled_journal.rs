@@ -0,0 +1,48 @@
+use crate::application::dtos::{JournalLineDTO, ScheduledJournalDTO, ScheduledJournalRunDTO};
+use crate::domain::aggregate::{ScheduledJournal, ScheduledJournalRun};
+
+pub fn schedule_to_dto(s: &ScheduledJournal) -> ScheduledJournalDTO {
+    let t = s.template();
+    ScheduledJournalDTO {
+        public_id: s.public_id().value().to_string(),
+        external_ref_type: t.external_ref_type.as_code().to_string(),
+        external_ref: t.external_ref.as_str().to_string(),
+        description: t.description.clone(),
+        created_by: t.created_by.clone(),
+        lines: t.lines().iter().map(|l| JournalLineDTO {
+            account_id: l.account_id,
+            asset_id: None,
+            amount_minor: l.amount.minor(),
+            memo: l.memo.clone(),
+        }).collect(),
+        metadata: t.metadata.as_map().clone(),
+        tags: t.tags().iter().map(|g| g.as_str().to_string()).collect(),
+        multi_asset: t.multi_asset,
+        recurrence: s.recurrence().as_code().to_string(),
+        interval_seconds: s.recurrence().interval_seconds(),
+        starts_at: s.starts_at(),
+        ends_at: s.ends_at(),
+        max_occurrences: s.max_occurrences(),
+        status: s.status().as_code().to_string(),
+        next_occurrence: s.next_occurrence(),
+        next_run_at: s.next_run_at(),
+        posted_count: s.posted_count(),
+        consecutive_failures: s.consecutive_failures(),
+        last_error: s.last_error().map(str::to_string),
+        status_changed_at: s.status_changed_at(),
+        status_changed_by: s.status_changed_by().map(str::to_string),
+    }
+}
+
+pub fn schedule_run_to_dto(s: &ScheduledJournal, r: &ScheduledJournalRun) -> ScheduledJournalRunDTO {
+    ScheduledJournalRunDTO {
+        occurrence: r.occurrence,
+        external_ref: s
+            .occurrence_ref(r.occurrence)
+            .map(|e| e.as_str().to_string())
+            .unwrap_or_default(),
+        scheduled_for: r.scheduled_for,
+        journal_id: r.journal_id,
+        posted_at: r.posted_at,
+    }
+}
@@ -0,0 +1,24 @@
+use crate::application::dtos::SolvencyReportDTO;
+use crate::domain::aggregate::SolvencyReport;
+
+pub fn solvency_report_to_dto(r: &SolvencyReport) -> SolvencyReportDTO {
+    let b = r.balances();
+    SolvencyReportDTO {
+        public_id: r.public_id().value().to_string(),
+        asset_id: r.asset_id(),
+        as_of: r.as_of(),
+        trigger: r.trigger().as_code().to_string(),
+        status: r.status().as_code().to_string(),
+        user_available_minor: b.user_available,
+        user_locked_minor: b.user_locked,
+        treasury_available_minor: b.treasury_available,
+        treasury_locked_minor: b.treasury_locked,
+        platform_clearing_minor: b.platform_clearing,
+        liabilities_minor: r.liabilities_minor(),
+        holdings_minor: r.holdings_minor(),
+        surplus_minor: r.surplus_minor(),
+        coverage_bps: r.coverage_bps(),
+        requested_by: r.requested_by().to_string(),
+        alerted_at: r.alerted_at(),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::application::dtos::{
+    BalanceSheetDTO, CoaNodeDTO, IncomeStatementDTO, MovementSummaryDTO, NodeBalanceDTO, NodeMovementDTO,
+};
+use crate::domain::entities::{CoaNode, NodeBalance};
+use crate::domain::services::{BalanceSheet, IncomeStatement, MovementSummary};
+
+pub fn coa_node_to_dto(n: &CoaNode) -> CoaNodeDTO {
+    CoaNodeDTO {
+        id: n.id,
+        code: n.code.clone(),
+        name: n.name.clone(),
+        gl_class: n.gl_class.as_code().to_string(),
+        normal_balance: n.normal_balance.as_code().to_string(),
+        parent_id: n.parent_id,
+    }
+}
+
+fn node_balances_to_dto(nodes: &[NodeBalance]) -> Vec<NodeBalanceDTO> {
+    nodes.iter().map(|n| NodeBalanceDTO {
+        node_id: n.node_id,
+        code: n.code.clone(),
+        name: n.name.clone(),
+        gl_class: n.gl_class.as_code().to_string(),
+        normal_balance: n.normal_balance.as_code().to_string(),
+        parent_id: n.parent_id,
+        depth: n.depth,
+        own_minor: n.own_minor,
+        total_minor: n.total_minor,
+    }).collect()
+}
+
+pub fn balance_sheet_to_dto(b: &BalanceSheet) -> BalanceSheetDTO {
+    BalanceSheetDTO {
+        asset_id: b.asset_id,
+        as_of: b.as_of,
+        assets: node_balances_to_dto(&b.assets),
+        liabilities: node_balances_to_dto(&b.liabilities),
+        equity: node_balances_to_dto(&b.equity),
+        total_assets: b.total_assets,
+        total_liabilities: b.total_liabilities,
+        total_equity: b.total_equity,
+        current_earnings: b.current_earnings,
+        balanced: b.is_balanced(),
+    }
+}
+
+pub fn income_statement_to_dto(i: &IncomeStatement) -> IncomeStatementDTO {
+    IncomeStatementDTO {
+        asset_id: i.asset_id,
+        from: i.from,
+        to: i.to,
+        revenue: node_balances_to_dto(&i.revenue),
+        expenses: node_balances_to_dto(&i.expenses),
+        total_revenue: i.total_revenue,
+        total_expenses: i.total_expenses,
+        net_income: i.net_income,
+    }
+}
+
+pub fn movement_summary_to_dto(m: &MovementSummary) -> MovementSummaryDTO {
+    MovementSummaryDTO {
+        asset_id: m.asset_id,
+        from: m.from,
+        to: m.to,
+        nodes: m.nodes.iter().map(|n| NodeMovementDTO {
+            node_id: n.node_id,
+            code: n.code.clone(),
+            name: n.name.clone(),
+            gl_class: n.gl_class.as_code().to_string(),
+            depth: n.depth,
+            opening_minor: n.opening_minor,
+            increase_minor: n.increase_minor,
+            decrease_minor: n.decrease_minor,
+            closing_minor: n.closing_minor,
+        }).collect(),
+    }
+}
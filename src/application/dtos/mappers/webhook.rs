@@ -0,0 +1,43 @@
+use crate::application::dtos::{WebhookAttemptDTO, WebhookDeliveryDTO, WebhookSubscriptionDTO};
+use crate::domain::aggregate::{WebhookAttempt, WebhookDelivery, WebhookSubscription};
+
+pub fn webhook_subscription_to_dto(s: &WebhookSubscription) -> WebhookSubscriptionDTO {
+    WebhookSubscriptionDTO {
+        public_id: s.public_id().value().to_string(),
+        owner_id: s.owner_id().to_string(),
+        url: s.url().to_string(),
+        event_types: s.event_types().iter().map(|t| t.as_code().to_string()).collect(),
+        active: s.is_active(),
+        created_by: s.created_by().to_string(),
+        created_at: s.created_at(),
+        updated_at: s.updated_at(),
+    }
+}
+
+fn webhook_attempt_to_dto(a: &WebhookAttempt) -> WebhookAttemptDTO {
+    WebhookAttemptDTO {
+        attempt: a.attempt,
+        attempted_at: a.attempted_at,
+        status_code: a.status_code,
+        error: a.error.clone(),
+    }
+}
+
+pub fn webhook_delivery_to_dto(d: &WebhookDelivery, log: &[WebhookAttempt]) -> WebhookDeliveryDTO {
+    let state = d.state();
+    WebhookDeliveryDTO {
+        public_id: d.public_id().value().to_string(),
+        subscription_id: d.subscription_id(),
+        event_id: d.event_id().to_string(),
+        event_type: d.event_type().as_code().to_string(),
+        status: state.status.as_code().to_string(),
+        attempts: state.attempts,
+        failures: state.failures,
+        next_attempt_at: state.next_attempt_at,
+        last_error: state.last_error.clone(),
+        delivered_at: state.delivered_at,
+        created_at: d.created_at(),
+        payload: d.payload().to_string(),
+        log: log.iter().map(webhook_attempt_to_dto).collect(),
+    }
+}
@@ -0,0 +1,18 @@
+use crate::application::dtos::WithdrawalDTO;
+use crate::domain::aggregate::Withdrawal;
+
+pub fn withdrawal_to_dto(w: &Withdrawal) -> WithdrawalDTO {
+    WithdrawalDTO {
+        public_id: w.public_id().value().to_string(),
+        owner_id: w.owner_id().to_string(),
+        asset_id: w.asset_id(),
+        amount_minor: w.amount_minor(),
+        destination: w.destination().to_string(),
+        status: w.status().as_code().to_string(),
+        rail_reference: w.rail_reference().map(str::to_string),
+        failure_reason: w.failure_reason().map(str::to_string),
+        created_by: w.created_by().to_string(),
+        status_changed_at: w.status_changed_at(),
+        status_changed_by: w.status_changed_by().map(str::to_string),
+    }
+}
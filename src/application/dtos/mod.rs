@@ -5,6 +5,24 @@ mod account_balance;
 mod list_journal_filter;
 mod create_account;
 mod post_journal;
+mod fee;
+mod manual_adjustment;
+mod account_status;
+mod statements;
+mod accounting_period;
+mod scheduled_journal;
+mod pending_journal;
+mod hold;
+mod withdrawal;
+mod deposit;
+mod transfer;
+mod fx_rate;
+mod fx_revaluation;
+mod proof_of_reserves;
+mod solvency;
+mod journal_chain;
+mod change_feed;
+mod webhook;
 pub mod mappers;
 
 pub use self::{
@@ -12,6 +30,33 @@ pub use self::{
     account_balance::AccountBalanceDTO,
     create_account::CreateAccountDTO,
     journal::PostedJournalDTO,
+    list_journal_filter::ListJournalsFilterDTO,
     ledger_account::LedgerAccountDTO,
-    post_journal::PostJournalRequestDTO
+    post_journal::PostJournalRequestDTO,
+    fee::FeeRequestDTO,
+    manual_adjustment::{AdjustmentAuditEntryDTO, ManualAdjustmentDTO},
+    account_status::{AccountStatusChangeDTO, ChangeAccountStatusDTO},
+    statements::{
+        BalanceSheetDTO, CoaNodeDTO, FinancialStatementsDTO, IncomeStatementDTO, MovementSummaryDTO,
+        NodeBalanceDTO, NodeMovementDTO, ReportExportDTO,
+    },
+    accounting_period::{AccountingPeriodDTO, ClosingBalanceDTO, DefinePeriodDTO},
+    scheduled_journal::{
+        CreateScheduleDTO, ScheduledJournalDTO, ScheduledJournalRunDTO, SchedulerRunReportDTO,
+    },
+    pending_journal::{AuthorizePendingDTO, AvailableBalanceDTO, PendingJournalDTO},
+    hold::{HoldDTO, HoldExpiryReportDTO, HoldJournalDTO, PlaceHoldDTO},
+    withdrawal::{RequestWithdrawalDTO, WithdrawalDTO, WithdrawalSyncReportDTO},
+    deposit::{DepositDTO, DetectDepositDTO, PendingDepositsDTO},
+    transfer::TransferRequestDTO,
+    fx_rate::{FxConversionDTO, FxQuoteDTO, FxRateDTO, RequestFxQuoteDTO, SetFxRateDTO},
+    fx_revaluation::{FxRevaluationDTO, FxRevaluationLineDTO, FxRevaluationRunDTO, RunFxRevaluationDTO},
+    proof_of_reserves::{InclusionProofDTO, ProofStepDTO, ReserveSnapshotDTO, TakeReserveSnapshotDTO},
+    solvency::{RunSolvencyReportDTO, SolvencyReportDTO, SolvencyRunReportDTO},
+    journal_chain::{ChainBreakDTO, ChainHeadDTO, ChainVerificationDTO},
+    change_feed::{CreateFeedConsumerDTO, FeedBatchDTO, FeedCursorDTO},
+    webhook::{
+        CreateWebhookSubscriptionDTO, WebhookAttemptDTO, WebhookDeliveryDTO, WebhookDeliveryRunDTO,
+        WebhookDispatchReportDTO, WebhookEventDTO, WebhookSubscriptionDTO,
+    },
 };
@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::application::dtos::{JournalLineDTO, PostJournalRequestDTO};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizePendingDTO {
+    /// Journal to reserve; committing posts it under the same `public_id` and `external_ref`.
+    pub journal: PostJournalRequestDTO,
+    /// The reservation is released if not committed or voided by then.
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingJournalDTO {
+    pub public_id: String,
+    pub external_ref_type: String,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub asset_id: i16,
+    pub lines: Vec<JournalLineDTO>,
+    pub metadata: BTreeMap<String, String>,
+    pub tags: Vec<String>,
+    pub authorized_amount_minor: i128,
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub committed_amount_minor: Option<i128>,
+    pub posted_journal_id: Option<i64>,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailableBalanceDTO {
+    pub account_id: i64,
+    pub asset_id: i16,
+    pub posted_minor: i128,
+    pub pending_inflow_minor: i128,
+    pub pending_outflow_minor: i128,
+    /// Posted balance less pending outflows: what can still be spent.
+    pub available_minor: i128,
+    /// Posted balance once every pending journal commits in full.
+    pub pending_minor: i128,
+}
@@ -1,5 +1,8 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use crate::application::dtos::JournalLineDTO;
+use crate::application::dtos::{FeeRequestDTO, JournalLineDTO};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostJournalRequestDTO {
     pub public_id: String,
@@ -8,4 +11,16 @@ pub struct PostJournalRequestDTO {
     pub description: Option<String>,
     pub created_by: String,
     pub lines: Vec<JournalLineDTO>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Value date; omitted means effective when posted.
+    #[serde(default)]
+    pub effective_at: Option<DateTime<Utc>>,
+    /// Lets the lines span assets so they commit together; each asset must balance.
+    #[serde(default)]
+    pub multi_asset: bool,
+    #[serde(default)]
+    pub fee: Option<FeeRequestDTO>,
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeReserveSnapshotDTO {
+    /// Snapshot id chosen by the caller; taking it again returns the snapshot already taken.
+    pub public_id: String,
+    pub asset_code: String,
+    pub created_by: String,
+}
+
+/// What is published for a snapshot: the root commits to every owner's balance and the
+/// total is the sum of them all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReserveSnapshotDTO {
+    pub public_id: String,
+    pub asset_id: i16,
+    pub taken_at: DateTime<Utc>,
+    /// Hex-encoded SHA-256.
+    pub root_hash: String,
+    pub total_minor: i128,
+    pub leaf_count: usize,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofStepDTO {
+    /// `LEFT` or `RIGHT`: where the sibling sits relative to the running hash.
+    pub side: String,
+    pub hash: String,
+    pub sum_minor: i128,
+}
+
+/// Everything an owner needs to check their balance is in the published total.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProofDTO {
+    pub snapshot_id: String,
+    pub asset_id: i16,
+    pub root_hash: String,
+    pub total_minor: i128,
+    pub owner_id: String,
+    /// Hex-encoded salt mixed into owner hashes for this snapshot.
+    pub salt: String,
+    pub owner_hash: String,
+    pub balance_minor: i128,
+    /// From the leaf up to the root.
+    pub steps: Vec<ProofStepDTO>,
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::application::dtos::{JournalLineDTO, PostJournalRequestDTO};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateScheduleDTO {
+    /// Template posted on each occurrence; its `public_id` identifies the schedule and
+    /// its `external_ref` prefixes the per-occurrence refs.
+    pub journal: PostJournalRequestDTO,
+    /// `ONCE`, `INTERVAL` or `MONTHLY`.
+    pub recurrence: String,
+    #[serde(default)]
+    pub interval_seconds: Option<i64>,
+    pub starts_at: DateTime<Utc>,
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub max_occurrences: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJournalDTO {
+    pub public_id: String,
+    pub external_ref_type: String,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub lines: Vec<JournalLineDTO>,
+    pub metadata: BTreeMap<String, String>,
+    pub tags: Vec<String>,
+    pub multi_asset: bool,
+    pub recurrence: String,
+    pub interval_seconds: Option<i64>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub max_occurrences: Option<u32>,
+    pub status: String,
+    pub next_occurrence: u32,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub posted_count: u32,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJournalRunDTO {
+    pub occurrence: u32,
+    pub external_ref: String,
+    pub scheduled_for: DateTime<Utc>,
+    pub journal_id: i64,
+    pub posted_at: DateTime<Utc>,
+}
+
+/// Outcome of one worker pass over due schedules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchedulerRunReportDTO {
+    pub claimed: usize,
+    pub posted: usize,
+    pub failed: usize,
+    /// Claimed but paused, cancelled or already advanced by the time it was locked.
+    pub skipped: usize,
+    /// `<schedule public_id>: <error>` for each failed occurrence.
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSolvencyReportDTO {
+    /// Report id chosen by the caller; running it again returns the stored report.
+    pub public_id: String,
+    pub asset_code: String,
+    pub requested_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolvencyReportDTO {
+    pub public_id: String,
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    /// `ON_DEMAND` or `SCHEDULED`.
+    pub trigger: String,
+    /// `COVERED` or `SHORTFALL`.
+    pub status: String,
+    /// Bucket totals as signed ledger balances: pools holding funds are negative.
+    pub user_available_minor: i128,
+    pub user_locked_minor: i128,
+    pub treasury_available_minor: i128,
+    pub treasury_locked_minor: i128,
+    pub platform_clearing_minor: i128,
+    pub liabilities_minor: i128,
+    /// The pool buckets' total, negated: what the platform holds, counted positive.
+    pub holdings_minor: i128,
+    /// Holdings less liabilities; negative by the size of a shortfall.
+    pub surplus_minor: i128,
+    /// Holdings over liabilities in basis points (10 000 = fully covered), rounded down.
+    /// `None` when nothing is owed to users.
+    pub coverage_bps: Option<i128>,
+    pub requested_by: String,
+    pub alerted_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of one scheduled pass over every active asset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SolvencyRunReportDTO {
+    pub reports: Vec<SolvencyReportDTO>,
+    pub shortfalls: usize,
+    pub failed: usize,
+    /// `<asset_id>: <error>` for each asset that could not be reported on or alerted.
+    pub errors: Vec<String>,
+}
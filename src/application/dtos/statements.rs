@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoaNodeDTO {
+    pub id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: String,
+    pub normal_balance: String,
+    pub parent_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeBalanceDTO {
+    pub node_id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: String,
+    pub normal_balance: String,
+    pub parent_id: Option<i64>,
+    pub depth: usize,
+    pub own_minor: i128,
+    pub total_minor: i128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceSheetDTO {
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    pub assets: Vec<NodeBalanceDTO>,
+    pub liabilities: Vec<NodeBalanceDTO>,
+    pub equity: Vec<NodeBalanceDTO>,
+    pub total_assets: i128,
+    pub total_liabilities: i128,
+    pub total_equity: i128,
+    pub current_earnings: i128,
+    pub balanced: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeStatementDTO {
+    pub asset_id: i16,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub revenue: Vec<NodeBalanceDTO>,
+    pub expenses: Vec<NodeBalanceDTO>,
+    pub total_revenue: i128,
+    pub total_expenses: i128,
+    pub net_income: i128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeMovementDTO {
+    pub node_id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: String,
+    pub depth: usize,
+    pub opening_minor: i128,
+    pub increase_minor: i128,
+    pub decrease_minor: i128,
+    pub closing_minor: i128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovementSummaryDTO {
+    pub asset_id: i16,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub nodes: Vec<NodeMovementDTO>,
+}
+
+/// Balance sheet as of `to`, plus the income statement and movement summary for `[from, to)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancialStatementsDTO {
+    pub asset_id: i16,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// `POSTED` or `EFFECTIVE`: which journal timestamp placed entries in the period.
+    pub basis: String,
+    pub balance_sheet: BalanceSheetDTO,
+    pub income_statement: IncomeStatementDTO,
+    pub movement_summary: MovementSummaryDTO,
+}
+
+/// A rendered report, ready to hand to a client or write to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportExportDTO {
+    pub content_type: String,
+    pub file_name: String,
+    pub body: String,
+}
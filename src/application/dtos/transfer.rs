@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRequestDTO {
+    /// Becomes the journal's `TRANSFER_INTENT` reference; sending the same key again
+    /// returns the journal already posted for it.
+    pub idempotency_key: String,
+    pub sender_owner_id: String,
+    pub receiver_owner_id: String,
+    pub asset_code: String,
+    pub amount_minor: i128,
+    /// Charges the sender any fee configured for transfers in this asset.
+    #[serde(default)]
+    pub charge_fee: bool,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub created_by: String,
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookSubscriptionDTO {
+    /// Subscription id chosen by the caller; creating it again returns the stored one.
+    pub public_id: String,
+    pub owner_id: String,
+    pub url: String,
+    /// Signs every delivery; at least 16 bytes. Never returned.
+    pub secret: String,
+    /// `journal.posted` and/or `balance.changed`.
+    pub event_types: Vec<String>,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscriptionDTO {
+    pub public_id: String,
+    pub owner_id: String,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The body posted to the endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEventDTO {
+    /// Stable across retries; also sent as a header.
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub created_at: DateTime<Utc>,
+    /// The journal (with only the owner's lines) or the balance change.
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookAttemptDTO {
+    pub attempt: u32,
+    pub attempted_at: DateTime<Utc>,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryDTO {
+    pub public_id: String,
+    pub subscription_id: i64,
+    pub event_id: String,
+    pub event_type: String,
+    /// `PENDING`, `DELIVERED` or `DEAD_LETTERED`.
+    pub status: String,
+    pub attempts: u32,
+    /// Failed attempts since enqueued or last redriven.
+    pub failures: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub payload: String,
+    /// The delivery log; empty in listings.
+    pub log: Vec<WebhookAttemptDTO>,
+}
+
+/// Outcome of turning committed journals into deliveries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookDispatchReportDTO {
+    pub journals: usize,
+    pub enqueued: usize,
+    /// Ledger sequence the webhook consumer has handled up to.
+    pub cursor: i64,
+}
+
+/// Outcome of one pass over due deliveries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookDeliveryRunDTO {
+    pub claimed: usize,
+    pub delivered: usize,
+    pub retrying: usize,
+    pub dead_lettered: usize,
+    /// Recorded by another worker first, or no longer pending.
+    pub skipped: usize,
+    /// `<delivery public id>: <error>` for each delivery whose outcome could not be saved.
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestWithdrawalDTO {
+    /// Withdrawal id; also the id of the hold on its funds. Requesting it again is a replay.
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    pub destination: String,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalDTO {
+    pub public_id: String,
+    pub owner_id: String,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    pub destination: String,
+    pub status: String,
+    pub rail_reference: Option<String>,
+    pub failure_reason: Option<String>,
+    pub created_by: String,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+/// Outcome of one pass asking the rail about submitted withdrawals.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WithdrawalSyncReportDTO {
+    pub checked: usize,
+    pub confirmed: usize,
+    pub failed: usize,
+    pub pending: usize,
+    /// `<withdrawal public_id>: <error>` for each withdrawal that could not be updated.
+    pub errors: Vec<String>,
+}
@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+use crate::application::contracts::{PayoutRailError, SolvencyAlertError};
 use crate::domain::error::DomainError;
 use crate::domain::repository::RepoError;
 
@@ -14,6 +15,12 @@ pub enum AppError {
     #[error(transparent)]
     Uuid(#[from] uuid::Error),
 
+    #[error(transparent)]
+    PayoutRail(#[from] PayoutRailError),
+
+    #[error(transparent)]
+    SolvencyAlert(#[from] SolvencyAlertError),
+
     #[error("query repository error")]
     QueryRepo {
         #[source]
@@ -1,8 +1,12 @@
+use std::collections::BTreeMap;
+
 use crate::domain::value_objects::ExternalRefType;
 
 pub struct ListJournalsQuery {
     pub external_ref_type: Option<ExternalRefType>,
     pub created_by: Option<String>,
+    pub metadata: BTreeMap<String, String>,
+    pub tags: Vec<String>,
     pub limit: usize,
     pub offset: usize,
-}
\ No newline at end of file
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::AccountStatusService;
+use crate::application::contracts::repository::{AccountStatusRepository, UnitOfWork};
+use crate::application::dtos::{AccountStatusChangeDTO, ChangeAccountStatusDTO, LedgerAccountDTO};
+use crate::application::dtos::mappers::{map_account_to_dto, status_change_to_dto};
+use crate::application::AppError;
+
+use crate::domain::entities::{AccountStatus, StatusReasonCode};
+use crate::domain::repository::RepoError;
+
+pub struct AccountStatusServiceImpl<S: AccountStatusRepository, U: UnitOfWork> {
+    repo: Arc<S>,
+    uow: U,
+}
+
+impl<S, U> AccountStatusServiceImpl<S, U>
+where
+    S: AccountStatusRepository + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+{
+    pub fn new(repo: S, uow: U) -> Self {
+        Self { repo: Arc::new(repo), uow }
+    }
+}
+
+#[async_trait]
+impl<S, U> AccountStatusService for AccountStatusServiceImpl<S, U>
+where
+    S: AccountStatusRepository + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+{
+    async fn change_status(&self, req: ChangeAccountStatusDTO) -> Result<LedgerAccountDTO, AppError> {
+        let to = AccountStatus::from_code(&req.status)?;
+        let reason = StatusReasonCode::from_code(&req.reason_code)?;
+        let account_id = req.account_id;
+
+        // The transition is decided against the locked row and balance, so a
+        // concurrent posting cannot slip in between the zero-balance check and the close.
+        let repo = Arc::clone(&self.repo);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let (mut account, balance) = repo
+                    .lock_account_tx(tx, account_id)
+                    .await?
+                    .ok_or_else(|| RepoError::NotFound {
+                        entity: format!("ledger_account id={account_id}"),
+                    })?;
+
+                let change = match account.change_status(to, reason, req.note, &req.actor, balance, Utc::now()) {
+                    Ok(change) => change,
+                    Err(e) => return Ok(Err(e)),
+                };
+
+                repo.save_status_change_tx(tx, &change).await?;
+                Ok(Ok(account))
+            })
+        }).await?;
+
+        let account = result?;
+        Ok(map_account_to_dto(&account))
+    }
+
+    async fn status_timeline(&self, account_id: i64) -> Result<Vec<AccountStatusChangeDTO>, AppError> {
+        let timeline = self.repo.status_timeline(account_id).await?;
+        if timeline.entries().is_empty() {
+            return Err(AppError::NotFound { entity: format!("ledger_account id={account_id}") });
+        }
+        Ok(timeline.entries().iter().map(status_change_to_dto).collect())
+    }
+}
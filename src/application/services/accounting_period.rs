@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::AccountingPeriodService;
+use crate::application::contracts::repository::{
+    AccountingPeriodRepository, ChartOfAccountsRepository, UnitOfWork,
+};
+use crate::application::dtos::{AccountingPeriodDTO, BalanceSheetDTO, ClosingBalanceDTO, DefinePeriodDTO};
+use crate::application::dtos::mappers::{balance_sheet_to_dto, period_to_dto};
+use crate::application::AppError;
+
+use crate::domain::entities::{AccountingPeriod, NewAccountingPeriod, PeriodStatus};
+use crate::domain::repository::RepoError;
+use crate::domain::services::FinancialStatements;
+
+pub struct AccountingPeriodServiceImpl<P, C, U>
+where
+    P: AccountingPeriodRepository,
+    C: ChartOfAccountsRepository,
+    U: UnitOfWork,
+{
+    periods: Arc<P>,
+    chart: C,
+    uow: U,
+}
+
+impl<P, C, U> AccountingPeriodServiceImpl<P, C, U>
+where
+    P: AccountingPeriodRepository + Send + Sync + 'static,
+    C: ChartOfAccountsRepository + Send + Sync,
+    U: UnitOfWork + Send + Sync,
+{
+    pub fn new(periods: P, chart: C, uow: U) -> Self {
+        Self { periods: Arc::new(periods), chart, uow }
+    }
+
+    fn ensure_closed(period: &AccountingPeriod) -> Result<(), AppError> {
+        if period.status() != PeriodStatus::Closed {
+            return Err(AppError::InvalidRequest {
+                message: format!(
+                    "period {} is {}; closing balances exist once it is CLOSED",
+                    period.name(),
+                    period.status().as_code()
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    async fn load(&self, period_id: i64) -> Result<AccountingPeriod, AppError> {
+        self.periods
+            .find(period_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("accounting_period id={period_id}") })
+    }
+
+    async fn store(&self, spec: NewAccountingPeriod) -> Result<AccountingPeriodDTO, AppError> {
+        let period = self.periods.insert(&spec).await?;
+        Ok(period_to_dto(&period))
+    }
+
+    /// Moves the period to `to` under its row lock; closing also snapshots balances
+    /// in the same transaction, after in-flight postings into the period have committed.
+    async fn transition(
+        &self,
+        period_id: i64,
+        to: PeriodStatus,
+        actor: String,
+    ) -> Result<AccountingPeriodDTO, AppError> {
+        let periods = Arc::clone(&self.periods);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut period = periods
+                    .lock_tx(tx, period_id)
+                    .await?
+                    .ok_or_else(|| RepoError::NotFound {
+                        entity: format!("accounting_period id={period_id}"),
+                    })?;
+
+                if let Err(e) = period.transition(to, &actor, Utc::now()) {
+                    return Ok(Err(e));
+                }
+
+                periods.save_status_tx(tx, &period).await?;
+                if to == PeriodStatus::Closed {
+                    periods.snapshot_closing_balances_tx(tx, &period).await?;
+                }
+                Ok(Ok(period))
+            })
+        }).await?;
+
+        let period = result?;
+        Ok(period_to_dto(&period))
+    }
+}
+
+#[async_trait]
+impl<P, C, U> AccountingPeriodService for AccountingPeriodServiceImpl<P, C, U>
+where
+    P: AccountingPeriodRepository + Send + Sync + 'static,
+    C: ChartOfAccountsRepository + Send + Sync,
+    U: UnitOfWork + Send + Sync,
+{
+    async fn define_period(&self, req: DefinePeriodDTO) -> Result<AccountingPeriodDTO, AppError> {
+        let spec = AccountingPeriod::define(req.asset_id, req.name, req.starts_at, req.ends_at)?;
+        self.store(spec).await
+    }
+
+    async fn define_month(&self, asset_id: i16, year: i32, month: u32) -> Result<AccountingPeriodDTO, AppError> {
+        let spec = AccountingPeriod::monthly(asset_id, year, month)?;
+        self.store(spec).await
+    }
+
+    async fn list_periods(&self, asset_id: i16) -> Result<Vec<AccountingPeriodDTO>, AppError> {
+        let periods = self.periods.list(asset_id).await?;
+        Ok(periods.iter().map(period_to_dto).collect())
+    }
+
+    async fn soft_close(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError> {
+        self.transition(period_id, PeriodStatus::SoftClosed, actor).await
+    }
+
+    async fn reopen(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError> {
+        self.transition(period_id, PeriodStatus::Open, actor).await
+    }
+
+    async fn close(&self, period_id: i64, actor: String) -> Result<AccountingPeriodDTO, AppError> {
+        self.transition(period_id, PeriodStatus::Closed, actor).await
+    }
+
+    async fn closing_balances(&self, period_id: i64) -> Result<Vec<ClosingBalanceDTO>, AppError> {
+        let period = self.load(period_id).await?;
+        Self::ensure_closed(&period)?;
+
+        let mut balances: Vec<ClosingBalanceDTO> = self
+            .periods
+            .closing_balances(period_id)
+            .await?
+            .into_iter()
+            .map(|(account_id, balance_minor)| ClosingBalanceDTO { period_id, account_id, balance_minor })
+            .collect();
+        balances.sort_by_key(|b| b.account_id);
+        Ok(balances)
+    }
+
+    async fn closing_balance_sheet(&self, period_id: i64) -> Result<BalanceSheetDTO, AppError> {
+        let period = self.load(period_id).await?;
+        Self::ensure_closed(&period)?;
+
+        let chart = self.chart.load_chart().await?;
+        let balances = self.periods.closing_balances_by_node(period_id).await?;
+        let sheet = FinancialStatements::balance_sheet(&chart, period.asset_id(), period.ends_at(), &balances)?;
+        Ok(balance_sheet_to_dto(&sheet))
+    }
+}
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+use crate::application::contracts::ChangeFeedService;
+use crate::application::contracts::repository::{FeedCursorRepository, JournalQueryRepository};
+use crate::application::dtos::{CreateFeedConsumerDTO, FeedBatchDTO, FeedCursorDTO};
+use crate::application::dtos::mappers::{feed_cursor_to_dto, posted_to_dto};
+use crate::application::AppError;
+use crate::application::services::common::ensure_limit;
+
+use crate::domain::aggregate::{FeedCursor, PostedJournal};
+
+/// Longest a read may wait for new journals; callers behind proxies usually want less.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// How long each read in `stream` waits; an empty batch doubles as a keep-alive.
+const STREAM_WAIT: Duration = Duration::from_secs(15);
+
+pub struct ChangeFeedServiceImpl<Q, C>
+where
+    Q: JournalQueryRepository,
+    C: FeedCursorRepository,
+{
+    journals: Q,
+    cursors: C,
+    /// How often a waiting read checks for new journals.
+    poll_interval: Duration,
+}
+
+impl<Q, C> ChangeFeedServiceImpl<Q, C>
+where
+    Q: JournalQueryRepository + Send + Sync,
+    C: FeedCursorRepository + Send + Sync,
+{
+    pub fn new(journals: Q, cursors: C, poll_interval: Duration) -> Self {
+        Self { journals, cursors, poll_interval }
+    }
+
+    /// Pushes batches after `after_sequence` into `sink` until `shutdown` flips to true
+    /// or the receiver goes away, as a server-sent events endpoint does for one client.
+    /// Each batch starts after the last one, so nothing is skipped or sent twice.
+    pub async fn stream(
+        &self,
+        after_sequence: i64,
+        batch_size: usize,
+        sink: mpsc::Sender<FeedBatchDTO>,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        let mut after = after_sequence;
+        while !*shutdown.borrow() {
+            let batch = tokio::select! {
+                batch = self.read(after, batch_size, STREAM_WAIT) => batch?,
+                _ = shutdown.changed() => break,
+            };
+            after = batch.next_cursor;
+            if sink.send(batch).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    async fn cursor(&self, consumer: String) -> Result<FeedCursor, AppError> {
+        let consumer = FeedCursor::normalize(consumer)?;
+        self.cursors
+            .find(&consumer)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("feed_cursor consumer={consumer}") })
+    }
+
+    /// Journals after `after`, waiting up to `wait` for the first one to be posted.
+    async fn wait_for_journals(
+        &self,
+        after: i64,
+        limit: usize,
+        wait: Duration,
+    ) -> Result<Vec<PostedJournal>, AppError> {
+        let deadline = Instant::now() + wait;
+        loop {
+            // sequence numbers are handed out in commit order, so anything past `after`
+            // that is visible now stays the next journal
+            if self.journals.latest_sequence().await? > after {
+                return Ok(self.journals.journals_after_sequence(after, limit).await?);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(Vec::new());
+            }
+            tokio::time::sleep(self.poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    async fn batch(&self, after: i64, limit: usize, wait: Duration) -> Result<FeedBatchDTO, AppError> {
+        ensure_limit(limit)?;
+        if after < 0 {
+            return Err(AppError::InvalidRequest { message: "after_sequence must not be negative".to_string() });
+        }
+        if wait > MAX_WAIT {
+            return Err(AppError::InvalidRequest {
+                message: format!("wait must be at most {}s", MAX_WAIT.as_secs()),
+            });
+        }
+
+        let journals = self.wait_for_journals(after, limit, wait).await?;
+        let next_cursor = journals.last().map_or(after, |j| j.sequence);
+        let latest_sequence = self.journals.latest_sequence().await?.max(next_cursor);
+        Ok(FeedBatchDTO {
+            after_sequence: after,
+            journals: journals.iter().map(posted_to_dto).collect(),
+            next_cursor,
+            latest_sequence,
+        })
+    }
+}
+
+#[async_trait]
+impl<Q, C> ChangeFeedService for ChangeFeedServiceImpl<Q, C>
+where
+    Q: JournalQueryRepository + Send + Sync,
+    C: FeedCursorRepository + Send + Sync,
+{
+    async fn read(&self, after_sequence: i64, limit: usize, wait: Duration) -> Result<FeedBatchDTO, AppError> {
+        self.batch(after_sequence, limit, wait).await
+    }
+
+    async fn create_consumer(&self, req: CreateFeedConsumerDTO) -> Result<FeedCursorDTO, AppError> {
+        let latest = self.journals.latest_sequence().await?;
+        if req.start_after_sequence > latest {
+            return Err(AppError::InvalidRequest {
+                message: format!("start_after_sequence must be at most the latest sequence {latest}"),
+            });
+        }
+        let cursor = FeedCursor::new(req.consumer, req.start_after_sequence, Utc::now())?;
+        let stored = self.cursors.insert(&cursor).await?;
+        Ok(feed_cursor_to_dto(&stored, latest))
+    }
+
+    async fn find_consumer(&self, consumer: String) -> Result<Option<FeedCursorDTO>, AppError> {
+        let consumer = FeedCursor::normalize(consumer)?;
+        let Some(cursor) = self.cursors.find(&consumer).await? else {
+            return Ok(None);
+        };
+        let latest = self.journals.latest_sequence().await?;
+        Ok(Some(feed_cursor_to_dto(&cursor, latest)))
+    }
+
+    async fn list_consumers(&self, limit: usize, offset: usize) -> Result<Vec<FeedCursorDTO>, AppError> {
+        ensure_limit(limit)?;
+        let cursors = self.cursors.list(limit, offset).await?;
+        let latest = self.journals.latest_sequence().await?;
+        Ok(cursors.iter().map(|c| feed_cursor_to_dto(c, latest)).collect())
+    }
+
+    async fn read_consumer(&self, consumer: String, limit: usize, wait: Duration) -> Result<FeedBatchDTO, AppError> {
+        let cursor = self.cursor(consumer).await?;
+        self.batch(cursor.position(), limit, wait).await
+    }
+
+    async fn acknowledge(&self, consumer: String, sequence: i64) -> Result<FeedCursorDTO, AppError> {
+        let mut cursor = self.cursor(consumer).await?;
+        let latest = self.journals.latest_sequence().await?;
+        if sequence > latest {
+            return Err(AppError::InvalidRequest {
+                message: format!("sequence {sequence} is past the latest sequence {latest}"),
+            });
+        }
+
+        if cursor.advance(sequence, Utc::now())? {
+            cursor = self.cursors.save_position(&cursor).await?;
+        }
+        Ok(feed_cursor_to_dto(&cursor, latest))
+    }
+}
@@ -0,0 +1,74 @@
+// Helpers shared by the service implementations. Services keep the repositories their
+// transaction closures use behind `Arc`, since the closures passed to `UnitOfWork::with_tx`
+// must own what they capture.
+
+use std::future::Future;
+
+use tokio::sync::watch;
+
+use crate::application::AppError;
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::value_objects::PublicId;
+
+/// Largest page any list endpoint returns.
+pub(crate) const MAX_LIST_LIMIT: usize = 500;
+
+pub(crate) fn ensure_limit(limit: usize) -> Result<(), AppError> {
+    if limit == 0 || limit > MAX_LIST_LIMIT {
+        return Err(AppError::InvalidRequest {
+            message: format!("limit must be between 1 and {MAX_LIST_LIMIT}"),
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_public_id(public_id: &str) -> Result<PublicId, AppError> {
+    Ok(PublicId::new(uuid::Uuid::parse_str(public_id)?))
+}
+
+/// The row a service read before its transaction was gone once locked inside it.
+pub(crate) fn locked_not_found(entity: &str, public_id: PublicId) -> RepoError {
+    RepoError::NotFound { entity: format!("{entity} public_id={}", public_id.value()) }
+}
+
+pub(crate) async fn find_account<R>(
+    repo: &R,
+    owner_type: OwnerType,
+    owner_id: Option<uuid::Uuid>,
+    account_type: AccountType,
+    asset_id: i16,
+) -> Result<LedgerAccount, AppError>
+where
+    R: LedgerRepository + Send + Sync,
+{
+    repo.find_account(owner_type, owner_id, account_type, asset_id)
+        .await?
+        .ok_or_else(|| {
+            let owner = owner_id.map(|id| format!(" owner_id={id}")).unwrap_or_default();
+            AppError::NotFound { entity: format!("{} account{owner} asset_id={asset_id}", account_type.as_str()) }
+        })
+}
+
+/// Runs `pass` until `shutdown` flips to true. A pass that reports a full batch runs
+/// again straight away instead of waiting out `poll_interval`.
+pub(crate) async fn run_worker<F, Fut>(
+    poll_interval: std::time::Duration,
+    mut shutdown: watch::Receiver<bool>,
+    mut pass: F,
+) -> Result<(), AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<bool, AppError>>,
+{
+    while !*shutdown.borrow() {
+        if pass().await? {
+            continue;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(poll_interval) => {}
+            _ = shutdown.changed() => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::DepositService;
+use crate::application::contracts::repository::{DepositRepository, LedgerRepositoryTx, UnitOfWork};
+use crate::application::dtos::{DepositDTO, DetectDepositDTO, PendingDepositsDTO};
+use crate::application::dtos::mappers::deposit_to_dto;
+use crate::application::services::common::{find_account, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{DepositIntent, DepositPolicy, DepositStatus};
+use crate::domain::entities::{AccountType, OwnerType};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+
+pub struct DepositServiceImpl<R, RX, U, D>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    D: DepositRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    deposits: Arc<D>,
+    uow: U,
+    policy: DepositPolicy,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, D> DepositServiceImpl<R, RX, U, D>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    D: DepositRepository + Send + Sync + 'static,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, deposits: D, policy: DepositPolicy, dating: ValueDatingPolicy) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            deposits: Arc::new(deposits),
+            uow,
+            policy,
+            dating,
+        }
+    }
+
+    /// Records `confirmations` against the deposit and, when that reaches its threshold,
+    /// posts the credit in the same transaction. The credit is validated against a
+    /// preview first; the locked deposit decides whether it is still due.
+    async fn apply_confirmations(
+        &self,
+        preview: DepositIntent,
+        confirmations: u32,
+        actor: String,
+    ) -> Result<DepositIntent, AppError> {
+        if preview.status() == DepositStatus::Credited {
+            return Ok(preview);
+        }
+
+        let mut updated = preview.clone();
+        let changed = updated.record_confirmations(confirmations, Utc::now());
+        if !changed && !updated.is_creditable() {
+            return Ok(preview);
+        }
+
+        let posting = if updated.is_creditable() {
+            let source = preview.source();
+            let asset_id = preview.asset_id();
+            let source = find_account(&self.repo, source.owner_type(), None, source.account_type(), asset_id).await?;
+            let owner = Some(preview.owner_id());
+            let available = find_account(&self.repo, OwnerType::User, owner, AccountType::UserAvailable, asset_id).await?;
+            let draft = preview.credit_draft(&source, &available, &actor)?;
+            Some(validate_draft(&self.repo, draft, &self.dating).await?)
+        } else {
+            None
+        };
+
+        let public_id = preview.public_id();
+        let deposits = Arc::clone(&self.deposits);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut deposit = deposits
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("deposit", public_id))?;
+
+                // credited by a concurrent update, which posted the only credit
+                if deposit.status() == DepositStatus::Credited {
+                    return Ok(Ok(deposit));
+                }
+
+                let now = Utc::now();
+                let changed = deposit.record_confirmations(confirmations, now);
+                if deposit.is_creditable() {
+                    let Some(posting) = posting else {
+                        return Err(RepoError::Conflict {
+                            message: format!("deposit {} changed concurrently; retry", public_id.value()),
+                        });
+                    };
+                    let posted = match repo_tx.insert_posting_atomic_tx(tx, posting).await? {
+                        Ok(posted) => posted,
+                        Err(e) => return Ok(Err(e)),
+                    };
+                    if let Err(e) = deposit.mark_credited(posted.db_id, now) {
+                        return Ok(Err(e));
+                    }
+                } else if !changed {
+                    return Ok(Ok(deposit));
+                }
+
+                deposits.save_tx(tx, &deposit).await?;
+                Ok(Ok(deposit))
+            })
+        }).await?;
+
+        Ok(result?)
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, D> DepositService for DepositServiceImpl<R, RX, U, D>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    D: DepositRepository + Send + Sync + 'static,
+{
+    async fn detect(&self, req: DetectDepositDTO) -> Result<DepositDTO, AppError> {
+        let spec = DepositIntent::detect(
+            parse_public_id(&req.public_id)?,
+            uuid::Uuid::parse_str(&req.owner_id)?,
+            req.asset_id,
+            req.amount_minor,
+            req.tx_reference,
+            req.confirmations,
+            &self.policy,
+            &req.created_by,
+        )?;
+
+        let deposit = self.deposits.insert(&spec).await?;
+        if !spec.matches(&deposit) {
+            return Err(RepoError::Conflict {
+                message: format!(
+                    "deposit {} or transfer {} is already recorded with different details",
+                    spec.public_id.value(),
+                    spec.tx_reference
+                ),
+            }
+            .into());
+        }
+
+        // a repeated detection may carry a newer count; never move it backwards here
+        let confirmations = spec.confirmations.max(deposit.confirmations());
+        let deposit = self.apply_confirmations(deposit, confirmations, req.created_by).await?;
+        Ok(deposit_to_dto(&deposit))
+    }
+
+    async fn update_confirmations(
+        &self,
+        public_id: String,
+        confirmations: u32,
+        actor: String,
+    ) -> Result<DepositDTO, AppError> {
+        if actor.trim().is_empty() {
+            return Err(AppError::InvalidRequest { message: "actor is required".to_string() });
+        }
+        let public_id = parse_public_id(&public_id)?;
+        let deposit = self
+            .deposits
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("deposit public_id={}", public_id.value()) })?;
+
+        let deposit = self.apply_confirmations(deposit, confirmations, actor).await?;
+        Ok(deposit_to_dto(&deposit))
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<DepositDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.deposits.find_by_public_id(public_id).await?.as_ref().map(deposit_to_dto))
+    }
+
+    async fn pending(&self, owner_id: String, asset_id: i16) -> Result<PendingDepositsDTO, AppError> {
+        let owner = uuid::Uuid::parse_str(&owner_id)?;
+        let deposits = self.deposits.list_pending(owner, Some(asset_id)).await?;
+
+        Ok(PendingDepositsDTO {
+            owner_id: owner.to_string(),
+            asset_id,
+            pending_minor: deposits.iter().map(DepositIntent::amount_minor).sum(),
+            deposits: deposits.iter().map(deposit_to_dto).collect(),
+        })
+    }
+}
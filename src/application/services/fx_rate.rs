@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::FxRateService;
+use crate::application::contracts::repository::{FxRepository, LedgerRepositoryTx, UnitOfWork};
+use crate::application::dtos::{FxConversionDTO, FxQuoteDTO, FxRateDTO, RequestFxQuoteDTO, SetFxRateDTO};
+use crate::application::dtos::mappers::{fx_quote_to_dto, fx_rate_to_dto, posted_to_dto};
+use crate::application::services::common::{find_account, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{FxPolicy, FxQuote, FxQuoteStatus, FxRate, PostedJournal};
+use crate::domain::entities::{AccountType, OwnerType};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::{Asset, AssetCode, ExchangeRate, ExternalRef, ExternalRefType, PublicId};
+
+pub struct FxRateServiceImpl<R, RX, U, X>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    X: FxRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    fx: Arc<X>,
+    uow: U,
+    policy: FxPolicy,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, X> FxRateServiceImpl<R, RX, U, X>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    X: FxRepository + Send + Sync + 'static,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, fx: X, policy: FxPolicy, dating: ValueDatingPolicy) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            fx: Arc::new(fx),
+            uow,
+            policy,
+            dating,
+        }
+    }
+
+    async fn active_asset(&self, code: String) -> Result<Asset, AppError> {
+        let code = AssetCode::new(code)?;
+        self.repo
+            .find_asset_by_code(&code)
+            .await?
+            .filter(|a| a.is_active())
+            .ok_or_else(|| AppError::NotFound { entity: format!("active asset code={}", code.as_str()) })
+    }
+
+    /// The two legs an executed quote posted.
+    async fn posted_legs(&self, quote: &FxQuote) -> Result<(PostedJournal, PostedJournal), AppError> {
+        let (sell_ref, buy_ref) = quote.leg_refs()?;
+        Ok((self.posted_leg(&sell_ref).await?, self.posted_leg(&buy_ref).await?))
+    }
+
+    async fn posted_leg(&self, leg: &ExternalRef) -> Result<PostedJournal, AppError> {
+        self.repo
+            .find_posted_by_external_ref(ExternalRefType::FxConversion, leg)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("fx conversion journal {}", leg.as_str()) })
+    }
+
+    async fn find(&self, public_id: PublicId) -> Result<FxQuote, AppError> {
+        self.fx
+            .find_quote(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("fx quote public_id={}", public_id.value()) })
+    }
+}
+
+fn conversion_dto(quote: &FxQuote, sell: &PostedJournal, buy: &PostedJournal) -> FxConversionDTO {
+    FxConversionDTO {
+        quote: fx_quote_to_dto(quote, Utc::now()),
+        sell_journal: posted_to_dto(sell),
+        buy_journal: posted_to_dto(buy),
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, X> FxRateService for FxRateServiceImpl<R, RX, U, X>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    X: FxRepository + Send + Sync + 'static,
+{
+    async fn set_rate(&self, req: SetFxRateDTO) -> Result<FxRateDTO, AppError> {
+        let base = self.active_asset(req.base_asset_code).await?;
+        let quote = self.active_asset(req.quote_asset_code).await?;
+        let spec = FxRate::define(
+            base.id(),
+            quote.id(),
+            ExchangeRate::parse(&req.rate)?,
+            req.valid_from,
+            req.valid_to,
+            &req.source,
+            &req.created_by,
+        )?;
+
+        let rate = self.fx.insert_rate(&spec).await?;
+        Ok(fx_rate_to_dto(&rate))
+    }
+
+    async fn current_rate(&self, base_asset_code: String, quote_asset_code: String) -> Result<Option<FxRateDTO>, AppError> {
+        let base = self.active_asset(base_asset_code).await?;
+        let quote = self.active_asset(quote_asset_code).await?;
+        let rate = self.fx.rate_at(base.id(), quote.id(), Utc::now()).await?;
+        Ok(rate.as_ref().map(fx_rate_to_dto))
+    }
+
+    async fn rates(&self, base_asset_code: String, quote_asset_code: String, limit: usize) -> Result<Vec<FxRateDTO>, AppError> {
+        let base = self.active_asset(base_asset_code).await?;
+        let quote = self.active_asset(quote_asset_code).await?;
+        let rates = self.fx.list_rates(base.id(), quote.id(), limit).await?;
+        Ok(rates.iter().map(fx_rate_to_dto).collect())
+    }
+
+    async fn quote(&self, req: RequestFxQuoteDTO) -> Result<FxQuoteDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        let from = self.active_asset(req.from_asset_code).await?;
+        let to = self.active_asset(req.to_asset_code).await?;
+
+        let now = Utc::now();
+        let rate = self.fx.rate_at(from.id(), to.id(), now).await?.ok_or_else(|| AppError::NotFound {
+            entity: format!("fx rate {}/{} valid now", from.code().as_str(), to.code().as_str()),
+        })?;
+
+        let spec = FxQuote::issue(
+            public_id,
+            uuid::Uuid::parse_str(&req.owner_id)?,
+            &rate,
+            &from,
+            &to,
+            req.from_amount_minor,
+            &self.policy,
+            &req.created_by,
+            now,
+        )?;
+
+        let quote = self.fx.insert_quote(&spec).await?;
+        if !spec.matches(&quote) {
+            return Err(RepoError::Conflict {
+                message: format!("fx quote {} is already issued with different terms", public_id.value()),
+            }
+            .into());
+        }
+        Ok(fx_quote_to_dto(&quote, Utc::now()))
+    }
+
+    async fn execute(&self, quote_id: String, actor: String) -> Result<FxConversionDTO, AppError> {
+        if actor.trim().is_empty() {
+            return Err(AppError::InvalidRequest { message: "actor is required".to_string() });
+        }
+        let public_id = parse_public_id(&quote_id)?;
+        let preview = self.find(public_id).await?;
+
+        if preview.status() == FxQuoteStatus::Executed {
+            let (sell, buy) = self.posted_legs(&preview).await?;
+            return Ok(conversion_dto(&preview, &sell, &buy));
+        }
+        preview.ensure_executable(Utc::now())?;
+
+        let liquidity = preview.liquidity();
+        let (owner, from, to) = (Some(preview.owner_id()), preview.from_asset_id(), preview.to_asset_id());
+        let user_from = find_account(&self.repo, OwnerType::User, owner, AccountType::UserAvailable, from).await?;
+        let pool_from = find_account(&self.repo, liquidity.owner_type(), None, liquidity.account_type(), from).await?;
+        let pool_to = find_account(&self.repo, liquidity.owner_type(), None, liquidity.account_type(), to).await?;
+        let user_to = find_account(&self.repo, OwnerType::User, owner, AccountType::UserAvailable, to).await?;
+
+        let (sell, buy) = preview.conversion_drafts(&user_from, &pool_from, &pool_to, &user_to, &actor)?;
+        let sell = validate_draft(&self.repo, sell, &self.dating).await?;
+        let buy = validate_draft(&self.repo, buy, &self.dating).await?;
+
+        let fx = Arc::clone(&self.fx);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut quote = fx
+                    .lock_quote_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("fx quote", public_id))?;
+
+                // executed by a concurrent request, which posted the only legs
+                if quote.status() == FxQuoteStatus::Executed {
+                    return Ok(Ok((quote, None)));
+                }
+
+                let now = Utc::now();
+                if let Err(e) = quote.ensure_executable(now) {
+                    return Ok(Err(e));
+                }
+                let sell = match repo_tx.insert_posting_atomic_tx(tx, sell).await? {
+                    Ok(posted) => posted,
+                    Err(e) => return Ok(Err(e)),
+                };
+                let buy = match repo_tx.insert_posting_atomic_tx(tx, buy).await? {
+                    Ok(posted) => posted,
+                    Err(e) => return Ok(Err(e)),
+                };
+                if let Err(e) = quote.mark_executed(sell.db_id, buy.db_id, &actor, now) {
+                    return Ok(Err(e));
+                }
+
+                fx.save_quote_tx(tx, &quote).await?;
+                Ok(Ok((quote, Some((sell, buy)))))
+            })
+        }).await?;
+
+        let (quote, legs) = result?;
+        let (sell, buy) = match legs {
+            Some(legs) => legs,
+            None => self.posted_legs(&quote).await?,
+        };
+        Ok(conversion_dto(&quote, &sell, &buy))
+    }
+
+    async fn find_quote(&self, quote_id: String) -> Result<Option<FxQuoteDTO>, AppError> {
+        let public_id = parse_public_id(&quote_id)?;
+        let quote = self.fx.find_quote(public_id).await?;
+        Ok(quote.as_ref().map(|q| fx_quote_to_dto(q, Utc::now())))
+    }
+}
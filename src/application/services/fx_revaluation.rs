@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::FxRevaluationService;
+use crate::application::contracts::repository::{
+    AccountingPeriodRepository, FxRepository, FxRevaluationRepository, LedgerRepositoryTx, UnitOfWork,
+};
+use crate::application::dtos::{FxRevaluationDTO, FxRevaluationRunDTO, RunFxRevaluationDTO};
+use crate::application::dtos::mappers::{fx_revaluation_preview_to_dto, fx_revaluation_to_dto, posted_to_dto};
+use crate::application::services::common::{find_account, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{FxRevaluation, FxRevaluationStatus, NewFxRevaluation, PostedJournal, RevaluationLine};
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::{AssetCode, ExternalRefType, PublicId};
+
+pub struct FxRevaluationServiceImpl<R, RX, U, X, P, V>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    X: FxRepository,
+    P: AccountingPeriodRepository,
+    V: FxRevaluationRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    revaluations: Arc<V>,
+    uow: U,
+    fx: X,
+    periods: P,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, X, P, V> FxRevaluationServiceImpl<R, RX, U, X, P, V>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    X: FxRepository + Send + Sync,
+    P: AccountingPeriodRepository + Send + Sync,
+    V: FxRevaluationRepository + Send + Sync + 'static,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, fx: X, periods: P, revaluations: V, dating: ValueDatingPolicy) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            revaluations: Arc::new(revaluations),
+            uow,
+            fx,
+            periods,
+            dating,
+        }
+    }
+
+    /// Values the pools' foreign holdings at the period's end. Every non-zero holding
+    /// needs a rate into the reporting asset at both ends of the period.
+    async fn compute(&self, req: &RunFxRevaluationDTO, public_id: PublicId) -> Result<NewFxRevaluation, AppError> {
+        let code = AssetCode::new(req.reporting_asset_code.clone())?;
+        let reporting = self
+            .repo
+            .find_asset_by_code(&code)
+            .await?
+            .filter(|a| a.is_active())
+            .ok_or_else(|| AppError::NotFound { entity: format!("active asset code={}", code.as_str()) })?;
+        let period = self
+            .periods
+            .find(req.period_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("accounting period id={}", req.period_id) })?;
+
+        let positions = self.revaluations.positions_at(reporting.id(), period.ends_at()).await?;
+        let mut lines = Vec::with_capacity(positions.len());
+        for position in &positions {
+            let rate_at = |at| self.fx.rate_at(position.asset_id, reporting.id(), at);
+            let (opening, closing) = (rate_at(period.starts_at()).await?, rate_at(period.ends_at()).await?);
+            let (Some(opening), Some(closing)) = (opening, closing) else {
+                return Err(AppError::NotFound {
+                    entity: format!(
+                        "fx rate asset_id={} -> {} at the start and end of period {}",
+                        position.asset_id,
+                        code.as_str(),
+                        period.name()
+                    ),
+                });
+            };
+            lines.push(RevaluationLine::value(position, &reporting, &opening, &closing)?);
+        }
+
+        Ok(FxRevaluation::prepare(public_id, &reporting, &period, lines, &req.created_by, Utc::now())?)
+    }
+
+    /// The reserve and unrealised FX accounts in the reporting asset.
+    async fn accounts(&self, reporting_asset_id: i16) -> Result<(LedgerAccount, LedgerAccount), AppError> {
+        Ok((
+            find_account(&self.repo, OwnerType::Treasury, None, AccountType::TreasuryRevaluation, reporting_asset_id)
+                .await?,
+            find_account(&self.repo, OwnerType::Platform, None, AccountType::PlatformUnrealisedFx, reporting_asset_id)
+                .await?,
+        ))
+    }
+
+    /// The journals a run posted or, when `reversal`, the ones that reversed it.
+    async fn posted_journals(&self, run: &FxRevaluation, reversal: bool) -> Result<Vec<PostedJournal>, AppError> {
+        let mut journals = Vec::new();
+        for line in run.lines().iter().filter(|l| l.journal_id.is_some()) {
+            let external_ref = FxRevaluation::journal_ref(run.public_id(), line.asset_id, reversal)?;
+            let journal = self
+                .repo
+                .find_posted_by_external_ref(ExternalRefType::FxRevaluation, &external_ref)
+                .await?
+                .ok_or_else(|| AppError::NotFound {
+                    entity: format!("fx revaluation journal {}", external_ref.as_str()),
+                })?;
+            journals.push(journal);
+        }
+        Ok(journals)
+    }
+
+    async fn find_run(&self, public_id: PublicId) -> Result<FxRevaluation, AppError> {
+        self.revaluations
+            .find(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("fx revaluation public_id={}", public_id.value()) })
+    }
+}
+
+fn run_dto(run: &FxRevaluation, journals: &[PostedJournal]) -> FxRevaluationRunDTO {
+    FxRevaluationRunDTO {
+        revaluation: fx_revaluation_to_dto(run),
+        journals: journals.iter().map(posted_to_dto).collect(),
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, X, P, V> FxRevaluationService for FxRevaluationServiceImpl<R, RX, U, X, P, V>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    X: FxRepository + Send + Sync,
+    P: AccountingPeriodRepository + Send + Sync,
+    V: FxRevaluationRepository + Send + Sync + 'static,
+{
+    async fn preview(&self, req: RunFxRevaluationDTO) -> Result<FxRevaluationDTO, AppError> {
+        // previews are never stored, so any id will do
+        let spec = self.compute(&req, PublicId::new(uuid::Uuid::new_v4())).await?;
+        Ok(fx_revaluation_preview_to_dto(&spec))
+    }
+
+    async fn run(&self, req: RunFxRevaluationDTO) -> Result<FxRevaluationRunDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        if let Some(existing) = self.revaluations.find_posted_for_period(req.period_id).await? {
+            let journals = self.posted_journals(&existing, false).await?;
+            return Ok(run_dto(&existing, &journals));
+        }
+
+        // a run id is spent once, even when its run has been reversed
+        if self.revaluations.find(public_id).await?.is_some() {
+            return Err(RepoError::Conflict {
+                message: format!("fx revaluation {} already exists", public_id.value()),
+            }
+            .into());
+        }
+
+        let mut spec = self.compute(&req, public_id).await?;
+        let (adjustment, gain_loss) = self.accounts(spec.reporting_asset_id).await?;
+        let mut drafts = Vec::new();
+        for (asset_id, draft) in spec.journal_drafts(&adjustment, &gain_loss, &req.created_by)? {
+            drafts.push((asset_id, validate_draft(&self.repo, draft, &self.dating).await?));
+        }
+
+        let revaluations = Arc::clone(&self.revaluations);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut journals = Vec::with_capacity(drafts.len());
+                for (asset_id, validated) in drafts {
+                    let posted = match repo_tx.insert_posting_atomic_tx(tx, validated).await? {
+                        Ok(posted) => posted,
+                        Err(e) => return Ok(Err(e)),
+                    };
+                    spec.record_journal(asset_id, posted.db_id);
+                    journals.push(posted);
+                }
+                // a concurrent run posted the period first; this transaction's journals roll back
+                let Some(run) = revaluations.insert_tx(tx, &spec).await? else {
+                    return Err(RepoError::Conflict {
+                        message: format!("period id={} already has a posted fx revaluation", spec.period_id),
+                    });
+                };
+                Ok(Ok((run, journals)))
+            })
+        }).await;
+
+        match result {
+            Ok(Ok((run, journals))) => Ok(run_dto(&run, &journals)),
+            Ok(Err(e)) => Err(e.into()),
+            Err(e @ RepoError::Conflict { .. }) => match self.revaluations.find_posted_for_period(req.period_id).await? {
+                Some(existing) => {
+                    let journals = self.posted_journals(&existing, false).await?;
+                    Ok(run_dto(&existing, &journals))
+                }
+                None => Err(e.into()),
+            },
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn reverse(&self, revaluation_id: String, actor: String) -> Result<FxRevaluationRunDTO, AppError> {
+        if actor.trim().is_empty() {
+            return Err(AppError::InvalidRequest { message: "actor is required".to_string() });
+        }
+        let public_id = parse_public_id(&revaluation_id)?;
+        let preview = self.find_run(public_id).await?;
+        if preview.status() == FxRevaluationStatus::Reversed {
+            let journals = self.posted_journals(&preview, true).await?;
+            return Ok(run_dto(&preview, &journals));
+        }
+
+        let (adjustment, gain_loss) = self.accounts(preview.reporting_asset_id()).await?;
+        let mut drafts = Vec::new();
+        for (asset_id, draft) in preview.reversal_drafts(&adjustment, &gain_loss, &actor)? {
+            drafts.push((asset_id, validate_draft(&self.repo, draft, &self.dating).await?));
+        }
+
+        let revaluations = Arc::clone(&self.revaluations);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut run = revaluations
+                    .lock_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("fx revaluation", public_id))?;
+
+                // reversed by a concurrent request, which posted the only mirror journals
+                if run.status() == FxRevaluationStatus::Reversed {
+                    return Ok(Ok((run, None)));
+                }
+
+                let mut journals = Vec::with_capacity(drafts.len());
+                let mut reversal_ids = Vec::with_capacity(drafts.len());
+                for (asset_id, validated) in drafts {
+                    let posted = match repo_tx.insert_posting_atomic_tx(tx, validated).await? {
+                        Ok(posted) => posted,
+                        Err(e) => return Ok(Err(e)),
+                    };
+                    reversal_ids.push((asset_id, posted.db_id));
+                    journals.push(posted);
+                }
+                if let Err(e) = run.mark_reversed(&reversal_ids, &actor, Utc::now()) {
+                    return Ok(Err(e));
+                }
+
+                revaluations.save_tx(tx, &run).await?;
+                Ok(Ok((run, Some(journals))))
+            })
+        }).await?;
+
+        let (run, journals) = result?;
+        let journals = match journals {
+            Some(journals) => journals,
+            None => self.posted_journals(&run, true).await?,
+        };
+        Ok(run_dto(&run, &journals))
+    }
+
+    async fn find(&self, revaluation_id: String) -> Result<Option<FxRevaluationDTO>, AppError> {
+        let public_id = parse_public_id(&revaluation_id)?;
+        let run = self.revaluations.find(public_id).await?;
+        Ok(run.as_ref().map(fx_revaluation_to_dto))
+    }
+
+    async fn history(&self, period_id: i64) -> Result<Vec<FxRevaluationDTO>, AppError> {
+        let runs = self.revaluations.list_for_period(period_id).await?;
+        Ok(runs.iter().map(fx_revaluation_to_dto).collect())
+    }
+}
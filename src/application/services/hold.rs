@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+use tokio::sync::watch;
+
+use crate::application::contracts::HoldService;
+use crate::application::contracts::repository::{HoldRepository, LedgerRepositoryTx, UnitOfWork};
+use crate::application::dtos::{HoldDTO, HoldExpiryReportDTO, HoldJournalDTO, PlaceHoldDTO};
+use crate::application::dtos::mappers::{hold_journal_to_dto, hold_to_dto};
+use crate::application::services::common::{self, find_account, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{Hold, HoldAction, HoldJournal, NewHold, ValidatedJournal, EXPIRY_ACTOR};
+use crate::domain::entities::{AccountType, OwnerType};
+use crate::domain::error::DomainError;
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::PublicId;
+
+/// The journal a hold step posts, and how it is recorded against the hold.
+pub(crate) struct HoldStep {
+    pub(crate) posting: ValidatedJournal,
+    pub(crate) action: HoldAction,
+    pub(crate) amount_minor: i128,
+    pub(crate) counterparty_account_id: i64,
+    pub(crate) actor: String,
+}
+
+impl HoldStep {
+    pub(crate) async fn capture<R: LedgerRepository + Send + Sync>(
+        repo: &R,
+        dating: &ValueDatingPolicy,
+        hold: &Hold,
+        amount_minor: Option<i128>,
+        destination_account_id: i64,
+        actor: &str,
+    ) -> Result<Self, AppError> {
+        let (draft, amount) = hold.capture_draft(amount_minor, destination_account_id, actor)?;
+        Ok(Self {
+            posting: validate_draft(repo, draft, dating).await?,
+            action: HoldAction::Capture,
+            amount_minor: amount,
+            counterparty_account_id: destination_account_id,
+            actor: actor.to_string(),
+        })
+    }
+
+    /// Returns the remainder to `UserAvailable`; `action` is `Release` or `Expire`.
+    pub(crate) async fn release<R: LedgerRepository + Send + Sync>(
+        repo: &R,
+        dating: &ValueDatingPolicy,
+        hold: &Hold,
+        action: HoldAction,
+        actor: &str,
+    ) -> Result<Self, AppError> {
+        let draft = hold.release_draft(action, actor)?;
+        Ok(Self {
+            posting: validate_draft(repo, draft, dating).await?,
+            action,
+            amount_minor: hold.remaining_minor(),
+            counterparty_account_id: hold.available_account_id(),
+            actor: actor.to_string(),
+        })
+    }
+}
+
+/// Stores the hold and posts its placing journal inside `tx`. A hold already stored
+/// under the same id is returned as is. A rejected posting leaves the stored hold in
+/// `tx`, so run this under `with_domain_tx`.
+pub(crate) async fn place_hold_tx<H, RX>(
+    holds: &H,
+    repo_tx: &RX,
+    tx: &mut Transaction<'_, Postgres>,
+    spec: &NewHold,
+    posting: ValidatedJournal,
+) -> Result<Result<Hold, DomainError>, RepoError>
+where
+    H: HoldRepository + ?Sized,
+    RX: LedgerRepositoryTx + ?Sized,
+{
+    let Some(hold) = holds.insert_tx(tx, spec).await? else {
+        return holds
+            .lock_by_public_id_tx(tx, spec.public_id)
+            .await?
+            .ok_or_else(|| locked_not_found("hold", spec.public_id))
+            .map(Ok);
+    };
+
+    let posted = match repo_tx.insert_posting_atomic_tx(tx, posting).await? {
+        Ok(posted) => posted,
+        Err(e) => return Ok(Err(e)),
+    };
+    let journal = HoldJournal {
+        seq: 0,
+        action: HoldAction::Place,
+        amount_minor: spec.amount_minor,
+        journal_id: posted.db_id,
+        counterparty_account_id: spec.available_account_id,
+        actor: spec.created_by.clone(),
+        created_at: Utc::now(),
+    };
+    holds.record_journal_tx(tx, &hold, &journal).await?;
+    Ok(Ok(hold))
+}
+
+/// Posts `step` and applies `apply` to the locked hold inside `tx`. The step was built
+/// from a read of the hold with `expected_journals` journals; if another step landed in
+/// between, this one conflicts.
+pub(crate) async fn post_hold_step_tx<H, RX>(
+    holds: &H,
+    repo_tx: &RX,
+    tx: &mut Transaction<'_, Postgres>,
+    public_id: PublicId,
+    expected_journals: u32,
+    step: HoldStep,
+    apply: impl FnOnce(&mut Hold, DateTime<Utc>) -> Result<(), DomainError>,
+) -> Result<Result<Hold, DomainError>, RepoError>
+where
+    H: HoldRepository + ?Sized,
+    RX: LedgerRepositoryTx + ?Sized,
+{
+    let mut hold = holds
+        .lock_by_public_id_tx(tx, public_id)
+        .await?
+        .ok_or_else(|| locked_not_found("hold", public_id))?;
+
+    if hold.journal_count() != expected_journals {
+        return Err(RepoError::Conflict {
+            message: format!("hold {} changed concurrently; retry", public_id.value()),
+        });
+    }
+
+    let now = Utc::now();
+    let seq = hold.journal_count();
+    if let Err(e) = apply(&mut hold, now) {
+        return Ok(Err(e));
+    }
+
+    let posted = match repo_tx.insert_posting_atomic_tx(tx, step.posting).await? {
+        Ok(posted) => posted,
+        Err(e) => return Ok(Err(e)),
+    };
+    let journal = HoldJournal {
+        seq,
+        action: step.action,
+        amount_minor: step.amount_minor,
+        journal_id: posted.db_id,
+        counterparty_account_id: step.counterparty_account_id,
+        actor: step.actor,
+        created_at: now,
+    };
+    holds.record_journal_tx(tx, &hold, &journal).await?;
+    holds.save_tx(tx, &hold).await?;
+    Ok(Ok(hold))
+}
+
+pub struct HoldServiceImpl<R, RX, U, H>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    H: HoldRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    holds: Arc<H>,
+    uow: U,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, H> HoldServiceImpl<R, RX, U, H>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    H: HoldRepository + Send + Sync + 'static,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, holds: H, dating: ValueDatingPolicy) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            holds: Arc::new(holds),
+            uow,
+            dating,
+        }
+    }
+
+    /// Releases expired holds until `shutdown` flips to true.
+    pub async fn run_worker(
+        &self,
+        batch_size: usize,
+        poll_interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(poll_interval, shutdown, || async move {
+            Ok(self.expire_due(batch_size).await?.expired >= batch_size)
+        })
+        .await
+    }
+
+    async fn load(&self, public_id: PublicId) -> Result<Hold, AppError> {
+        self.holds
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("hold public_id={}", public_id.value()) })
+    }
+
+    async fn post_step(
+        &self,
+        preview: &Hold,
+        step: HoldStep,
+        apply: impl FnOnce(&mut Hold, DateTime<Utc>) -> Result<(), DomainError> + Send + 'static,
+    ) -> Result<Hold, AppError> {
+        let public_id = preview.public_id();
+        let expected = preview.journal_count();
+
+        let holds = Arc::clone(&self.holds);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                post_hold_step_tx(holds.as_ref(), repo_tx.as_ref(), tx, public_id, expected, step, apply).await
+            })
+        }).await?;
+
+        Ok(result?)
+    }
+
+    async fn expire_one(&self, public_id: PublicId) -> Result<(), AppError> {
+        let preview = self.load(public_id).await?;
+        let step = HoldStep::release(&self.repo, &self.dating, &preview, HoldAction::Expire, EXPIRY_ACTOR).await?;
+        self.post_step(&preview, step, |h, now| h.expire(now)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, H> HoldService for HoldServiceImpl<R, RX, U, H>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    H: HoldRepository + Send + Sync + 'static,
+{
+    async fn place(&self, req: PlaceHoldDTO) -> Result<HoldDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        if let Some(existing) = self.holds.find_by_public_id(public_id).await? {
+            return Ok(hold_to_dto(&existing));
+        }
+
+        let owner_id = uuid::Uuid::parse_str(&req.owner_id)?;
+        let owner = Some(owner_id);
+        let available = find_account(&self.repo, OwnerType::User, owner, AccountType::UserAvailable, req.asset_id).await?;
+        let locked = find_account(&self.repo, OwnerType::User, owner, AccountType::UserLocked, req.asset_id).await?;
+
+        let spec = Hold::place(
+            public_id,
+            &available,
+            &locked,
+            req.amount_minor,
+            req.expires_at,
+            req.description,
+            &req.created_by,
+            Utc::now(),
+        )?;
+        let posting = validate_draft(&self.repo, spec.place_draft()?, &self.dating).await?;
+
+        let holds = Arc::clone(&self.holds);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let hold = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move { place_hold_tx(holds.as_ref(), repo_tx.as_ref(), tx, &spec, posting).await })
+        }).await??;
+
+        Ok(hold_to_dto(&hold))
+    }
+
+    async fn capture(
+        &self,
+        public_id: String,
+        amount_minor: Option<i128>,
+        destination_account_id: i64,
+        actor: String,
+    ) -> Result<HoldDTO, AppError> {
+        let preview = self.load(parse_public_id(&public_id)?).await?;
+        preview.ensure_active(Utc::now())?;
+
+        let step =
+            HoldStep::capture(&self.repo, &self.dating, &preview, amount_minor, destination_account_id, &actor).await?;
+        let amount = step.amount_minor;
+        let hold = self.post_step(&preview, step, move |h, now| h.record_capture(amount, &actor, now)).await?;
+        Ok(hold_to_dto(&hold))
+    }
+
+    async fn release(&self, public_id: String, actor: String) -> Result<HoldDTO, AppError> {
+        let preview = self.load(parse_public_id(&public_id)?).await?;
+        preview.ensure_active(Utc::now())?;
+
+        let step = HoldStep::release(&self.repo, &self.dating, &preview, HoldAction::Release, &actor).await?;
+        let hold = self.post_step(&preview, step, move |h, now| h.record_release(&actor, now)).await?;
+        Ok(hold_to_dto(&hold))
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<HoldDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.holds.find_by_public_id(public_id).await?.as_ref().map(hold_to_dto))
+    }
+
+    async fn journals(&self, public_id: String) -> Result<Vec<HoldJournalDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        self.load(public_id).await?;
+        Ok(self.holds.journals(public_id).await?.iter().map(hold_journal_to_dto).collect())
+    }
+
+    async fn list_active(&self, owner_id: String, asset_id: Option<i16>) -> Result<Vec<HoldDTO>, AppError> {
+        let owner_id = uuid::Uuid::parse_str(&owner_id)?;
+        Ok(self.holds.list_active(owner_id, asset_id).await?.iter().map(hold_to_dto).collect())
+    }
+
+    async fn expire_due(&self, batch_size: usize) -> Result<HoldExpiryReportDTO, AppError> {
+        let due = self.holds.find_expired(Utc::now(), batch_size).await?;
+        let mut report = HoldExpiryReportDTO::default();
+
+        for public_id in due {
+            match self.expire_one(public_id).await {
+                Ok(()) => report.expired += 1,
+                Err(e) => {
+                    report.failed += 1;
+                    report.errors.push(format!("{}: {e}", public_id.value()));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
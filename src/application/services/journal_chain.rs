@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+
+use crate::application::contracts::JournalChainService;
+use crate::application::contracts::repository::JournalChainRepository;
+use crate::application::dtos::{ChainHeadDTO, ChainVerificationDTO};
+use crate::application::dtos::mappers::{chain_head_to_dto, chain_verification_to_dto};
+use crate::application::AppError;
+
+use crate::domain::services::ChainVerifier;
+
+pub struct JournalChainServiceImpl<C: JournalChainRepository> {
+    chain: C,
+}
+
+impl<C> JournalChainServiceImpl<C>
+where
+    C: JournalChainRepository + Send + Sync,
+{
+    pub fn new(chain: C) -> Self {
+        Self { chain }
+    }
+
+    fn ensure_batch(batch_size: usize) -> Result<(), AppError> {
+        if batch_size == 0 {
+            return Err(AppError::InvalidRequest { message: "batch_size must be at least 1".to_string() });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C> JournalChainService for JournalChainServiceImpl<C>
+where
+    C: JournalChainRepository + Send + Sync,
+{
+    async fn head(&self) -> Result<ChainHeadDTO, AppError> {
+        Ok(chain_head_to_dto(&self.chain.chain_head().await?))
+    }
+
+    async fn verify(&self, batch_size: usize) -> Result<ChainVerificationDTO, AppError> {
+        Self::ensure_batch(batch_size)?;
+        // read first: links posted while the walk runs are past it and not checked
+        let head = self.chain.chain_head().await?;
+
+        let mut verifier = ChainVerifier::new();
+        let mut after = 0;
+        'walk: loop {
+            let links = self.chain.chain_links(after, head.position, batch_size).await?;
+            for (link, journal) in &links {
+                if !verifier.check(link, journal.as_ref()) {
+                    break 'walk;
+                }
+                after = link.position;
+            }
+            if links.len() < batch_size {
+                break;
+            }
+        }
+
+        Ok(chain_verification_to_dto(&head, &verifier.finish(&head)))
+    }
+
+    async fn backfill(&self, batch_size: usize) -> Result<usize, AppError> {
+        Self::ensure_batch(batch_size)?;
+        Ok(self.chain.chain_unlinked(batch_size).await?)
+    }
+}
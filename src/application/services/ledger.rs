@@ -1,42 +1,71 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::application::contracts::LedgerService;
-use crate::application::contracts::repository::{LedgerRepositoryTx, UnitOfWork};
+use crate::application::contracts::repository::{
+    FeeScheduleRepository, JournalFilter, JournalQueryRepository, LedgerRepositoryTx, UnitOfWork,
+};
 use crate::application::dtos::{
-    CreateAccountDTO, LedgerAccountDTO,
+    AccountBalanceDTO, CreateAccountDTO, FeeRequestDTO, LedgerAccountDTO, ListJournalsFilterDTO,
     PostJournalRequestDTO, PostedJournalDTO,
 };
 use crate::application::dtos::mappers::{
     map_create_account_to_spec, map_account_to_dto,
     map_post_journal_request, posted_to_dto,
 };
+use crate::application::services::common::ensure_limit;
+use crate::application::services::posting::{apply_fees, validate_draft};
 use crate::application::AppError;
 
+use crate::domain::aggregate::JournalDraft;
 use crate::domain::repository::LedgerRepository;
+use crate::domain::services::{BalanceBasis, ValueDatingPolicy};
+use crate::domain::value_objects::{ExternalRefType, JournalMetadata, JournalTag};
 
-pub struct LedgerServiceImpl<R: LedgerRepository, RX: LedgerRepositoryTx, U: UnitOfWork> {
+pub struct LedgerServiceImpl<R: LedgerRepository, RX: LedgerRepositoryTx, U: UnitOfWork, F: FeeScheduleRepository> {
     repo: R,
-    repo_tx: RX,
+    repo_tx: Arc<RX>,
     uow: U,
+    fees: F,
+    dating: ValueDatingPolicy,
 }
 
-impl<R, RX, U> LedgerServiceImpl<R, RX, U>
+impl<R, RX, U, F> LedgerServiceImpl<R, RX, U, F>
 where
     R: LedgerRepository + Send + Sync,
-    RX: LedgerRepositoryTx + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
     U: UnitOfWork + Send + Sync,
+    F: FeeScheduleRepository + Send + Sync,
 {
-    pub fn new(repo: R, repo_tx: RX, uow: U) -> Self {
-        Self { repo, repo_tx, uow }
+    pub fn new(repo: R, repo_tx: RX, uow: U, fees: F, dating: ValueDatingPolicy) -> Self {
+        Self { repo, repo_tx: Arc::new(repo_tx), uow, fees, dating }
+    }
+
+    /// Appends fee lines for the draft's posting kind, charged to the requested payer.
+    async fn apply_fees(&self, draft: &mut JournalDraft, fee: FeeRequestDTO) -> Result<(), AppError> {
+        let payer = self
+            .repo
+            .get_accounts_by_ids(&[fee.payer_account_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::NotFound {
+                entity: format!("ledger_account id={}", fee.payer_account_id),
+            })?;
+
+        apply_fees(&self.repo, &self.fees, draft, &payer, fee.base_amount_minor).await
     }
 }
 
 #[async_trait]
-impl<R, RX, U> LedgerService for LedgerServiceImpl<R, RX, U>
+impl<R, RX, U, F> LedgerService for LedgerServiceImpl<R, RX, U, F>
 where
-    R: LedgerRepository + Send + Sync,
-    RX: LedgerRepositoryTx + Send + Sync,
+    R: LedgerRepository + JournalQueryRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
     U: UnitOfWork + Send + Sync,
+    F: FeeScheduleRepository + Send + Sync,
 {
     async fn create_account(&self, req: CreateAccountDTO) -> Result<LedgerAccountDTO, AppError> {
         let spec = map_create_account_to_spec(req)?;
@@ -48,19 +77,25 @@ where
         &self,
         req: PostJournalRequestDTO
     ) -> Result<PostedJournalDTO, AppError> {
-        let draft = map_post_journal_request(req)?;
+        let fee = req.fee.clone();
+        let mut draft = map_post_journal_request(req)?;
 
-        let result = self.uow.with_tx(|tx| {
-            Box::pin(async move {
-                // TODO:
-                // 1. Load accounts using repo inside tx
-                // 2. Validate draft.validate_with_accounts()
-                // 3. Call repo_tx.insert_posting_atomic_tx(tx, validated)
-                // 4. Return PostedJournal
+        if draft.external_ref_type == ExternalRefType::ManualAdjustment {
+            return Err(AppError::InvalidRequest {
+                message: "manual adjustments must be submitted for maker-checker approval".to_string(),
+            });
+        }
 
-                todo!("Implement domain posting flow");
-            })
-        }).await?;
+        if let Some(fee) = fee {
+            self.apply_fees(&mut draft, fee).await?;
+        }
+
+        let posting = validate_draft(&self.repo, draft, &self.dating).await?;
+
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move { repo_tx.insert_posting_atomic_tx(tx, posting).await })
+        }).await??;
 
         let dto = posted_to_dto(&result);
         Ok(dto)
@@ -80,4 +115,77 @@ where
 
         Ok(None)
     }
+
+    async fn list_journals(&self, filter: ListJournalsFilterDTO) -> Result<Vec<PostedJournalDTO>, AppError> {
+        ensure_limit(filter.limit)?;
+
+        let filter = JournalFilter {
+            external_ref_type: filter.external_ref_type,
+            created_by: filter.created_by,
+            metadata: JournalMetadata::new(filter.metadata)?,
+            tags: filter.tags.into_iter().map(JournalTag::new).collect::<Result<_, _>>()?,
+            limit: filter.limit,
+            offset: filter.offset,
+        };
+
+        let journals = self.repo.list_journals(&filter).await?;
+        Ok(journals.iter().map(posted_to_dto).collect())
+    }
+
+    async fn balance_as_of(
+        &self,
+        account_id: i64,
+        at: DateTime<Utc>,
+        basis: String,
+    ) -> Result<AccountBalanceDTO, AppError> {
+        let basis = BalanceBasis::from_code(&basis)?;
+        let account = self
+            .repo
+            .get_accounts_by_ids(&[account_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::NotFound { entity: format!("ledger_account id={account_id}") })?;
+
+        let balance_minor = self.repo.balance_as_of(account_id, at, basis).await?;
+        Ok(AccountBalanceDTO { account_id, asset_id: account.asset_id(), balance_minor })
+    }
+
+    async fn latest_sequence(&self) -> Result<i64, AppError> {
+        Ok(self.repo.latest_sequence().await?)
+    }
+
+    async fn journals_after_sequence(
+        &self,
+        after_sequence: i64,
+        limit: usize,
+    ) -> Result<Vec<PostedJournalDTO>, AppError> {
+        ensure_limit(limit)?;
+        if after_sequence < 0 {
+            return Err(AppError::InvalidRequest { message: "after_sequence must not be negative".to_string() });
+        }
+
+        let journals = self.repo.journals_after_sequence(after_sequence, limit).await?;
+        Ok(journals.iter().map(posted_to_dto).collect())
+    }
+
+    async fn balance_as_of_sequence(&self, account_id: i64, sequence: i64) -> Result<AccountBalanceDTO, AppError> {
+        // past the latest sequence the answer could still change as postings commit
+        let latest = self.repo.latest_sequence().await?;
+        if sequence < 0 || sequence > latest {
+            return Err(AppError::InvalidRequest {
+                message: format!("sequence must be between 0 and the latest committed sequence {latest}"),
+            });
+        }
+        let account = self
+            .repo
+            .get_accounts_by_ids(&[account_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::NotFound { entity: format!("ledger_account id={account_id}") })?;
+
+        let balance_minor = self.repo.balance_as_of_sequence(account_id, sequence).await?;
+        Ok(AccountBalanceDTO { account_id, asset_id: account.asset_id(), balance_minor })
+    }
 }
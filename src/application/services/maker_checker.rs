@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::application::contracts::ManualAdjustmentService;
+use crate::application::contracts::repository::{LedgerRepositoryTx, ManualAdjustmentRepository, UnitOfWork};
+use crate::application::dtos::{AdjustmentAuditEntryDTO, ManualAdjustmentDTO, PostJournalRequestDTO};
+use crate::application::dtos::mappers::{adjustment_to_dto, audit_entry_to_dto, map_post_journal_request};
+use crate::application::services::common::{locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{ApprovalPolicy, ManualAdjustment};
+use crate::domain::error::DomainError;
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::PublicId;
+
+pub struct ManualAdjustmentServiceImpl<R, RX, U, M>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    M: ManualAdjustmentRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    adjustments: Arc<M>,
+    uow: U,
+    policy: ApprovalPolicy,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, M> ManualAdjustmentServiceImpl<R, RX, U, M>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    M: ManualAdjustmentRepository + Send + Sync + 'static,
+{
+    pub fn new(
+        repo: R,
+        repo_tx: RX,
+        uow: U,
+        adjustments: M,
+        policy: ApprovalPolicy,
+        dating: ValueDatingPolicy,
+    ) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            adjustments: Arc::new(adjustments),
+            uow,
+            policy,
+            dating,
+        }
+    }
+
+    async fn load(&self, public_id: PublicId) -> Result<ManualAdjustment, AppError> {
+        self.adjustments
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound {
+                entity: format!("manual_adjustment public_id={}", public_id.value()),
+            })
+    }
+}
+
+/// Transitions are re-applied to the locked row; a rule that now fails means another
+/// checker got there first.
+fn conflict(e: DomainError) -> RepoError {
+    RepoError::Conflict { message: e.to_string() }
+}
+
+#[async_trait]
+impl<R, RX, U, M> ManualAdjustmentService for ManualAdjustmentServiceImpl<R, RX, U, M>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    M: ManualAdjustmentRepository + Send + Sync + 'static,
+{
+    async fn submit(&self, req: PostJournalRequestDTO) -> Result<ManualAdjustmentDTO, AppError> {
+        if req.fee.is_some() {
+            return Err(AppError::InvalidRequest {
+                message: "fees are not applied to manual adjustments".to_string(),
+            });
+        }
+
+        let draft = map_post_journal_request(req)?;
+        let validated = validate_draft(&self.repo, draft.clone(), &self.dating).await?;
+        let spec = ManualAdjustment::submit(draft, &validated, &self.policy, Utc::now())?;
+
+        let adjustments = Arc::clone(&self.adjustments);
+        let adjustment = self.uow.with_tx(move |tx| {
+            Box::pin(async move { adjustments.insert_tx(tx, &spec).await })
+        }).await?;
+
+        Ok(adjustment_to_dto(&adjustment))
+    }
+
+    async fn approve(&self, public_id: String, approver: String) -> Result<ManualAdjustmentDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+
+        // Check the transition and validate the journal outside the transaction;
+        // balances are re-checked under lock when posting.
+        let mut preview = self.load(public_id).await?;
+        let ready = preview.approve(&approver, Utc::now())?;
+        let posting = if ready {
+            Some(validate_draft(&self.repo, preview.draft().clone(), &self.dating).await?)
+        } else {
+            None
+        };
+
+        let adjustments = Arc::clone(&self.adjustments);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let adjustment = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut adjustment = adjustments
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("manual_adjustment", public_id))?;
+
+                let now = Utc::now();
+                if adjustment.approve(&approver, now).map_err(conflict)? {
+                    let posting = posting.ok_or_else(|| RepoError::Conflict {
+                        message: "manual adjustment was approved concurrently; retry".to_string(),
+                    })?;
+                    let posted = match repo_tx.insert_posting_atomic_tx(tx, posting).await? {
+                        Ok(posted) => posted,
+                        Err(e) => return Ok(Err(e)),
+                    };
+                    adjustment.mark_posted(posted.db_id, &approver, now).map_err(conflict)?;
+                }
+
+                adjustments.save_tx(tx, &mut adjustment).await?;
+                Ok(Ok(adjustment))
+            })
+        }).await??;
+
+        Ok(adjustment_to_dto(&adjustment))
+    }
+
+    async fn reject(
+        &self,
+        public_id: String,
+        approver: String,
+        reason: String,
+    ) -> Result<ManualAdjustmentDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+
+        let mut preview = self.load(public_id).await?;
+        preview.reject(&approver, &reason, Utc::now())?;
+
+        let adjustments = Arc::clone(&self.adjustments);
+        let adjustment = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut adjustment = adjustments
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("manual_adjustment", public_id))?;
+
+                adjustment.reject(&approver, &reason, Utc::now()).map_err(conflict)?;
+                adjustments.save_tx(tx, &mut adjustment).await?;
+                Ok(adjustment)
+            })
+        }).await?;
+
+        Ok(adjustment_to_dto(&adjustment))
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<ManualAdjustmentDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.adjustments.find_by_public_id(public_id).await?.as_ref().map(adjustment_to_dto))
+    }
+
+    async fn audit_trail(&self, public_id: String) -> Result<Vec<AdjustmentAuditEntryDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        self.load(public_id).await?;
+
+        let trail = self.adjustments.audit_trail(public_id).await?;
+        Ok(trail.iter().map(audit_entry_to_dto).collect())
+    }
+}
@@ -1,3 +1,21 @@
 mod ledger;
+mod posting;
+mod common;
 mod transfer;
-mod fx_rate;
\ No newline at end of file
+mod fx_rate;
+mod fx_revaluation;
+mod proof_of_reserves;
+mod solvency;
+mod journal_chain;
+mod change_feed;
+mod webhook;
+mod maker_checker;
+mod account_status;
+mod reporting;
+mod report_export;
+mod accounting_period;
+mod scheduler;
+mod pending_journal;
+mod hold;
+mod withdrawal;
+mod deposit;
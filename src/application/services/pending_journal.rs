@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tokio::sync::watch;
+
+use crate::application::contracts::PendingJournalService;
+use crate::application::contracts::repository::{LedgerRepositoryTx, PendingJournalRepository, UnitOfWork};
+use crate::application::dtos::{AuthorizePendingDTO, AvailableBalanceDTO, PendingJournalDTO};
+use crate::application::dtos::mappers::{available_balance_to_dto, map_post_journal_request, pending_to_dto};
+use crate::application::services::common::{self, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{PendingJournal, PendingStatus};
+use crate::domain::error::DomainError;
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::PublicId;
+
+pub struct PendingJournalServiceImpl<R, RX, U, P>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    P: PendingJournalRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    pendings: Arc<P>,
+    uow: U,
+    dating: ValueDatingPolicy,
+    max_window: Duration,
+}
+
+impl<R, RX, U, P> PendingJournalServiceImpl<R, RX, U, P>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    P: PendingJournalRepository + Send + Sync + 'static,
+{
+    pub fn new(
+        repo: R,
+        repo_tx: RX,
+        uow: U,
+        pendings: P,
+        dating: ValueDatingPolicy,
+        max_window: Duration,
+    ) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            pendings: Arc::new(pendings),
+            uow,
+            dating,
+            max_window,
+        }
+    }
+
+    /// Expires overdue pending journals until `shutdown` flips to true.
+    pub async fn run_worker(
+        &self,
+        batch_size: usize,
+        poll_interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(poll_interval, shutdown, || async move {
+            Ok(self.expire_due(batch_size).await? >= batch_size)
+        })
+        .await
+    }
+
+    async fn load(&self, public_id: PublicId) -> Result<PendingJournal, AppError> {
+        self.pendings
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound {
+                entity: format!("pending_journal public_id={}", public_id.value()),
+            })
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, P> PendingJournalService for PendingJournalServiceImpl<R, RX, U, P>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    P: PendingJournalRepository + Send + Sync + 'static,
+{
+    async fn authorize(&self, req: AuthorizePendingDTO) -> Result<PendingJournalDTO, AppError> {
+        if req.journal.fee.is_some() {
+            return Err(AppError::InvalidRequest {
+                message: "fees are not applied to pending journals".to_string(),
+            });
+        }
+
+        let draft = map_post_journal_request(req.journal)?;
+        let validated = validate_draft(&self.repo, draft, &self.dating).await?;
+        let spec = PendingJournal::authorize(&validated, req.expires_at, self.max_window, Utc::now())?;
+
+        let pendings = Arc::clone(&self.pendings);
+        let pending = self.uow.with_tx(move |tx| {
+            Box::pin(async move { pendings.authorize_tx(tx, &spec).await })
+        }).await?;
+
+        Ok(pending_to_dto(&pending))
+    }
+
+    async fn commit(
+        &self,
+        public_id: String,
+        amount_minor: Option<i128>,
+        actor: String,
+    ) -> Result<PendingJournalDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        let preview = self.load(public_id).await?;
+        if preview.status() == PendingStatus::Committed {
+            return Ok(pending_to_dto(&preview));
+        }
+
+        // Built outside the transaction; the locked row is re-checked before it posts.
+        let now = Utc::now();
+        let posting = if preview.status() == PendingStatus::Pending && !preview.is_expired(now) {
+            let draft = preview.commit_draft(amount_minor, &actor)?;
+            Some(validate_draft(&self.repo, draft, &self.dating).await?)
+        } else {
+            None
+        };
+        let amount = amount_minor.unwrap_or_else(|| preview.authorized_amount_minor());
+
+        let pendings = Arc::clone(&self.pendings);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        // A rejected posting rolls the release back, so the reservation stays; an expiry
+        // found here commits and is reported once the transaction is done.
+        let result = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut pending = pendings
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("pending_journal", public_id))?;
+
+                if pending.status() == PendingStatus::Committed {
+                    return Ok(Ok(pending));
+                }
+
+                let now = Utc::now();
+                // past its deadline: release it here rather than wait for the expiry worker
+                if pending.status() == PendingStatus::Pending && pending.is_expired(now) {
+                    pendings.release_tx(tx, &pending).await?;
+                    if let Err(e) = pending.expire(now) {
+                        return Ok(Err(e));
+                    }
+                    pendings.save_tx(tx, &pending).await?;
+                    return Ok(Ok(pending));
+                }
+                if let Err(e) = pending.ensure_open(now) {
+                    return Ok(Err(e));
+                }
+                let Some(posting) = posting else {
+                    return Ok(Err(DomainError::PendingJournalNotPending {
+                        status: preview.status().as_code().to_string(),
+                    }));
+                };
+
+                // Release first so the posting's funds check sees the reservation as spendable.
+                pendings.release_tx(tx, &pending).await?;
+                let posted = match repo_tx.insert_posting_atomic_tx(tx, posting).await? {
+                    Ok(posted) => posted,
+                    Err(e) => return Ok(Err(e)),
+                };
+                if let Err(e) = pending.mark_committed(posted.db_id, amount, &actor, now) {
+                    return Ok(Err(e));
+                }
+                pendings.save_tx(tx, &pending).await?;
+                Ok(Ok(pending))
+            })
+        }).await?;
+
+        let pending = result?;
+        if pending.status() == PendingStatus::Expired {
+            return Err(DomainError::PendingJournalExpired { expired_at: pending.expires_at().to_rfc3339() }.into());
+        }
+        Ok(pending_to_dto(&pending))
+    }
+
+    async fn void(&self, public_id: String, actor: String) -> Result<PendingJournalDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+
+        let pendings = Arc::clone(&self.pendings);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut pending = pendings
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("pending_journal", public_id))?;
+
+                if let Err(e) = pending.void(&actor, Utc::now()) {
+                    return Ok(Err(e));
+                }
+                pendings.release_tx(tx, &pending).await?;
+                pendings.save_tx(tx, &pending).await?;
+                Ok(Ok(pending))
+            })
+        }).await?;
+
+        let pending = result?;
+        Ok(pending_to_dto(&pending))
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<PendingJournalDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.pendings.find_by_public_id(public_id).await?.as_ref().map(pending_to_dto))
+    }
+
+    async fn expire_due(&self, batch_size: usize) -> Result<usize, AppError> {
+        let pendings = Arc::clone(&self.pendings);
+        let expired = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let now = Utc::now();
+                let mut due = pendings.lock_expired_tx(tx, now, batch_size).await?;
+                for pending in &mut due {
+                    pendings.release_tx(tx, pending).await?;
+                    pending
+                        .expire(now)
+                        .map_err(|e| RepoError::Conflict { message: e.to_string() })?;
+                    pendings.save_tx(tx, pending).await?;
+                }
+                Ok(due.len())
+            })
+        }).await?;
+
+        Ok(expired)
+    }
+
+    async fn available_balance(&self, account_id: i64) -> Result<AvailableBalanceDTO, AppError> {
+        let balance = self
+            .pendings
+            .available_balance(account_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("ledger_account id={account_id}") })?;
+        Ok(available_balance_to_dto(&balance))
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use crate::application::contracts::repository::FeeScheduleRepository;
+use crate::application::AppError;
+use crate::application::services::common::find_account;
+use crate::domain::aggregate::{JournalDraft, ValidatedJournal};
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::repository::LedgerRepository;
+use crate::domain::services::{FeeContext, FeeEngine, LedgerPostingService, PolicyValidatedJournal, ValueDatingPolicy};
+
+/// Runs the aggregate and policy checks for a draft against the current account snapshot.
+/// Balances, active flags and status on the value date are re-checked under lock by
+/// `insert_posting_atomic_tx`.
+pub(crate) async fn validate_draft<R>(
+    repo: &R,
+    draft: JournalDraft,
+    dating: &ValueDatingPolicy,
+) -> Result<ValidatedJournal, AppError>
+where
+    R: LedgerRepository + Send + Sync,
+{
+    dating.check(draft.effective_at, Utc::now())?;
+
+    let accounts = repo.get_accounts_by_ids(&draft.account_ids()).await?;
+    let by_id: HashMap<i64, &LedgerAccount> = accounts.iter().map(|a| (a.id(), a)).collect();
+
+    let validated = draft.validate_with_accounts(&by_id)?;
+    let PolicyValidatedJournal(validated) = LedgerPostingService::validate(validated, &by_id)?;
+
+    Ok(validated)
+}
+
+/// Appends fee lines for the draft's posting kind, charged to `payer` and collected in
+/// the platform revenue account of the payer's asset. No matching rule, no lines.
+pub(crate) async fn apply_fees<R, F>(
+    repo: &R,
+    fees: &F,
+    draft: &mut JournalDraft,
+    payer: &LedgerAccount,
+    base_amount_minor: i128,
+) -> Result<(), AppError>
+where
+    R: LedgerRepository + Send + Sync,
+    F: FeeScheduleRepository + Send + Sync,
+{
+    let schedule = fees.load_schedule(payer.asset_id(), draft.external_ref_type).await?;
+    if schedule.matching(payer.asset_id(), draft.external_ref_type).next().is_none() {
+        return Ok(());
+    }
+
+    let revenue = find_account(repo, OwnerType::Platform, None, AccountType::PlatformRevenue, payer.asset_id()).await?;
+
+    FeeEngine::apply(
+        &schedule,
+        draft,
+        &FeeContext {
+            asset_id: payer.asset_id(),
+            payer_account_id: payer.id(),
+            revenue_account_id: revenue.id(),
+            base_amount_minor,
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::contracts::ProofOfReservesService;
+use crate::application::contracts::repository::ReserveSnapshotRepository;
+use crate::application::dtos::{InclusionProofDTO, ReserveSnapshotDTO, TakeReserveSnapshotDTO};
+use crate::application::dtos::mappers::{inclusion_proof_from_dto, inclusion_proof_to_dto, reserve_snapshot_to_dto};
+use crate::application::AppError;
+use crate::application::services::common::parse_public_id;
+
+use crate::domain::aggregate::ReserveSnapshot;
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::verify_owner_inclusion;
+use crate::domain::value_objects::{Asset, AssetCode};
+
+pub struct ProofOfReservesServiceImpl<R, S>
+where
+    R: LedgerRepository,
+    S: ReserveSnapshotRepository,
+{
+    repo: R,
+    snapshots: S,
+}
+
+impl<R, S> ProofOfReservesServiceImpl<R, S>
+where
+    R: LedgerRepository + Send + Sync,
+    S: ReserveSnapshotRepository + Send + Sync,
+{
+    pub fn new(repo: R, snapshots: S) -> Self {
+        Self { repo, snapshots }
+    }
+
+    async fn asset(&self, code: String) -> Result<Asset, AppError> {
+        let code = AssetCode::new(code)?;
+        self.repo
+            .find_asset_by_code(&code)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("asset code={}", code.as_str()) })
+    }
+}
+
+/// A fresh 32-byte salt per snapshot, so owner hashes cannot be linked across snapshots.
+fn new_salt() -> Vec<u8> {
+    [Uuid::new_v4().into_bytes(), Uuid::new_v4().into_bytes()].concat()
+}
+
+#[async_trait]
+impl<R, S> ProofOfReservesService for ProofOfReservesServiceImpl<R, S>
+where
+    R: LedgerRepository + Send + Sync,
+    S: ReserveSnapshotRepository + Send + Sync,
+{
+    async fn take_snapshot(&self, req: TakeReserveSnapshotDTO) -> Result<ReserveSnapshotDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        if let Some(existing) = self.snapshots.find(public_id).await? {
+            return Ok(reserve_snapshot_to_dto(&existing));
+        }
+        let asset = self.asset(req.asset_code).await?;
+
+        let taken_at = Utc::now();
+        let liabilities = self.snapshots.owner_liabilities(asset.id()).await?;
+        let spec = ReserveSnapshot::take(public_id, asset.id(), liabilities, new_salt(), &req.created_by, taken_at)?;
+
+        let snapshot = self.snapshots.insert(&spec).await?;
+        if snapshot.asset_id() != spec.asset_id {
+            return Err(RepoError::Conflict {
+                message: format!("reserve snapshot {} is already taken for another asset", public_id.value()),
+            }
+            .into());
+        }
+        Ok(reserve_snapshot_to_dto(&snapshot))
+    }
+
+    async fn find_snapshot(&self, snapshot_id: String) -> Result<Option<ReserveSnapshotDTO>, AppError> {
+        let snapshot = self.snapshots.find(parse_public_id(&snapshot_id)?).await?;
+        Ok(snapshot.as_ref().map(reserve_snapshot_to_dto))
+    }
+
+    async fn latest_snapshot(&self, asset_code: String) -> Result<Option<ReserveSnapshotDTO>, AppError> {
+        let asset = self.asset(asset_code).await?;
+        let snapshot = self.snapshots.latest(asset.id()).await?;
+        Ok(snapshot.as_ref().map(reserve_snapshot_to_dto))
+    }
+
+    async fn inclusion_proof(&self, snapshot_id: String, owner_id: String) -> Result<Option<InclusionProofDTO>, AppError> {
+        let public_id = parse_public_id(&snapshot_id)?;
+        let owner_id = Uuid::parse_str(&owner_id)?;
+        let snapshot = self
+            .snapshots
+            .find(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("reserve snapshot public_id={}", public_id.value()) })?;
+
+        let proof = snapshot.proof_for(owner_id)?;
+        Ok(proof.as_ref().map(|p| inclusion_proof_to_dto(&snapshot, owner_id, p)))
+    }
+
+    fn verify_proof(&self, proof: &InclusionProofDTO) -> Result<(), AppError> {
+        let (root, parsed) = inclusion_proof_from_dto(proof)?;
+        let salt = hex::decode(&proof.salt)
+            .map_err(|_| AppError::InvalidRequest { message: "salt is not hex".to_string() })?;
+        verify_owner_inclusion(&root, Uuid::parse_str(&proof.owner_id)?, &salt, &parsed)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use serde::Serialize;
+
+use crate::application::dtos::{FinancialStatementsDTO, NodeBalanceDTO, ReportExportDTO};
+use crate::application::AppError;
+
+pub(crate) enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl ReportFormat {
+    pub(crate) fn from_code(s: &str) -> Result<Self, AppError> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            other => Err(AppError::InvalidRequest {
+                message: format!("unsupported report format: {other}"),
+            }),
+        }
+    }
+}
+
+/// One flat CSV record; statements share the layout and leave unused columns empty.
+#[derive(Serialize)]
+struct CsvRow<'a> {
+    statement: &'a str,
+    section: &'a str,
+    code: &'a str,
+    name: &'a str,
+    depth: Option<usize>,
+    opening_minor: Option<i128>,
+    increase_minor: Option<i128>,
+    decrease_minor: Option<i128>,
+    amount_minor: i128,
+}
+
+impl<'a> CsvRow<'a> {
+    fn total(statement: &'a str, name: &'a str, amount_minor: i128) -> Self {
+        Self {
+            statement,
+            section: "TOTAL",
+            code: "",
+            name,
+            depth: None,
+            opening_minor: None,
+            increase_minor: None,
+            decrease_minor: None,
+            amount_minor,
+        }
+    }
+
+    fn node(statement: &'a str, n: &'a NodeBalanceDTO) -> Self {
+        Self {
+            statement,
+            section: &n.gl_class,
+            code: &n.code,
+            name: &n.name,
+            depth: Some(n.depth),
+            opening_minor: None,
+            increase_minor: None,
+            decrease_minor: None,
+            amount_minor: n.total_minor,
+        }
+    }
+}
+
+pub(crate) fn render(
+    statements: &FinancialStatementsDTO,
+    format: ReportFormat,
+) -> Result<ReportExportDTO, AppError> {
+    let stem = format!(
+        "financial-statements-{}-{}-{}-{}",
+        statements.asset_id,
+        statements.basis.to_ascii_lowercase(),
+        statements.from.format("%Y%m%d"),
+        statements.to.format("%Y%m%d")
+    );
+
+    match format {
+        ReportFormat::Json => {
+            let body = serde_json::to_string_pretty(statements)
+                .map_err(|e| AppError::Unexpected { message: format!("json export: {e}") })?;
+            Ok(ReportExportDTO { content_type: "application/json".into(), file_name: format!("{stem}.json"), body })
+        }
+        ReportFormat::Csv => Ok(ReportExportDTO {
+            content_type: "text/csv".into(),
+            file_name: format!("{stem}.csv"),
+            body: render_csv(statements)?,
+        }),
+    }
+}
+
+fn render_csv(s: &FinancialStatementsDTO) -> Result<String, AppError> {
+    let bs = &s.balance_sheet;
+    let is = &s.income_statement;
+
+    let mut rows: Vec<CsvRow> = Vec::new();
+    rows.extend(bs.assets.iter().chain(&bs.liabilities).chain(&bs.equity).map(|n| CsvRow::node("BALANCE_SHEET", n)));
+    rows.push(CsvRow::total("BALANCE_SHEET", "total assets", bs.total_assets));
+    rows.push(CsvRow::total("BALANCE_SHEET", "total liabilities", bs.total_liabilities));
+    rows.push(CsvRow::total("BALANCE_SHEET", "total equity", bs.total_equity));
+    rows.push(CsvRow::total("BALANCE_SHEET", "current earnings", bs.current_earnings));
+
+    rows.extend(is.revenue.iter().chain(&is.expenses).map(|n| CsvRow::node("INCOME_STATEMENT", n)));
+    rows.push(CsvRow::total("INCOME_STATEMENT", "total revenue", is.total_revenue));
+    rows.push(CsvRow::total("INCOME_STATEMENT", "total expenses", is.total_expenses));
+    rows.push(CsvRow::total("INCOME_STATEMENT", "net income", is.net_income));
+
+    rows.extend(s.movement_summary.nodes.iter().map(|n| CsvRow {
+        statement: "MOVEMENT_SUMMARY",
+        section: &n.gl_class,
+        code: &n.code,
+        name: &n.name,
+        depth: Some(n.depth),
+        opening_minor: Some(n.opening_minor),
+        increase_minor: Some(n.increase_minor),
+        decrease_minor: Some(n.decrease_minor),
+        amount_minor: n.closing_minor,
+    }));
+
+    let mut w = csv::Writer::from_writer(Vec::new());
+    for row in &rows {
+        w.serialize(row).map_err(|e| AppError::Unexpected { message: format!("csv export: {e}") })?;
+    }
+    let bytes = w.into_inner().map_err(|e| AppError::Unexpected { message: format!("csv export: {e}") })?;
+    String::from_utf8(bytes).map_err(|e| AppError::Unexpected { message: format!("csv export: {e}") })
+}
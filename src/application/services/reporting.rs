@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::application::contracts::ReportingService;
+use crate::application::contracts::repository::ChartOfAccountsRepository;
+use crate::application::dtos::{
+    BalanceSheetDTO, CoaNodeDTO, FinancialStatementsDTO, IncomeStatementDTO, ReportExportDTO,
+};
+use crate::application::dtos::mappers::{
+    balance_sheet_to_dto, coa_node_to_dto, income_statement_to_dto, movement_summary_to_dto,
+};
+use crate::application::services::report_export::{self, ReportFormat};
+use crate::application::AppError;
+
+use crate::domain::repository::LedgerRepository;
+use crate::domain::services::{BalanceBasis, FinancialStatements};
+
+pub struct ReportingServiceImpl<C: ChartOfAccountsRepository, R: LedgerRepository> {
+    chart: C,
+    repo: R,
+}
+
+impl<C, R> ReportingServiceImpl<C, R>
+where
+    C: ChartOfAccountsRepository + Send + Sync,
+    R: LedgerRepository + Send + Sync,
+{
+    pub fn new(chart: C, repo: R) -> Self {
+        Self { chart, repo }
+    }
+
+    fn ensure_period(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<(), AppError> {
+        if from >= to {
+            return Err(AppError::InvalidRequest { message: "`from` must be before `to`".to_string() });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C, R> ReportingService for ReportingServiceImpl<C, R>
+where
+    C: ChartOfAccountsRepository + Send + Sync,
+    R: LedgerRepository + Send + Sync,
+{
+    async fn chart_of_accounts(&self) -> Result<Vec<CoaNodeDTO>, AppError> {
+        let chart = self.chart.load_chart().await?;
+        Ok(chart.nodes().iter().map(coa_node_to_dto).collect())
+    }
+
+    async fn assign_account_node(&self, account_id: i64, coa_node_id: i64) -> Result<(), AppError> {
+        let chart = self.chart.load_chart().await?;
+        let node = chart
+            .node(coa_node_id)
+            .ok_or_else(|| AppError::NotFound { entity: format!("coa_node id={coa_node_id}") })?;
+
+        let account = self
+            .repo
+            .get_accounts_by_ids(&[account_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::NotFound { entity: format!("ledger_account id={account_id}") })?;
+
+        // keeps customer funds on the liability side, platform clearing on the asset side, etc.
+        let expected = account.account_type().gl_class();
+        if node.gl_class != expected {
+            return Err(AppError::InvalidRequest {
+                message: format!(
+                    "{} accounts report under {}, node {} is {}",
+                    account.account_type().as_str(),
+                    expected.as_code(),
+                    node.code,
+                    node.gl_class.as_code()
+                ),
+            });
+        }
+
+        self.chart.assign_account(account_id, coa_node_id).await?;
+        Ok(())
+    }
+
+    async fn balance_sheet(&self, asset_id: i16) -> Result<BalanceSheetDTO, AppError> {
+        let chart = self.chart.load_chart().await?;
+        let balances = self.chart.balances_by_node(asset_id).await?;
+        let sheet = FinancialStatements::balance_sheet(&chart, asset_id, Utc::now(), &balances)?;
+        Ok(balance_sheet_to_dto(&sheet))
+    }
+
+    async fn balance_sheet_as_of(
+        &self,
+        asset_id: i16,
+        at: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<BalanceSheetDTO, AppError> {
+        let basis = BalanceBasis::from_code(basis)?;
+        let chart = self.chart.load_chart().await?;
+        let balances = self.chart.balances_by_node_as_of(asset_id, at, basis).await?;
+        let sheet = FinancialStatements::balance_sheet(&chart, asset_id, at, &balances)?;
+        Ok(balance_sheet_to_dto(&sheet))
+    }
+
+    async fn income_statement(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<IncomeStatementDTO, AppError> {
+        Self::ensure_period(from, to)?;
+        let basis = BalanceBasis::from_code(basis)?;
+
+        let chart = self.chart.load_chart().await?;
+        let movements = self.chart.movements_by_node(asset_id, from, to, basis).await?;
+        let statement = FinancialStatements::income_statement(&chart, asset_id, from, to, &movements)?;
+        Ok(income_statement_to_dto(&statement))
+    }
+
+    async fn financial_statements(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+    ) -> Result<FinancialStatementsDTO, AppError> {
+        Self::ensure_period(from, to)?;
+        let basis = BalanceBasis::from_code(basis)?;
+
+        let chart = self.chart.load_chart().await?;
+        let opening = self.chart.balances_by_node_as_of(asset_id, from, basis).await?;
+        let closing = self.chart.balances_by_node_as_of(asset_id, to, basis).await?;
+        let movements = self.chart.movements_by_node(asset_id, from, to, basis).await?;
+        let flows = self.chart.flows_by_node(asset_id, from, to, basis).await?;
+
+        let sheet = FinancialStatements::balance_sheet(&chart, asset_id, to, &closing)?;
+        let income = FinancialStatements::income_statement(&chart, asset_id, from, to, &movements)?;
+        let summary = FinancialStatements::movement_summary(&chart, asset_id, from, to, &opening, &flows)?;
+
+        Ok(FinancialStatementsDTO {
+            asset_id,
+            from,
+            to,
+            basis: basis.as_code().to_string(),
+            balance_sheet: balance_sheet_to_dto(&sheet),
+            income_statement: income_statement_to_dto(&income),
+            movement_summary: movement_summary_to_dto(&summary),
+        })
+    }
+
+    async fn export_financial_statements(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: &str,
+        format: &str,
+    ) -> Result<ReportExportDTO, AppError> {
+        let format = ReportFormat::from_code(format)?;
+        let statements = self.financial_statements(asset_id, from, to, basis).await?;
+        report_export::render(&statements, format)
+    }
+}
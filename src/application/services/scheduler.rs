@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::watch;
+
+use crate::application::contracts::JournalSchedulerService;
+use crate::application::contracts::repository::{LedgerRepositoryTx, ScheduledJournalRepository, UnitOfWork};
+use crate::application::dtos::{
+    CreateScheduleDTO, ScheduledJournalDTO, ScheduledJournalRunDTO, SchedulerRunReportDTO,
+};
+use crate::application::dtos::mappers::{map_post_journal_request, schedule_run_to_dto, schedule_to_dto};
+use crate::application::services::common::{self, ensure_limit, locked_not_found, parse_public_id};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{Recurrence, RetryPolicy, ScheduleStatus, ScheduledJournal};
+use crate::domain::error::DomainError;
+use crate::domain::repository::LedgerRepository;
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::PublicId;
+
+enum Outcome {
+    Posted,
+    Skipped,
+}
+
+pub struct JournalSchedulerImpl<R, RX, U, S>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    S: ScheduledJournalRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    schedules: Arc<S>,
+    uow: U,
+    dating: ValueDatingPolicy,
+    retry: RetryPolicy,
+    lease: Duration,
+}
+
+impl<R, RX, U, S> JournalSchedulerImpl<R, RX, U, S>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    S: ScheduledJournalRepository + Send + Sync + 'static,
+{
+    pub fn new(
+        repo: R,
+        repo_tx: RX,
+        uow: U,
+        schedules: S,
+        dating: ValueDatingPolicy,
+        retry: RetryPolicy,
+        lease: Duration,
+    ) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            schedules: Arc::new(schedules),
+            uow,
+            dating,
+            retry,
+            lease,
+        }
+    }
+
+    /// Polls for due schedules until `shutdown` flips to true.
+    pub async fn run_worker(
+        &self,
+        batch_size: usize,
+        poll_interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(poll_interval, shutdown, || async move {
+            Ok(self.run_due(batch_size).await?.claimed >= batch_size)
+        })
+        .await
+    }
+
+    async fn load(&self, public_id: PublicId) -> Result<ScheduledJournal, AppError> {
+        self.schedules
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound {
+                entity: format!("scheduled_journal public_id={}", public_id.value()),
+            })
+    }
+
+    /// Active with its pending occurrence at or before `now`. The claim lease moves
+    /// `due_at`, so the scheduled time is what decides.
+    fn is_due(schedule: &ScheduledJournal, now: DateTime<Utc>) -> bool {
+        schedule.status() == ScheduleStatus::Active && schedule.next_run_at().is_some_and(|at| at <= now)
+    }
+
+    /// Posts the pending occurrence of a claimed schedule and advances it in one transaction.
+    async fn run_occurrence(&self, public_id: PublicId) -> Result<Outcome, AppError> {
+        let preview = self.load(public_id).await?;
+        if !Self::is_due(&preview, Utc::now()) {
+            return Ok(Outcome::Skipped);
+        }
+
+        let occurrence = preview.next_occurrence();
+        let draft = preview.occurrence_draft(PublicId::new(uuid::Uuid::new_v4()))?;
+        let posting = validate_draft(&self.repo, draft, &self.dating).await?;
+
+        let schedules = Arc::clone(&self.schedules);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let outcome = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut schedule = schedules
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("scheduled_journal", public_id))?;
+
+                // paused, cancelled or posted by another worker since the preview
+                let now = Utc::now();
+                if !Self::is_due(&schedule, now) || schedule.next_occurrence() != occurrence {
+                    return Ok(Ok(Outcome::Skipped));
+                }
+
+                let posted = match repo_tx.insert_posting_atomic_tx(tx, posting).await? {
+                    Ok(posted) => posted,
+                    Err(e) => return Ok(Err(e)),
+                };
+                schedules.record_run_tx(tx, &schedule, posted.db_id).await?;
+                schedule.record_posted(now);
+                schedules.save_tx(tx, &schedule).await?;
+                Ok(Ok(Outcome::Posted))
+            })
+        }).await??;
+
+        Ok(outcome)
+    }
+
+    /// Backs the occurrence off in its own transaction; the posting transaction has rolled back.
+    async fn record_failure(&self, public_id: PublicId, error: String) -> Result<(), AppError> {
+        let schedules = Arc::clone(&self.schedules);
+        let retry = self.retry.clone();
+        self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut schedule = schedules
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("scheduled_journal", public_id))?;
+
+                if schedule.status() != ScheduleStatus::Active {
+                    return Ok(());
+                }
+                schedule.record_failure(error, Utc::now(), &retry);
+                schedules.save_tx(tx, &schedule).await
+            })
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn change_status(
+        &self,
+        public_id: String,
+        change: impl FnOnce(&mut ScheduledJournal, DateTime<Utc>) -> Result<(), DomainError> + Send + 'static,
+    ) -> Result<ScheduledJournalDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+
+        let schedules = Arc::clone(&self.schedules);
+        let result = self.uow.with_tx(move |tx| {
+            Box::pin(async move {
+                let mut schedule = schedules
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("scheduled_journal", public_id))?;
+
+                if let Err(e) = change(&mut schedule, Utc::now()) {
+                    return Ok(Err(e));
+                }
+                schedules.save_tx(tx, &schedule).await?;
+                Ok(Ok(schedule))
+            })
+        }).await?;
+
+        let schedule = result?;
+        Ok(schedule_to_dto(&schedule))
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, S> JournalSchedulerService for JournalSchedulerImpl<R, RX, U, S>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    S: ScheduledJournalRepository + Send + Sync + 'static,
+{
+    async fn create_schedule(&self, req: CreateScheduleDTO) -> Result<ScheduledJournalDTO, AppError> {
+        if req.journal.fee.is_some() {
+            return Err(AppError::InvalidRequest {
+                message: "fees are not applied to scheduled journals".to_string(),
+            });
+        }
+
+        let template = map_post_journal_request(req.journal)?;
+        let recurrence = Recurrence::from_parts(&req.recurrence, req.interval_seconds)?;
+        let spec = ScheduledJournal::create(template, recurrence, req.starts_at, req.ends_at, req.max_occurrences)?;
+
+        // Accounts and posting policy are checked now so a bad template fails fast;
+        // balances are only known when each occurrence posts.
+        let mut probe = spec.template.clone();
+        probe.effective_at = None;
+        validate_draft(&self.repo, probe, &self.dating).await?;
+
+        let schedule = self.schedules.insert(&spec).await?;
+        Ok(schedule_to_dto(&schedule))
+    }
+
+    async fn find_schedule(&self, public_id: String) -> Result<Option<ScheduledJournalDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.schedules.find_by_public_id(public_id).await?.as_ref().map(schedule_to_dto))
+    }
+
+    async fn list_schedules(
+        &self,
+        status: Option<String>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<ScheduledJournalDTO>, AppError> {
+        ensure_limit(limit)?;
+        let status = status.as_deref().map(ScheduleStatus::from_code).transpose()?;
+
+        let schedules = self.schedules.list(status, limit, offset).await?;
+        Ok(schedules.iter().map(schedule_to_dto).collect())
+    }
+
+    async fn pause(&self, public_id: String, actor: String) -> Result<ScheduledJournalDTO, AppError> {
+        self.change_status(public_id, move |s, now| s.pause(&actor, now)).await
+    }
+
+    async fn resume(
+        &self,
+        public_id: String,
+        actor: String,
+        skip_missed: bool,
+    ) -> Result<ScheduledJournalDTO, AppError> {
+        self.change_status(public_id, move |s, now| s.resume(&actor, skip_missed, now)).await
+    }
+
+    async fn cancel(&self, public_id: String, actor: String) -> Result<ScheduledJournalDTO, AppError> {
+        self.change_status(public_id, move |s, now| s.cancel(&actor, now)).await
+    }
+
+    async fn runs(&self, public_id: String) -> Result<Vec<ScheduledJournalRunDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        let schedule = self.load(public_id).await?;
+
+        let runs = self.schedules.runs(public_id).await?;
+        Ok(runs.iter().map(|r| schedule_run_to_dto(&schedule, r)).collect())
+    }
+
+    async fn run_due(&self, batch_size: usize) -> Result<SchedulerRunReportDTO, AppError> {
+        let claimed = self.schedules.claim_due(batch_size, self.lease).await?;
+        let mut report = SchedulerRunReportDTO { claimed: claimed.len(), ..Default::default() };
+
+        for public_id in claimed {
+            match self.run_occurrence(public_id).await {
+                Ok(Outcome::Posted) => report.posted += 1,
+                Ok(Outcome::Skipped) => report.skipped += 1,
+                Err(e) => {
+                    report.failed += 1;
+                    report.errors.push(format!("{}: {e}", public_id.value()));
+                    // if this fails too, the claim lease expires and the occurrence is retried
+                    if let Err(e) = self.record_failure(public_id, e.to_string()).await {
+                        report.errors.push(format!("{}: recording failure: {e}", public_id.value()));
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
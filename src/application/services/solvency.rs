@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::watch;
+use uuid::Uuid;
+
+use crate::application::contracts::{SolvencyAlert, SolvencyAlertSink, SolvencyService};
+use crate::application::contracts::repository::SolvencyReportRepository;
+use crate::application::dtos::{RunSolvencyReportDTO, SolvencyReportDTO, SolvencyRunReportDTO};
+use crate::application::dtos::mappers::solvency_report_to_dto;
+use crate::application::AppError;
+use crate::application::services::common::{self, ensure_limit, parse_public_id};
+
+use crate::domain::aggregate::{SolvencyReport, SolvencyStatus, SolvencyTrigger, SOLVENCY_SCHEDULE_ACTOR};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::value_objects::{Asset, AssetCode, PublicId};
+
+pub struct SolvencyServiceImpl<R, S, A>
+where
+    R: LedgerRepository,
+    S: SolvencyReportRepository,
+    A: SolvencyAlertSink,
+{
+    repo: R,
+    reports: S,
+    alerts: A,
+}
+
+impl<R, S, A> SolvencyServiceImpl<R, S, A>
+where
+    R: LedgerRepository + Send + Sync,
+    S: SolvencyReportRepository + Send + Sync,
+    A: SolvencyAlertSink + Send + Sync,
+{
+    pub fn new(repo: R, reports: S, alerts: A) -> Self {
+        Self { repo, reports, alerts }
+    }
+
+    /// Runs the scheduled report every `interval` until `shutdown` flips to true.
+    /// Failures for one asset are reported in the pass's result and do not stop the worker.
+    pub async fn run_worker(
+        &self,
+        interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(interval, shutdown, || async move {
+            self.run_scheduled().await?;
+            Ok(false)
+        })
+        .await
+    }
+
+    async fn asset(&self, code: String) -> Result<Asset, AppError> {
+        let code = AssetCode::new(code)?;
+        self.repo
+            .find_asset_by_code(&code)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("asset code={}", code.as_str()) })
+    }
+
+    /// Assesses the asset as it stands now, stores the report and alerts on a shortfall.
+    async fn report(
+        &self,
+        public_id: PublicId,
+        asset_id: i16,
+        trigger: SolvencyTrigger,
+        requested_by: &str,
+    ) -> Result<SolvencyReport, AppError> {
+        let balances = self.reports.balances(asset_id).await?;
+        let spec = SolvencyReport::assess(public_id, asset_id, balances, trigger, requested_by, Utc::now())?;
+
+        let report = self.reports.insert(&spec).await?;
+        if report.asset_id() != asset_id {
+            return Err(RepoError::Conflict {
+                message: format!("solvency report {} was run for another asset", public_id.value()),
+            }
+            .into());
+        }
+        self.alert_if_short(report).await
+    }
+
+    /// Raises the alert for an unalerted shortfall and records that it went out. An
+    /// undelivered alert leaves the report unalerted, so running it again retries.
+    async fn alert_if_short(&self, mut report: SolvencyReport) -> Result<SolvencyReport, AppError> {
+        if !report.needs_alert() {
+            return Ok(report);
+        }
+        self.alerts
+            .raise(&SolvencyAlert {
+                report_id: report.public_id(),
+                asset_id: report.asset_id(),
+                as_of: report.as_of(),
+                liabilities_minor: report.liabilities_minor(),
+                holdings_minor: report.holdings_minor(),
+                shortfall_minor: -report.surplus_minor(),
+                coverage_bps: report.coverage_bps(),
+            })
+            .await?;
+
+        let at = Utc::now();
+        self.reports.mark_alerted(report.id(), at).await?;
+        report.mark_alerted(at)?;
+        Ok(report)
+    }
+}
+
+#[async_trait]
+impl<R, S, A> SolvencyService for SolvencyServiceImpl<R, S, A>
+where
+    R: LedgerRepository + Send + Sync,
+    S: SolvencyReportRepository + Send + Sync,
+    A: SolvencyAlertSink + Send + Sync,
+{
+    async fn run(&self, req: RunSolvencyReportDTO) -> Result<SolvencyReportDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        if let Some(existing) = self.reports.find(public_id).await? {
+            let existing = self.alert_if_short(existing).await?;
+            return Ok(solvency_report_to_dto(&existing));
+        }
+        let asset = self.asset(req.asset_code).await?;
+
+        let report = self.report(public_id, asset.id(), SolvencyTrigger::OnDemand, &req.requested_by).await?;
+        Ok(solvency_report_to_dto(&report))
+    }
+
+    async fn run_scheduled(&self) -> Result<SolvencyRunReportDTO, AppError> {
+        let asset_ids = self.reports.active_asset_ids().await?;
+        let mut run = SolvencyRunReportDTO::default();
+
+        for asset_id in asset_ids {
+            let public_id = PublicId::new(Uuid::new_v4());
+            match self.report(public_id, asset_id, SolvencyTrigger::Scheduled, SOLVENCY_SCHEDULE_ACTOR).await {
+                Ok(report) => {
+                    if report.status() == SolvencyStatus::Shortfall {
+                        run.shortfalls += 1;
+                    }
+                    run.reports.push(solvency_report_to_dto(&report));
+                }
+                Err(e) => {
+                    run.failed += 1;
+                    run.errors.push(format!("{asset_id}: {e}"));
+                }
+            }
+        }
+
+        Ok(run)
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<SolvencyReportDTO>, AppError> {
+        let report = self.reports.find(parse_public_id(&public_id)?).await?;
+        Ok(report.as_ref().map(solvency_report_to_dto))
+    }
+
+    async fn history(&self, asset_code: String, limit: usize, offset: usize) -> Result<Vec<SolvencyReportDTO>, AppError> {
+        ensure_limit(limit)?;
+        let asset = self.asset(asset_code).await?;
+        let reports = self.reports.history(asset.id(), limit, offset).await?;
+        Ok(reports.iter().map(solvency_report_to_dto).collect())
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::contracts::TransferService;
+use crate::application::contracts::repository::{FeeScheduleRepository, LedgerRepositoryTx, UnitOfWork};
+use crate::application::dtos::{PostedJournalDTO, TransferRequestDTO};
+use crate::application::dtos::mappers::posted_to_dto;
+use crate::application::services::common::find_account;
+use crate::application::services::posting::{apply_fees, validate_draft};
+use crate::application::AppError;
+
+use crate::domain::aggregate::TransferIntent;
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::repository::LedgerRepository;
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::{AssetCode, ExternalRef, ExternalRefType};
+
+pub struct TransferServiceImpl<R, RX, U, F>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    F: FeeScheduleRepository,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    uow: U,
+    fees: F,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, F> TransferServiceImpl<R, RX, U, F>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    F: FeeScheduleRepository + Send + Sync,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, fees: F, dating: ValueDatingPolicy) -> Self {
+        Self { repo, repo_tx: Arc::new(repo_tx), uow, fees, dating }
+    }
+
+    async fn user_available(&self, owner_id: &str, asset_id: i16) -> Result<LedgerAccount, AppError> {
+        let owner = uuid::Uuid::parse_str(owner_id)?;
+        find_account(&self.repo, OwnerType::User, Some(owner), AccountType::UserAvailable, asset_id).await
+    }
+}
+
+#[async_trait]
+impl<R, RX, U, F> TransferService for TransferServiceImpl<R, RX, U, F>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    F: FeeScheduleRepository + Send + Sync,
+{
+    async fn transfer(&self, req: TransferRequestDTO) -> Result<PostedJournalDTO, AppError> {
+        let key = ExternalRef::new(req.idempotency_key)?;
+
+        // a replay answers with the original even if an account has since been restricted
+        if let Some(posted) = self.repo.find_posted_by_external_ref(ExternalRefType::TransferIntent, &key).await? {
+            return Ok(posted_to_dto(&posted));
+        }
+
+        let code = AssetCode::new(req.asset_code)?;
+        let asset = self
+            .repo
+            .find_asset_by_code(&code)
+            .await?
+            .filter(|a| a.is_active())
+            .ok_or_else(|| AppError::NotFound { entity: format!("active asset code={}", code.as_str()) })?;
+
+        let sender = self.user_available(&req.sender_owner_id, asset.id()).await?;
+        let receiver = self.user_available(&req.receiver_owner_id, asset.id()).await?;
+        let intent = TransferIntent::new(key, &sender, &receiver, req.amount_minor, &req.created_by, req.description)?;
+
+        let mut draft = intent.draft()?;
+        if req.charge_fee {
+            apply_fees(&self.repo, &self.fees, &mut draft, &sender, intent.amount_minor()).await?;
+        }
+        let posting = validate_draft(&self.repo, draft, &self.dating).await?;
+
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let posted = self.uow.with_tx(move |tx| {
+            Box::pin(async move { repo_tx.insert_posting_atomic_tx(tx, posting).await })
+        }).await??;
+
+        Ok(posted_to_dto(&posted))
+    }
+
+    async fn find(&self, idempotency_key: String) -> Result<Option<PostedJournalDTO>, AppError> {
+        let key = ExternalRef::new(idempotency_key)?;
+        let posted = self.repo.find_posted_by_external_ref(ExternalRefType::TransferIntent, &key).await?;
+        Ok(posted.as_ref().map(posted_to_dto))
+    }
+}
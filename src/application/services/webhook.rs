@@ -0,0 +1,374 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tokio::sync::watch;
+use uuid::Uuid;
+
+use crate::application::contracts::{BalanceChange, WebhookRequest, WebhookService, WebhookTransport};
+use crate::application::contracts::repository::{FeedCursorRepository, JournalQueryRepository, WebhookRepository};
+use crate::application::dtos::{
+    CreateWebhookSubscriptionDTO, WebhookDeliveryDTO, WebhookDeliveryRunDTO, WebhookDispatchReportDTO, WebhookEventDTO,
+    WebhookSubscriptionDTO,
+};
+use crate::application::dtos::mappers::{posted_to_dto, webhook_delivery_to_dto, webhook_subscription_to_dto};
+use crate::application::AppError;
+use crate::application::services::common::{self, ensure_limit, parse_public_id};
+
+use crate::domain::aggregate::{
+    FeedCursor, NewWebhookDelivery, PostedJournal, RetryPolicy, WebhookDelivery, WebhookDeliveryStatus,
+    WebhookEventType, WebhookSubscription, WebhookUrlPolicy, WEBHOOK_CONSUMER,
+};
+use crate::domain::entities::LedgerAccount;
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::sign_webhook;
+use crate::domain::value_objects::PublicId;
+
+pub struct WebhookServiceImpl<R, Q, C, W, T>
+where
+    R: LedgerRepository,
+    Q: JournalQueryRepository,
+    C: FeedCursorRepository,
+    W: WebhookRepository,
+    T: WebhookTransport,
+{
+    repo: R,
+    journals: Q,
+    cursors: C,
+    webhooks: W,
+    transport: T,
+    retry: RetryPolicy,
+    url_policy: WebhookUrlPolicy,
+    lease: Duration,
+}
+
+impl<R, Q, C, W, T> WebhookServiceImpl<R, Q, C, W, T>
+where
+    R: LedgerRepository + Send + Sync,
+    Q: JournalQueryRepository + Send + Sync,
+    C: FeedCursorRepository + Send + Sync,
+    W: WebhookRepository + Send + Sync,
+    T: WebhookTransport + Send + Sync,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo: R,
+        journals: Q,
+        cursors: C,
+        webhooks: W,
+        transport: T,
+        retry: RetryPolicy,
+        url_policy: WebhookUrlPolicy,
+        lease: Duration,
+    ) -> Self {
+        Self { repo, journals, cursors, webhooks, transport, retry, url_policy, lease }
+    }
+
+    /// Dispatches committed journals and delivers what is due until `shutdown` flips to true.
+    pub async fn run_worker(
+        &self,
+        batch_size: usize,
+        poll_interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(poll_interval, shutdown, || async move {
+            let dispatched = self.dispatch_committed(batch_size).await?;
+            let delivered = self.deliver_due(batch_size).await?;
+            Ok(dispatched.journals >= batch_size || delivered.claimed >= batch_size)
+        })
+        .await
+    }
+
+    async fn subscription(&self, public_id: &str) -> Result<WebhookSubscription, AppError> {
+        let public_id = parse_public_id(public_id)?;
+        self.webhooks
+            .find_subscription(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("webhook_subscription public_id={}", public_id.value()) })
+    }
+
+    /// The webhook consumer's cursor. The first dispatch starts from the latest journal:
+    /// history before webhooks were switched on is not replayed.
+    async fn cursor(&self) -> Result<FeedCursor, AppError> {
+        if let Some(cursor) = self.cursors.find(WEBHOOK_CONSUMER).await? {
+            return Ok(cursor);
+        }
+        let latest = self.journals.latest_sequence().await?;
+        let cursor = FeedCursor::new(WEBHOOK_CONSUMER, latest, Utc::now())?;
+        Ok(self.cursors.insert(&cursor).await?)
+    }
+
+    /// The deliveries `journal` causes: for each subscription of an owner whose accounts it
+    /// touches, the journal trimmed to that owner's lines and/or the new balance of each of
+    /// those accounts. Subscriptions created after the posting do not get it.
+    async fn events_for(
+        &self,
+        journal: &PostedJournal,
+        owned: &HashMap<i64, LedgerAccount>,
+        subscriptions: &[WebhookSubscription],
+    ) -> Result<Vec<NewWebhookDelivery>, AppError> {
+        let mut accounts_by_owner: HashMap<Uuid, Vec<i64>> = HashMap::new();
+        for line in &journal.lines {
+            if let Some(owner) = owned.get(&line.account_id).and_then(|a| a.owner_id()) {
+                accounts_by_owner.entry(owner).or_default().push(line.account_id);
+            }
+        }
+
+        let mut balances: HashMap<i64, i128> = HashMap::new();
+        let mut out = Vec::new();
+        for sub in subscriptions {
+            let Some(accounts) = accounts_by_owner.get(&sub.owner_id()) else { continue };
+            if journal.posted_at < sub.created_at() {
+                continue;
+            }
+
+            if sub.wants(WebhookEventType::JournalPosted) {
+                let mut own = journal.clone();
+                own.lines.retain(|l| accounts.contains(&l.account_id));
+                let data = serde_json::to_value(posted_to_dto(&own)).map_err(payload_error)?;
+                let event_id = format!("journal.posted:{}", journal.sequence);
+                out.push(self.delivery(sub, event_id, WebhookEventType::JournalPosted, journal, data)?);
+            }
+
+            if sub.wants(WebhookEventType::BalanceChanged) {
+                for &account_id in accounts {
+                    let balance_minor = match balances.get(&account_id) {
+                        Some(b) => *b,
+                        None => {
+                            let b = self.journals.balance_as_of_sequence(account_id, journal.sequence).await?;
+                            balances.insert(account_id, b);
+                            b
+                        }
+                    };
+                    let account = &owned[&account_id];
+                    let change = BalanceChange {
+                        account_id,
+                        owner_type: account.owner_type().as_str().to_string(),
+                        owner_id: Some(sub.owner_id().to_string()),
+                        asset_id: account.asset_id(),
+                        balance_minor,
+                        journal_id: journal.db_id,
+                    };
+                    let data = serde_json::to_value(change).map_err(payload_error)?;
+                    let event_id = format!("balance.changed:{}:{account_id}", journal.sequence);
+                    out.push(self.delivery(sub, event_id, WebhookEventType::BalanceChanged, journal, data)?);
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn delivery(
+        &self,
+        sub: &WebhookSubscription,
+        event_id: String,
+        event_type: WebhookEventType,
+        journal: &PostedJournal,
+        data: serde_json::Value,
+    ) -> Result<NewWebhookDelivery, AppError> {
+        let event = WebhookEventDTO {
+            id: event_id.clone(),
+            event_type: event_type.as_code().to_string(),
+            created_at: journal.posted_at,
+            data,
+        };
+        let payload = serde_json::to_string(&event).map_err(payload_error)?;
+        Ok(WebhookDelivery::enqueue(
+            PublicId::new(Uuid::new_v4()),
+            sub.id(),
+            event_id,
+            event_type,
+            payload,
+            Utc::now(),
+        )?)
+    }
+
+    async fn with_log(&self, delivery: &WebhookDelivery) -> Result<WebhookDeliveryDTO, AppError> {
+        let log = self.webhooks.attempts(delivery.id()).await?;
+        Ok(webhook_delivery_to_dto(delivery, &log))
+    }
+}
+
+fn payload_error(e: serde_json::Error) -> AppError {
+    AppError::InvalidRequest { message: format!("webhook payload could not be encoded: {e}") }
+}
+
+#[async_trait]
+impl<R, Q, C, W, T> WebhookService for WebhookServiceImpl<R, Q, C, W, T>
+where
+    R: LedgerRepository + Send + Sync,
+    Q: JournalQueryRepository + Send + Sync,
+    C: FeedCursorRepository + Send + Sync,
+    W: WebhookRepository + Send + Sync,
+    T: WebhookTransport + Send + Sync,
+{
+    async fn create_subscription(&self, req: CreateWebhookSubscriptionDTO) -> Result<WebhookSubscriptionDTO, AppError> {
+        let public_id = parse_public_id(&req.public_id)?;
+        let owner_id = Uuid::parse_str(&req.owner_id)?;
+        if let Some(existing) = self.webhooks.find_subscription(public_id).await? {
+            if existing.owner_id() != owner_id {
+                return Err(RepoError::Conflict {
+                    message: format!("webhook subscription {} belongs to another owner", public_id.value()),
+                }
+                .into());
+            }
+            return Ok(webhook_subscription_to_dto(&existing));
+        }
+
+        let event_types = req
+            .event_types
+            .iter()
+            .map(|t| WebhookEventType::from_code(t))
+            .collect::<Result<Vec<_>, _>>()?;
+        let spec = WebhookSubscription::define(
+            public_id,
+            owner_id,
+            req.url,
+            req.secret,
+            &event_types,
+            &req.created_by,
+            Utc::now(),
+            &self.url_policy,
+        )?;
+        let stored = self.webhooks.insert_subscription(&spec).await?;
+        Ok(webhook_subscription_to_dto(&stored))
+    }
+
+    async fn find_subscription(&self, public_id: String) -> Result<Option<WebhookSubscriptionDTO>, AppError> {
+        let sub = self.webhooks.find_subscription(parse_public_id(&public_id)?).await?;
+        Ok(sub.as_ref().map(webhook_subscription_to_dto))
+    }
+
+    async fn list_subscriptions(&self, owner_id: String) -> Result<Vec<WebhookSubscriptionDTO>, AppError> {
+        let subs = self.webhooks.subscriptions_for_owner(Uuid::parse_str(&owner_id)?).await?;
+        Ok(subs.iter().map(webhook_subscription_to_dto).collect())
+    }
+
+    async fn deactivate_subscription(&self, public_id: String) -> Result<WebhookSubscriptionDTO, AppError> {
+        let mut sub = self.subscription(&public_id).await?;
+        sub.deactivate(Utc::now())?;
+        self.webhooks.save_subscription(&sub).await?;
+        Ok(webhook_subscription_to_dto(&sub))
+    }
+
+    async fn dispatch_committed(&self, batch_size: usize) -> Result<WebhookDispatchReportDTO, AppError> {
+        ensure_limit(batch_size)?;
+        let mut cursor = self.cursor().await?;
+        let journals = self.journals.journals_after_sequence(cursor.position(), batch_size).await?;
+        let Some(last) = journals.last().map(|j| j.sequence) else {
+            return Ok(WebhookDispatchReportDTO { journals: 0, enqueued: 0, cursor: cursor.position() });
+        };
+
+        let account_ids: Vec<i64> = journals
+            .iter()
+            .flat_map(|j| j.lines.iter().map(|l| l.account_id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let owned: HashMap<i64, LedgerAccount> = self
+            .repo
+            .get_accounts_by_ids(&account_ids)
+            .await?
+            .into_iter()
+            .filter(|a| a.owner_id().is_some())
+            .map(|a| (a.id(), a))
+            .collect();
+        let owner_ids: Vec<Uuid> =
+            owned.values().filter_map(|a| a.owner_id()).collect::<HashSet<_>>().into_iter().collect();
+        let subscriptions = if owner_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.webhooks.active_subscriptions(&owner_ids).await?
+        };
+
+        let mut deliveries = Vec::new();
+        if !subscriptions.is_empty() {
+            for journal in &journals {
+                deliveries.extend(self.events_for(journal, &owned, &subscriptions).await?);
+            }
+        }
+        // enqueueing skips events already there, so a pass cut short before the cursor
+        // moves is simply redone
+        let enqueued = if deliveries.is_empty() { 0 } else { self.webhooks.enqueue(&deliveries).await? };
+
+        if cursor.advance(last, Utc::now())? {
+            cursor = self.cursors.save_position(&cursor).await?;
+        }
+        Ok(WebhookDispatchReportDTO { journals: journals.len(), enqueued, cursor: cursor.position() })
+    }
+
+    async fn deliver_due(&self, limit: usize) -> Result<WebhookDeliveryRunDTO, AppError> {
+        ensure_limit(limit)?;
+        let claimed = self.webhooks.claim_due(limit, self.lease).await?;
+        let mut run = WebhookDeliveryRunDTO { claimed: claimed.len(), ..Default::default() };
+        let mut subscriptions: HashMap<i64, Option<WebhookSubscription>> = HashMap::new();
+
+        for mut delivery in claimed {
+            let sub_id = delivery.subscription_id();
+            if let Entry::Vacant(slot) = subscriptions.entry(sub_id) {
+                slot.insert(self.webhooks.subscription_by_id(sub_id).await?);
+            }
+
+            let at = Utc::now();
+            let attempt = match &subscriptions[&sub_id] {
+                Some(sub) if sub.is_active() => {
+                    let timestamp = at.timestamp();
+                    let request = WebhookRequest {
+                        url: sub.url().to_string(),
+                        event_id: delivery.event_id().to_string(),
+                        event_type: delivery.event_type().as_code().to_string(),
+                        timestamp,
+                        signature: sign_webhook(sub.secret(), timestamp, delivery.payload()),
+                        body: delivery.payload().to_string(),
+                    };
+                    match self.transport.post(&request).await {
+                        Ok(status_code) => delivery.record_response(status_code, at, &self.retry).map(Some),
+                        Err(e) => delivery.record_error(e.to_string(), at, &self.retry).map(Some),
+                    }
+                }
+                _ => delivery.abandon("subscription is inactive").map(|_| None),
+            };
+            let Ok(attempt) = attempt else {
+                run.skipped += 1;
+                continue;
+            };
+
+            match self.webhooks.record_attempt(&delivery, attempt.as_ref()).await {
+                Ok(()) => match delivery.status() {
+                    WebhookDeliveryStatus::Delivered => run.delivered += 1,
+                    WebhookDeliveryStatus::DeadLettered => run.dead_lettered += 1,
+                    WebhookDeliveryStatus::Pending => run.retrying += 1,
+                },
+                Err(RepoError::Conflict { .. }) => run.skipped += 1,
+                Err(e) => run.errors.push(format!("{}: {e}", delivery.public_id().value())),
+            }
+        }
+        Ok(run)
+    }
+
+    async fn find_delivery(&self, public_id: String) -> Result<Option<WebhookDeliveryDTO>, AppError> {
+        match self.webhooks.find_delivery(parse_public_id(&public_id)?).await? {
+            Some(d) => Ok(Some(self.with_log(&d).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn dead_letters(&self, limit: usize, offset: usize) -> Result<Vec<WebhookDeliveryDTO>, AppError> {
+        ensure_limit(limit)?;
+        let deliveries = self.webhooks.dead_letters(limit, offset).await?;
+        Ok(deliveries.iter().map(|d| webhook_delivery_to_dto(d, &[])).collect())
+    }
+
+    async fn redrive(&self, public_id: String) -> Result<WebhookDeliveryDTO, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        let mut delivery = self
+            .webhooks
+            .find_delivery(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("webhook_delivery public_id={}", public_id.value()) })?;
+        delivery.redrive(Utc::now())?;
+        self.webhooks.requeue(&delivery).await?;
+        self.with_log(&delivery).await
+    }
+}
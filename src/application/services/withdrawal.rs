@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::watch;
+
+use crate::application::contracts::repository::{HoldRepository, LedgerRepositoryTx, UnitOfWork, WithdrawalRepository};
+use crate::application::contracts::{PayoutInstruction, PayoutRail, PayoutRailError, PayoutStatus, WithdrawalService};
+use crate::application::dtos::{RequestWithdrawalDTO, WithdrawalDTO, WithdrawalSyncReportDTO};
+use crate::application::dtos::mappers::withdrawal_to_dto;
+use crate::application::services::common::{self, find_account, locked_not_found, parse_public_id};
+use crate::application::services::hold::{place_hold_tx, post_hold_step_tx, HoldStep};
+use crate::application::services::posting::validate_draft;
+use crate::application::AppError;
+
+use crate::domain::aggregate::{
+    Hold, HoldAction, NewHold, ValidatedJournal, Withdrawal, WithdrawalStatus, PAYOUT_RAIL_ACTOR,
+};
+use crate::domain::entities::{AccountType, OwnerType};
+use crate::domain::repository::{LedgerRepository, RepoError};
+use crate::domain::services::ValueDatingPolicy;
+use crate::domain::value_objects::PublicId;
+
+/// What a transition does to the withdrawal's hold, built from a read taken before the
+/// transaction.
+enum HoldMove {
+    None,
+    Place(NewHold, ValidatedJournal),
+    /// The step and the hold's journal count it was built against.
+    Capture(HoldStep, u32),
+    Release(HoldStep, u32),
+}
+
+pub struct WithdrawalServiceImpl<R, RX, U, W, H, P>
+where
+    R: LedgerRepository,
+    RX: LedgerRepositoryTx,
+    U: UnitOfWork,
+    W: WithdrawalRepository,
+    H: HoldRepository,
+    P: PayoutRail,
+{
+    repo: R,
+    repo_tx: Arc<RX>,
+    withdrawals: Arc<W>,
+    holds: Arc<H>,
+    uow: U,
+    rail: P,
+    dating: ValueDatingPolicy,
+}
+
+impl<R, RX, U, W, H, P> WithdrawalServiceImpl<R, RX, U, W, H, P>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    W: WithdrawalRepository + Send + Sync + 'static,
+    H: HoldRepository + Send + Sync + 'static,
+    P: PayoutRail,
+{
+    pub fn new(repo: R, repo_tx: RX, uow: U, withdrawals: W, holds: H, rail: P, dating: ValueDatingPolicy) -> Self {
+        Self {
+            repo,
+            repo_tx: Arc::new(repo_tx),
+            withdrawals: Arc::new(withdrawals),
+            holds: Arc::new(holds),
+            uow,
+            rail,
+            dating,
+        }
+    }
+
+    /// Polls the rail for submitted withdrawals until `shutdown` flips to true.
+    pub async fn run_worker(
+        &self,
+        batch_size: usize,
+        poll_interval: std::time::Duration,
+        shutdown: watch::Receiver<bool>,
+    ) -> Result<(), AppError> {
+        common::run_worker(poll_interval, shutdown, || async move {
+            let report = self.sync_submitted(batch_size).await?;
+            Ok(report.checked >= batch_size && report.pending < report.checked)
+        })
+        .await
+    }
+
+    async fn load(&self, public_id: PublicId) -> Result<Withdrawal, AppError> {
+        self.withdrawals
+            .find_by_public_id(public_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("withdrawal public_id={}", public_id.value()) })
+    }
+
+    async fn load_hold(&self, withdrawal: &Withdrawal) -> Result<Hold, AppError> {
+        self.holds
+            .find_by_public_id(withdrawal.hold_id())
+            .await?
+            .ok_or_else(|| AppError::NotFound { entity: format!("hold public_id={}", withdrawal.hold_id().value()) })
+    }
+
+    /// Moves `preview` to `to` and applies `hold_move` in one transaction. A withdrawal
+    /// already in `to` is returned unchanged; one that moved elsewhere since `preview`
+    /// was read conflicts, since the hold move was built for the old state.
+    async fn transition(
+        &self,
+        preview: &Withdrawal,
+        to: WithdrawalStatus,
+        actor: String,
+        detail: Option<String>,
+        hold_move: HoldMove,
+    ) -> Result<Withdrawal, AppError> {
+        let public_id = preview.public_id();
+        let from = preview.status();
+
+        let withdrawals = Arc::clone(&self.withdrawals);
+        let holds = Arc::clone(&self.holds);
+        let repo_tx = Arc::clone(&self.repo_tx);
+        let result = self.uow.with_domain_tx(move |tx| {
+            Box::pin(async move {
+                let mut withdrawal = withdrawals
+                    .lock_by_public_id_tx(tx, public_id)
+                    .await?
+                    .ok_or_else(|| locked_not_found("withdrawal", public_id))?;
+
+                if withdrawal.status() == to {
+                    return Ok(Ok(withdrawal));
+                }
+                if withdrawal.status() != from {
+                    return Err(RepoError::Conflict {
+                        message: format!("withdrawal {} changed concurrently; retry", public_id.value()),
+                    });
+                }
+
+                let now = Utc::now();
+                let detail = detail.unwrap_or_default();
+                let moved = match to {
+                    WithdrawalStatus::Submitted => withdrawal.mark_submitted(&detail, &actor, now),
+                    WithdrawalStatus::Failed => withdrawal.mark_failed(&detail, &actor, now),
+                    _ => withdrawal.transition(to, &actor, now),
+                };
+                if let Err(e) = moved {
+                    return Ok(Err(e));
+                }
+
+                let (holds, repo_tx) = (holds.as_ref(), repo_tx.as_ref());
+                let hold_id = withdrawal.hold_id();
+                let applied = match hold_move {
+                    HoldMove::None => Ok(()),
+                    HoldMove::Place(spec, posting) => place_hold_tx(holds, repo_tx, tx, &spec, posting).await?.map(|_| ()),
+                    HoldMove::Capture(step, expected) => {
+                        let amount = step.amount_minor;
+                        post_hold_step_tx(holds, repo_tx, tx, hold_id, expected, step, |h, now| {
+                            h.record_capture(amount, &actor, now)
+                        })
+                        .await?
+                        .map(|_| ())
+                    }
+                    HoldMove::Release(step, expected) => {
+                        post_hold_step_tx(holds, repo_tx, tx, hold_id, expected, step, |h, now| {
+                            h.record_release(&actor, now)
+                        })
+                        .await?
+                        .map(|_| ())
+                    }
+                };
+                if let Err(e) = applied {
+                    return Ok(Err(e));
+                }
+
+                withdrawals.save_tx(tx, &withdrawal).await?;
+                Ok(Ok(withdrawal))
+            })
+        }).await?;
+
+        Ok(result?)
+    }
+
+    async fn hold_funds(&self, withdrawal: &Withdrawal) -> Result<Withdrawal, AppError> {
+        let owner = Some(withdrawal.owner_id());
+        let asset_id = withdrawal.asset_id();
+        let available = find_account(&self.repo, OwnerType::User, owner, AccountType::UserAvailable, asset_id).await?;
+        let locked = find_account(&self.repo, OwnerType::User, owner, AccountType::UserLocked, asset_id).await?;
+
+        let spec = Hold::place(
+            withdrawal.hold_id(),
+            &available,
+            &locked,
+            withdrawal.amount_minor(),
+            None,
+            Some(format!("withdrawal {}", withdrawal.public_id().value())),
+            withdrawal.created_by(),
+            Utc::now(),
+        )?;
+        let posting = validate_draft(&self.repo, spec.place_draft()?, &self.dating).await?;
+
+        let actor = withdrawal.created_by().to_string();
+        self.transition(withdrawal, WithdrawalStatus::Held, actor, None, HoldMove::Place(spec, posting)).await
+    }
+
+    async fn fail_withdrawal(&self, withdrawal: &Withdrawal, reason: String, actor: String) -> Result<Withdrawal, AppError> {
+        if withdrawal.status() == WithdrawalStatus::Failed {
+            return Ok(withdrawal.clone());
+        }
+
+        let hold_move = if withdrawal.has_hold() {
+            let hold = self.load_hold(withdrawal).await?;
+            let step = HoldStep::release(&self.repo, &self.dating, &hold, HoldAction::Release, &actor).await?;
+            HoldMove::Release(step, hold.journal_count())
+        } else {
+            HoldMove::None
+        };
+        self.transition(withdrawal, WithdrawalStatus::Failed, actor, Some(reason), hold_move).await
+    }
+
+    async fn confirm_withdrawal(&self, withdrawal: &Withdrawal, actor: String) -> Result<Withdrawal, AppError> {
+        if withdrawal.status() == WithdrawalStatus::Confirmed {
+            return Ok(withdrawal.clone());
+        }
+        // an early confirm gets the domain's transition error, not a capture attempt
+        withdrawal.clone().transition(WithdrawalStatus::Confirmed, &actor, Utc::now())?;
+
+        let asset_id = withdrawal.asset_id();
+        let clearing = find_account(&self.repo, OwnerType::Platform, None, AccountType::PlatformClearing, asset_id).await?;
+        let hold = self.load_hold(withdrawal).await?;
+        let step = HoldStep::capture(&self.repo, &self.dating, &hold, None, clearing.id(), &actor).await?;
+        self.transition(withdrawal, WithdrawalStatus::Confirmed, actor, None, HoldMove::Capture(step, hold.journal_count()))
+            .await
+    }
+
+    async fn refresh_withdrawal(&self, withdrawal: Withdrawal) -> Result<Withdrawal, AppError> {
+        let Some(reference) = withdrawal.rail_reference().filter(|_| withdrawal.status() == WithdrawalStatus::Submitted)
+        else {
+            return Ok(withdrawal);
+        };
+
+        match self.rail.status(reference).await? {
+            PayoutStatus::Pending => Ok(withdrawal),
+            PayoutStatus::Confirmed => self.confirm_withdrawal(&withdrawal, PAYOUT_RAIL_ACTOR.to_string()).await,
+            PayoutStatus::Failed { reason } => {
+                self.fail_withdrawal(&withdrawal, reason, PAYOUT_RAIL_ACTOR.to_string()).await
+            }
+        }
+    }
+}
+
+/// Whether a failed hold attempt is worth retrying rather than failing the withdrawal.
+fn is_retryable(e: &AppError) -> bool {
+    matches!(
+        e,
+        AppError::Repo(RepoError::Transient { .. } | RepoError::Unexpected { .. }) | AppError::Unexpected { .. }
+    )
+}
+
+#[async_trait]
+impl<R, RX, U, W, H, P> WithdrawalService for WithdrawalServiceImpl<R, RX, U, W, H, P>
+where
+    R: LedgerRepository + Send + Sync,
+    RX: LedgerRepositoryTx + Send + Sync + 'static,
+    U: UnitOfWork + Send + Sync,
+    W: WithdrawalRepository + Send + Sync + 'static,
+    H: HoldRepository + Send + Sync + 'static,
+    P: PayoutRail,
+{
+    async fn request(&self, req: RequestWithdrawalDTO) -> Result<WithdrawalDTO, AppError> {
+        let spec = Withdrawal::request(
+            parse_public_id(&req.public_id)?,
+            uuid::Uuid::parse_str(&req.owner_id)?,
+            req.asset_id,
+            req.amount_minor,
+            req.destination,
+            &req.created_by,
+        )?;
+
+        let withdrawal = self.withdrawals.insert(&spec).await?;
+        if withdrawal.status() != WithdrawalStatus::Requested {
+            return Ok(withdrawal_to_dto(&withdrawal));
+        }
+
+        let withdrawal = match self.hold_funds(&withdrawal).await {
+            Ok(w) => w,
+            Err(e) if is_retryable(&e) => return Err(e),
+            Err(e) => {
+                let actor = withdrawal.created_by().to_string();
+                self.fail_withdrawal(&withdrawal, e.to_string(), actor).await?
+            }
+        };
+        Ok(withdrawal_to_dto(&withdrawal))
+    }
+
+    async fn submit(&self, public_id: String, actor: String) -> Result<WithdrawalDTO, AppError> {
+        let withdrawal = self.load(parse_public_id(&public_id)?).await?;
+        if matches!(withdrawal.status(), WithdrawalStatus::Submitted | WithdrawalStatus::Confirmed) {
+            return Ok(withdrawal_to_dto(&withdrawal));
+        }
+        withdrawal.clone().transition(WithdrawalStatus::Submitted, &actor, Utc::now())?;
+
+        let instruction = PayoutInstruction {
+            withdrawal_id: withdrawal.public_id(),
+            asset_id: withdrawal.asset_id(),
+            amount_minor: withdrawal.amount_minor(),
+            destination: withdrawal.destination().to_string(),
+        };
+        let withdrawal = match self.rail.submit(&instruction).await {
+            Ok(reference) => {
+                self.transition(&withdrawal, WithdrawalStatus::Submitted, actor, Some(reference), HoldMove::None)
+                    .await?
+            }
+            Err(PayoutRailError::Rejected { reason }) => self.fail_withdrawal(&withdrawal, reason, actor).await?,
+            Err(e) => return Err(e.into()),
+        };
+        Ok(withdrawal_to_dto(&withdrawal))
+    }
+
+    async fn confirm(&self, public_id: String, actor: String) -> Result<WithdrawalDTO, AppError> {
+        let withdrawal = self.load(parse_public_id(&public_id)?).await?;
+        let withdrawal = self.confirm_withdrawal(&withdrawal, actor).await?;
+        Ok(withdrawal_to_dto(&withdrawal))
+    }
+
+    async fn fail(&self, public_id: String, reason: String, actor: String) -> Result<WithdrawalDTO, AppError> {
+        let withdrawal = self.load(parse_public_id(&public_id)?).await?;
+        let withdrawal = self.fail_withdrawal(&withdrawal, reason, actor).await?;
+        Ok(withdrawal_to_dto(&withdrawal))
+    }
+
+    async fn refresh(&self, public_id: String) -> Result<WithdrawalDTO, AppError> {
+        let withdrawal = self.load(parse_public_id(&public_id)?).await?;
+        let withdrawal = self.refresh_withdrawal(withdrawal).await?;
+        Ok(withdrawal_to_dto(&withdrawal))
+    }
+
+    async fn find(&self, public_id: String) -> Result<Option<WithdrawalDTO>, AppError> {
+        let public_id = parse_public_id(&public_id)?;
+        Ok(self.withdrawals.find_by_public_id(public_id).await?.as_ref().map(withdrawal_to_dto))
+    }
+
+    async fn sync_submitted(&self, batch_size: usize) -> Result<WithdrawalSyncReportDTO, AppError> {
+        let submitted = self.withdrawals.list_by_status(WithdrawalStatus::Submitted, batch_size).await?;
+        let mut report = WithdrawalSyncReportDTO { checked: submitted.len(), ..Default::default() };
+
+        for withdrawal in submitted {
+            let public_id = withdrawal.public_id();
+            match self.refresh_withdrawal(withdrawal).await {
+                Ok(w) => match w.status() {
+                    WithdrawalStatus::Confirmed => report.confirmed += 1,
+                    WithdrawalStatus::Failed => report.failed += 1,
+                    _ => report.pending += 1,
+                },
+                Err(e) => report.errors.push(format!("{}: {e}", public_id.value())),
+            }
+        }
+
+        Ok(report)
+    }
+}
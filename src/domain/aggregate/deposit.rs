@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::aggregate::JournalDraft;
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, Money, PublicId};
+
+/// Metadata key linking the credit journal back to its deposit.
+pub const DEPOSIT_ID_KEY: &str = "deposit_id";
+/// Metadata key carrying the on-chain transfer the deposit was detected from.
+pub const DEPOSIT_TX_KEY: &str = "deposit_tx";
+
+const MAX_TX_REFERENCE_LEN: usize = 200;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DepositStatus {
+    /// Detected on chain, waiting for confirmations; shown to the user but not spendable.
+    Pending,
+    /// Credited to the user's `UserAvailable` bucket.
+    Credited,
+}
+
+impl DepositStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            DepositStatus::Pending => "PENDING",
+            DepositStatus::Credited => "CREDITED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PENDING" => Ok(Self::Pending),
+            "CREDITED" => Ok(Self::Credited),
+            other => Err(DomainError::InvalidDepositStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// Platform account a deposit's credit is funded from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DepositSource {
+    /// The platform's clearing account, settled against the custody wallet.
+    PlatformClearing,
+    /// The treasury's available bucket.
+    Treasury,
+}
+
+impl DepositSource {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            DepositSource::PlatformClearing => "PLATFORM_CLEARING",
+            DepositSource::Treasury => "TREASURY",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PLATFORM_CLEARING" => Ok(Self::PlatformClearing),
+            "TREASURY" => Ok(Self::Treasury),
+            other => Err(DomainError::InvalidDepositSource { value: other.to_string() }),
+        }
+    }
+
+    pub fn owner_type(&self) -> OwnerType {
+        match self {
+            DepositSource::PlatformClearing => OwnerType::Platform,
+            DepositSource::Treasury => OwnerType::Treasury,
+        }
+    }
+
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            DepositSource::PlatformClearing => AccountType::PlatformClearing,
+            DepositSource::Treasury => AccountType::TreasuryAvailable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmationThreshold {
+    pub asset_id: i16,
+    pub required_confirmations: u32,
+    pub source: DepositSource,
+}
+
+/// Per-asset confirmation thresholds. Deposits of an asset without one are refused.
+#[derive(Debug, Clone, Default)]
+pub struct DepositPolicy {
+    thresholds: Vec<ConfirmationThreshold>,
+}
+
+impl DepositPolicy {
+    pub fn new(thresholds: Vec<ConfirmationThreshold>) -> Self {
+        Self { thresholds }
+    }
+
+    pub fn threshold(&self, asset_id: i16) -> Result<&ConfirmationThreshold, DomainError> {
+        self.thresholds
+            .iter()
+            .find(|t| t.asset_id == asset_id)
+            .ok_or(DomainError::DepositAssetNotConfigured { asset_id })
+    }
+}
+
+/// A validated deposit, not yet stored. The threshold and source in force at detection
+/// stay with it, so a later policy change does not move deposits already in flight.
+#[derive(Debug, Clone)]
+pub struct NewDepositIntent {
+    pub public_id: PublicId,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    /// The on-chain transfer (for example `<tx hash>:<output index>`); unique per asset.
+    pub tx_reference: String,
+    pub required_confirmations: u32,
+    pub source: DepositSource,
+    pub confirmations: u32,
+    pub created_by: String,
+}
+
+impl NewDepositIntent {
+    /// Whether this detection describes the same transfer as `existing`, so a repeated
+    /// detection is a replay rather than a clash.
+    pub fn matches(&self, existing: &DepositIntent) -> bool {
+        self.owner_id == existing.owner_id()
+            && self.asset_id == existing.asset_id()
+            && self.amount_minor == existing.amount_minor()
+            && self.tx_reference == existing.tx_reference()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DepositIntent {
+    id: i64,
+    spec: NewDepositIntent,
+    confirmations: u32,
+    status: DepositStatus,
+    journal_id: Option<i64>,
+    confirmed_at: Option<DateTime<Utc>>,
+    credited_at: Option<DateTime<Utc>>,
+}
+
+impl DepositIntent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn detect(
+        public_id: PublicId,
+        owner_id: Uuid,
+        asset_id: i16,
+        amount_minor: i128,
+        tx_reference: impl Into<String>,
+        confirmations: u32,
+        policy: &DepositPolicy,
+        created_by: &str,
+    ) -> Result<NewDepositIntent, DomainError> {
+        let invalid = |reason: String| DomainError::DepositInvalid { reason };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if amount_minor <= 0 {
+            return Err(invalid("amount must be positive".into()));
+        }
+        let tx_reference = tx_reference.into().trim().to_string();
+        if tx_reference.is_empty() || tx_reference.len() > MAX_TX_REFERENCE_LEN {
+            return Err(invalid(format!("tx reference must be 1-{MAX_TX_REFERENCE_LEN} bytes")));
+        }
+        let threshold = policy.threshold(asset_id)?;
+
+        Ok(NewDepositIntent {
+            public_id,
+            owner_id,
+            asset_id,
+            amount_minor,
+            tx_reference,
+            required_confirmations: threshold.required_confirmations,
+            source: threshold.source,
+            confirmations,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(
+        id: i64,
+        spec: NewDepositIntent,
+        confirmations: u32,
+        status: DepositStatus,
+        journal_id: Option<i64>,
+        confirmed_at: Option<DateTime<Utc>>,
+        credited_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self { id, spec, confirmations, status, journal_id, confirmed_at, credited_at }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn owner_id(&self) -> Uuid { self.spec.owner_id }
+    pub fn asset_id(&self) -> i16 { self.spec.asset_id }
+    pub fn amount_minor(&self) -> i128 { self.spec.amount_minor }
+    pub fn tx_reference(&self) -> &str { &self.spec.tx_reference }
+    pub fn required_confirmations(&self) -> u32 { self.spec.required_confirmations }
+    pub fn source(&self) -> DepositSource { self.spec.source }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+    pub fn confirmations(&self) -> u32 { self.confirmations }
+    pub fn status(&self) -> DepositStatus { self.status }
+    pub fn journal_id(&self) -> Option<i64> { self.journal_id }
+    /// When the latest confirmation count was recorded.
+    pub fn confirmed_at(&self) -> Option<DateTime<Utc>> { self.confirmed_at }
+    pub fn credited_at(&self) -> Option<DateTime<Utc>> { self.credited_at }
+
+    /// Pending with enough confirmations to credit.
+    pub fn is_creditable(&self) -> bool {
+        self.status == DepositStatus::Pending && self.confirmations >= self.spec.required_confirmations
+    }
+
+    /// Records the chain's latest confirmation count. The count may go down after a
+    /// reorg; once credited, further updates are ignored. Returns whether anything changed.
+    pub fn record_confirmations(&mut self, confirmations: u32, at: DateTime<Utc>) -> bool {
+        if self.status != DepositStatus::Pending || self.confirmations == confirmations {
+            return false;
+        }
+        self.confirmations = confirmations;
+        self.confirmed_at = Some(at);
+        true
+    }
+
+    /// The journal crediting the user from the deposit's source account.
+    pub fn credit_draft(
+        &self,
+        source: &LedgerAccount,
+        available: &LedgerAccount,
+        actor: &str,
+    ) -> Result<JournalDraft, DomainError> {
+        let invalid = |reason: &str| DomainError::DepositInvalid { reason: reason.to_string() };
+
+        if source.account_type() != self.spec.source.account_type() || source.asset_id() != self.spec.asset_id {
+            return Err(invalid("source account does not match the deposit's source"));
+        }
+        if available.account_type() != AccountType::UserAvailable
+            || available.owner_id() != Some(self.spec.owner_id)
+            || available.asset_id() != self.spec.asset_id
+        {
+            return Err(invalid("deposits credit the owner's UserAvailable account in the deposit's asset"));
+        }
+
+        let mut draft = JournalDraft::new(
+            PublicId::new(Uuid::new_v4()),
+            ExternalRefType::Deposit,
+            ExternalRef::new(format!("deposit:{}:credit", self.spec.public_id.value()))?,
+            actor,
+            Some(format!("deposit {}", self.spec.tx_reference)),
+        )?;
+        draft.metadata = JournalMetadata::new(BTreeMap::from([
+            (DEPOSIT_ID_KEY.to_string(), self.spec.public_id.value().to_string()),
+            (DEPOSIT_TX_KEY.to_string(), self.spec.tx_reference.clone()),
+        ]))?;
+        draft.add_line(source.id(), Money::credit(self.spec.amount_minor)?);
+        draft.add_line(available.id(), Money::debit(self.spec.amount_minor)?);
+        Ok(draft)
+    }
+
+    pub fn mark_credited(&mut self, journal_id: i64, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.is_creditable() {
+            return Err(DomainError::DepositInvalid {
+                reason: format!(
+                    "deposit is not creditable (status={}, confirmations={}/{})",
+                    self.status.as_code(),
+                    self.confirmations,
+                    self.spec.required_confirmations
+                ),
+            });
+        }
+        self.status = DepositStatus::Credited;
+        self.journal_id = Some(journal_id);
+        self.credited_at = Some(at);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+
+const MAX_CONSUMER_LEN: usize = 64;
+
+/// A named consumer's place in the change feed: the last ledger sequence it has
+/// acknowledged. Reading resumes after it, so a consumer that stops and restarts sees
+/// every journal exactly where it left off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedCursor {
+    consumer: String,
+    position: i64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl FeedCursor {
+    /// A cursor for `consumer` (`analytics`, `search-index`), stored lowercase, starting
+    /// after `position` (0 to read the ledger from the start).
+    pub fn new(consumer: impl Into<String>, position: i64, at: DateTime<Utc>) -> Result<Self, DomainError> {
+        let consumer = Self::normalize(consumer)?;
+        if position < 0 {
+            return Err(DomainError::FeedCursorInvalid { reason: "position must not be negative".into() });
+        }
+        Ok(Self { consumer, position, created_at: at, updated_at: at })
+    }
+
+    pub fn restore(consumer: String, position: i64, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Self {
+        Self { consumer, position, created_at, updated_at }
+    }
+
+    /// Validates a consumer name: 1 to 64 of `a-z`, `0-9`, `_`, `-` and `.`.
+    pub fn normalize(consumer: impl Into<String>) -> Result<String, DomainError> {
+        let s = consumer.into().trim().to_ascii_lowercase();
+        let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.');
+        if s.is_empty() || s.len() > MAX_CONSUMER_LEN || !s.chars().all(valid_char) {
+            return Err(DomainError::InvalidFeedConsumer { value: s });
+        }
+        Ok(s)
+    }
+
+    /// Moves the cursor forward to `sequence` once the consumer has handled everything up
+    /// to it. Acknowledging the current position again is a no-op; moving back is refused
+    /// so a late, stale acknowledgement cannot replay journals.
+    pub fn advance(&mut self, sequence: i64, at: DateTime<Utc>) -> Result<bool, DomainError> {
+        if sequence < self.position {
+            return Err(DomainError::FeedCursorInvalid {
+                reason: format!("cannot move {} back from {} to {sequence}", self.consumer, self.position),
+            });
+        }
+        if sequence == self.position {
+            return Ok(false);
+        }
+        self.position = sequence;
+        self.updated_at = at;
+        Ok(true)
+    }
+
+    pub fn consumer(&self) -> &str {
+        &self.consumer
+    }
+
+    /// The last acknowledged sequence; 0 before anything is acknowledged.
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::aggregate::JournalDraft;
+use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Asset, ExchangeRate, ExternalRef, ExternalRefType, JournalMetadata, Money, PublicId};
+
+/// Metadata keys recorded on both legs of a conversion.
+pub const FX_QUOTE_ID_KEY: &str = "fx_quote_id";
+pub const FX_LEG_KEY: &str = "fx_leg";
+pub const FX_RATE_KEY: &str = "fx_rate";
+pub const FX_SPREAD_KEY: &str = "fx_spread_bps";
+pub const FX_APPLIED_RATE_KEY: &str = "fx_applied_rate";
+
+const MAX_SOURCE_LEN: usize = 64;
+
+/// Platform buckets on the other side of every conversion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FxLiquidity {
+    Treasury,
+    Inventory,
+}
+
+impl FxLiquidity {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            FxLiquidity::Treasury => "TREASURY",
+            FxLiquidity::Inventory => "INVENTORY",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "TREASURY" => Ok(Self::Treasury),
+            "INVENTORY" => Ok(Self::Inventory),
+            other => Err(DomainError::InvalidFxLiquidity { value: other.to_string() }),
+        }
+    }
+
+    pub fn owner_type(&self) -> OwnerType {
+        match self {
+            FxLiquidity::Treasury => OwnerType::Treasury,
+            FxLiquidity::Inventory => OwnerType::Platform,
+        }
+    }
+
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            FxLiquidity::Treasury => AccountType::TreasuryAvailable,
+            FxLiquidity::Inventory => AccountType::InventoryAvailable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairSpread {
+    pub base_asset_id: i16,
+    pub quote_asset_id: i16,
+    pub spread_bps: u32,
+}
+
+/// How quotes are priced and settled.
+#[derive(Debug, Clone)]
+pub struct FxPolicy {
+    quote_ttl: Duration,
+    default_spread_bps: u32,
+    spreads: Vec<PairSpread>,
+    liquidity: FxLiquidity,
+}
+
+impl FxPolicy {
+    pub fn new(quote_ttl: Duration, default_spread_bps: u32, spreads: Vec<PairSpread>, liquidity: FxLiquidity) -> Self {
+        Self { quote_ttl, default_spread_bps, spreads, liquidity }
+    }
+
+    pub fn quote_ttl(&self) -> Duration { self.quote_ttl }
+    pub fn liquidity(&self) -> FxLiquidity { self.liquidity }
+
+    pub fn spread_bps(&self, base_asset_id: i16, quote_asset_id: i16) -> u32 {
+        self.spreads
+            .iter()
+            .find(|s| s.base_asset_id == base_asset_id && s.quote_asset_id == quote_asset_id)
+            .map_or(self.default_spread_bps, |s| s.spread_bps)
+    }
+}
+
+/// A validated rate, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewFxRate {
+    pub base_asset_id: i16,
+    pub quote_asset_id: i16,
+    pub rate: ExchangeRate,
+    pub valid_from: DateTime<Utc>,
+    /// `None` stays valid until a later rate for the pair takes over.
+    pub valid_to: Option<DateTime<Utc>>,
+    pub source: String,
+    pub created_by: String,
+}
+
+/// Mid rate for converting `base` into `quote` during `[valid_from, valid_to)`. Where
+/// windows overlap, the rate that started last applies.
+#[derive(Debug, Clone)]
+pub struct FxRate {
+    id: i64,
+    spec: NewFxRate,
+}
+
+impl FxRate {
+    #[allow(clippy::too_many_arguments)]
+    pub fn define(
+        base_asset_id: i16,
+        quote_asset_id: i16,
+        rate: ExchangeRate,
+        valid_from: DateTime<Utc>,
+        valid_to: Option<DateTime<Utc>>,
+        source: &str,
+        created_by: &str,
+    ) -> Result<NewFxRate, DomainError> {
+        let invalid = |reason: &str| DomainError::FxRateInvalid { reason: reason.to_string() };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if base_asset_id == quote_asset_id {
+            return Err(invalid("base and quote assets must differ"));
+        }
+        if valid_to.is_some_and(|to| to <= valid_from) {
+            return Err(invalid("validity window must end after it starts"));
+        }
+        let source = source.trim();
+        if source.is_empty() || source.len() > MAX_SOURCE_LEN {
+            return Err(invalid(&format!("source must be 1-{MAX_SOURCE_LEN} bytes")));
+        }
+
+        Ok(NewFxRate {
+            base_asset_id,
+            quote_asset_id,
+            rate,
+            valid_from,
+            valid_to,
+            source: source.to_string(),
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(id: i64, spec: NewFxRate) -> Self {
+        Self { id, spec }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn base_asset_id(&self) -> i16 { self.spec.base_asset_id }
+    pub fn quote_asset_id(&self) -> i16 { self.spec.quote_asset_id }
+    pub fn rate(&self) -> ExchangeRate { self.spec.rate }
+    pub fn valid_from(&self) -> DateTime<Utc> { self.spec.valid_from }
+    pub fn valid_to(&self) -> Option<DateTime<Utc>> { self.spec.valid_to }
+    pub fn source(&self) -> &str { &self.spec.source }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.spec.valid_from <= at && self.spec.valid_to.is_none_or(|to| at < to)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FxQuoteStatus {
+    /// Rate locked until `expires_at`.
+    Open,
+    /// Both legs posted.
+    Executed,
+    /// Open past its lock; never stored, only reported.
+    Expired,
+}
+
+impl FxQuoteStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            FxQuoteStatus::Open => "OPEN",
+            FxQuoteStatus::Executed => "EXECUTED",
+            FxQuoteStatus::Expired => "EXPIRED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "OPEN" => Ok(Self::Open),
+            "EXECUTED" => Ok(Self::Executed),
+            "EXPIRED" => Ok(Self::Expired),
+            other => Err(DomainError::InvalidFxQuoteStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// A priced quote, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewFxQuote {
+    pub public_id: PublicId,
+    pub owner_id: Uuid,
+    pub from_asset_id: i16,
+    pub to_asset_id: i16,
+    pub rate_id: i64,
+    pub mid_rate: ExchangeRate,
+    pub spread_bps: u32,
+    /// The mid rate less the spread; what the customer gets.
+    pub applied_rate: ExchangeRate,
+    pub from_amount_minor: i128,
+    pub to_amount_minor: i128,
+    pub liquidity: FxLiquidity,
+    pub expires_at: DateTime<Utc>,
+    pub created_by: String,
+}
+
+impl NewFxQuote {
+    /// Whether this request asks for the same conversion as `existing`, so a repeated
+    /// request is a replay rather than a clash. The price is not compared: it moves.
+    pub fn matches(&self, existing: &FxQuote) -> bool {
+        self.owner_id == existing.owner_id()
+            && self.from_asset_id == existing.from_asset_id()
+            && self.to_asset_id == existing.to_asset_id()
+            && self.from_amount_minor == existing.from_amount_minor()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FxQuote {
+    id: i64,
+    spec: NewFxQuote,
+    status: FxQuoteStatus,
+    sell_journal_id: Option<i64>,
+    buy_journal_id: Option<i64>,
+    executed_at: Option<DateTime<Utc>>,
+    executed_by: Option<String>,
+}
+
+impl FxQuote {
+    /// Prices selling `from_amount_minor` of `from` for `to` at `rate` less the pair's
+    /// spread. The lock lasts the policy's TTL, cut short where the rate itself ends.
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue(
+        public_id: PublicId,
+        owner_id: Uuid,
+        rate: &FxRate,
+        from: &Asset,
+        to: &Asset,
+        from_amount_minor: i128,
+        policy: &FxPolicy,
+        created_by: &str,
+        now: DateTime<Utc>,
+    ) -> Result<NewFxQuote, DomainError> {
+        let invalid = |reason: &str| DomainError::FxQuoteInvalid { reason: reason.to_string() };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if from_amount_minor <= 0 {
+            return Err(invalid("amount must be positive"));
+        }
+        if rate.base_asset_id() != from.id() || rate.quote_asset_id() != to.id() {
+            return Err(invalid("rate is for a different asset pair"));
+        }
+        if !rate.is_valid_at(now) {
+            return Err(invalid("rate is not valid now"));
+        }
+
+        let spread_bps = policy.spread_bps(from.id(), to.id());
+        let applied_rate = rate.rate().less_spread(spread_bps)?;
+        let to_amount_minor = applied_rate.convert(from_amount_minor, from.decimals(), to.decimals())?;
+        if to_amount_minor <= 0 {
+            return Err(invalid("amount converts to less than one minor unit"));
+        }
+
+        let lock_until = now + policy.quote_ttl();
+        let expires_at = rate.valid_to().map_or(lock_until, |to| to.min(lock_until));
+
+        Ok(NewFxQuote {
+            public_id,
+            owner_id,
+            from_asset_id: from.id(),
+            to_asset_id: to.id(),
+            rate_id: rate.id(),
+            mid_rate: rate.rate(),
+            spread_bps,
+            applied_rate,
+            from_amount_minor,
+            to_amount_minor,
+            liquidity: policy.liquidity(),
+            expires_at,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(
+        id: i64,
+        spec: NewFxQuote,
+        status: FxQuoteStatus,
+        sell_journal_id: Option<i64>,
+        buy_journal_id: Option<i64>,
+        executed_at: Option<DateTime<Utc>>,
+        executed_by: Option<String>,
+    ) -> Self {
+        Self { id, spec, status, sell_journal_id, buy_journal_id, executed_at, executed_by }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn owner_id(&self) -> Uuid { self.spec.owner_id }
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_asset_id(&self) -> i16 { self.spec.from_asset_id }
+    pub fn to_asset_id(&self) -> i16 { self.spec.to_asset_id }
+    pub fn rate_id(&self) -> i64 { self.spec.rate_id }
+    pub fn mid_rate(&self) -> ExchangeRate { self.spec.mid_rate }
+    pub fn spread_bps(&self) -> u32 { self.spec.spread_bps }
+    pub fn applied_rate(&self) -> ExchangeRate { self.spec.applied_rate }
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_amount_minor(&self) -> i128 { self.spec.from_amount_minor }
+    pub fn to_amount_minor(&self) -> i128 { self.spec.to_amount_minor }
+    pub fn liquidity(&self) -> FxLiquidity { self.spec.liquidity }
+    pub fn expires_at(&self) -> DateTime<Utc> { self.spec.expires_at }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+    pub fn status(&self) -> FxQuoteStatus { self.status }
+    pub fn sell_journal_id(&self) -> Option<i64> { self.sell_journal_id }
+    pub fn buy_journal_id(&self) -> Option<i64> { self.buy_journal_id }
+    pub fn executed_at(&self) -> Option<DateTime<Utc>> { self.executed_at }
+    pub fn executed_by(&self) -> Option<&str> { self.executed_by.as_deref() }
+
+    /// The stored status, reporting an open quote past its lock as expired.
+    pub fn status_at(&self, now: DateTime<Utc>) -> FxQuoteStatus {
+        match self.status {
+            FxQuoteStatus::Open if now >= self.spec.expires_at => FxQuoteStatus::Expired,
+            status => status,
+        }
+    }
+
+    pub fn ensure_executable(&self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        match self.status_at(now) {
+            FxQuoteStatus::Open => Ok(()),
+            FxQuoteStatus::Expired => Err(DomainError::FxQuoteExpired { expired_at: self.spec.expires_at.to_rfc3339() }),
+            status => Err(DomainError::FxQuoteNotOpen { status: status.as_code().to_string() }),
+        }
+    }
+
+    /// The two single-asset legs: the customer sells into the pool in the `from` asset
+    /// and the pool pays out in the `to` asset. Each balances within its own asset.
+    pub fn conversion_drafts(
+        &self,
+        user_from: &LedgerAccount,
+        pool_from: &LedgerAccount,
+        pool_to: &LedgerAccount,
+        user_to: &LedgerAccount,
+        actor: &str,
+    ) -> Result<(JournalDraft, JournalDraft), DomainError> {
+        let invalid = |reason: &str| DomainError::FxQuoteInvalid { reason: reason.to_string() };
+
+        let user_ok = |a: &LedgerAccount, asset_id: i16| {
+            a.account_type() == AccountType::UserAvailable
+                && a.owner_id() == Some(self.spec.owner_id)
+                && a.asset_id() == asset_id
+        };
+        let pool_ok =
+            |a: &LedgerAccount, asset_id: i16| a.account_type() == self.spec.liquidity.account_type() && a.asset_id() == asset_id;
+
+        if !user_ok(user_from, self.spec.from_asset_id) || !user_ok(user_to, self.spec.to_asset_id) {
+            return Err(invalid("conversions settle in the owner's UserAvailable accounts of each asset"));
+        }
+        if !pool_ok(pool_from, self.spec.from_asset_id) || !pool_ok(pool_to, self.spec.to_asset_id) {
+            return Err(invalid("pool accounts do not match the quote's liquidity and assets"));
+        }
+
+        let mut sell = self.leg_draft("sell", actor)?;
+        sell.add_line(user_from.id(), Money::credit(self.spec.from_amount_minor)?);
+        sell.add_line(pool_from.id(), Money::debit(self.spec.from_amount_minor)?);
+
+        let mut buy = self.leg_draft("buy", actor)?;
+        buy.add_line(pool_to.id(), Money::credit(self.spec.to_amount_minor)?);
+        buy.add_line(user_to.id(), Money::debit(self.spec.to_amount_minor)?);
+
+        Ok((sell, buy))
+    }
+
+    /// The `FX_CONVERSION` references of the sell and buy legs.
+    pub fn leg_refs(&self) -> Result<(ExternalRef, ExternalRef), DomainError> {
+        Ok((self.leg_ref("sell")?, self.leg_ref("buy")?))
+    }
+
+    fn leg_ref(&self, leg: &str) -> Result<ExternalRef, DomainError> {
+        ExternalRef::new(format!("fx:{}:{leg}", self.spec.public_id.value()))
+    }
+
+    fn leg_draft(&self, leg: &str, actor: &str) -> Result<JournalDraft, DomainError> {
+        let quote_id = self.spec.public_id.value().to_string();
+        let mut draft = JournalDraft::new(
+            PublicId::new(Uuid::new_v4()),
+            ExternalRefType::FxConversion,
+            self.leg_ref(leg)?,
+            actor,
+            Some(format!("fx conversion {quote_id} ({leg})")),
+        )?;
+        draft.metadata = JournalMetadata::new(BTreeMap::from([
+            (FX_QUOTE_ID_KEY.to_string(), quote_id),
+            (FX_LEG_KEY.to_string(), leg.to_string()),
+            (FX_RATE_KEY.to_string(), self.spec.mid_rate.to_string()),
+            (FX_SPREAD_KEY.to_string(), self.spec.spread_bps.to_string()),
+            (FX_APPLIED_RATE_KEY.to_string(), self.spec.applied_rate.to_string()),
+        ]))?;
+        Ok(draft)
+    }
+
+    pub fn mark_executed(
+        &mut self,
+        sell_journal_id: i64,
+        buy_journal_id: i64,
+        actor: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        self.ensure_executable(at)?;
+        self.status = FxQuoteStatus::Executed;
+        self.sell_journal_id = Some(sell_journal_id);
+        self.buy_journal_id = Some(buy_journal_id);
+        self.executed_at = Some(at);
+        self.executed_by = Some(actor.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::aggregate::{FxRate, JournalDraft};
+use crate::domain::entities::{AccountType, AccountingPeriod, LedgerAccount};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Asset, ExchangeRate, ExternalRef, ExternalRefType, JournalMetadata, Money, PublicId};
+
+/// Metadata keys recorded on every revaluation journal.
+pub const FX_REVALUATION_ID_KEY: &str = "fx_revaluation_id";
+pub const FX_REVALUED_ASSET_KEY: &str = "fx_revalued_asset_id";
+pub const FX_OPENING_RATE_KEY: &str = "fx_opening_rate";
+pub const FX_CLOSING_RATE_KEY: &str = "fx_closing_rate";
+
+/// Pool account types whose foreign holdings are revalued.
+pub const REVALUED_ACCOUNT_TYPES: [AccountType; 4] = [
+    AccountType::TreasuryAvailable,
+    AccountType::TreasuryLocked,
+    AccountType::InventoryAvailable,
+    AccountType::InventoryLocked,
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FxRevaluationStatus {
+    /// Adjusting journals posted; the period's revaluation.
+    Posted,
+    /// Undone by mirror journals; the period may be revalued again.
+    Reversed,
+}
+
+impl FxRevaluationStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            FxRevaluationStatus::Posted => "POSTED",
+            FxRevaluationStatus::Reversed => "REVERSED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "POSTED" => Ok(Self::Posted),
+            "REVERSED" => Ok(Self::Reversed),
+            other => Err(DomainError::InvalidFxRevaluationStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// Net balance of one foreign asset across the revalued pools at the valuation instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevaluationPosition {
+    pub asset_id: i16,
+    pub decimals: i16,
+    pub balance_minor: i128,
+}
+
+/// One foreign asset's revaluation: its holding valued in the reporting asset at the
+/// period's opening and closing rates, and the difference between the two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevaluationLine {
+    pub asset_id: i16,
+    pub balance_minor: i128,
+    pub opening_rate_id: i64,
+    pub opening_rate: ExchangeRate,
+    pub closing_rate_id: i64,
+    pub closing_rate: ExchangeRate,
+    pub opening_value_minor: i128,
+    pub closing_value_minor: i128,
+    /// Closing less opening value; positive is a gain.
+    pub gain_minor: i128,
+    pub journal_id: Option<i64>,
+    pub reversal_journal_id: Option<i64>,
+}
+
+impl RevaluationLine {
+    /// Values `position` in `reporting` at both rates. Each value is rounded towards zero
+    /// so a holding and its negation revalue symmetrically.
+    pub fn value(
+        position: &RevaluationPosition,
+        reporting: &Asset,
+        opening: &FxRate,
+        closing: &FxRate,
+    ) -> Result<Self, DomainError> {
+        let invalid = |reason: String| DomainError::FxRevaluationInvalid { reason };
+
+        if position.asset_id == reporting.id() {
+            return Err(invalid("the reporting asset is not revalued".to_string()));
+        }
+        for rate in [opening, closing] {
+            if rate.base_asset_id() != position.asset_id || rate.quote_asset_id() != reporting.id() {
+                return Err(invalid(format!(
+                    "rate id={} does not price asset_id={} in asset_id={}",
+                    rate.id(),
+                    position.asset_id,
+                    reporting.id()
+                )));
+            }
+        }
+
+        let value = |rate: &FxRate| -> Result<i128, DomainError> {
+            let magnitude = rate.rate().convert(position.balance_minor.abs(), position.decimals, reporting.decimals())?;
+            Ok(magnitude * position.balance_minor.signum())
+        };
+        let opening_value_minor = value(opening)?;
+        let closing_value_minor = value(closing)?;
+
+        Ok(Self {
+            asset_id: position.asset_id,
+            balance_minor: position.balance_minor,
+            opening_rate_id: opening.id(),
+            opening_rate: opening.rate(),
+            closing_rate_id: closing.id(),
+            closing_rate: closing.rate(),
+            opening_value_minor,
+            closing_value_minor,
+            gain_minor: closing_value_minor - opening_value_minor,
+            journal_id: None,
+            reversal_journal_id: None,
+        })
+    }
+}
+
+/// A computed revaluation, not yet stored. Previews stop here.
+#[derive(Debug, Clone)]
+pub struct NewFxRevaluation {
+    pub public_id: PublicId,
+    pub reporting_asset_id: i16,
+    pub period_id: i64,
+    /// The period's end; balances and closing rates are taken as of this instant.
+    pub valued_at: DateTime<Utc>,
+    pub lines: Vec<RevaluationLine>,
+    pub created_by: String,
+}
+
+impl NewFxRevaluation {
+    pub fn total_gain_minor(&self) -> i128 {
+        self.lines.iter().map(|l| l.gain_minor).sum()
+    }
+
+    /// One journal per line with a gain or loss, in the reporting asset: the carrying
+    /// adjustment moves with the holding's value and the difference is taken to
+    /// unrealised FX. Returned with the revalued asset each belongs to.
+    pub fn journal_drafts(
+        &self,
+        adjustment: &LedgerAccount,
+        gain_loss: &LedgerAccount,
+        actor: &str,
+    ) -> Result<Vec<(i16, JournalDraft)>, DomainError> {
+        ensure_revaluation_accounts(self.reporting_asset_id, adjustment, gain_loss)?;
+
+        let mut drafts = Vec::new();
+        for line in self.lines.iter().filter(|l| l.gain_minor != 0) {
+            let mut draft = revaluation_draft(self, line, false, actor)?;
+            draft.add_line(adjustment.id(), Money::from_signed_minor(line.gain_minor)?);
+            draft.add_line(gain_loss.id(), Money::from_signed_minor(-line.gain_minor)?);
+            drafts.push((line.asset_id, draft));
+        }
+        Ok(drafts)
+    }
+
+    /// Records the journal posted for the revalued asset's line.
+    pub fn record_journal(&mut self, asset_id: i16, journal_id: i64) {
+        if let Some(line) = self.lines.iter_mut().find(|l| l.asset_id == asset_id) {
+            line.journal_id = Some(journal_id);
+        }
+    }
+}
+
+/// The unrealised FX gain or loss on foreign holdings in the treasury and inventory
+/// pools for one accounting period of the reporting asset.
+#[derive(Debug, Clone)]
+pub struct FxRevaluation {
+    id: i64,
+    spec: NewFxRevaluation,
+    status: FxRevaluationStatus,
+    created_at: DateTime<Utc>,
+    reversed_at: Option<DateTime<Utc>>,
+    reversed_by: Option<String>,
+}
+
+impl FxRevaluation {
+    /// Checks a revaluation of `period`, which must have ended and belong to the
+    /// reporting asset, over lines valued with [`RevaluationLine::value`].
+    pub fn prepare(
+        public_id: PublicId,
+        reporting: &Asset,
+        period: &AccountingPeriod,
+        lines: Vec<RevaluationLine>,
+        created_by: &str,
+        now: DateTime<Utc>,
+    ) -> Result<NewFxRevaluation, DomainError> {
+        let invalid = |reason: &str| DomainError::FxRevaluationInvalid { reason: reason.to_string() };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if period.asset_id() != reporting.id() {
+            return Err(invalid("period belongs to a different asset than the reporting asset"));
+        }
+        if now < period.ends_at() {
+            return Err(invalid("period has not ended"));
+        }
+        let mut seen = HashSet::new();
+        if !lines.iter().all(|l| seen.insert(l.asset_id)) {
+            return Err(invalid("each asset is revalued once"));
+        }
+        if lines.iter().any(|l| l.asset_id == reporting.id()) {
+            return Err(invalid("the reporting asset is not revalued"));
+        }
+
+        Ok(NewFxRevaluation {
+            public_id,
+            reporting_asset_id: reporting.id(),
+            period_id: period.id(),
+            valued_at: period.ends_at(),
+            lines,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(
+        id: i64,
+        spec: NewFxRevaluation,
+        status: FxRevaluationStatus,
+        created_at: DateTime<Utc>,
+        reversed_at: Option<DateTime<Utc>>,
+        reversed_by: Option<String>,
+    ) -> Self {
+        Self { id, spec, status, created_at, reversed_at, reversed_by }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn reporting_asset_id(&self) -> i16 { self.spec.reporting_asset_id }
+    pub fn period_id(&self) -> i64 { self.spec.period_id }
+    pub fn valued_at(&self) -> DateTime<Utc> { self.spec.valued_at }
+    pub fn lines(&self) -> &[RevaluationLine] { &self.spec.lines }
+    pub fn total_gain_minor(&self) -> i128 { self.spec.total_gain_minor() }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+    pub fn status(&self) -> FxRevaluationStatus { self.status }
+    pub fn created_at(&self) -> DateTime<Utc> { self.created_at }
+    pub fn reversed_at(&self) -> Option<DateTime<Utc>> { self.reversed_at }
+    pub fn reversed_by(&self) -> Option<&str> { self.reversed_by.as_deref() }
+
+    /// Mirror journals for every posted line, dated like the originals so the period's
+    /// result is undone where it was booked.
+    pub fn reversal_drafts(
+        &self,
+        adjustment: &LedgerAccount,
+        gain_loss: &LedgerAccount,
+        actor: &str,
+    ) -> Result<Vec<(i16, JournalDraft)>, DomainError> {
+        if self.status != FxRevaluationStatus::Posted {
+            return Err(DomainError::FxRevaluationInvalid { reason: "only a posted revaluation can be reversed".into() });
+        }
+        ensure_revaluation_accounts(self.spec.reporting_asset_id, adjustment, gain_loss)?;
+
+        let mut drafts = Vec::new();
+        for line in self.spec.lines.iter().filter(|l| l.journal_id.is_some()) {
+            let mut draft = revaluation_draft(&self.spec, line, true, actor)?;
+            draft.add_line(adjustment.id(), Money::from_signed_minor(-line.gain_minor)?);
+            draft.add_line(gain_loss.id(), Money::from_signed_minor(line.gain_minor)?);
+            drafts.push((line.asset_id, draft));
+        }
+        Ok(drafts)
+    }
+
+    pub fn mark_reversed(
+        &mut self,
+        reversal_journal_ids: &[(i16, i64)],
+        actor: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if self.status != FxRevaluationStatus::Posted {
+            return Err(DomainError::FxRevaluationInvalid { reason: "only a posted revaluation can be reversed".into() });
+        }
+        for (asset_id, journal_id) in reversal_journal_ids {
+            if let Some(line) = self.spec.lines.iter_mut().find(|l| l.asset_id == *asset_id) {
+                line.reversal_journal_id = Some(*journal_id);
+            }
+        }
+        self.status = FxRevaluationStatus::Reversed;
+        self.reversed_at = Some(at);
+        self.reversed_by = Some(actor.to_string());
+        Ok(())
+    }
+
+    /// The `FX_REVALUATION` reference of the journal for one revalued asset.
+    pub fn journal_ref(public_id: PublicId, asset_id: i16, reversal: bool) -> Result<ExternalRef, DomainError> {
+        let suffix = if reversal { ":reversal" } else { "" };
+        ExternalRef::new(format!("fx_reval:{}:{asset_id}{suffix}", public_id.value()))
+    }
+}
+
+fn ensure_revaluation_accounts(
+    reporting_asset_id: i16,
+    adjustment: &LedgerAccount,
+    gain_loss: &LedgerAccount,
+) -> Result<(), DomainError> {
+    if adjustment.account_type() != AccountType::TreasuryRevaluation
+        || gain_loss.account_type() != AccountType::PlatformUnrealisedFx
+        || adjustment.asset_id() != reporting_asset_id
+        || gain_loss.asset_id() != reporting_asset_id
+    {
+        return Err(DomainError::FxRevaluationInvalid {
+            reason: "revaluations post to the reporting asset's TREASURY_REVALUATION and PLATFORM_UNREALISED_FX accounts"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn revaluation_draft(
+    spec: &NewFxRevaluation,
+    line: &RevaluationLine,
+    reversal: bool,
+    actor: &str,
+) -> Result<JournalDraft, DomainError> {
+    let run_id = spec.public_id.value().to_string();
+    let description = if reversal {
+        format!("reversal of fx revaluation {run_id} (asset_id={})", line.asset_id)
+    } else {
+        format!("fx revaluation {run_id} (asset_id={})", line.asset_id)
+    };
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::FxRevaluation,
+        FxRevaluation::journal_ref(spec.public_id, line.asset_id, reversal)?,
+        actor,
+        Some(description),
+    )?;
+    // periods are half-open, so the last instant inside one is just before its end
+    draft.effective_at = Some(spec.valued_at - Duration::microseconds(1));
+    draft.metadata = JournalMetadata::new(BTreeMap::from([
+        (FX_REVALUATION_ID_KEY.to_string(), run_id),
+        (FX_REVALUED_ASSET_KEY.to_string(), line.asset_id.to_string()),
+        (FX_OPENING_RATE_KEY.to_string(), line.opening_rate.to_string()),
+        (FX_CLOSING_RATE_KEY.to_string(), line.closing_rate.to_string()),
+    ]))?;
+    Ok(draft)
+}
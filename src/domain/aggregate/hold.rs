@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::aggregate::{JournalDraft, EXPIRY_ACTOR};
+use crate::domain::entities::{AccountType, LedgerAccount};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, Money, PublicId};
+
+/// Metadata key linking each hold journal back to its hold.
+pub const HOLD_ID_KEY: &str = "hold_id";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HoldStatus {
+    /// Funds sit in `UserLocked`; may still be captured, released or expire.
+    Active,
+    /// Captured in full.
+    Captured,
+    /// Remainder given back to `UserAvailable` on request.
+    Released,
+    /// Remainder given back to `UserAvailable` after the deadline.
+    Expired,
+}
+
+impl HoldStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            HoldStatus::Active => "ACTIVE",
+            HoldStatus::Captured => "CAPTURED",
+            HoldStatus::Released => "RELEASED",
+            HoldStatus::Expired => "EXPIRED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ACTIVE" => Ok(Self::Active),
+            "CAPTURED" => Ok(Self::Captured),
+            "RELEASED" => Ok(Self::Released),
+            "EXPIRED" => Ok(Self::Expired),
+            other => Err(DomainError::InvalidHoldStatus { value: other.to_string() }),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HoldAction {
+    Place,
+    Capture,
+    Release,
+    Expire,
+}
+
+impl HoldAction {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            HoldAction::Place => "PLACE",
+            HoldAction::Capture => "CAPTURE",
+            HoldAction::Release => "RELEASE",
+            HoldAction::Expire => "EXPIRE",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PLACE" => Ok(Self::Place),
+            "CAPTURE" => Ok(Self::Capture),
+            "RELEASE" => Ok(Self::Release),
+            "EXPIRE" => Ok(Self::Expire),
+            other => Err(DomainError::HoldInvalid { reason: format!("unknown hold action {other}") }),
+        }
+    }
+}
+
+/// A journal posted for one step of a hold.
+#[derive(Debug, Clone)]
+pub struct HoldJournal {
+    pub seq: u32,
+    pub action: HoldAction,
+    pub amount_minor: i128,
+    pub journal_id: i64,
+    /// The account on the other side of `UserLocked`: available for place, release and
+    /// expire, the destination for a capture.
+    pub counterparty_account_id: i64,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A validated hold, not yet placed.
+#[derive(Debug, Clone)]
+pub struct NewHold {
+    pub public_id: PublicId,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub available_account_id: i64,
+    pub locked_account_id: i64,
+    pub amount_minor: i128,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+    pub created_by: String,
+}
+
+/// Funds moved from a user's `UserAvailable` to their `UserLocked` bucket under an id,
+/// so the captures and the release that follow can be traced back to it.
+#[derive(Debug, Clone)]
+pub struct Hold {
+    id: i64,
+    spec: NewHold,
+    captured_minor: i128,
+    released_minor: i128,
+    status: HoldStatus,
+    journal_count: u32,
+    status_changed_at: Option<DateTime<Utc>>,
+    status_changed_by: Option<String>,
+}
+
+fn hold_ref(public_id: PublicId, step: &str) -> Result<ExternalRef, DomainError> {
+    ExternalRef::new(format!("hold:{}:{step}", public_id.value()))
+}
+
+impl NewHold {
+    /// The journal placing the hold.
+    pub fn place_draft(&self) -> Result<JournalDraft, DomainError> {
+        let mut draft = hold_draft(self, "place", &self.created_by)?;
+        draft.add_line(self.available_account_id, Money::credit(self.amount_minor)?);
+        draft.add_line(self.locked_account_id, Money::debit(self.amount_minor)?);
+        Ok(draft)
+    }
+}
+
+fn hold_draft(spec: &NewHold, step: &str, actor: &str) -> Result<JournalDraft, DomainError> {
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::Hold,
+        hold_ref(spec.public_id, step)?,
+        actor,
+        spec.description.clone(),
+    )?;
+    draft.metadata = JournalMetadata::new(BTreeMap::from([(
+        HOLD_ID_KEY.to_string(),
+        spec.public_id.value().to_string(),
+    )]))?;
+    Ok(draft)
+}
+
+impl Hold {
+    #[allow(clippy::too_many_arguments)]
+    pub fn place(
+        public_id: PublicId,
+        available: &LedgerAccount,
+        locked: &LedgerAccount,
+        amount_minor: i128,
+        expires_at: Option<DateTime<Utc>>,
+        description: Option<String>,
+        created_by: &str,
+        now: DateTime<Utc>,
+    ) -> Result<NewHold, DomainError> {
+        let invalid = |reason: &str| DomainError::HoldInvalid { reason: reason.to_string() };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if amount_minor <= 0 {
+            return Err(invalid("amount must be positive"));
+        }
+        if available.account_type() != AccountType::UserAvailable || locked.account_type() != AccountType::UserLocked {
+            return Err(invalid("holds move funds from UserAvailable to UserLocked"));
+        }
+        let owner_id = available.owner_id().ok_or_else(|| invalid("hold accounts must belong to a user"))?;
+        if locked.owner_id() != Some(owner_id) {
+            return Err(DomainError::HoldMustBeSameUser {
+                available_account_id: available.id(),
+                locked_account_id: locked.id(),
+            });
+        }
+        if available.asset_id() != locked.asset_id() {
+            return Err(DomainError::CrossAssetPostingNotAllowed);
+        }
+        if expires_at.is_some_and(|at| at <= now) {
+            return Err(invalid("expires_at must be in the future"));
+        }
+
+        Ok(NewHold {
+            public_id,
+            owner_id,
+            asset_id: available.asset_id(),
+            available_account_id: available.id(),
+            locked_account_id: locked.id(),
+            amount_minor,
+            expires_at,
+            description,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: i64,
+        spec: NewHold,
+        captured_minor: i128,
+        released_minor: i128,
+        status: HoldStatus,
+        journal_count: u32,
+        status_changed_at: Option<DateTime<Utc>>,
+        status_changed_by: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            spec,
+            captured_minor,
+            released_minor,
+            status,
+            journal_count,
+            status_changed_at,
+            status_changed_by,
+        }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn owner_id(&self) -> Uuid { self.spec.owner_id }
+    pub fn asset_id(&self) -> i16 { self.spec.asset_id }
+    pub fn available_account_id(&self) -> i64 { self.spec.available_account_id }
+    pub fn locked_account_id(&self) -> i64 { self.spec.locked_account_id }
+    pub fn amount_minor(&self) -> i128 { self.spec.amount_minor }
+    pub fn captured_minor(&self) -> i128 { self.captured_minor }
+    pub fn released_minor(&self) -> i128 { self.released_minor }
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> { self.spec.expires_at }
+    pub fn description(&self) -> Option<&str> { self.spec.description.as_deref() }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+    pub fn status(&self) -> HoldStatus { self.status }
+    /// Journals posted so far, the placing one included; numbers the next one.
+    pub fn journal_count(&self) -> u32 { self.journal_count }
+    pub fn status_changed_at(&self) -> Option<DateTime<Utc>> { self.status_changed_at }
+    pub fn status_changed_by(&self) -> Option<&str> { self.status_changed_by.as_deref() }
+
+    /// Still sitting in `UserLocked`.
+    pub fn remaining_minor(&self) -> i128 {
+        self.spec.amount_minor - self.captured_minor - self.released_minor
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.spec.expires_at.is_some_and(|at| now >= at)
+    }
+
+    /// Active and before its deadline.
+    pub fn ensure_active(&self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != HoldStatus::Active {
+            return Err(DomainError::HoldNotActive { status: self.status.as_code().to_string() });
+        }
+        if let Some(at) = self.spec.expires_at.filter(|_| self.is_expired(now)) {
+            return Err(DomainError::HoldExpired { expired_at: at.to_rfc3339() });
+        }
+        Ok(())
+    }
+
+    /// The journal capturing `amount_minor` (the whole remainder when `None`) from
+    /// `UserLocked` to `destination_account_id`, and the amount it captures.
+    pub fn capture_draft(
+        &self,
+        amount_minor: Option<i128>,
+        destination_account_id: i64,
+        actor: &str,
+    ) -> Result<(JournalDraft, i128), DomainError> {
+        let remaining = self.remaining_minor();
+        let amount = amount_minor.unwrap_or(remaining);
+        if amount <= 0 || amount > remaining {
+            return Err(DomainError::HoldCaptureAmountInvalid {
+                reason: format!("must be between 1 and the remaining {remaining}"),
+            });
+        }
+        if destination_account_id == self.spec.locked_account_id {
+            return Err(DomainError::HoldInvalid { reason: "cannot capture into the locked account".into() });
+        }
+
+        let mut draft = hold_draft(&self.spec, &format!("capture:{}", self.journal_count), actor)?;
+        draft.add_line(self.spec.locked_account_id, Money::credit(amount)?);
+        draft.add_line(destination_account_id, Money::debit(amount)?);
+        Ok((draft, amount))
+    }
+
+    /// The journal returning the remainder to `UserAvailable`, on release or expiry.
+    pub fn release_draft(&self, action: HoldAction, actor: &str) -> Result<JournalDraft, DomainError> {
+        let step = match action {
+            HoldAction::Release => "release",
+            HoldAction::Expire => "expire",
+            other => {
+                return Err(DomainError::HoldInvalid {
+                    reason: format!("{} does not release a hold", other.as_code()),
+                })
+            }
+        };
+        let remaining = self.remaining_minor();
+        if remaining <= 0 {
+            return Err(DomainError::HoldNotActive { status: self.status.as_code().to_string() });
+        }
+
+        let mut draft = hold_draft(&self.spec, step, actor)?;
+        draft.add_line(self.spec.locked_account_id, Money::credit(remaining)?);
+        draft.add_line(self.spec.available_account_id, Money::debit(remaining)?);
+        Ok(draft)
+    }
+
+    /// Records a posted capture; capturing the last of the hold completes it.
+    pub fn record_capture(&mut self, amount_minor: i128, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_active(at)?;
+        if amount_minor <= 0 || amount_minor > self.remaining_minor() {
+            return Err(DomainError::HoldCaptureAmountInvalid {
+                reason: format!("must be between 1 and the remaining {}", self.remaining_minor()),
+            });
+        }
+        self.captured_minor += amount_minor;
+        self.journal_count += 1;
+        if self.remaining_minor() == 0 {
+            self.set_status(HoldStatus::Captured, actor, at);
+        }
+        Ok(())
+    }
+
+    /// Records a posted release of the remainder.
+    pub fn record_release(&mut self, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != HoldStatus::Active {
+            return Err(DomainError::HoldNotActive { status: self.status.as_code().to_string() });
+        }
+        self.release_remaining(HoldStatus::Released, actor, at);
+        Ok(())
+    }
+
+    /// Records the posted release of a hold whose deadline has passed.
+    pub fn expire(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != HoldStatus::Active {
+            return Err(DomainError::HoldNotActive { status: self.status.as_code().to_string() });
+        }
+        if !self.is_expired(at) {
+            return Err(DomainError::HoldInvalid { reason: "hold has not expired".into() });
+        }
+        self.release_remaining(HoldStatus::Expired, EXPIRY_ACTOR, at);
+        Ok(())
+    }
+
+    fn release_remaining(&mut self, status: HoldStatus, actor: &str, at: DateTime<Utc>) {
+        self.released_minor += self.remaining_minor();
+        self.journal_count += 1;
+        self.set_status(status, actor, at);
+    }
+
+    fn set_status(&mut self, status: HoldStatus, actor: &str, at: DateTime<Utc>) {
+        self.status = status;
+        self.status_changed_at = Some(at);
+        self.status_changed_by = Some(actor.to_string());
+    }
+}
@@ -1,13 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use crate::domain::entities::LedgerAccount;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::{AccountStatusTimeline, LedgerAccount, PostingDirection};
 use crate::domain::error::DomainError;
-use crate::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
+
+const MAX_MEMO_LEN: usize = 500;
+
+/// Metadata key on adjusting journals naming the period being corrected.
+pub const CORRECTS_PERIOD_KEY: &str = "corrects_period_id";
 
 #[derive(Debug, Clone)]
 pub struct JournalLineDraft {
     pub account_id: i64,
-    pub amount: Money, 
+    pub amount: Money,
+    pub memo: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -17,7 +25,15 @@ pub struct JournalDraft {
     pub external_ref: ExternalRef,
     pub description: Option<String>,
     pub created_by: String,
+    pub metadata: JournalMetadata,
+    /// Value date; `None` takes effect when posted.
+    pub effective_at: Option<DateTime<Utc>>,
+    /// Lets the lines span assets, as in a swap whose legs must commit together. Each
+    /// asset's lines must then balance on their own.
+    pub multi_asset: bool,
     lines: Vec<JournalLineDraft>,
+    tags: Vec<JournalTag>,
+    fee_rule_ids: Vec<i64>,
 }
 
 impl JournalDraft {
@@ -39,18 +55,57 @@ impl JournalDraft {
             external_ref,
             description,
             created_by,
+            metadata: JournalMetadata::default(),
+            effective_at: None,
+            multi_asset: false,
             lines: vec![],
+            tags: vec![],
+            fee_rule_ids: vec![],
         })
     }
 
     pub fn add_line(&mut self, account_id: i64, amount: Money) {
-        self.lines.push(JournalLineDraft { account_id, amount });
+        self.lines.push(JournalLineDraft { account_id, amount, memo: None });
+    }
+
+    /// Adds a line with a note describing it; blank memos are dropped.
+    pub fn add_line_with_memo(&mut self, account_id: i64, amount: Money, memo: Option<String>) {
+        let memo = memo.map(|m| m.trim().to_string()).filter(|m| !m.is_empty());
+        self.lines.push(JournalLineDraft { account_id, amount, memo });
+    }
+
+    pub fn add_tag(&mut self, tag: JournalTag) {
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    pub fn tags(&self) -> &[JournalTag] {
+        &self.tags
     }
 
     pub fn lines(&self) -> &[JournalLineDraft] {
         &self.lines
     }
 
+    /// Distinct account ids referenced by the draft, ascending.
+    pub fn account_ids(&self) -> Vec<i64> {
+        let mut ids: Vec<i64> = self.lines.iter().map(|l| l.account_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    pub fn record_fee_rule(&mut self, rule_id: i64) {
+        if !self.fee_rule_ids.contains(&rule_id) {
+            self.fee_rule_ids.push(rule_id);
+        }
+    }
+
+    pub fn fee_rule_ids(&self) -> &[i64] {
+        &self.fee_rule_ids
+    }
+
     // ---- pure invariants ----
 
     pub fn ensure_non_empty(&self) -> Result<(), DomainError> {
@@ -75,21 +130,46 @@ impl JournalDraft {
         Ok(())
     }
 
+    pub fn ensure_memo_lengths(&self) -> Result<(), DomainError> {
+        if self.lines.iter().filter_map(|l| l.memo.as_ref()).any(|m| m.chars().count() > MAX_MEMO_LEN) {
+            return Err(DomainError::JournalLineMemoTooLong { max: MAX_MEMO_LEN });
+        }
+        Ok(())
+    }
+
+    pub fn ensure_adjustment_target(&self) -> Result<(), DomainError> {
+        if self.external_ref_type == ExternalRefType::PeriodAdjustment
+            && self.metadata.get(CORRECTS_PERIOD_KEY).is_none_or(|v| v.parse::<i64>().is_err())
+        {
+            return Err(DomainError::AdjustingJournalPeriodMissing { key: CORRECTS_PERIOD_KEY });
+        }
+        Ok(())
+    }
+
     fn compress_lines(lines: Vec<JournalLineDraft>) -> Result<Vec<JournalLineDraft>, DomainError> {
-        let mut net: HashMap<i64, i128> = HashMap::new();
+        // memos of lines merged into one account are kept, in order, without repeats
+        let mut net: HashMap<i64, (i128, Vec<String>)> = HashMap::new();
         for l in lines {
-            *net.entry(l.account_id).or_insert(0) += l.amount.minor();
+            let entry = net.entry(l.account_id).or_default();
+            entry.0 += l.amount.minor();
+            if let Some(m) = l.memo
+                && !entry.1.contains(&m)
+            {
+                entry.1.push(m);
+            }
         }
 
         let mut out: Vec<JournalLineDraft> = Vec::with_capacity(net.len());
-        for (account_id, minor) in net {
+        for (account_id, (minor, memos)) in net {
             if minor == 0 {
                 continue;
             }
-            out.push(JournalLineDraft {
-                account_id,
-                amount: Money::from_signed_minor(minor)?,
-            });
+            let memo = if memos.is_empty() { None } else { Some(memos.join("; ")) };
+            // each memo fit on its own line, but the merged one must fit too
+            if memo.as_ref().is_some_and(|m| m.chars().count() > MAX_MEMO_LEN) {
+                return Err(DomainError::JournalLineMemoTooLong { max: MAX_MEMO_LEN });
+            }
+            out.push(JournalLineDraft { account_id, amount: Money::from_signed_minor(minor)?, memo });
         }
         Ok(out)
     }
@@ -101,32 +181,38 @@ impl JournalDraft {
         self.ensure_non_empty()?;
         self.ensure_balanced()?;
         self.ensure_no_zero_lines()?;
+        self.ensure_memo_lengths()?;
+        self.ensure_adjustment_target()?;
 
         // compress lines to reduce ambiguity/noise
         let compressed = Self::compress_lines(self.lines)?;
 
-        let mut asset_id: Option<i16> = None;
+        let mut lines: Vec<JournalLine> = Vec::with_capacity(compressed.len());
+        let mut net_by_asset: BTreeMap<i16, i128> = BTreeMap::new();
 
-        for line in &compressed {
+        for line in compressed {
             let acct = accounts_by_id
                 .get(&line.account_id)
                 .ok_or(DomainError::LedgerAccountNotFound { account_id: line.account_id })?;
 
-            acct.ensure_active()?;
+            acct.ensure_permits(PostingDirection::of(line.amount))?;
 
-            match asset_id {
-                None => asset_id = Some(acct.asset_id()),
-                Some(aid) if aid != acct.asset_id() => return Err(DomainError::CrossAssetPostingNotAllowed),
-                _ => {}
+            let asset_id = acct.asset_id();
+            if !self.multi_asset && net_by_asset.keys().any(|aid| *aid != asset_id) {
+                return Err(DomainError::CrossAssetPostingNotAllowed);
             }
-        }
+            *net_by_asset.entry(asset_id).or_insert(0) += line.amount.minor();
 
-        let lines = compressed
-            .into_iter()
-            .map(|l| JournalLine { account_id: l.account_id, amount: l.amount })
-            .collect();
+            lines.push(JournalLine { account_id: line.account_id, asset_id, amount: line.amount, memo: line.memo });
+        }
 
-        let asset_id = asset_id.ok_or(DomainError::JournalEmpty)?;
+        if net_by_asset.is_empty() {
+            return Err(DomainError::JournalEmpty);
+        }
+        if let Some((asset_id, _)) = net_by_asset.iter().find(|(_, net)| **net != 0) {
+            return Err(DomainError::JournalNotBalancedForAsset { asset_id: *asset_id });
+        }
+        let asset_id = if net_by_asset.len() == 1 { net_by_asset.keys().next().copied() } else { None };
 
         Ok(ValidatedJournal {
             public_id: self.public_id,
@@ -134,8 +220,13 @@ impl JournalDraft {
             external_ref: self.external_ref,
             description: self.description,
             created_by: self.created_by,
+            metadata: self.metadata,
+            tags: self.tags,
+            effective_at: self.effective_at,
+            multi_asset: self.multi_asset,
             asset_id,
             lines,
+            fee_rule_ids: self.fee_rule_ids,
         })
     }
 }
@@ -143,7 +234,10 @@ impl JournalDraft {
 #[derive(Debug, Clone)]
 pub struct JournalLine {
     pub account_id: i64,
+    /// The account's asset, recorded with the line.
+    pub asset_id: i16,
     pub amount: Money,
+    pub memo: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -153,33 +247,77 @@ pub struct ValidatedJournal {
     pub external_ref: ExternalRef,
     pub description: Option<String>,
     pub created_by: String,
-    pub asset_id: i16,
+    pub metadata: JournalMetadata,
+    pub tags: Vec<JournalTag>,
+    pub effective_at: Option<DateTime<Utc>>,
+    pub multi_asset: bool,
+    /// The asset shared by every line; `None` when the lines span assets.
+    pub asset_id: Option<i16>,
     pub lines: Vec<JournalLine>,
+    pub fee_rule_ids: Vec<i64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct PostedJournal {
     pub db_id: i64,
+    /// Gapless position in the ledger's commit order, starting at 1.
+    pub sequence: i64,
     pub public_id: PublicId,
     pub external_ref_type: ExternalRefType,
     pub external_ref: ExternalRef,
     pub description: Option<String>,
     pub created_by: String,
-    pub asset_id: i16,
+    pub metadata: JournalMetadata,
+    pub tags: Vec<JournalTag>,
+    pub effective_at: DateTime<Utc>,
+    pub posted_at: DateTime<Utc>,
+    pub multi_asset: bool,
+    /// The asset shared by every line; `None` when the lines span assets.
+    pub asset_id: Option<i16>,
     pub lines: Vec<JournalLine>,
+    pub fee_rule_ids: Vec<i64>,
 }
 
 impl ValidatedJournal {
-    pub fn into_posted(self, db_id: i64) -> PostedJournal {
+    /// The journal's asset, for uses that only handle journals in a single asset.
+    pub fn single_asset_id(&self) -> Result<i16, DomainError> {
+        self.asset_id.ok_or(DomainError::CrossAssetPostingNotAllowed)
+    }
+
+    /// Checks every line against its account's status history as of `at`,
+    /// for postings dated before the current account state.
+    pub fn ensure_status_at(
+        &self,
+        timelines: &HashMap<i64, AccountStatusTimeline>,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        for line in &self.lines {
+            let timeline = timelines.get(&line.account_id).ok_or_else(|| DomainError::AccountNotOpenAt {
+                account_id: line.account_id,
+                at: at.to_rfc3339(),
+            })?;
+            timeline.ensure_permits_at(at, PostingDirection::of(line.amount))?;
+        }
+        Ok(())
+    }
+
+    pub fn into_posted(self, db_id: i64, sequence: i64, posted_at: DateTime<Utc>) -> PostedJournal {
         PostedJournal {
             db_id,
+            sequence,
             public_id: self.public_id,
             external_ref_type: self.external_ref_type,
             external_ref: self.external_ref,
             description: self.description,
             created_by: self.created_by,
+            metadata: self.metadata,
+            tags: self.tags,
+            effective_at: self.effective_at.unwrap_or(posted_at),
+            posted_at,
+            multi_asset: self.multi_asset,
             asset_id: self.asset_id,
             lines: self.lines,
+            fee_rule_ids: self.fee_rule_ids,
         }
     }
 }
@@ -0,0 +1,281 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::aggregate::{JournalDraft, ValidatedJournal};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRefType, PublicId};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AdjustmentStatus {
+    PendingApproval,
+    Rejected,
+    Posted,
+}
+
+impl AdjustmentStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            AdjustmentStatus::PendingApproval => "PENDING_APPROVAL",
+            AdjustmentStatus::Rejected => "REJECTED",
+            AdjustmentStatus::Posted => "POSTED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PENDING_APPROVAL" => Ok(Self::PendingApproval),
+            "REJECTED" => Ok(Self::Rejected),
+            "POSTED" => Ok(Self::Posted),
+            other => Err(DomainError::InvalidAdjustmentStatus { value: other.to_string() }),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AdjustmentAction {
+    Submitted,
+    Approved,
+    Rejected,
+    Posted,
+}
+
+impl AdjustmentAction {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            AdjustmentAction::Submitted => "SUBMITTED",
+            AdjustmentAction::Approved => "APPROVED",
+            AdjustmentAction::Rejected => "REJECTED",
+            AdjustmentAction::Posted => "POSTED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "SUBMITTED" => Ok(Self::Submitted),
+            "APPROVED" => Ok(Self::Approved),
+            "REJECTED" => Ok(Self::Rejected),
+            "POSTED" => Ok(Self::Posted),
+            other => Err(DomainError::InvalidAdjustmentAction { value: other.to_string() }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjustmentAuditEntry {
+    pub action: AdjustmentAction,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// Adjustments at or above `min_amount_minor` (gross debits, in the asset's minor units)
+/// need `required_approvals` distinct checkers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalThreshold {
+    pub asset_id: i16,
+    pub min_amount_minor: i128,
+    pub required_approvals: u16,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ApprovalPolicy {
+    thresholds: Vec<ApprovalThreshold>,
+}
+
+impl ApprovalPolicy {
+    pub fn new(thresholds: Vec<ApprovalThreshold>) -> Self {
+        Self { thresholds }
+    }
+
+    /// One approval unless a threshold for the asset is met; the strictest matching threshold wins.
+    pub fn required_approvals(&self, asset_id: i16, gross_amount_minor: i128) -> u16 {
+        self.thresholds
+            .iter()
+            .filter(|t| t.asset_id == asset_id && gross_amount_minor >= t.min_amount_minor)
+            .map(|t| t.required_approvals)
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewManualAdjustment {
+    pub draft: JournalDraft,
+    pub asset_id: i16,
+    pub gross_amount_minor: i128,
+    pub required_approvals: u16,
+    pub submitted: AdjustmentAuditEntry,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManualAdjustment {
+    id: i64,
+    draft: JournalDraft,
+    asset_id: i16,
+    gross_amount_minor: i128,
+    status: AdjustmentStatus,
+    required_approvals: u16,
+    approvals: Vec<String>,
+    rejection_reason: Option<String>,
+    posted_journal_id: Option<i64>,
+    uncommitted_audit: Vec<AdjustmentAuditEntry>,
+}
+
+impl ManualAdjustment {
+    /// Builds the submission for a draft that already passed ledger validation.
+    /// The maker is the draft's `created_by`.
+    pub fn submit(
+        draft: JournalDraft,
+        validated: &ValidatedJournal,
+        policy: &ApprovalPolicy,
+        at: DateTime<Utc>,
+    ) -> Result<NewManualAdjustment, DomainError> {
+        if draft.external_ref_type != ExternalRefType::ManualAdjustment {
+            return Err(DomainError::ManualAdjustmentRefTypeRequired);
+        }
+
+        let gross_amount_minor: i128 = validated
+            .lines
+            .iter()
+            .map(|l| l.amount.minor())
+            .filter(|m| *m > 0)
+            .sum();
+
+        let submitted = AdjustmentAuditEntry {
+            action: AdjustmentAction::Submitted,
+            actor: draft.created_by.clone(),
+            reason: draft.description.clone(),
+            at,
+        };
+
+        let asset_id = validated.single_asset_id()?;
+        Ok(NewManualAdjustment {
+            required_approvals: policy.required_approvals(asset_id, gross_amount_minor),
+            asset_id,
+            gross_amount_minor,
+            draft,
+            submitted,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: i64,
+        draft: JournalDraft,
+        asset_id: i16,
+        gross_amount_minor: i128,
+        status: AdjustmentStatus,
+        required_approvals: u16,
+        approvals: Vec<String>,
+        rejection_reason: Option<String>,
+        posted_journal_id: Option<i64>,
+    ) -> Self {
+        Self {
+            id,
+            draft,
+            asset_id,
+            gross_amount_minor,
+            status,
+            required_approvals,
+            approvals,
+            rejection_reason,
+            posted_journal_id,
+            uncommitted_audit: vec![],
+        }
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.status != AdjustmentStatus::PendingApproval {
+            return Err(DomainError::ManualAdjustmentNotPending { status: self.status.as_code().to_string() });
+        }
+        Ok(())
+    }
+
+    fn ensure_checker(&self, actor: &str) -> Result<(), DomainError> {
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if actor == self.draft.created_by {
+            return Err(DomainError::ManualAdjustmentSelfApproval);
+        }
+        Ok(())
+    }
+
+    /// Records an approval. Returns `true` once enough distinct checkers have approved
+    /// and the draft should be posted.
+    pub fn approve(&mut self, approver: &str, at: DateTime<Utc>) -> Result<bool, DomainError> {
+        self.ensure_pending()?;
+        self.ensure_checker(approver)?;
+        if self.approvals.iter().any(|a| a == approver) {
+            return Err(DomainError::ManualAdjustmentAlreadyApproved { approver: approver.to_string() });
+        }
+
+        self.approvals.push(approver.to_string());
+        self.uncommitted_audit.push(AdjustmentAuditEntry {
+            action: AdjustmentAction::Approved,
+            actor: approver.to_string(),
+            reason: None,
+            at,
+        });
+
+        Ok(self.is_fully_approved())
+    }
+
+    pub fn reject(&mut self, approver: &str, reason: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+        self.ensure_checker(approver)?;
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(DomainError::RejectionReasonRequired);
+        }
+
+        self.status = AdjustmentStatus::Rejected;
+        self.rejection_reason = Some(reason.to_string());
+        self.uncommitted_audit.push(AdjustmentAuditEntry {
+            action: AdjustmentAction::Rejected,
+            actor: approver.to_string(),
+            reason: Some(reason.to_string()),
+            at,
+        });
+        Ok(())
+    }
+
+    pub fn mark_posted(&mut self, journal_id: i64, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+        if !self.is_fully_approved() {
+            return Err(DomainError::ManualAdjustmentNotApproved {
+                approvals: self.approvals.len(),
+                required: self.required_approvals,
+            });
+        }
+
+        self.status = AdjustmentStatus::Posted;
+        self.posted_journal_id = Some(journal_id);
+        self.uncommitted_audit.push(AdjustmentAuditEntry {
+            action: AdjustmentAction::Posted,
+            actor: actor.to_string(),
+            reason: None,
+            at,
+        });
+        Ok(())
+    }
+
+    pub fn is_fully_approved(&self) -> bool {
+        self.approvals.len() >= self.required_approvals as usize
+    }
+
+    pub fn take_audit(&mut self) -> Vec<AdjustmentAuditEntry> {
+        std::mem::take(&mut self.uncommitted_audit)
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.draft.public_id }
+    pub fn draft(&self) -> &JournalDraft { &self.draft }
+    pub fn asset_id(&self) -> i16 { self.asset_id }
+    pub fn gross_amount_minor(&self) -> i128 { self.gross_amount_minor }
+    pub fn status(&self) -> AdjustmentStatus { self.status }
+    pub fn required_approvals(&self) -> u16 { self.required_approvals }
+    pub fn approvals(&self) -> &[String] { &self.approvals }
+    pub fn rejection_reason(&self) -> Option<&str> { self.rejection_reason.as_deref() }
+    pub fn posted_journal_id(&self) -> Option<i64> { self.posted_journal_id }
+}
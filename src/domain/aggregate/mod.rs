@@ -1,4 +1,57 @@
 pub mod aggregate_root;
 mod journal;
+mod manual_adjustment;
 pub use self::journal::{PostedJournal, ValidatedJournal, JournalDraft};
+pub use self::journal::CORRECTS_PERIOD_KEY;
+pub use self::manual_adjustment::{
+    AdjustmentAction, AdjustmentAuditEntry, AdjustmentStatus, ApprovalPolicy, ApprovalThreshold,
+    ManualAdjustment, NewManualAdjustment,
+};
 
+mod scheduled_journal;
+pub use self::scheduled_journal::{
+    NewScheduledJournal, Recurrence, RetryPolicy, ScheduleStatus, ScheduledJournal, ScheduledJournalRun,
+    SCHEDULER_ACTOR,
+};
+mod pending_journal;
+pub use self::pending_journal::{
+    AvailableBalance, NewPendingJournal, PendingJournal, PendingStatus, EXPIRY_ACTOR,
+};
+mod hold;
+pub use self::hold::{Hold, HoldAction, HoldJournal, HoldStatus, NewHold, HOLD_ID_KEY};
+mod withdrawal;
+pub use self::withdrawal::{NewWithdrawal, Withdrawal, WithdrawalStatus, PAYOUT_RAIL_ACTOR};
+mod deposit;
+pub use self::deposit::{
+    ConfirmationThreshold, DepositIntent, DepositPolicy, DepositSource, DepositStatus, NewDepositIntent,
+    DEPOSIT_ID_KEY, DEPOSIT_TX_KEY,
+};
+mod transfer;
+pub use self::transfer::{TransferIntent, RECEIVER_OWNER_KEY, SENDER_OWNER_KEY};
+mod fx;
+pub use self::fx::{
+    FxLiquidity, FxPolicy, FxQuote, FxQuoteStatus, FxRate, NewFxQuote, NewFxRate, PairSpread, FX_APPLIED_RATE_KEY,
+    FX_LEG_KEY, FX_QUOTE_ID_KEY, FX_RATE_KEY, FX_SPREAD_KEY,
+};
+mod fx_revaluation;
+pub use self::fx_revaluation::{
+    FxRevaluation, FxRevaluationStatus, NewFxRevaluation, RevaluationLine, RevaluationPosition, FX_CLOSING_RATE_KEY,
+    FX_OPENING_RATE_KEY, FX_REVALUATION_ID_KEY, FX_REVALUED_ASSET_KEY, REVALUED_ACCOUNT_TYPES,
+};
+mod reserve_snapshot;
+pub use self::reserve_snapshot::{
+    NewReserveSnapshot, OwnerLiability, ReserveLeaf, ReserveSnapshot, LIABILITY_ACCOUNT_TYPES,
+};
+mod solvency_report;
+pub use self::solvency_report::{
+    NewSolvencyReport, SolvencyBalances, SolvencyReport, SolvencyStatus, SolvencyTrigger, FULL_COVERAGE_BPS,
+    HOLDING_ACCOUNT_TYPES, SOLVENCY_SCHEDULE_ACTOR,
+};
+mod feed_cursor;
+pub use self::feed_cursor::FeedCursor;
+mod webhook;
+pub use self::webhook::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookAttempt, WebhookDelivery, WebhookDeliveryState,
+    WebhookDeliveryStatus, WebhookEventType, WebhookSubscription, WebhookUrlPolicy, MIN_WEBHOOK_SECRET_LEN,
+    WEBHOOK_CONSUMER,
+};
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::aggregate::{JournalDraft, ValidatedJournal};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{Money, PublicId};
+
+/// Actor recorded when a pending journal lapses at its deadline.
+pub const EXPIRY_ACTOR: &str = "expiry";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PendingStatus {
+    /// Funds are reserved; the posted balance is unchanged.
+    Pending,
+    Committed,
+    Voided,
+    Expired,
+}
+
+impl PendingStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            PendingStatus::Pending => "PENDING",
+            PendingStatus::Committed => "COMMITTED",
+            PendingStatus::Voided => "VOIDED",
+            PendingStatus::Expired => "EXPIRED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PENDING" => Ok(Self::Pending),
+            "COMMITTED" => Ok(Self::Committed),
+            "VOIDED" => Ok(Self::Voided),
+            "EXPIRED" => Ok(Self::Expired),
+            other => Err(DomainError::InvalidPendingStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// Posted balance of an account next to what pending journals reserve on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvailableBalance {
+    pub account_id: i64,
+    pub asset_id: i16,
+    pub posted_minor: i128,
+    pub pending_inflow_minor: i128,
+    pub pending_outflow_minor: i128,
+}
+
+impl AvailableBalance {
+    /// Spendable now: incoming pending amounts are not counted until committed.
+    pub fn available_minor(&self) -> i128 {
+        self.posted_minor - self.pending_outflow_minor
+    }
+
+    /// The balance if every pending journal were committed in full.
+    pub fn pending_minor(&self) -> i128 {
+        self.posted_minor + self.pending_inflow_minor - self.pending_outflow_minor
+    }
+}
+
+/// A validated pending journal, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewPendingJournal {
+    pub draft: JournalDraft,
+    pub asset_id: i16,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// First phase of a two-phase journal. Its lines reserve funds until it is committed,
+/// voided or expires; committing posts it under the same external reference.
+#[derive(Debug, Clone)]
+pub struct PendingJournal {
+    id: i64,
+    draft: JournalDraft,
+    asset_id: i16,
+    status: PendingStatus,
+    expires_at: DateTime<Utc>,
+    committed_amount_minor: Option<i128>,
+    posted_journal_id: Option<i64>,
+    status_changed_at: Option<DateTime<Utc>>,
+    status_changed_by: Option<String>,
+}
+
+impl PendingJournal {
+    /// Builds the pending journal from a draft that already passed ledger validation.
+    /// Lines are kept in their validated (netted) form, which is what gets reserved.
+    pub fn authorize(
+        validated: &ValidatedJournal,
+        expires_at: DateTime<Utc>,
+        max_window: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<NewPendingJournal, DomainError> {
+        let invalid = |reason: &str| DomainError::PendingJournalInvalid { reason: reason.to_string() };
+
+        if validated.effective_at.is_some() {
+            return Err(invalid("pending journals are dated when committed; leave effective_at empty"));
+        }
+        if !validated.fee_rule_ids.is_empty() {
+            return Err(invalid("fees are not applied to pending journals"));
+        }
+        if expires_at <= now {
+            return Err(invalid("expires_at must be in the future"));
+        }
+        if expires_at > now + max_window {
+            return Err(invalid(&format!("expires_at may be at most {} hours ahead", max_window.num_hours())));
+        }
+
+        let mut draft = JournalDraft::new(
+            validated.public_id,
+            validated.external_ref_type,
+            validated.external_ref.clone(),
+            validated.created_by.clone(),
+            validated.description.clone(),
+        )?;
+        draft.metadata = validated.metadata.clone();
+        for t in &validated.tags {
+            draft.add_tag(t.clone());
+        }
+        for l in &validated.lines {
+            draft.add_line_with_memo(l.account_id, l.amount, l.memo.clone());
+        }
+
+        Ok(NewPendingJournal { draft, asset_id: validated.single_asset_id()?, expires_at })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: i64,
+        draft: JournalDraft,
+        asset_id: i16,
+        status: PendingStatus,
+        expires_at: DateTime<Utc>,
+        committed_amount_minor: Option<i128>,
+        posted_journal_id: Option<i64>,
+        status_changed_at: Option<DateTime<Utc>>,
+        status_changed_by: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            draft,
+            asset_id,
+            status,
+            expires_at,
+            committed_amount_minor,
+            posted_journal_id,
+            status_changed_at,
+            status_changed_by,
+        }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.draft.public_id }
+    pub fn draft(&self) -> &JournalDraft { &self.draft }
+    pub fn asset_id(&self) -> i16 { self.asset_id }
+    pub fn status(&self) -> PendingStatus { self.status }
+    pub fn expires_at(&self) -> DateTime<Utc> { self.expires_at }
+    pub fn committed_amount_minor(&self) -> Option<i128> { self.committed_amount_minor }
+    pub fn posted_journal_id(&self) -> Option<i64> { self.posted_journal_id }
+    pub fn status_changed_at(&self) -> Option<DateTime<Utc>> { self.status_changed_at }
+    pub fn status_changed_by(&self) -> Option<&str> { self.status_changed_by.as_deref() }
+
+    /// Gross amount authorized: the sum of the positive lines.
+    pub fn authorized_amount_minor(&self) -> i128 {
+        self.draft.lines().iter().map(|l| l.amount.minor()).filter(|m| *m > 0).sum()
+    }
+
+    /// Signed amount per account held while pending.
+    pub fn reservation(&self) -> HashMap<i64, i128> {
+        let mut out = HashMap::new();
+        for l in self.draft.lines() {
+            *out.entry(l.account_id).or_insert(0) += l.amount.minor();
+        }
+        out
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Still pending and before its deadline.
+    pub fn ensure_open(&self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PendingStatus::Pending {
+            return Err(DomainError::PendingJournalNotPending { status: self.status.as_code().to_string() });
+        }
+        if self.is_expired(now) {
+            return Err(DomainError::PendingJournalExpired { expired_at: self.expires_at.to_rfc3339() });
+        }
+        Ok(())
+    }
+
+    /// The journal to post for a commit of `amount_minor`, or of the full amount when `None`.
+    /// A partial commit scales a two-line journal down; with more lines only a full commit
+    /// is unambiguous.
+    pub fn commit_draft(&self, amount_minor: Option<i128>, committed_by: &str) -> Result<JournalDraft, DomainError> {
+        let full = self.authorized_amount_minor();
+        let amount = amount_minor.unwrap_or(full);
+        if amount <= 0 || amount > full {
+            return Err(DomainError::PendingCommitAmountInvalid {
+                reason: format!("must be between 1 and the authorized {full}"),
+            });
+        }
+
+        let mut draft = JournalDraft::new(
+            self.draft.public_id,
+            self.draft.external_ref_type,
+            self.draft.external_ref.clone(),
+            committed_by,
+            self.draft.description.clone(),
+        )?;
+        draft.metadata = self.draft.metadata.clone();
+        for t in self.draft.tags() {
+            draft.add_tag(t.clone());
+        }
+
+        if amount == full {
+            for l in self.draft.lines() {
+                draft.add_line_with_memo(l.account_id, l.amount, l.memo.clone());
+            }
+            return Ok(draft);
+        }
+
+        if self.draft.lines().len() != 2 {
+            return Err(DomainError::PendingCommitAmountInvalid {
+                reason: "partial commits need a journal with exactly two lines".to_string(),
+            });
+        }
+        for l in self.draft.lines() {
+            let signed = if l.amount.minor() > 0 { amount } else { -amount };
+            draft.add_line_with_memo(l.account_id, Money::from_signed_minor(signed)?, l.memo.clone());
+        }
+        Ok(draft)
+    }
+
+    pub fn mark_committed(
+        &mut self,
+        journal_id: i64,
+        amount_minor: i128,
+        actor: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        self.ensure_open(at)?;
+        self.set_status(PendingStatus::Committed, actor, at)?;
+        self.committed_amount_minor = Some(amount_minor);
+        self.posted_journal_id = Some(journal_id);
+        Ok(())
+    }
+
+    pub fn void(&mut self, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PendingStatus::Pending {
+            return Err(DomainError::PendingJournalNotPending { status: self.status.as_code().to_string() });
+        }
+        self.set_status(PendingStatus::Voided, actor, at)
+    }
+
+    /// Releases a pending journal whose deadline has passed.
+    pub fn expire(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PendingStatus::Pending {
+            return Err(DomainError::PendingJournalNotPending { status: self.status.as_code().to_string() });
+        }
+        if !self.is_expired(at) {
+            return Err(DomainError::PendingJournalInvalid {
+                reason: format!("does not expire until {}", self.expires_at.to_rfc3339()),
+            });
+        }
+        self.set_status(PendingStatus::Expired, EXPIRY_ACTOR, at)
+    }
+
+    fn set_status(&mut self, to: PendingStatus, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        self.status = to;
+        self.status_changed_at = Some(at);
+        self.status_changed_by = Some(actor.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::AccountType;
+use crate::domain::error::DomainError;
+use crate::domain::services::{hash_owner, Digest, InclusionProof, MerkleNode, MerkleSumTree};
+use crate::domain::value_objects::PublicId;
+
+/// User account types whose balances are liabilities covered by reserves.
+pub const LIABILITY_ACCOUNT_TYPES: [AccountType; 2] = [AccountType::UserAvailable, AccountType::UserLocked];
+
+const MIN_SALT_LEN: usize = 16;
+
+/// What the platform owes one owner in the asset: their available plus locked balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerLiability {
+    pub owner_id: Uuid,
+    pub balance_minor: i128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReserveLeaf {
+    pub owner_id: Uuid,
+    pub owner_hash: Digest,
+    pub balance_minor: i128,
+}
+
+/// A snapshot of user liabilities in one asset, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewReserveSnapshot {
+    pub public_id: PublicId,
+    pub asset_id: i16,
+    pub taken_at: DateTime<Utc>,
+    /// Mixed into every owner hash; handed to owners with their proofs.
+    pub salt: Vec<u8>,
+    /// Ordered by owner hash, which fixes each leaf's position in the tree.
+    pub leaves: Vec<ReserveLeaf>,
+    pub root: MerkleNode,
+    pub created_by: String,
+}
+
+/// Proof-of-reserves commitment: a Merkle sum tree over every owner's liability in the
+/// asset, keyed by salted owner hashes. The root hash and total are published; each owner
+/// can fetch a proof that their balance is counted in that total.
+#[derive(Debug, Clone)]
+pub struct ReserveSnapshot {
+    id: i64,
+    spec: NewReserveSnapshot,
+}
+
+impl ReserveSnapshot {
+    pub fn take(
+        public_id: PublicId,
+        asset_id: i16,
+        liabilities: Vec<OwnerLiability>,
+        salt: Vec<u8>,
+        created_by: &str,
+        taken_at: DateTime<Utc>,
+    ) -> Result<NewReserveSnapshot, DomainError> {
+        let invalid = |reason: String| DomainError::ReserveSnapshotInvalid { reason };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if salt.len() < MIN_SALT_LEN {
+            return Err(invalid(format!("salt must be at least {MIN_SALT_LEN} bytes")));
+        }
+        let mut seen = HashSet::new();
+        if let Some(dup) = liabilities.iter().find(|l| !seen.insert(l.owner_id)) {
+            return Err(invalid(format!("owner {} appears more than once", dup.owner_id)));
+        }
+        if let Some(negative) = liabilities.iter().find(|l| l.balance_minor < 0) {
+            return Err(invalid(format!("owner {} has a negative balance", negative.owner_id)));
+        }
+
+        let mut leaves: Vec<ReserveLeaf> = liabilities
+            .into_iter()
+            .map(|l| ReserveLeaf {
+                owner_id: l.owner_id,
+                owner_hash: hash_owner(&salt, l.owner_id),
+                balance_minor: l.balance_minor,
+            })
+            .collect();
+        leaves.sort_by_key(|l| l.owner_hash);
+
+        let root = Self::tree_of(&leaves)?.root();
+        Ok(NewReserveSnapshot {
+            public_id,
+            asset_id,
+            taken_at,
+            salt,
+            leaves,
+            root,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(id: i64, spec: NewReserveSnapshot) -> Self {
+        Self { id, spec }
+    }
+
+    fn tree_of(leaves: &[ReserveLeaf]) -> Result<MerkleSumTree, DomainError> {
+        MerkleSumTree::build(leaves.iter().map(|l| (l.owner_hash, l.balance_minor)).collect())
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn asset_id(&self) -> i16 { self.spec.asset_id }
+    pub fn taken_at(&self) -> DateTime<Utc> { self.spec.taken_at }
+    pub fn salt(&self) -> &[u8] { &self.spec.salt }
+    pub fn root(&self) -> MerkleNode { self.spec.root }
+    pub fn total_minor(&self) -> i128 { self.spec.root.sum }
+    pub fn leaf_count(&self) -> usize { self.spec.leaves.len() }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+
+    /// The owner's inclusion proof, or `None` when they had no account in the asset.
+    /// The tree is rebuilt from the stored leaves and must reproduce the published root.
+    pub fn proof_for(&self, owner_id: Uuid) -> Result<Option<InclusionProof>, DomainError> {
+        let tree = Self::tree_of(&self.spec.leaves)?;
+        if tree.root() != self.spec.root {
+            return Err(DomainError::ReserveSnapshotInvalid { reason: "stored leaves do not match the published root".into() });
+        }
+        let owner_hash = hash_owner(&self.spec.salt, owner_id);
+        Ok(tree.position(&owner_hash).and_then(|i| tree.proof(i)))
+    }
+}
@@ -0,0 +1,376 @@
+use chrono::{DateTime, Duration, Months, Utc};
+
+use crate::domain::aggregate::JournalDraft;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, PublicId};
+
+/// Actor recorded when the worker itself changes a schedule's status.
+pub const SCHEDULER_ACTOR: &str = "scheduler";
+
+const MIN_INTERVAL_SECS: i64 = 60;
+// leaves room for the `:<occurrence>` suffix within the 200 char external_ref limit
+const MAX_REF_PREFIX_LEN: usize = 180;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Recurrence {
+    /// A single posting at `starts_at`.
+    Once,
+    /// Every fixed interval from `starts_at`.
+    Interval(Duration),
+    /// Same day of month as `starts_at`, clamped to the month's last day.
+    Monthly,
+}
+
+impl Recurrence {
+    pub fn interval(every: Duration) -> Result<Self, DomainError> {
+        if every.num_seconds() < MIN_INTERVAL_SECS {
+            return Err(DomainError::InvalidRecurrence {
+                value: format!("interval must be at least {MIN_INTERVAL_SECS} seconds"),
+            });
+        }
+        Ok(Self::Interval(every))
+    }
+
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            Recurrence::Once => "ONCE",
+            Recurrence::Interval(_) => "INTERVAL",
+            Recurrence::Monthly => "MONTHLY",
+        }
+    }
+
+    pub fn interval_seconds(&self) -> Option<i64> {
+        match self {
+            Recurrence::Interval(d) => Some(d.num_seconds()),
+            _ => None,
+        }
+    }
+
+    pub fn from_parts(code: &str, interval_seconds: Option<i64>) -> Result<Self, DomainError> {
+        match (code, interval_seconds) {
+            ("ONCE", None) => Ok(Self::Once),
+            ("MONTHLY", None) => Ok(Self::Monthly),
+            ("INTERVAL", Some(secs)) => Self::interval(Duration::seconds(secs)),
+            ("INTERVAL", None) => Err(DomainError::InvalidRecurrence {
+                value: "INTERVAL requires interval_seconds".to_string(),
+            }),
+            (other, _) => Err(DomainError::InvalidRecurrence { value: other.to_string() }),
+        }
+    }
+
+    /// Scheduled time of occurrence `n` (0-based) for a schedule anchored at `starts_at`.
+    /// Computed from the anchor so monthly schedules do not drift after short months.
+    pub fn occurrence_at(&self, starts_at: DateTime<Utc>, n: u32) -> Option<DateTime<Utc>> {
+        match self {
+            Recurrence::Once => (n == 0).then_some(starts_at),
+            Recurrence::Interval(every) => starts_at.checked_add_signed(every.checked_mul(n as i32)?),
+            Recurrence::Monthly => starts_at.checked_add_months(Months::new(n)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    Active,
+    Paused,
+    /// Every occurrence has been posted or skipped.
+    Completed,
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            ScheduleStatus::Active => "ACTIVE",
+            ScheduleStatus::Paused => "PAUSED",
+            ScheduleStatus::Completed => "COMPLETED",
+            ScheduleStatus::Cancelled => "CANCELLED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ACTIVE" => Ok(Self::Active),
+            "PAUSED" => Ok(Self::Paused),
+            "COMPLETED" => Ok(Self::Completed),
+            "CANCELLED" => Ok(Self::Cancelled),
+            other => Err(DomainError::InvalidScheduleStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// Backoff between failed attempts at the same occurrence. After `max_failures`
+/// consecutive failures the schedule is paused for an operator to look at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    base: Duration,
+    max: Duration,
+    max_failures: u32,
+}
+
+impl RetryPolicy {
+    pub fn new(base: Duration, max: Duration, max_failures: u32) -> Self {
+        Self { base, max, max_failures: max_failures.max(1) }
+    }
+
+    /// `base * 2^(failures-1)`, capped at `max`.
+    pub fn delay(&self, failures: u32) -> Duration {
+        // 2^30 is the largest power of two an i32 holds; `max` is reached long before
+        let factor = 1i32 << failures.saturating_sub(1).min(30);
+        self.base.checked_mul(factor).map_or(self.max, |d| d.min(self.max))
+    }
+
+    pub fn max_failures(&self) -> u32 {
+        self.max_failures
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(Duration::minutes(1), Duration::hours(1), 10)
+    }
+}
+
+/// One posted occurrence of a schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledJournalRun {
+    pub occurrence: u32,
+    pub scheduled_for: DateTime<Utc>,
+    pub journal_id: i64,
+    pub posted_at: DateTime<Utc>,
+}
+
+/// A validated schedule, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewScheduledJournal {
+    pub template: JournalDraft,
+    pub recurrence: Recurrence,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub max_occurrences: Option<u32>,
+}
+
+/// A journal template posted at `starts_at` and then on its recurrence.
+///
+/// Occurrence `n` posts with external ref `<template ref>:<n>` and is dated to its
+/// scheduled time, so a retried or concurrently claimed occurrence replays the same
+/// journal instead of posting twice.
+#[derive(Debug, Clone)]
+pub struct ScheduledJournal {
+    id: i64,
+    template: JournalDraft,
+    recurrence: Recurrence,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+    max_occurrences: Option<u32>,
+    status: ScheduleStatus,
+    next_occurrence: u32,
+    next_run_at: Option<DateTime<Utc>>,
+    due_at: Option<DateTime<Utc>>,
+    posted_count: u32,
+    consecutive_failures: u32,
+    last_error: Option<String>,
+    status_changed_at: Option<DateTime<Utc>>,
+    status_changed_by: Option<String>,
+}
+
+impl ScheduledJournal {
+    /// Checks the template's shape; ledger validation against accounts is up to the caller.
+    pub fn create(
+        template: JournalDraft,
+        recurrence: Recurrence,
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+        max_occurrences: Option<u32>,
+    ) -> Result<NewScheduledJournal, DomainError> {
+        let invalid = |reason: &str| DomainError::ScheduleInvalid { reason: reason.to_string() };
+
+        if template.external_ref_type == ExternalRefType::ManualAdjustment {
+            return Err(invalid("manual adjustments require maker-checker approval"));
+        }
+        if template.effective_at.is_some() {
+            return Err(invalid("occurrences are dated to their scheduled time; leave effective_at empty"));
+        }
+        if template.external_ref.as_str().len() > MAX_REF_PREFIX_LEN {
+            return Err(DomainError::ExternalRefTooLong { max: MAX_REF_PREFIX_LEN });
+        }
+        if ends_at.is_some_and(|end| end <= starts_at) {
+            return Err(invalid("schedule must start before it ends"));
+        }
+        if max_occurrences == Some(0) {
+            return Err(invalid("max_occurrences must be at least 1"));
+        }
+        template.ensure_non_empty()?;
+        template.ensure_no_zero_lines()?;
+        template.ensure_balanced()?;
+
+        Ok(NewScheduledJournal { template, recurrence, starts_at, ends_at, max_occurrences })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: i64,
+        template: JournalDraft,
+        recurrence: Recurrence,
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+        max_occurrences: Option<u32>,
+        status: ScheduleStatus,
+        next_occurrence: u32,
+        next_run_at: Option<DateTime<Utc>>,
+        due_at: Option<DateTime<Utc>>,
+        posted_count: u32,
+        consecutive_failures: u32,
+        last_error: Option<String>,
+        status_changed_at: Option<DateTime<Utc>>,
+        status_changed_by: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            template,
+            recurrence,
+            starts_at,
+            ends_at,
+            max_occurrences,
+            status,
+            next_occurrence,
+            next_run_at,
+            due_at,
+            posted_count,
+            consecutive_failures,
+            last_error,
+            status_changed_at,
+            status_changed_by,
+        }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.template.public_id }
+    pub fn template(&self) -> &JournalDraft { &self.template }
+    pub fn recurrence(&self) -> Recurrence { self.recurrence }
+    pub fn starts_at(&self) -> DateTime<Utc> { self.starts_at }
+    pub fn ends_at(&self) -> Option<DateTime<Utc>> { self.ends_at }
+    pub fn max_occurrences(&self) -> Option<u32> { self.max_occurrences }
+    pub fn status(&self) -> ScheduleStatus { self.status }
+    pub fn next_occurrence(&self) -> u32 { self.next_occurrence }
+    pub fn next_run_at(&self) -> Option<DateTime<Utc>> { self.next_run_at }
+    pub fn due_at(&self) -> Option<DateTime<Utc>> { self.due_at }
+    pub fn posted_count(&self) -> u32 { self.posted_count }
+    pub fn consecutive_failures(&self) -> u32 { self.consecutive_failures }
+    pub fn last_error(&self) -> Option<&str> { self.last_error.as_deref() }
+    pub fn status_changed_at(&self) -> Option<DateTime<Utc>> { self.status_changed_at }
+    pub fn status_changed_by(&self) -> Option<&str> { self.status_changed_by.as_deref() }
+
+    pub fn occurrence_ref(&self, n: u32) -> Result<ExternalRef, DomainError> {
+        ExternalRef::new(format!("{}:{n}", self.template.external_ref.as_str()))
+    }
+
+    /// The journal for the pending occurrence, dated to its scheduled time.
+    pub fn occurrence_draft(&self, public_id: PublicId) -> Result<JournalDraft, DomainError> {
+        let at = match (self.status, self.next_run_at) {
+            (ScheduleStatus::Active, Some(at)) => at,
+            _ => {
+                return Err(DomainError::ScheduleInvalid {
+                    reason: format!("schedule is {} with nothing due", self.status.as_code()),
+                });
+            }
+        };
+
+        let mut draft = self.template.clone();
+        draft.public_id = public_id;
+        draft.external_ref = self.occurrence_ref(self.next_occurrence)?;
+        draft.effective_at = Some(at);
+        Ok(draft)
+    }
+
+    pub fn record_posted(&mut self, at: DateTime<Utc>) {
+        self.posted_count += 1;
+        self.consecutive_failures = 0;
+        self.last_error = None;
+        self.advance(at);
+    }
+
+    /// Backs off before the same occurrence is tried again; pauses after too many failures.
+    pub fn record_failure(&mut self, error: impl Into<String>, at: DateTime<Utc>, policy: &RetryPolicy) {
+        self.consecutive_failures += 1;
+        self.last_error = Some(error.into());
+        self.due_at = Some(at + policy.delay(self.consecutive_failures));
+        if self.consecutive_failures >= policy.max_failures() {
+            self.set_status(ScheduleStatus::Paused, SCHEDULER_ACTOR, at);
+        }
+    }
+
+    pub fn pause(&mut self, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_transition(actor, &[ScheduleStatus::Active], ScheduleStatus::Paused)?;
+        self.set_status(ScheduleStatus::Paused, actor, at);
+        Ok(())
+    }
+
+    /// Occurrences that fell due while paused are posted in order on resume, unless
+    /// `skip_missed` drops those scheduled before `at`.
+    pub fn resume(&mut self, actor: &str, skip_missed: bool, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_transition(actor, &[ScheduleStatus::Paused], ScheduleStatus::Active)?;
+        self.set_status(ScheduleStatus::Active, actor, at);
+        self.consecutive_failures = 0;
+        self.due_at = self.next_run_at;
+
+        if skip_missed {
+            while self.status == ScheduleStatus::Active && self.next_run_at.is_some_and(|next| next < at) {
+                self.advance(at);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, actor: &str, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_transition(
+            actor,
+            &[ScheduleStatus::Active, ScheduleStatus::Paused],
+            ScheduleStatus::Cancelled,
+        )?;
+        self.set_status(ScheduleStatus::Cancelled, actor, at);
+        self.due_at = None;
+        Ok(())
+    }
+
+    fn ensure_transition(
+        &self,
+        actor: &str,
+        from: &[ScheduleStatus],
+        to: ScheduleStatus,
+    ) -> Result<(), DomainError> {
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if !from.contains(&self.status) {
+            return Err(DomainError::ScheduleTransitionNotAllowed {
+                from: self.status.as_code().to_string(),
+                to: to.as_code().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn set_status(&mut self, to: ScheduleStatus, actor: &str, at: DateTime<Utc>) {
+        self.status = to;
+        self.status_changed_at = Some(at);
+        self.status_changed_by = Some(actor.to_string());
+    }
+
+    /// Moves to the next occurrence; completes once past `ends_at` or `max_occurrences`
+    /// (skipped occurrences count towards the maximum).
+    fn advance(&mut self, at: DateTime<Utc>) {
+        self.next_occurrence += 1;
+        let next = self
+            .recurrence
+            .occurrence_at(self.starts_at, self.next_occurrence)
+            .filter(|t| self.ends_at.is_none_or(|end| *t < end))
+            .filter(|_| self.max_occurrences.is_none_or(|max| self.next_occurrence < max));
+
+        self.next_run_at = next;
+        self.due_at = next;
+        if next.is_none() {
+            self.set_status(ScheduleStatus::Completed, SCHEDULER_ACTOR, at);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::AccountType;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::PublicId;
+
+/// Pool account types whose balances back user liabilities. Money arriving for a user is
+/// credited to one of them (a deposit debits the user and credits its source), so they
+/// carry negative balances while they hold funds.
+pub const HOLDING_ACCOUNT_TYPES: [AccountType; 3] = [
+    AccountType::TreasuryAvailable,
+    AccountType::TreasuryLocked,
+    AccountType::PlatformClearing,
+];
+
+/// Recorded as the requester of scheduled runs.
+pub const SOLVENCY_SCHEDULE_ACTOR: &str = "solvency_schedule";
+
+/// Coverage ratios are kept in basis points: 10 000 means holdings exactly cover liabilities.
+pub const FULL_COVERAGE_BPS: i128 = 10_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SolvencyTrigger {
+    OnDemand,
+    Scheduled,
+}
+
+impl SolvencyTrigger {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            SolvencyTrigger::OnDemand => "ON_DEMAND",
+            SolvencyTrigger::Scheduled => "SCHEDULED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ON_DEMAND" => Ok(Self::OnDemand),
+            "SCHEDULED" => Ok(Self::Scheduled),
+            other => Err(DomainError::InvalidSolvencyTrigger { value: other.to_string() }),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SolvencyStatus {
+    /// Holdings are at least the liabilities.
+    Covered,
+    /// Holdings fall short of the liabilities; raises an alert.
+    Shortfall,
+}
+
+impl SolvencyStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            SolvencyStatus::Covered => "COVERED",
+            SolvencyStatus::Shortfall => "SHORTFALL",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "COVERED" => Ok(Self::Covered),
+            "SHORTFALL" => Ok(Self::Shortfall),
+            other => Err(DomainError::InvalidSolvencyStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// Per-bucket totals across every account of the asset, read at one instant, as signed
+/// ledger balances.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SolvencyBalances {
+    pub user_available: i128,
+    pub user_locked: i128,
+    pub treasury_available: i128,
+    pub treasury_locked: i128,
+    pub platform_clearing: i128,
+}
+
+impl SolvencyBalances {
+    /// What users are owed: available plus locked.
+    pub fn liabilities_minor(&self) -> Result<i128, DomainError> {
+        self.user_available.checked_add(self.user_locked).ok_or(DomainError::AmountOverflow)
+    }
+
+    /// What the ledger says backs them: treasury buckets plus platform clearing, negated so
+    /// that a pool holding funds counts positive. Every posting balances, so this equals
+    /// liabilities plus whatever revenue, fee and other platform accounts net to; it is
+    /// the ledger's own figure, not one read from a custodian or bank.
+    pub fn holdings_minor(&self) -> Result<i128, DomainError> {
+        [self.treasury_available, self.treasury_locked, self.platform_clearing]
+            .into_iter()
+            .try_fold(0i128, |acc, v| acc.checked_add(v))
+            .and_then(i128::checked_neg)
+            .ok_or(DomainError::AmountOverflow)
+    }
+}
+
+/// An assessed report, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewSolvencyReport {
+    pub public_id: PublicId,
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    pub trigger: SolvencyTrigger,
+    pub balances: SolvencyBalances,
+    pub liabilities_minor: i128,
+    pub holdings_minor: i128,
+    /// Holdings over liabilities in basis points, rounded down; `None` when nothing is owed.
+    pub coverage_bps: Option<i128>,
+    pub status: SolvencyStatus,
+    pub requested_by: String,
+}
+
+/// One asset's solvency at a point in time, as an internal consistency check: user
+/// liabilities against the pool holdings the ledger itself records. Both sides come from
+/// the same balanced books, so a shortfall means value left the pools through platform
+/// accounts (fees paid out, revenue swept, adjustments), not that a custodian holds less
+/// than the ledger claims; that needs reconciling the pools against external statements.
+/// Every run is stored, so the history shows how coverage moved.
+#[derive(Debug, Clone)]
+pub struct SolvencyReport {
+    id: i64,
+    spec: NewSolvencyReport,
+    alerted_at: Option<DateTime<Utc>>,
+}
+
+impl SolvencyReport {
+    pub fn assess(
+        public_id: PublicId,
+        asset_id: i16,
+        balances: SolvencyBalances,
+        trigger: SolvencyTrigger,
+        requested_by: &str,
+        as_of: DateTime<Utc>,
+    ) -> Result<NewSolvencyReport, DomainError> {
+        if requested_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        let liabilities_minor = balances.liabilities_minor()?;
+        let holdings_minor = balances.holdings_minor()?;
+
+        let coverage_bps = if liabilities_minor > 0 {
+            let scaled = holdings_minor.checked_mul(FULL_COVERAGE_BPS).ok_or(DomainError::AmountOverflow)?;
+            Some(scaled.div_euclid(liabilities_minor))
+        } else {
+            None
+        };
+        let status = if holdings_minor < liabilities_minor { SolvencyStatus::Shortfall } else { SolvencyStatus::Covered };
+
+        Ok(NewSolvencyReport {
+            public_id,
+            asset_id,
+            as_of,
+            trigger,
+            balances,
+            liabilities_minor,
+            holdings_minor,
+            coverage_bps,
+            status,
+            requested_by: requested_by.to_string(),
+        })
+    }
+
+    pub fn restore(id: i64, spec: NewSolvencyReport, alerted_at: Option<DateTime<Utc>>) -> Self {
+        Self { id, spec, alerted_at }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn asset_id(&self) -> i16 { self.spec.asset_id }
+    pub fn as_of(&self) -> DateTime<Utc> { self.spec.as_of }
+    pub fn trigger(&self) -> SolvencyTrigger { self.spec.trigger }
+    pub fn balances(&self) -> &SolvencyBalances { &self.spec.balances }
+    pub fn liabilities_minor(&self) -> i128 { self.spec.liabilities_minor }
+    pub fn holdings_minor(&self) -> i128 { self.spec.holdings_minor }
+    pub fn coverage_bps(&self) -> Option<i128> { self.spec.coverage_bps }
+    pub fn status(&self) -> SolvencyStatus { self.spec.status }
+    pub fn requested_by(&self) -> &str { &self.spec.requested_by }
+    pub fn alerted_at(&self) -> Option<DateTime<Utc>> { self.alerted_at }
+
+    /// Holdings less liabilities; negative by the size of a shortfall.
+    pub fn surplus_minor(&self) -> i128 {
+        self.spec.holdings_minor - self.spec.liabilities_minor
+    }
+
+    /// A shortfall that has not been alerted on yet.
+    pub fn needs_alert(&self) -> bool {
+        self.spec.status == SolvencyStatus::Shortfall && self.alerted_at.is_none()
+    }
+
+    pub fn mark_alerted(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.needs_alert() {
+            return Err(DomainError::SolvencyReportInvalid {
+                reason: "only an unalerted shortfall can be marked alerted".into(),
+            });
+        }
+        self.alerted_at = Some(at);
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use crate::domain::aggregate::JournalDraft;
+use crate::domain::entities::{AccountType, LedgerAccount};
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, Money, PublicId};
+
+/// Metadata keys naming the two parties, so transfers can be found by owner.
+pub const SENDER_OWNER_KEY: &str = "sender_owner_id";
+pub const RECEIVER_OWNER_KEY: &str = "receiver_owner_id";
+
+/// A movement between two users' `UserAvailable` accounts in one asset, keyed by the
+/// caller's idempotency key.
+#[derive(Debug, Clone)]
+pub struct TransferIntent {
+    idempotency_key: ExternalRef,
+    sender_owner_id: Uuid,
+    receiver_owner_id: Uuid,
+    sender_account_id: i64,
+    receiver_account_id: i64,
+    amount_minor: i128,
+    created_by: String,
+    description: Option<String>,
+}
+
+impl TransferIntent {
+    pub fn new(
+        idempotency_key: ExternalRef,
+        sender: &LedgerAccount,
+        receiver: &LedgerAccount,
+        amount_minor: i128,
+        created_by: &str,
+        description: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let invalid = |reason: &str| DomainError::TransferInvalid { reason: reason.to_string() };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if amount_minor <= 0 {
+            return Err(invalid("amount must be positive"));
+        }
+        if sender.account_type() != AccountType::UserAvailable || receiver.account_type() != AccountType::UserAvailable {
+            return Err(invalid("transfers move funds between UserAvailable accounts"));
+        }
+        let (Some(sender_owner_id), Some(receiver_owner_id)) = (sender.owner_id(), receiver.owner_id()) else {
+            return Err(invalid("both accounts must belong to a user"));
+        };
+        if sender_owner_id == receiver_owner_id {
+            return Err(invalid("sender and receiver must differ"));
+        }
+        if sender.asset_id() != receiver.asset_id() {
+            return Err(DomainError::CrossAssetPostingNotAllowed);
+        }
+
+        Ok(Self {
+            idempotency_key,
+            sender_owner_id,
+            receiver_owner_id,
+            sender_account_id: sender.id(),
+            receiver_account_id: receiver.id(),
+            amount_minor,
+            created_by: created_by.to_string(),
+            description,
+        })
+    }
+
+    pub fn idempotency_key(&self) -> &ExternalRef { &self.idempotency_key }
+    pub fn sender_account_id(&self) -> i64 { self.sender_account_id }
+    pub fn receiver_account_id(&self) -> i64 { self.receiver_account_id }
+    pub fn amount_minor(&self) -> i128 { self.amount_minor }
+
+    /// The journal moving the amount; fee lines, if any, are added to it afterwards.
+    pub fn draft(&self) -> Result<JournalDraft, DomainError> {
+        let mut draft = JournalDraft::new(
+            PublicId::new(Uuid::new_v4()),
+            ExternalRefType::TransferIntent,
+            self.idempotency_key.clone(),
+            &self.created_by,
+            self.description.clone(),
+        )?;
+        draft.metadata = JournalMetadata::new(BTreeMap::from([
+            (SENDER_OWNER_KEY.to_string(), self.sender_owner_id.to_string()),
+            (RECEIVER_OWNER_KEY.to_string(), self.receiver_owner_id.to_string()),
+        ]))?;
+        draft.add_line(self.sender_account_id, Money::credit(self.amount_minor)?);
+        draft.add_line(self.receiver_account_id, Money::debit(self.amount_minor)?);
+        Ok(draft)
+    }
+}
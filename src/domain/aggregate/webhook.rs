@@ -0,0 +1,450 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use chrono::{DateTime, Utc};
+use url::{Host, Url};
+use uuid::Uuid;
+
+use crate::domain::aggregate::RetryPolicy;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::PublicId;
+
+/// Change feed consumer that turns committed journals into webhook deliveries.
+pub const WEBHOOK_CONSUMER: &str = "webhooks";
+
+pub const MIN_WEBHOOK_SECRET_LEN: usize = 16;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    /// A journal touching one of the owner's accounts was posted.
+    JournalPosted,
+    /// A posting moved the balance of one of the owner's accounts.
+    BalanceChanged,
+}
+
+impl WebhookEventType {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            WebhookEventType::JournalPosted => "journal.posted",
+            WebhookEventType::BalanceChanged => "balance.changed",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "journal.posted" => Ok(Self::JournalPosted),
+            "balance.changed" => Ok(Self::BalanceChanged),
+            other => Err(DomainError::InvalidWebhookEventType { value: other.to_string() }),
+        }
+    }
+}
+
+/// Which endpoints a subscription may point at. Deliveries are signed POSTs of ledger
+/// data, so by default only https urls on public hosts are accepted: loopback, private,
+/// link-local and unspecified addresses and `localhost` names are refused unless the host
+/// is allowlisted. Names are checked as written, not resolved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebhookUrlPolicy {
+    allow_http: bool,
+    allowed_hosts: Vec<String>,
+}
+
+impl WebhookUrlPolicy {
+    /// `allow_http` is for test setups posting to a local endpoint; `allowed_hosts` are
+    /// accepted even when they name an internal address.
+    pub fn new(allow_http: bool, allowed_hosts: Vec<String>) -> Self {
+        Self { allow_http, allowed_hosts: allowed_hosts.into_iter().map(|h| h.to_ascii_lowercase()).collect() }
+    }
+
+    /// The url as it will be delivered to, or why it is refused.
+    pub fn check(&self, url: &str) -> Result<Url, String> {
+        let url = Url::parse(url).map_err(|_| "url must be an absolute http(s) url".to_string())?;
+        match url.scheme() {
+            "https" => {}
+            "http" if self.allow_http => {}
+            "http" => return Err("url must use https".into()),
+            _ => return Err("url must be an absolute http(s) url".into()),
+        }
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err("url must not carry credentials".into());
+        }
+        let host = url.host().ok_or_else(|| "url must be an absolute http(s) url".to_string())?;
+        let internal = match &host {
+            Host::Domain(name) => *name == "localhost" || name.ends_with(".localhost"),
+            Host::Ipv4(ip) => is_internal_v4(ip),
+            Host::Ipv6(ip) => is_internal_v6(ip),
+        };
+        if internal && !self.allowed_hosts.iter().any(|h| *h == host.to_string()) {
+            return Err(format!("url host {host} is internal and not allowlisted"));
+        }
+        Ok(url)
+    }
+}
+
+fn is_internal_v4(ip: &Ipv4Addr) -> bool {
+    ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast()
+}
+
+fn is_internal_v6(ip: &Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_internal_v4(&v4);
+    }
+    let first = ip.segments()[0];
+    // fc00::/7 unique local, fe80::/10 link-local
+    ip.is_loopback() || ip.is_unspecified() || first & 0xfe00 == 0xfc00 || first & 0xffc0 == 0xfe80
+}
+
+/// A validated subscription, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewWebhookSubscription {
+    pub public_id: PublicId,
+    pub owner_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A partner endpoint receiving the chosen events for one owner's accounts.
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    id: i64,
+    spec: NewWebhookSubscription,
+    active: bool,
+    updated_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    #[allow(clippy::too_many_arguments)]
+    pub fn define(
+        public_id: PublicId,
+        owner_id: Uuid,
+        url: impl Into<String>,
+        secret: impl Into<String>,
+        event_types: &[WebhookEventType],
+        created_by: &str,
+        at: DateTime<Utc>,
+        url_policy: &WebhookUrlPolicy,
+    ) -> Result<NewWebhookSubscription, DomainError> {
+        let invalid = |reason: &str| DomainError::WebhookSubscriptionInvalid { reason: reason.to_string() };
+
+        let url = url.into();
+        let url = url.trim();
+        if url.chars().any(char::is_whitespace) {
+            return Err(invalid("url must be an absolute http(s) url"));
+        }
+        let url = String::from(url_policy.check(url).map_err(|reason| invalid(&reason))?);
+        let secret = secret.into();
+        if secret.len() < MIN_WEBHOOK_SECRET_LEN {
+            return Err(DomainError::WebhookSubscriptionInvalid {
+                reason: format!("secret must be at least {MIN_WEBHOOK_SECRET_LEN} bytes"),
+            });
+        }
+        let mut types = Vec::with_capacity(event_types.len());
+        for t in event_types {
+            if !types.contains(t) {
+                types.push(*t);
+            }
+        }
+        if types.is_empty() {
+            return Err(invalid("at least one event type is required"));
+        }
+        let created_by = created_by.trim();
+        if created_by.is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+
+        Ok(NewWebhookSubscription {
+            public_id,
+            owner_id,
+            url,
+            secret,
+            event_types: types,
+            created_by: created_by.to_string(),
+            created_at: at,
+        })
+    }
+
+    pub fn restore(id: i64, spec: NewWebhookSubscription, active: bool, updated_at: DateTime<Utc>) -> Self {
+        Self { id, spec, active, updated_at }
+    }
+
+    /// Whether events of `event_type` go to this endpoint.
+    pub fn wants(&self, event_type: WebhookEventType) -> bool {
+        self.active && self.spec.event_types.contains(&event_type)
+    }
+
+    /// Stops new deliveries; pending ones are dead-lettered when they come due.
+    pub fn deactivate(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.active {
+            return Err(DomainError::WebhookSubscriptionInvalid { reason: "subscription is already inactive".into() });
+        }
+        self.active = false;
+        self.updated_at = at;
+        Ok(())
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn public_id(&self) -> PublicId {
+        self.spec.public_id
+    }
+
+    pub fn owner_id(&self) -> Uuid {
+        self.spec.owner_id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.spec.url
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.spec.secret
+    }
+
+    pub fn event_types(&self) -> &[WebhookEventType] {
+        &self.spec.event_types
+    }
+
+    pub fn created_by(&self) -> &str {
+        &self.spec.created_by
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.spec.created_at
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first or next attempt.
+    Pending,
+    /// The endpoint answered 2xx.
+    Delivered,
+    /// Retries are used up; waits in the dead-letter queue until redriven.
+    DeadLettered,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "PENDING",
+            WebhookDeliveryStatus::Delivered => "DELIVERED",
+            WebhookDeliveryStatus::DeadLettered => "DEAD_LETTERED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "PENDING" => Ok(Self::Pending),
+            "DELIVERED" => Ok(Self::Delivered),
+            "DEAD_LETTERED" => Ok(Self::DeadLettered),
+            other => Err(DomainError::InvalidWebhookDeliveryStatus { value: other.to_string() }),
+        }
+    }
+}
+
+/// One event for one subscription, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub public_id: PublicId,
+    pub subscription_id: i64,
+    /// Identifies the event across retries and redrives, so receivers can deduplicate.
+    pub event_id: String,
+    pub event_type: WebhookEventType,
+    /// The JSON body sent, fixed when the event is enqueued.
+    pub payload: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Where a delivery stands after its attempts so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDeliveryState {
+    pub status: WebhookDeliveryStatus,
+    /// Every attempt made, across redrives; numbers the delivery log.
+    pub attempts: u32,
+    /// Failed attempts since it was enqueued or last redriven; drives the backoff.
+    pub failures: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// An entry in the delivery log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookAttempt {
+    pub attempt: u32,
+    pub attempted_at: DateTime<Utc>,
+    /// The endpoint's HTTP status; absent when it could not be reached.
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+impl WebhookAttempt {
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    id: i64,
+    spec: NewWebhookDelivery,
+    state: WebhookDeliveryState,
+}
+
+impl WebhookDelivery {
+    pub fn enqueue(
+        public_id: PublicId,
+        subscription_id: i64,
+        event_id: impl Into<String>,
+        event_type: WebhookEventType,
+        payload: String,
+        at: DateTime<Utc>,
+    ) -> Result<NewWebhookDelivery, DomainError> {
+        let event_id = event_id.into();
+        if event_id.trim().is_empty() {
+            return Err(DomainError::WebhookDeliveryInvalid { reason: "event_id is required".into() });
+        }
+        Ok(NewWebhookDelivery { public_id, subscription_id, event_id, event_type, payload, created_at: at })
+    }
+
+    /// The state of a freshly enqueued delivery: due straight away.
+    pub fn initial_state(spec: &NewWebhookDelivery) -> WebhookDeliveryState {
+        WebhookDeliveryState {
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            failures: 0,
+            next_attempt_at: spec.created_at,
+            last_error: None,
+            delivered_at: None,
+        }
+    }
+
+    pub fn restore(id: i64, spec: NewWebhookDelivery, state: WebhookDeliveryState) -> Self {
+        Self { id, spec, state }
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.state.status != WebhookDeliveryStatus::Pending {
+            return Err(DomainError::WebhookDeliveryInvalid {
+                reason: format!("delivery is {}, not PENDING", self.state.status.as_code()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Records the endpoint's answer: 2xx delivers, anything else is a failed attempt.
+    pub fn record_response(
+        &mut self,
+        status_code: u16,
+        at: DateTime<Utc>,
+        policy: &RetryPolicy,
+    ) -> Result<WebhookAttempt, DomainError> {
+        self.ensure_pending()?;
+        if (200..300).contains(&status_code) {
+            self.state.attempts += 1;
+            self.state.status = WebhookDeliveryStatus::Delivered;
+            self.state.delivered_at = Some(at);
+            self.state.last_error = None;
+            return Ok(WebhookAttempt {
+                attempt: self.state.attempts,
+                attempted_at: at,
+                status_code: Some(status_code),
+                error: None,
+            });
+        }
+        Ok(self.fail(Some(status_code), format!("endpoint answered HTTP {status_code}"), at, policy))
+    }
+
+    /// Records an attempt that got no answer (connection refused, timeout, ...).
+    pub fn record_error(
+        &mut self,
+        error: impl Into<String>,
+        at: DateTime<Utc>,
+        policy: &RetryPolicy,
+    ) -> Result<WebhookAttempt, DomainError> {
+        self.ensure_pending()?;
+        Ok(self.fail(None, error.into(), at, policy))
+    }
+
+    /// Backs off by the policy's delay, or dead-letters once its failures are used up.
+    fn fail(&mut self, status_code: Option<u16>, error: String, at: DateTime<Utc>, policy: &RetryPolicy) -> WebhookAttempt {
+        self.state.attempts += 1;
+        self.state.failures += 1;
+        self.state.last_error = Some(error.clone());
+        if self.state.failures >= policy.max_failures() {
+            self.state.status = WebhookDeliveryStatus::DeadLettered;
+        } else {
+            self.state.next_attempt_at = at + policy.delay(self.state.failures);
+        }
+        WebhookAttempt { attempt: self.state.attempts, attempted_at: at, status_code, error: Some(error) }
+    }
+
+    /// Dead-letters without an attempt, e.g. when the subscription was deactivated.
+    pub fn abandon(&mut self, reason: impl Into<String>) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+        self.state.status = WebhookDeliveryStatus::DeadLettered;
+        self.state.last_error = Some(reason.into());
+        Ok(())
+    }
+
+    /// Takes a dead-lettered delivery out of the queue for a fresh round of retries.
+    pub fn redrive(&mut self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.state.status != WebhookDeliveryStatus::DeadLettered {
+            return Err(DomainError::WebhookDeliveryInvalid {
+                reason: format!("only dead-lettered deliveries can be redriven (status={})", self.state.status.as_code()),
+            });
+        }
+        self.state.status = WebhookDeliveryStatus::Pending;
+        self.state.failures = 0;
+        self.state.next_attempt_at = at;
+        Ok(())
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn public_id(&self) -> PublicId {
+        self.spec.public_id
+    }
+
+    pub fn subscription_id(&self) -> i64 {
+        self.spec.subscription_id
+    }
+
+    pub fn event_id(&self) -> &str {
+        &self.spec.event_id
+    }
+
+    pub fn event_type(&self) -> WebhookEventType {
+        self.spec.event_type
+    }
+
+    pub fn payload(&self) -> &str {
+        &self.spec.payload
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.spec.created_at
+    }
+
+    pub fn state(&self) -> &WebhookDeliveryState {
+        &self.state
+    }
+
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.state.status
+    }
+}
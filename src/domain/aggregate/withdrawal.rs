@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::PublicId;
+
+/// Actor recorded on transitions driven by the payout rail's answer.
+pub const PAYOUT_RAIL_ACTOR: &str = "payout_rail";
+
+const MAX_DESTINATION_LEN: usize = 256;
+const MAX_FAILURE_REASON_LEN: usize = 500;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WithdrawalStatus {
+    /// Recorded; funds not yet held.
+    Requested,
+    /// Funds held in the user's `UserLocked` bucket.
+    Held,
+    /// Handed to the payout rail.
+    Submitted,
+    /// Paid out; the hold was captured to `PlatformClearing`.
+    Confirmed,
+    /// Rejected or failed; any hold was released back to `UserAvailable`.
+    Failed,
+}
+
+impl WithdrawalStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            WithdrawalStatus::Requested => "REQUESTED",
+            WithdrawalStatus::Held => "HELD",
+            WithdrawalStatus::Submitted => "SUBMITTED",
+            WithdrawalStatus::Confirmed => "CONFIRMED",
+            WithdrawalStatus::Failed => "FAILED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "REQUESTED" => Ok(Self::Requested),
+            "HELD" => Ok(Self::Held),
+            "SUBMITTED" => Ok(Self::Submitted),
+            "CONFIRMED" => Ok(Self::Confirmed),
+            "FAILED" => Ok(Self::Failed),
+            other => Err(DomainError::InvalidWithdrawalStatus { value: other.to_string() }),
+        }
+    }
+
+    /// Forward one step at a time; any unfinished withdrawal may fail.
+    pub fn can_transition_to(&self, to: WithdrawalStatus) -> bool {
+        use WithdrawalStatus::*;
+        matches!(
+            (self, to),
+            (Requested, Held)
+                | (Held, Submitted)
+                | (Submitted, Confirmed)
+                | (Requested, Failed)
+                | (Held, Failed)
+                | (Submitted, Failed)
+        )
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self, WithdrawalStatus::Confirmed | WithdrawalStatus::Failed)
+    }
+}
+
+/// A validated withdrawal request, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewWithdrawal {
+    pub public_id: PublicId,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub amount_minor: i128,
+    /// Where the rail pays out to (bank account, wallet address), opaque to the ledger.
+    pub destination: String,
+    pub created_by: String,
+}
+
+/// A user's payout. Its funds are held under a hold with the withdrawal's id, captured
+/// on confirmation and released on failure.
+#[derive(Debug, Clone)]
+pub struct Withdrawal {
+    id: i64,
+    spec: NewWithdrawal,
+    status: WithdrawalStatus,
+    rail_reference: Option<String>,
+    failure_reason: Option<String>,
+    status_changed_at: Option<DateTime<Utc>>,
+    status_changed_by: Option<String>,
+}
+
+impl Withdrawal {
+    pub fn request(
+        public_id: PublicId,
+        owner_id: Uuid,
+        asset_id: i16,
+        amount_minor: i128,
+        destination: impl Into<String>,
+        created_by: &str,
+    ) -> Result<NewWithdrawal, DomainError> {
+        let invalid = |reason: String| DomainError::WithdrawalInvalid { reason };
+
+        if created_by.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if amount_minor <= 0 {
+            return Err(invalid("amount must be positive".into()));
+        }
+        let destination = destination.into().trim().to_string();
+        if destination.is_empty() || destination.len() > MAX_DESTINATION_LEN {
+            return Err(invalid(format!("destination must be 1-{MAX_DESTINATION_LEN} bytes")));
+        }
+
+        Ok(NewWithdrawal {
+            public_id,
+            owner_id,
+            asset_id,
+            amount_minor,
+            destination,
+            created_by: created_by.to_string(),
+        })
+    }
+
+    pub fn restore(
+        id: i64,
+        spec: NewWithdrawal,
+        status: WithdrawalStatus,
+        rail_reference: Option<String>,
+        failure_reason: Option<String>,
+        status_changed_at: Option<DateTime<Utc>>,
+        status_changed_by: Option<String>,
+    ) -> Self {
+        Self { id, spec, status, rail_reference, failure_reason, status_changed_at, status_changed_by }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn public_id(&self) -> PublicId { self.spec.public_id }
+    pub fn owner_id(&self) -> Uuid { self.spec.owner_id }
+    pub fn asset_id(&self) -> i16 { self.spec.asset_id }
+    pub fn amount_minor(&self) -> i128 { self.spec.amount_minor }
+    pub fn destination(&self) -> &str { &self.spec.destination }
+    pub fn created_by(&self) -> &str { &self.spec.created_by }
+    pub fn status(&self) -> WithdrawalStatus { self.status }
+    pub fn rail_reference(&self) -> Option<&str> { self.rail_reference.as_deref() }
+    pub fn failure_reason(&self) -> Option<&str> { self.failure_reason.as_deref() }
+    pub fn status_changed_at(&self) -> Option<DateTime<Utc>> { self.status_changed_at }
+    pub fn status_changed_by(&self) -> Option<&str> { self.status_changed_by.as_deref() }
+
+    /// The hold carrying the withdrawal's funds shares its id.
+    pub fn hold_id(&self) -> PublicId {
+        self.spec.public_id
+    }
+
+    /// Whether funds sit in a hold that confirmation captures or failure releases.
+    pub fn has_hold(&self) -> bool {
+        matches!(self.status, WithdrawalStatus::Held | WithdrawalStatus::Submitted)
+    }
+
+    /// Moves to `to`. Returns `false` without changing anything when the withdrawal is
+    /// already there, so a replayed transition is a no-op.
+    pub fn transition(&mut self, to: WithdrawalStatus, actor: &str, at: DateTime<Utc>) -> Result<bool, DomainError> {
+        if self.status == to {
+            return Ok(false);
+        }
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if !self.status.can_transition_to(to) {
+            return Err(DomainError::WithdrawalTransitionNotAllowed {
+                from: self.status.as_code().to_string(),
+                to: to.as_code().to_string(),
+            });
+        }
+        self.status = to;
+        self.status_changed_at = Some(at);
+        self.status_changed_by = Some(actor.to_string());
+        Ok(true)
+    }
+
+    pub fn mark_submitted(&mut self, rail_reference: &str, actor: &str, at: DateTime<Utc>) -> Result<bool, DomainError> {
+        if rail_reference.trim().is_empty() {
+            return Err(DomainError::WithdrawalInvalid { reason: "rail reference is empty".into() });
+        }
+        let moved = self.transition(WithdrawalStatus::Submitted, actor, at)?;
+        if moved {
+            self.rail_reference = Some(rail_reference.to_string());
+        }
+        Ok(moved)
+    }
+
+    pub fn mark_failed(&mut self, reason: &str, actor: &str, at: DateTime<Utc>) -> Result<bool, DomainError> {
+        let moved = self.transition(WithdrawalStatus::Failed, actor, at)?;
+        if moved {
+            let mut reason = reason.trim().to_string();
+            if reason.len() > MAX_FAILURE_REASON_LEN {
+                let mut cut = MAX_FAILURE_REASON_LEN;
+                while !reason.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                reason.truncate(cut);
+            }
+            self.failure_reason = Some(reason);
+        }
+        Ok(moved)
+    }
+}
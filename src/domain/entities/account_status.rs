@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::Money;
+
+/// Which way funds move on an account, seen from the account holder. Holder balances are
+/// positive, so a positive line (`Money::debit`, e.g. a deposit) is an inflow and a negative
+/// line (`Money::credit`, e.g. a withdrawal) an outflow.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PostingDirection {
+    Inflow,
+    Outflow,
+}
+
+impl PostingDirection {
+    pub fn of(amount: Money) -> Self {
+        if amount.minor() < 0 {
+            PostingDirection::Outflow
+        } else {
+            PostingDirection::Inflow
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostingDirection::Inflow => "INFLOW",
+            PostingDirection::Outflow => "OUTFLOW",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AccountStatus {
+    Active,
+    /// Compliance hold: funds may arrive but cannot leave.
+    Frozen,
+    /// Credit and debit in the holder's sense: funds may only arrive.
+    CreditOnly,
+    /// Funds may only leave, e.g. while an account is wound down.
+    DebitOnly,
+    /// Terminal; only reachable with a zero balance.
+    Closed,
+}
+
+impl AccountStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            AccountStatus::Active => "ACTIVE",
+            AccountStatus::Frozen => "FROZEN",
+            AccountStatus::CreditOnly => "CREDIT_ONLY",
+            AccountStatus::DebitOnly => "DEBIT_ONLY",
+            AccountStatus::Closed => "CLOSED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ACTIVE" => Ok(Self::Active),
+            "FROZEN" => Ok(Self::Frozen),
+            "CREDIT_ONLY" => Ok(Self::CreditOnly),
+            "DEBIT_ONLY" => Ok(Self::DebitOnly),
+            "CLOSED" => Ok(Self::Closed),
+            other => Err(DomainError::InvalidAccountStatus { value: other.to_string() }),
+        }
+    }
+
+    pub fn permits(&self, direction: PostingDirection) -> bool {
+        match self {
+            AccountStatus::Active => true,
+            AccountStatus::Frozen | AccountStatus::CreditOnly => direction == PostingDirection::Inflow,
+            AccountStatus::DebitOnly => direction == PostingDirection::Outflow,
+            AccountStatus::Closed => false,
+        }
+    }
+
+    /// A frozen account has to be unfrozen before any other change, and nothing leaves `Closed`.
+    pub fn can_transition_to(&self, next: AccountStatus) -> bool {
+        use AccountStatus::*;
+        match (self, next) {
+            (a, b) if *a == b => false,
+            (Closed, _) => false,
+            (Frozen, Active) => true,
+            (Frozen, _) => false,
+            (Active | CreditOnly | DebitOnly, _) => true,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatusReasonCode {
+    AccountOpened,
+    CustomerRequest,
+    ComplianceReview,
+    FraudSuspected,
+    LegalOrder,
+    Dormancy,
+    Offboarding,
+    ReviewCleared,
+    Other,
+}
+
+impl StatusReasonCode {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            StatusReasonCode::AccountOpened => "ACCOUNT_OPENED",
+            StatusReasonCode::CustomerRequest => "CUSTOMER_REQUEST",
+            StatusReasonCode::ComplianceReview => "COMPLIANCE_REVIEW",
+            StatusReasonCode::FraudSuspected => "FRAUD_SUSPECTED",
+            StatusReasonCode::LegalOrder => "LEGAL_ORDER",
+            StatusReasonCode::Dormancy => "DORMANCY",
+            StatusReasonCode::Offboarding => "OFFBOARDING",
+            StatusReasonCode::ReviewCleared => "REVIEW_CLEARED",
+            StatusReasonCode::Other => "OTHER",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ACCOUNT_OPENED" => Ok(Self::AccountOpened),
+            "CUSTOMER_REQUEST" => Ok(Self::CustomerRequest),
+            "COMPLIANCE_REVIEW" => Ok(Self::ComplianceReview),
+            "FRAUD_SUSPECTED" => Ok(Self::FraudSuspected),
+            "LEGAL_ORDER" => Ok(Self::LegalOrder),
+            "DORMANCY" => Ok(Self::Dormancy),
+            "OFFBOARDING" => Ok(Self::Offboarding),
+            "REVIEW_CLEARED" => Ok(Self::ReviewCleared),
+            "OTHER" => Ok(Self::Other),
+            other => Err(DomainError::InvalidStatusReasonCode { value: other.to_string() }),
+        }
+    }
+}
+
+/// One entry of an account's status history. Also covers changes of the legacy
+/// `is_active` flag, where `from` and `to` are equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountStatusChange {
+    pub account_id: i64,
+    pub from: AccountStatus,
+    pub to: AccountStatus,
+    pub was_active: bool,
+    pub is_active: bool,
+    pub reason: StatusReasonCode,
+    pub note: Option<String>,
+    pub actor: String,
+    pub at: DateTime<Utc>,
+}
+
+/// An account's status history, oldest first, for point-in-time checks.
+#[derive(Debug, Clone)]
+pub struct AccountStatusTimeline {
+    account_id: i64,
+    entries: Vec<AccountStatusChange>,
+}
+
+impl AccountStatusTimeline {
+    pub fn new(account_id: i64, mut entries: Vec<AccountStatusChange>) -> Self {
+        entries.sort_by_key(|e| e.at);
+        Self { account_id, entries }
+    }
+
+    pub fn account_id(&self) -> i64 { self.account_id }
+    pub fn entries(&self) -> &[AccountStatusChange] { &self.entries }
+
+    /// Status and active flag in force at `at`; `None` if the account had no recorded state yet.
+    pub fn state_at(&self, at: DateTime<Utc>) -> Option<(AccountStatus, bool)> {
+        self.entries
+            .iter()
+            .take_while(|e| e.at <= at)
+            .last()
+            .map(|e| (e.to, e.is_active))
+    }
+
+    pub fn was_active_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(self.state_at(at), Some((status, true)) if status != AccountStatus::Closed)
+    }
+
+    pub fn ensure_permits_at(&self, at: DateTime<Utc>, direction: PostingDirection) -> Result<(), DomainError> {
+        let Some((status, is_active)) = self.state_at(at) else {
+            return Err(DomainError::AccountNotOpenAt { account_id: self.account_id, at: at.to_rfc3339() });
+        };
+        if !is_active {
+            return Err(DomainError::LedgerAccountInactive);
+        }
+        if !status.permits(direction) {
+            return Err(DomainError::AccountStatusBlocksPosting {
+                account_id: self.account_id,
+                status: status.as_code().to_string(),
+                direction: direction.as_str().to_string(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::ExternalRefType;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PeriodStatus {
+    /// Accepts every posting.
+    Open,
+    /// Being reconciled: only adjusting journals may still be dated into it.
+    SoftClosed,
+    /// Locked; closing balances are snapshotted and nothing may be dated into it.
+    Closed,
+}
+
+impl PeriodStatus {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            PeriodStatus::Open => "OPEN",
+            PeriodStatus::SoftClosed => "SOFT_CLOSED",
+            PeriodStatus::Closed => "CLOSED",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "OPEN" => Ok(Self::Open),
+            "SOFT_CLOSED" => Ok(Self::SoftClosed),
+            "CLOSED" => Ok(Self::Closed),
+            other => Err(DomainError::InvalidPeriodStatus { value: other.to_string() }),
+        }
+    }
+
+    /// Open and soft-closed move back and forth; closing is final.
+    pub fn can_transition_to(&self, to: PeriodStatus) -> bool {
+        matches!(
+            (self, to),
+            (PeriodStatus::Open, PeriodStatus::SoftClosed)
+                | (PeriodStatus::Open, PeriodStatus::Closed)
+                | (PeriodStatus::SoftClosed, PeriodStatus::Open)
+                | (PeriodStatus::SoftClosed, PeriodStatus::Closed)
+        )
+    }
+}
+
+/// A validated period definition, not yet stored.
+#[derive(Debug, Clone)]
+pub struct NewAccountingPeriod {
+    pub asset_id: i16,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// A span of effective dates `[starts_at, ends_at)` for one asset.
+#[derive(Debug, Clone)]
+pub struct AccountingPeriod {
+    id: i64,
+    asset_id: i16,
+    name: String,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    status: PeriodStatus,
+    status_changed_at: Option<DateTime<Utc>>,
+    status_changed_by: Option<String>,
+}
+
+impl AccountingPeriod {
+    pub fn define(
+        asset_id: i16,
+        name: impl Into<String>,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Result<NewAccountingPeriod, DomainError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::PeriodInvalid { reason: "name is empty".into() });
+        }
+        if starts_at >= ends_at {
+            return Err(DomainError::PeriodInvalid { reason: "period must start before it ends".into() });
+        }
+        Ok(NewAccountingPeriod { asset_id, name, starts_at, ends_at })
+    }
+
+    /// Calendar month in UTC, named `YYYY-MM`.
+    pub fn monthly(asset_id: i16, year: i32, month: u32) -> Result<NewAccountingPeriod, DomainError> {
+        let invalid = || DomainError::PeriodInvalid { reason: format!("invalid month {year}-{month:02}") };
+        let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(invalid)?;
+        let next = if month == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(year, month + 1, 1)
+        }
+        .ok_or_else(invalid)?;
+
+        let start = first.and_hms_opt(0, 0, 0).ok_or_else(invalid)?.and_utc();
+        let end = next.and_hms_opt(0, 0, 0).ok_or_else(invalid)?.and_utc();
+        Self::define(asset_id, format!("{}-{:02}", first.year(), first.month()), start, end)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: i64,
+        asset_id: i16,
+        name: String,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        status: PeriodStatus,
+        status_changed_at: Option<DateTime<Utc>>,
+        status_changed_by: Option<String>,
+    ) -> Self {
+        Self { id, asset_id, name, starts_at, ends_at, status, status_changed_at, status_changed_by }
+    }
+
+    pub fn id(&self) -> i64 { self.id }
+    pub fn asset_id(&self) -> i16 { self.asset_id }
+    pub fn name(&self) -> &str { &self.name }
+    pub fn starts_at(&self) -> DateTime<Utc> { self.starts_at }
+    pub fn ends_at(&self) -> DateTime<Utc> { self.ends_at }
+    pub fn status(&self) -> PeriodStatus { self.status }
+    pub fn status_changed_at(&self) -> Option<DateTime<Utc>> { self.status_changed_at }
+    pub fn status_changed_by(&self) -> Option<&str> { self.status_changed_by.as_deref() }
+
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.starts_at <= at && at < self.ends_at
+    }
+
+    /// Whether a journal of `ref_type` may take effect inside this period. A soft-closed
+    /// period still takes adjustments and the closing revaluation.
+    pub fn ensure_accepts(&self, ref_type: ExternalRefType) -> Result<(), DomainError> {
+        match self.status {
+            PeriodStatus::Open => Ok(()),
+            PeriodStatus::SoftClosed
+                if matches!(ref_type, ExternalRefType::PeriodAdjustment | ExternalRefType::FxRevaluation) =>
+            {
+                Ok(())
+            }
+            PeriodStatus::SoftClosed => Err(DomainError::PeriodSoftClosed { period: self.name.clone() }),
+            PeriodStatus::Closed => Err(DomainError::PeriodClosed { period: self.name.clone() }),
+        }
+    }
+
+    pub fn transition(
+        &mut self,
+        to: PeriodStatus,
+        actor: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        if !self.status.can_transition_to(to) {
+            return Err(DomainError::PeriodTransitionNotAllowed {
+                from: self.status.as_code().to_string(),
+                to: to.as_code().to_string(),
+            });
+        }
+        self.status = to;
+        self.status_changed_at = Some(at);
+        self.status_changed_by = Some(actor.to_string());
+        Ok(())
+    }
+}
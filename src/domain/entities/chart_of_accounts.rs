@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GlClass {
+    Asset,
+    Liability,
+    Equity,
+    Revenue,
+    Expense,
+}
+
+impl GlClass {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            GlClass::Asset => "ASSET",
+            GlClass::Liability => "LIABILITY",
+            GlClass::Equity => "EQUITY",
+            GlClass::Revenue => "REVENUE",
+            GlClass::Expense => "EXPENSE",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "ASSET" => Ok(Self::Asset),
+            "LIABILITY" => Ok(Self::Liability),
+            "EQUITY" => Ok(Self::Equity),
+            "REVENUE" => Ok(Self::Revenue),
+            "EXPENSE" => Ok(Self::Expense),
+            other => Err(DomainError::InvalidGlClass { value: other.to_string() }),
+        }
+    }
+
+    pub fn default_normal_balance(&self) -> NormalBalance {
+        match self {
+            GlClass::Asset | GlClass::Expense => NormalBalance::Debit,
+            GlClass::Liability | GlClass::Equity | GlClass::Revenue => NormalBalance::Credit,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NormalBalance {
+    Debit,
+    Credit,
+}
+
+impl NormalBalance {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            NormalBalance::Debit => "DEBIT",
+            NormalBalance::Credit => "CREDIT",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "DEBIT" => Ok(Self::Debit),
+            "CREDIT" => Ok(Self::Credit),
+            other => Err(DomainError::InvalidNormalBalance { value: other.to_string() }),
+        }
+    }
+
+    /// Converts a ledger amount into this side's presentation.
+    ///
+    /// Ledger balances are kept from the holder's side: a positive balance is what the
+    /// platform owes the holder, which in the platform's general ledger is a credit. So
+    /// credit-normal nodes show ledger amounts as-is and debit-normal nodes negate them.
+    pub fn present(&self, ledger_minor: i128) -> i128 {
+        match self {
+            NormalBalance::Credit => ledger_minor,
+            NormalBalance::Debit => -ledger_minor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoaNode {
+    pub id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: GlClass,
+    pub normal_balance: NormalBalance,
+    pub parent_id: Option<i64>,
+}
+
+/// A node's balance after rolling its descendants up into it, in the node's
+/// normal-balance presentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeBalance {
+    pub node_id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: GlClass,
+    pub normal_balance: NormalBalance,
+    pub parent_id: Option<i64>,
+    pub depth: usize,
+    pub own_minor: i128,
+    pub total_minor: i128,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChartOfAccounts {
+    // depth-first order, siblings by code
+    nodes: Vec<CoaNode>,
+    depth: HashMap<i64, usize>,
+}
+
+impl ChartOfAccounts {
+    /// Validates the tree: unique ids and codes, known parents, no cycles, and children
+    /// in the same GL class as their parent.
+    pub fn new(nodes: Vec<CoaNode>) -> Result<Self, DomainError> {
+        let by_id: HashMap<i64, &CoaNode> = nodes.iter().map(|n| (n.id, n)).collect();
+        if by_id.len() != nodes.len() {
+            return Err(DomainError::ChartOfAccountsInvalid { reason: "duplicate node id".into() });
+        }
+        let mut codes: Vec<&str> = nodes.iter().map(|n| n.code.as_str()).collect();
+        codes.sort_unstable();
+        if codes.windows(2).any(|w| w[0] == w[1]) {
+            return Err(DomainError::ChartOfAccountsInvalid { reason: "duplicate node code".into() });
+        }
+
+        let mut children: HashMap<Option<i64>, Vec<&CoaNode>> = HashMap::new();
+        for n in &nodes {
+            if let Some(pid) = n.parent_id {
+                let parent = by_id.get(&pid).ok_or_else(|| DomainError::ChartOfAccountsInvalid {
+                    reason: format!("node {} has unknown parent {pid}", n.code),
+                })?;
+                if parent.gl_class != n.gl_class {
+                    return Err(DomainError::ChartOfAccountsInvalid {
+                        reason: format!(
+                            "node {} is {} but parent {} is {}",
+                            n.code,
+                            n.gl_class.as_code(),
+                            parent.code,
+                            parent.gl_class.as_code()
+                        ),
+                    });
+                }
+            }
+            children.entry(n.parent_id).or_default().push(n);
+        }
+        for list in children.values_mut() {
+            list.sort_by(|a, b| a.code.cmp(&b.code));
+        }
+
+        // Walk from the roots; nodes on a cycle are never reached.
+        let mut ordered = Vec::with_capacity(nodes.len());
+        let mut depth = HashMap::with_capacity(nodes.len());
+        let mut stack: Vec<(&CoaNode, usize)> =
+            children.get(&None).map(|r| r.iter().rev().map(|n| (*n, 0)).collect()).unwrap_or_default();
+        while let Some((node, d)) = stack.pop() {
+            ordered.push(node.clone());
+            depth.insert(node.id, d);
+            if let Some(kids) = children.get(&Some(node.id)) {
+                stack.extend(kids.iter().rev().map(|k| (*k, d + 1)));
+            }
+        }
+        if ordered.len() != nodes.len() {
+            return Err(DomainError::ChartOfAccountsInvalid { reason: "cycle in parent links".into() });
+        }
+
+        Ok(Self { nodes: ordered, depth })
+    }
+
+    pub fn nodes(&self) -> &[CoaNode] {
+        &self.nodes
+    }
+
+    pub fn node(&self, id: i64) -> Option<&CoaNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+
+    pub fn children(&self, id: i64) -> impl Iterator<Item = &CoaNode> {
+        self.nodes.iter().filter(move |n| n.parent_id == Some(id))
+    }
+
+    /// Rolls ledger amounts posted directly to nodes up to every ancestor.
+    /// Output follows the chart's depth-first order.
+    pub fn rollup(&self, ledger_by_node: &HashMap<i64, i128>) -> Result<Vec<NodeBalance>, DomainError> {
+        if let Some(unknown) = ledger_by_node.keys().find(|id| !self.depth.contains_key(id)) {
+            return Err(DomainError::ChartOfAccountsInvalid {
+                reason: format!("amounts for unknown node {unknown}"),
+            });
+        }
+
+        let mut subtree: HashMap<i64, i128> = HashMap::with_capacity(self.nodes.len());
+        // children come after their parent in depth-first order, so fold bottom-up
+        for n in self.nodes.iter().rev() {
+            let own = ledger_by_node.get(&n.id).copied().unwrap_or(0);
+            let total = subtree
+                .get(&n.id)
+                .copied()
+                .unwrap_or(0)
+                .checked_add(own)
+                .ok_or(DomainError::AmountOverflow)?;
+            subtree.insert(n.id, total);
+            if let Some(pid) = n.parent_id {
+                let p = subtree.entry(pid).or_insert(0);
+                *p = p.checked_add(total).ok_or(DomainError::AmountOverflow)?;
+            }
+        }
+
+        Ok(self
+            .nodes
+            .iter()
+            .map(|n| NodeBalance {
+                node_id: n.id,
+                code: n.code.clone(),
+                name: n.name.clone(),
+                gl_class: n.gl_class,
+                normal_balance: n.normal_balance,
+                parent_id: n.parent_id,
+                depth: self.depth[&n.id],
+                own_minor: n.normal_balance.present(ledger_by_node.get(&n.id).copied().unwrap_or(0)),
+                total_minor: n.normal_balance.present(subtree[&n.id]),
+            })
+            .collect())
+    }
+}
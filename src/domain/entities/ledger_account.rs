@@ -1,5 +1,7 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::domain::entities::{AccountStatus, AccountStatusChange, GlClass, PostingDirection, StatusReasonCode};
 use crate::domain::error::DomainError;
 use crate::domain::value_objects::PublicId;
 
@@ -15,10 +17,58 @@ pub enum AccountType {
     UserAvailable,
     UserLocked,
     PlatformClearing,
+    PlatformRevenue,
     TreasuryAvailable,
     TreasuryLocked,
     InventoryAvailable,
     InventoryLocked,
+    /// Reporting-asset carrying adjustment for foreign holdings in the treasury and
+    /// inventory pools, moved by revaluation runs.
+    TreasuryRevaluation,
+    /// Unrealised gains and losses from revaluing foreign holdings.
+    PlatformUnrealisedFx,
+}
+
+impl OwnerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OwnerType::User => "USER",
+            OwnerType::Platform => "PLATFORM",
+            OwnerType::Treasury => "TREASURY",
+        }
+    }
+}
+
+impl AccountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountType::UserAvailable => "USER_AVAILABLE",
+            AccountType::UserLocked => "USER_LOCKED",
+            AccountType::PlatformClearing => "PLATFORM_CLEARING",
+            AccountType::PlatformRevenue => "PLATFORM_REVENUE",
+            AccountType::TreasuryAvailable => "TREASURY_AVAILABLE",
+            AccountType::TreasuryLocked => "TREASURY_LOCKED",
+            AccountType::InventoryAvailable => "INVENTORY_AVAILABLE",
+            AccountType::InventoryLocked => "INVENTORY_LOCKED",
+            AccountType::TreasuryRevaluation => "TREASURY_REVALUATION",
+            AccountType::PlatformUnrealisedFx => "PLATFORM_UNREALISED_FX",
+        }
+    }
+
+    /// General-ledger class an account of this type reports under. Customer funds are
+    /// owed back to customers, so user buckets are liabilities of the platform.
+    pub fn gl_class(&self) -> GlClass {
+        match self {
+            AccountType::UserAvailable | AccountType::UserLocked => GlClass::Liability,
+            AccountType::PlatformClearing => GlClass::Asset,
+            AccountType::PlatformRevenue | AccountType::PlatformUnrealisedFx => GlClass::Revenue,
+            AccountType::TreasuryAvailable
+            | AccountType::TreasuryLocked
+            | AccountType::InventoryAvailable
+            | AccountType::InventoryLocked
+            | AccountType::TreasuryRevaluation => GlClass::Equity,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -30,6 +80,7 @@ pub struct LedgerAccount {
     account_type: AccountType,
     asset_id: i16,
     is_active: bool,
+    status: AccountStatus,
 }
 
 impl LedgerAccount {
@@ -50,9 +101,15 @@ impl LedgerAccount {
             account_type,
             asset_id,
             is_active,
+            status: AccountStatus::Active,
         }
     }
 
+    pub fn with_status(mut self, status: AccountStatus) -> Self {
+        self.status = status;
+        self
+    }
+
     pub fn ensure_active(&self) -> Result<(), DomainError> {
         if !self.is_active {
             return Err(DomainError::LedgerAccountInactive);
@@ -60,6 +117,110 @@ impl LedgerAccount {
         Ok(())
     }
 
+    /// Checks both the legacy active flag and the lifecycle status for a posting
+    /// that moves funds in `direction`.
+    pub fn ensure_permits(&self, direction: PostingDirection) -> Result<(), DomainError> {
+        self.ensure_active()?;
+        if !self.status.permits(direction) {
+            return Err(DomainError::AccountStatusBlocksPosting {
+                account_id: self.id,
+                status: self.status.as_code().to_string(),
+                direction: direction.as_str().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn change_note(reason: StatusReasonCode, note: Option<String>, actor: &str) -> Result<Option<String>, DomainError> {
+        if actor.trim().is_empty() {
+            return Err(DomainError::CreatedByEmpty);
+        }
+        let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+        if reason == StatusReasonCode::Other && note.is_none() {
+            return Err(DomainError::StatusNoteRequired);
+        }
+        Ok(note)
+    }
+
+    /// Applies a lifecycle transition. `balance_minor` must be the current running
+    /// balance, read under lock, since closing requires it to be zero.
+    pub fn change_status(
+        &mut self,
+        to: AccountStatus,
+        reason: StatusReasonCode,
+        note: Option<String>,
+        actor: &str,
+        balance_minor: i128,
+        at: DateTime<Utc>,
+    ) -> Result<AccountStatusChange, DomainError> {
+        let note = Self::change_note(reason, note, actor)?;
+        if !self.status.can_transition_to(to) {
+            return Err(DomainError::AccountStatusTransitionNotAllowed {
+                from: self.status.as_code().to_string(),
+                to: to.as_code().to_string(),
+            });
+        }
+        if to == AccountStatus::Closed && balance_minor != 0 {
+            return Err(DomainError::AccountCloseNonZeroBalance { account_id: self.id, balance_minor });
+        }
+
+        let (from, was_active) = (self.status, self.is_active);
+        self.status = to;
+        if to == AccountStatus::Closed {
+            self.is_active = false;
+        }
+
+        Ok(AccountStatusChange {
+            account_id: self.id,
+            from,
+            to,
+            was_active,
+            is_active: self.is_active,
+            reason,
+            note,
+            actor: actor.to_string(),
+            at,
+        })
+    }
+
+    /// Toggles the legacy active flag. Closed accounts cannot be reactivated.
+    pub fn set_active(
+        &mut self,
+        active: bool,
+        reason: StatusReasonCode,
+        note: Option<String>,
+        actor: &str,
+        at: DateTime<Utc>,
+    ) -> Result<AccountStatusChange, DomainError> {
+        let note = Self::change_note(reason, note, actor)?;
+        if active == self.is_active {
+            return Err(DomainError::AccountActiveFlagUnchanged {
+                state: if active { "active" } else { "inactive" }.to_string(),
+            });
+        }
+        if active && self.status == AccountStatus::Closed {
+            return Err(DomainError::AccountStatusTransitionNotAllowed {
+                from: AccountStatus::Closed.as_code().to_string(),
+                to: AccountStatus::Closed.as_code().to_string(),
+            });
+        }
+
+        let was_active = self.is_active;
+        self.is_active = active;
+
+        Ok(AccountStatusChange {
+            account_id: self.id,
+            from: self.status,
+            to: self.status,
+            was_active,
+            is_active: active,
+            reason,
+            note,
+            actor: actor.to_string(),
+            at,
+        })
+    }
+
     pub fn id(&self) -> i64 { self.id }
     pub fn public_id(&self) -> PublicId { self.public_id }
     pub fn owner_type(&self) -> OwnerType { self.owner_type }
@@ -67,4 +228,5 @@ impl LedgerAccount {
     pub fn account_type(&self) -> AccountType { self.account_type }
     pub fn asset_id(&self) -> i16 { self.asset_id }
     pub fn is_active(&self) -> bool { self.is_active }
+    pub fn status(&self) -> AccountStatus { self.status }
 }
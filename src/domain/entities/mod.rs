@@ -1,3 +1,11 @@
 mod ledger_account;
+mod account_status;
+mod chart_of_accounts;
+mod accounting_period;
 
 pub use ledger_account::{AccountType, LedgerAccount, OwnerType};
+pub use account_status::{
+    AccountStatus, AccountStatusChange, AccountStatusTimeline, PostingDirection, StatusReasonCode,
+};
+pub use chart_of_accounts::{ChartOfAccounts, CoaNode, GlClass, NodeBalance, NormalBalance};
+pub use accounting_period::{AccountingPeriod, NewAccountingPeriod, PeriodStatus};
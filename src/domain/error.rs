@@ -26,6 +26,30 @@ pub enum DomainError {
     #[error("ledger account is inactive")]
     LedgerAccountInactive,
 
+    #[error("account {account_id} is {status} and does not accept {direction} postings")]
+    AccountStatusBlocksPosting { account_id: i64, status: String, direction: String },
+
+    #[error("account status transition not allowed: from {from} to {to}")]
+    AccountStatusTransitionNotAllowed { from: String, to: String },
+
+    #[error("account {account_id} cannot be closed with non-zero balance {balance_minor}")]
+    AccountCloseNonZeroBalance { account_id: i64, balance_minor: i128 },
+
+    #[error("invalid account status: {value}")]
+    InvalidAccountStatus { value: String },
+
+    #[error("invalid status reason code: {value}")]
+    InvalidStatusReasonCode { value: String },
+
+    #[error("a note is required when the status reason is OTHER")]
+    StatusNoteRequired,
+
+    #[error("account is already {state}")]
+    AccountActiveFlagUnchanged { state: String },
+
+    #[error("account {account_id} was not open at {at}")]
+    AccountNotOpenAt { account_id: i64, at: String },
+
     #[error("created_by cannot be empty")]
     CreatedByEmpty,
 
@@ -41,6 +65,9 @@ pub enum DomainError {
     #[error("journal transaction is not balanced")]
     JournalNotBalanced,
 
+    #[error("journal lines in asset_id={asset_id} are not balanced")]
+    JournalNotBalancedForAsset { asset_id: i16 },
+
     #[error("cross-asset posting not allowed")]
     CrossAssetPostingNotAllowed,
 
@@ -74,4 +101,211 @@ pub enum DomainError {
     #[error("money cannot be zero")]
     MoneyZeroNotAllowed,
 
+    #[error("fee rule {rule_id} is invalid: {reason}")]
+    FeeRuleInvalid { rule_id: i64, reason: String },
+
+    #[error("invalid fee rounding: {value}")]
+    InvalidFeeRounding { value: String },
+
+    #[error("fee base amount must be greater than zero")]
+    FeeBaseAmountInvalid,
+
+    #[error("fee payer cannot be the revenue account (account_id={account_id})")]
+    FeePayerIsRevenueAccount { account_id: i64 },
+
+    #[error("manual adjustments must use external_ref_type MANUAL_ADJUSTMENT")]
+    ManualAdjustmentRefTypeRequired,
+
+    #[error("manual adjustment is not pending approval (status={status})")]
+    ManualAdjustmentNotPending { status: String },
+
+    #[error("manual adjustment cannot be approved or rejected by its submitter")]
+    ManualAdjustmentSelfApproval,
+
+    #[error("manual adjustment already approved by {approver}")]
+    ManualAdjustmentAlreadyApproved { approver: String },
+
+    #[error("manual adjustment needs {required} approvals, has {approvals}")]
+    ManualAdjustmentNotApproved { approvals: usize, required: u16 },
+
+    #[error("rejection reason cannot be empty")]
+    RejectionReasonRequired,
+
+    #[error("invalid adjustment status: {value}")]
+    InvalidAdjustmentStatus { value: String },
+
+    #[error("invalid adjustment action: {value}")]
+    InvalidAdjustmentAction { value: String },
+
+    #[error("invalid GL class: {value}")]
+    InvalidGlClass { value: String },
+
+    #[error("invalid normal balance side: {value}")]
+    InvalidNormalBalance { value: String },
+
+    #[error("chart of accounts is invalid: {reason}")]
+    ChartOfAccountsInvalid { reason: String },
+
+    #[error("amount overflow")]
+    AmountOverflow,
+
+    #[error("journal metadata is invalid: {reason}")]
+    JournalMetadataInvalid { reason: String },
+
+    #[error("invalid journal tag: '{value}'")]
+    JournalTagInvalid { value: String },
+
+    #[error("journal line memo too long (max {max} chars)")]
+    JournalLineMemoTooLong { max: usize },
+
+    #[error("effective date {effective_at} is earlier than the allowed {earliest}")]
+    EffectiveDateTooEarly { effective_at: String, earliest: String },
+
+    #[error("effective date {effective_at} is later than the allowed {latest}")]
+    EffectiveDateTooLate { effective_at: String, latest: String },
+
+    #[error("invalid balance basis: {value}")]
+    InvalidBalanceBasis { value: String },
+
+    #[error("invalid period status: {value}")]
+    InvalidPeriodStatus { value: String },
+
+    #[error("accounting period is invalid: {reason}")]
+    PeriodInvalid { reason: String },
+
+    #[error("accounting period {period} is closed; post a correction in the current period")]
+    PeriodClosed { period: String },
+
+    #[error("accounting period {period} is soft-closed and only accepts adjusting journals")]
+    PeriodSoftClosed { period: String },
+
+    #[error("period status transition not allowed: {from} -> {to}")]
+    PeriodTransitionNotAllowed { from: String, to: String },
+
+    #[error("adjusting journals must name the period they correct in metadata `{key}`")]
+    AdjustingJournalPeriodMissing { key: &'static str },
+
+    #[error("invalid schedule status: {value}")]
+    InvalidScheduleStatus { value: String },
+
+    #[error("invalid recurrence: {value}")]
+    InvalidRecurrence { value: String },
+
+    #[error("journal schedule is invalid: {reason}")]
+    ScheduleInvalid { reason: String },
+
+    #[error("schedule status transition not allowed: {from} -> {to}")]
+    ScheduleTransitionNotAllowed { from: String, to: String },
+
+    #[error("invalid pending journal status: {value}")]
+    InvalidPendingStatus { value: String },
+
+    #[error("pending journal is invalid: {reason}")]
+    PendingJournalInvalid { reason: String },
+
+    #[error("pending journal is not pending (status={status})")]
+    PendingJournalNotPending { status: String },
+
+    #[error("pending journal expired at {expired_at}")]
+    PendingJournalExpired { expired_at: String },
+
+    #[error("commit amount is invalid: {reason}")]
+    PendingCommitAmountInvalid { reason: String },
+
+    #[error("invalid hold status: {value}")]
+    InvalidHoldStatus { value: String },
+
+    #[error("hold is invalid: {reason}")]
+    HoldInvalid { reason: String },
+
+    #[error("hold is not active (status={status})")]
+    HoldNotActive { status: String },
+
+    #[error("hold expired at {expired_at}")]
+    HoldExpired { expired_at: String },
+
+    #[error("capture amount is invalid: {reason}")]
+    HoldCaptureAmountInvalid { reason: String },
+
+    #[error("invalid withdrawal status: {value}")]
+    InvalidWithdrawalStatus { value: String },
+
+    #[error("withdrawal is invalid: {reason}")]
+    WithdrawalInvalid { reason: String },
+
+    #[error("withdrawal status transition not allowed: {from} -> {to}")]
+    WithdrawalTransitionNotAllowed { from: String, to: String },
+
+    #[error("invalid deposit status: {value}")]
+    InvalidDepositStatus { value: String },
+
+    #[error("invalid deposit source: {value}")]
+    InvalidDepositSource { value: String },
+
+    #[error("deposit is invalid: {reason}")]
+    DepositInvalid { reason: String },
+
+    #[error("deposits are not enabled for asset_id={asset_id}")]
+    DepositAssetNotConfigured { asset_id: i16 },
+
+    #[error("transfer is invalid: {reason}")]
+    TransferInvalid { reason: String },
+
+    #[error("fx rate is invalid: {reason}")]
+    FxRateInvalid { reason: String },
+
+    #[error("invalid fx liquidity pool: {value}")]
+    InvalidFxLiquidity { value: String },
+
+    #[error("invalid fx quote status: {value}")]
+    InvalidFxQuoteStatus { value: String },
+
+    #[error("fx quote is invalid: {reason}")]
+    FxQuoteInvalid { reason: String },
+
+    #[error("fx quote is not open (status={status})")]
+    FxQuoteNotOpen { status: String },
+
+    #[error("fx quote expired at {expired_at}")]
+    FxQuoteExpired { expired_at: String },
+
+    #[error("invalid fx revaluation status: {value}")]
+    InvalidFxRevaluationStatus { value: String },
+
+    #[error("fx revaluation is invalid: {reason}")]
+    FxRevaluationInvalid { reason: String },
+
+    #[error("merkle proof is invalid: {reason}")]
+    MerkleProofInvalid { reason: String },
+
+    #[error("reserve snapshot is invalid: {reason}")]
+    ReserveSnapshotInvalid { reason: String },
+
+    #[error("invalid solvency trigger: {value}")]
+    InvalidSolvencyTrigger { value: String },
+
+    #[error("invalid solvency status: {value}")]
+    InvalidSolvencyStatus { value: String },
+
+    #[error("solvency report is invalid: {reason}")]
+    SolvencyReportInvalid { reason: String },
+
+    #[error("invalid feed consumer: {value}")]
+    InvalidFeedConsumer { value: String },
+
+    #[error("feed cursor is invalid: {reason}")]
+    FeedCursorInvalid { reason: String },
+
+    #[error("invalid webhook event type: {value}")]
+    InvalidWebhookEventType { value: String },
+
+    #[error("invalid webhook delivery status: {value}")]
+    InvalidWebhookDeliveryStatus { value: String },
+
+    #[error("webhook subscription is invalid: {reason}")]
+    WebhookSubscriptionInvalid { reason: String },
+
+    #[error("webhook delivery is invalid: {reason}")]
+    WebhookDeliveryInvalid { reason: String },
+
 }
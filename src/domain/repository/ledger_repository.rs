@@ -1,7 +1,7 @@
 use crate::domain::aggregate::PostedJournal;
 //use crate::domain::services::PolicyValidatedJournal;
-use crate::domain::entities::{LedgerAccount, AccountType, OwnerType};
-use crate::domain::value_objects::{PublicId, ExternalRef, ExternalRefType};
+use crate::domain::entities::{LedgerAccount, AccountType, OwnerType, StatusReasonCode};
+use crate::domain::value_objects::{Asset, AssetCode, PublicId, ExternalRef, ExternalRefType};
 use crate::domain::repository::error::RepoError;
 
 #[derive(Debug, Clone)]
@@ -12,18 +12,37 @@ pub struct NewLedgerAccountSpec {
     pub account_type: AccountType,
     pub asset_id: i16,
     pub is_active: bool,
+    /// Chart-of-accounts node; `None` uses the default node for the account type.
+    pub coa_node_id: Option<i64>,
 }
 
 pub trait LedgerRepository {
     fn create_account(&self, spec: NewLedgerAccountSpec)
                       -> Result<LedgerAccount, RepoError>;
 
-    fn set_account_active(&self, account_id: i64, active: bool)
-                          -> Result<(), RepoError>;
+    /// Toggles `is_active` and records who did it and why in the status history.
+    fn set_account_active(
+        &self,
+        account_id: i64,
+        active: bool,
+        reason: StatusReasonCode,
+        actor: &str,
+    ) -> Result<(), RepoError>;
 
     fn get_accounts_by_ids(&self, ids: &[i64])
                            -> Result<Vec<LedgerAccount>, RepoError>;
 
+    /// Looks up an account by its natural key (owner, bucket, asset).
+    fn find_account(
+        &self,
+        owner_type: OwnerType,
+        owner_id: Option<uuid::Uuid>,
+        account_type: AccountType,
+        asset_id: i16,
+    ) -> Result<Option<LedgerAccount>, RepoError>;
+
+    fn find_asset_by_code(&self, code: &AssetCode) -> Result<Option<Asset>, RepoError>;
+
     fn find_posted_by_external_ref(
         &self,
         external_ref_type: ExternalRefType,
@@ -0,0 +1,245 @@
+use crate::domain::aggregate::JournalDraft;
+use crate::domain::error::DomainError;
+use crate::domain::value_objects::{ExternalRefType, Money};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeeRounding {
+    Down,
+    Up,
+    HalfUp,
+    HalfEven,
+}
+
+impl FeeRounding {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            FeeRounding::Down => "DOWN",
+            FeeRounding::Up => "UP",
+            FeeRounding::HalfUp => "HALF_UP",
+            FeeRounding::HalfEven => "HALF_EVEN",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "DOWN" => Ok(Self::Down),
+            "UP" => Ok(Self::Up),
+            "HALF_UP" => Ok(Self::HalfUp),
+            "HALF_EVEN" => Ok(Self::HalfEven),
+            other => Err(DomainError::InvalidFeeRounding { value: other.to_string() }),
+        }
+    }
+
+    /// Integer division of a non-negative numerator by a positive denominator.
+    fn div(self, num: i128, den: i128) -> i128 {
+        let q = num / den;
+        let r = num % den;
+        if r == 0 {
+            return q;
+        }
+
+        match self {
+            FeeRounding::Down => q,
+            FeeRounding::Up => q + 1,
+            FeeRounding::HalfUp => if r * 2 >= den { q + 1 } else { q },
+            FeeRounding::HalfEven => match (r * 2).cmp(&den) {
+                std::cmp::Ordering::Greater => q + 1,
+                std::cmp::Ordering::Less => q,
+                std::cmp::Ordering::Equal => if q % 2 == 0 { q } else { q + 1 },
+            },
+        }
+    }
+}
+
+/// One bracket of a tiered fee. The bracket containing the base amount prices the whole amount.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTier {
+    /// Inclusive upper bound of the bracket; `None` for the last, open-ended bracket.
+    pub up_to_minor: Option<i128>,
+    pub rate_bps: u32,
+    pub flat_minor: i128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeeCalculation {
+    Flat { amount_minor: i128 },
+    Percentage { rate_bps: u32 },
+    Tiered { tiers: Vec<FeeTier> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeRule {
+    pub id: i64,
+    pub asset_id: i16,
+    pub posting_kind: ExternalRefType,
+    pub calculation: FeeCalculation,
+    pub min_fee_minor: Option<i128>,
+    pub max_fee_minor: Option<i128>,
+    pub rounding: FeeRounding,
+}
+
+impl FeeRule {
+    pub fn ensure_valid(&self) -> Result<(), DomainError> {
+        let invalid = |reason: &str| DomainError::FeeRuleInvalid {
+            rule_id: self.id,
+            reason: reason.to_string(),
+        };
+
+        match &self.calculation {
+            FeeCalculation::Flat { amount_minor } if *amount_minor < 0 => {
+                return Err(invalid("flat amount cannot be negative"));
+            }
+            FeeCalculation::Tiered { tiers } => {
+                if tiers.is_empty() {
+                    return Err(invalid("tiered rule has no tiers"));
+                }
+                let mut prev: Option<i128> = None;
+                for (i, t) in tiers.iter().enumerate() {
+                    if t.flat_minor < 0 {
+                        return Err(invalid("tier flat amount cannot be negative"));
+                    }
+                    match t.up_to_minor {
+                        None if i + 1 != tiers.len() => {
+                            return Err(invalid("only the last tier may be open-ended"));
+                        }
+                        None => {}
+                        Some(up_to) => {
+                            if prev.is_some_and(|p| up_to <= p) {
+                                return Err(invalid("tier bounds must be strictly ascending"));
+                            }
+                            prev = Some(up_to);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if self.min_fee_minor.is_some_and(|m| m < 0) || self.max_fee_minor.is_some_and(|m| m < 0) {
+            return Err(invalid("min/max fee cannot be negative"));
+        }
+        if let (Some(min), Some(max)) = (self.min_fee_minor, self.max_fee_minor)
+            && min > max
+        {
+            return Err(invalid("min fee exceeds max fee"));
+        }
+
+        Ok(())
+    }
+
+    /// Fee in minor units for `base_minor`, after rounding and min/max clamping.
+    pub fn compute(&self, base_minor: i128) -> Result<i128, DomainError> {
+        if base_minor <= 0 {
+            return Err(DomainError::FeeBaseAmountInvalid);
+        }
+
+        let overflow = || DomainError::FeeRuleInvalid {
+            rule_id: self.id,
+            reason: "fee computation overflow".to_string(),
+        };
+
+        let pct = |rate_bps: u32| -> Result<i128, DomainError> {
+            let num = base_minor.checked_mul(rate_bps as i128).ok_or_else(overflow)?;
+            Ok(self.rounding.div(num, BPS_DENOMINATOR))
+        };
+
+        let raw = match &self.calculation {
+            FeeCalculation::Flat { amount_minor } => *amount_minor,
+            FeeCalculation::Percentage { rate_bps } => pct(*rate_bps)?,
+            FeeCalculation::Tiered { tiers } => {
+                let tier = tiers
+                    .iter()
+                    .find(|t| t.up_to_minor.is_none_or(|up_to| base_minor <= up_to))
+                    .or(tiers.last())
+                    .ok_or_else(|| DomainError::FeeRuleInvalid {
+                        rule_id: self.id,
+                        reason: "tiered rule has no tiers".to_string(),
+                    })?;
+                pct(tier.rate_bps)?.checked_add(tier.flat_minor).ok_or_else(overflow)?
+            }
+        };
+
+        let mut fee = raw;
+        if let Some(min) = self.min_fee_minor {
+            fee = fee.max(min);
+        }
+        if let Some(max) = self.max_fee_minor {
+            fee = fee.min(max);
+        }
+        Ok(fee)
+    }
+}
+
+/// Active fee rules for a single (asset, posting kind) pair, applied in ascending rule id order.
+#[derive(Debug, Clone, Default)]
+pub struct FeeSchedule {
+    rules: Vec<FeeRule>,
+}
+
+impl FeeSchedule {
+    pub fn new(mut rules: Vec<FeeRule>) -> Result<Self, DomainError> {
+        for r in &rules {
+            r.ensure_valid()?;
+        }
+        rules.sort_by_key(|r| r.id);
+        Ok(Self { rules })
+    }
+
+    pub fn rules(&self) -> &[FeeRule] {
+        &self.rules
+    }
+
+    pub fn matching(&self, asset_id: i16, posting_kind: ExternalRefType) -> impl Iterator<Item = &FeeRule> {
+        self.rules
+            .iter()
+            .filter(move |r| r.asset_id == asset_id && r.posting_kind == posting_kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FeeContext {
+    pub asset_id: i16,
+    pub payer_account_id: i64,
+    pub revenue_account_id: i64,
+    pub base_amount_minor: i128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppliedFee {
+    pub rule_id: i64,
+    pub amount_minor: i128,
+}
+
+pub struct FeeEngine;
+
+impl FeeEngine {
+    /// Appends one payer -> revenue line pair per matching rule and records the rule ids on the draft.
+    /// Rules that price to zero are skipped and not recorded.
+    pub fn apply(
+        schedule: &FeeSchedule,
+        draft: &mut JournalDraft,
+        ctx: &FeeContext,
+    ) -> Result<Vec<AppliedFee>, DomainError> {
+        if ctx.payer_account_id == ctx.revenue_account_id {
+            return Err(DomainError::FeePayerIsRevenueAccount { account_id: ctx.payer_account_id });
+        }
+
+        let mut applied = vec![];
+        for rule in schedule.matching(ctx.asset_id, draft.external_ref_type) {
+            let fee = rule.compute(ctx.base_amount_minor)?;
+            if fee == 0 {
+                continue;
+            }
+
+            draft.add_line(ctx.payer_account_id, Money::credit(fee)?);
+            draft.add_line(ctx.revenue_account_id, Money::debit(fee)?);
+            draft.record_fee_rule(rule.id);
+
+            applied.push(AppliedFee { rule_id: rule.id, amount_minor: fee });
+        }
+
+        Ok(applied)
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::{ChartOfAccounts, GlClass, NodeBalance};
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Clone)]
+pub struct BalanceSheet {
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    pub assets: Vec<NodeBalance>,
+    pub liabilities: Vec<NodeBalance>,
+    pub equity: Vec<NodeBalance>,
+    pub total_assets: i128,
+    pub total_liabilities: i128,
+    pub total_equity: i128,
+    /// Revenue less expenses not yet closed into equity.
+    pub current_earnings: i128,
+}
+
+impl BalanceSheet {
+    pub fn is_balanced(&self) -> bool {
+        self.total_assets == self.total_liabilities + self.total_equity + self.current_earnings
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IncomeStatement {
+    pub asset_id: i16,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub revenue: Vec<NodeBalance>,
+    pub expenses: Vec<NodeBalance>,
+    pub total_revenue: i128,
+    pub total_expenses: i128,
+    pub net_income: i128,
+}
+
+/// Gross ledger movement on a node: positive lines and negative lines kept apart.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct NodeFlows {
+    pub inflow_minor: i128,
+    pub outflow_minor: i128,
+}
+
+/// Opening balance, gross increases and decreases, and closing balance of a node over
+/// a period, in the node's normal-balance presentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeMovement {
+    pub node_id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: GlClass,
+    pub depth: usize,
+    pub opening_minor: i128,
+    pub increase_minor: i128,
+    pub decrease_minor: i128,
+    pub closing_minor: i128,
+}
+
+#[derive(Debug, Clone)]
+pub struct MovementSummary {
+    pub asset_id: i16,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub nodes: Vec<NodeMovement>,
+}
+
+pub struct FinancialStatements;
+
+impl FinancialStatements {
+    /// `balances_by_node` holds ledger balances as of `as_of`, summed per chart node for one asset.
+    pub fn balance_sheet(
+        chart: &ChartOfAccounts,
+        asset_id: i16,
+        as_of: DateTime<Utc>,
+        balances_by_node: &HashMap<i64, i128>,
+    ) -> Result<BalanceSheet, DomainError> {
+        let rolled = chart.rollup(balances_by_node)?;
+        let by_class = Self::class_totals(&rolled)?;
+        let totals = |class| by_class.get(&class).copied().unwrap_or(0);
+
+        Ok(BalanceSheet {
+            asset_id,
+            as_of,
+            total_assets: totals(GlClass::Asset),
+            total_liabilities: totals(GlClass::Liability),
+            total_equity: totals(GlClass::Equity),
+            current_earnings: totals(GlClass::Revenue) - totals(GlClass::Expense),
+            assets: Self::section(&rolled, GlClass::Asset),
+            liabilities: Self::section(&rolled, GlClass::Liability),
+            equity: Self::section(&rolled, GlClass::Equity),
+        })
+    }
+
+    /// `movements_by_node` holds the net ledger movement per chart node over `[from, to)`.
+    pub fn income_statement(
+        chart: &ChartOfAccounts,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        movements_by_node: &HashMap<i64, i128>,
+    ) -> Result<IncomeStatement, DomainError> {
+        let rolled = chart.rollup(movements_by_node)?;
+        let by_class = Self::class_totals(&rolled)?;
+        let totals = |class| by_class.get(&class).copied().unwrap_or(0);
+        let (total_revenue, total_expenses) = (totals(GlClass::Revenue), totals(GlClass::Expense));
+
+        Ok(IncomeStatement {
+            asset_id,
+            from,
+            to,
+            revenue: Self::section(&rolled, GlClass::Revenue),
+            expenses: Self::section(&rolled, GlClass::Expense),
+            total_revenue,
+            total_expenses,
+            net_income: total_revenue - total_expenses,
+        })
+    }
+
+    /// Cash-flow style summary: for every node, where the balance started, how much
+    /// flowed in each direction, and where it ended.
+    pub fn movement_summary(
+        chart: &ChartOfAccounts,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        opening_by_node: &HashMap<i64, i128>,
+        flows_by_node: &HashMap<i64, NodeFlows>,
+    ) -> Result<MovementSummary, DomainError> {
+        let inflows: HashMap<i64, i128> = flows_by_node.iter().map(|(id, f)| (*id, f.inflow_minor)).collect();
+        let outflows: HashMap<i64, i128> = flows_by_node.iter().map(|(id, f)| (*id, f.outflow_minor)).collect();
+
+        let opening = chart.rollup(opening_by_node)?;
+        let inflows = chart.rollup(&inflows)?;
+        let outflows = chart.rollup(&outflows)?;
+
+        let nodes = opening
+            .into_iter()
+            .zip(inflows)
+            .zip(outflows)
+            .map(|((o, i), out)| {
+                // Presented in the node's side, one of the two flows increases the
+                // balance and the other decreases it.
+                let (a, b) = (i.total_minor, out.total_minor);
+                let increase = a.max(0) + b.max(0);
+                let decrease = -(a.min(0) + b.min(0));
+                NodeMovement {
+                    node_id: o.node_id,
+                    code: o.code,
+                    name: o.name,
+                    gl_class: o.gl_class,
+                    depth: o.depth,
+                    opening_minor: o.total_minor,
+                    increase_minor: increase,
+                    decrease_minor: decrease,
+                    closing_minor: o.total_minor + increase - decrease,
+                }
+            })
+            .collect();
+
+        Ok(MovementSummary { asset_id, from, to, nodes })
+    }
+
+    fn section(rolled: &[NodeBalance], class: GlClass) -> Vec<NodeBalance> {
+        rolled.iter().filter(|n| n.gl_class == class).cloned().collect()
+    }
+
+    /// Sums root nodes per class, each in its class's natural sign, so contra
+    /// roots reduce their class total.
+    fn class_totals(rolled: &[NodeBalance]) -> Result<HashMap<GlClass, i128>, DomainError> {
+        let mut totals: HashMap<GlClass, i128> = HashMap::new();
+        for n in rolled.iter().filter(|n| n.parent_id.is_none()) {
+            let natural = if n.normal_balance == n.gl_class.default_normal_balance() {
+                n.total_minor
+            } else {
+                -n.total_minor
+            };
+            let t = totals.entry(n.gl_class).or_insert(0);
+            *t = t.checked_add(natural).ok_or(DomainError::AmountOverflow)?;
+        }
+        Ok(totals)
+    }
+}
@@ -0,0 +1,211 @@
+use sha2::{Digest as _, Sha256};
+
+use crate::domain::aggregate::PostedJournal;
+use crate::domain::services::Digest;
+
+const JOURNAL_TAG: &[u8] = b"sirara:journal:v1";
+
+/// What the first journal in the chain links back to.
+pub const GENESIS_HASH: Digest = [0; 32];
+
+/// One journal's place in the ledger-wide hash chain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ChainLink {
+    /// 1-based and contiguous; a missing position means a link was removed.
+    pub position: i64,
+    pub journal_id: i64,
+    pub prev_hash: Digest,
+    pub entry_hash: Digest,
+}
+
+/// The last link recorded, kept apart from the links so truncating the chain shows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ChainHead {
+    /// 0 before anything is chained.
+    pub position: i64,
+    pub entry_hash: Digest,
+}
+
+fn put_bytes(h: &mut Sha256, bytes: &[u8]) {
+    // length-prefixed so adjacent fields cannot run into each other
+    h.update((bytes.len() as u64).to_be_bytes());
+    h.update(bytes);
+}
+
+fn put_opt(h: &mut Sha256, s: Option<&str>) {
+    match s {
+        Some(s) => {
+            h.update([1]);
+            put_bytes(h, s.as_bytes());
+        }
+        None => h.update([0]),
+    }
+}
+
+/// Hash over the journal's canonical content, its ledger sequence number and the previous
+/// link's hash. Lines and tags are taken in sorted order, so the hash depends only on what
+/// was posted, not on the order rows come back in; renumbering a journal breaks its link.
+pub fn journal_hash(prev_hash: &Digest, journal: &PostedJournal) -> Digest {
+    let mut h = Sha256::new();
+    h.update(JOURNAL_TAG);
+    h.update(prev_hash);
+    h.update(journal.sequence.to_be_bytes());
+    h.update(journal.public_id.value().as_bytes());
+    put_bytes(&mut h, journal.external_ref_type.as_code().as_bytes());
+    put_bytes(&mut h, journal.external_ref.as_str().as_bytes());
+    put_opt(&mut h, journal.description.as_deref());
+    put_bytes(&mut h, journal.created_by.as_bytes());
+
+    h.update((journal.metadata.as_map().len() as u64).to_be_bytes());
+    for (k, v) in journal.metadata.as_map() {
+        put_bytes(&mut h, k.as_bytes());
+        put_bytes(&mut h, v.as_bytes());
+    }
+    let mut tags: Vec<&str> = journal.tags.iter().map(|t| t.as_str()).collect();
+    tags.sort_unstable();
+    h.update((tags.len() as u64).to_be_bytes());
+    for t in tags {
+        put_bytes(&mut h, t.as_bytes());
+    }
+
+    h.update(journal.effective_at.timestamp_micros().to_be_bytes());
+    h.update(journal.posted_at.timestamp_micros().to_be_bytes());
+    h.update([journal.multi_asset as u8]);
+
+    let mut lines: Vec<_> = journal
+        .lines
+        .iter()
+        .map(|l| (l.account_id, l.asset_id, l.amount.minor(), l.memo.as_deref()))
+        .collect();
+    lines.sort_unstable();
+    h.update((lines.len() as u64).to_be_bytes());
+    for (account_id, asset_id, amount, memo) in lines {
+        h.update(account_id.to_be_bytes());
+        h.update(asset_id.to_be_bytes());
+        h.update(amount.to_be_bytes());
+        put_opt(&mut h, memo);
+    }
+
+    let mut fee_rule_ids = journal.fee_rule_ids.clone();
+    fee_rule_ids.sort_unstable();
+    h.update((fee_rule_ids.len() as u64).to_be_bytes());
+    for id in fee_rule_ids {
+        h.update(id.to_be_bytes());
+    }
+
+    h.finalize().into()
+}
+
+/// How a chain link failed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainBreakKind {
+    /// The link is not at the next position: links in between were removed.
+    Gap { expected_position: i64 },
+    /// The link does not point at the previous link's hash.
+    PrevHashMismatch,
+    /// The journal's content no longer hashes to the recorded entry hash.
+    ContentMismatch,
+    /// The link refers to a journal that is gone.
+    MissingJournal,
+    /// The recorded chain head is not the last link.
+    HeadMismatch,
+}
+
+impl ChainBreakKind {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            ChainBreakKind::Gap { .. } => "GAP",
+            ChainBreakKind::PrevHashMismatch => "PREV_HASH_MISMATCH",
+            ChainBreakKind::ContentMismatch => "CONTENT_MISMATCH",
+            ChainBreakKind::MissingJournal => "MISSING_JOURNAL",
+            ChainBreakKind::HeadMismatch => "HEAD_MISMATCH",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    pub position: i64,
+    pub journal_id: Option<i64>,
+    pub kind: ChainBreakKind,
+}
+
+/// Walks the chain one link at a time, in position order, and stops at the first
+/// broken link.
+#[derive(Debug, Clone)]
+pub struct ChainVerifier {
+    next_position: i64,
+    prev_hash: Digest,
+    checked: u64,
+    broken: Option<ChainBreak>,
+}
+
+impl Default for ChainVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainVerifier {
+    pub fn new() -> Self {
+        Self { next_position: 1, prev_hash: GENESIS_HASH, checked: 0, broken: None }
+    }
+
+    /// Checks the next link against its journal (`None` when the journal is missing).
+    /// Returns false once the chain is broken; later links are not checked.
+    pub fn check(&mut self, link: &ChainLink, journal: Option<&PostedJournal>) -> bool {
+        if self.broken.is_some() {
+            return false;
+        }
+        let kind = if link.position != self.next_position {
+            Some(ChainBreakKind::Gap { expected_position: self.next_position })
+        } else if link.prev_hash != self.prev_hash {
+            Some(ChainBreakKind::PrevHashMismatch)
+        } else {
+            match journal {
+                None => Some(ChainBreakKind::MissingJournal),
+                Some(j) if j.db_id != link.journal_id || journal_hash(&link.prev_hash, j) != link.entry_hash => {
+                    Some(ChainBreakKind::ContentMismatch)
+                }
+                Some(_) => None,
+            }
+        };
+
+        if let Some(kind) = kind {
+            self.broken = Some(ChainBreak { position: link.position, journal_id: Some(link.journal_id), kind });
+            return false;
+        }
+        self.next_position += 1;
+        self.prev_hash = link.entry_hash;
+        self.checked += 1;
+        true
+    }
+
+    /// Ends the walk against the recorded head: its position and hash must be those of
+    /// the last link checked, so links cut from the end are caught too.
+    pub fn finish(mut self, head: &ChainHead) -> ChainVerification {
+        if self.broken.is_none() && (head.position != self.next_position - 1 || head.entry_hash != self.prev_hash) {
+            self.broken = Some(ChainBreak {
+                position: head.position,
+                journal_id: None,
+                kind: ChainBreakKind::HeadMismatch,
+            });
+        }
+        ChainVerification { checked: self.checked, last_hash: self.prev_hash, first_break: self.broken }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainVerification {
+    /// Links verified before the first break (all of them when intact).
+    pub checked: u64,
+    /// Hash of the last verified link.
+    pub last_hash: Digest,
+    pub first_break: Option<ChainBreak>,
+}
+
+impl ChainVerification {
+    pub fn is_intact(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
@@ -33,8 +33,8 @@ impl LedgerPostingService {
 
         let expected_owner = match acct.account_type() {
             UserAvailable | UserLocked => User,
-            PlatformClearing => Platform,
-            TreasuryAvailable | TreasuryLocked => Treasury,
+            PlatformClearing | PlatformRevenue | PlatformUnrealisedFx => Platform,
+            TreasuryAvailable | TreasuryLocked | TreasuryRevaluation => Treasury,
             InventoryAvailable | InventoryLocked => Platform,
         };
 
@@ -65,14 +65,15 @@ impl LedgerPostingService {
         }
         net.retain(|_, v| *v != 0);
 
-        let mut avail: Vec<&LedgerAccount> = vec![];
-        let mut locked: Vec<&LedgerAccount> = vec![];
+        // checked per asset: a multi-asset journal may move several users' funds
+        let mut by_asset: HashMap<i16, (Vec<&LedgerAccount>, Vec<&LedgerAccount>)> = HashMap::new();
 
-        for (account_id, _) in &net {
+        for account_id in net.keys() {
             let acct = accounts_by_id
                 .get(account_id)
                 .ok_or(DomainError::LedgerAccountNotFound { account_id: *account_id })?;
 
+            let (avail, locked) = by_asset.entry(acct.asset_id()).or_default();
             match acct.account_type() {
                 UserAvailable => avail.push(*acct),
                 UserLocked => locked.push(*acct),
@@ -80,7 +81,10 @@ impl LedgerPostingService {
             }
         }
 
-        if !avail.is_empty() && !locked.is_empty() {
+        for (avail, locked) in by_asset.values() {
+            if avail.is_empty() || locked.is_empty() {
+                continue;
+            }
             if avail.len() != 1 || locked.len() != 1 {
                 return Err(DomainError::HoldPostingAmbiguous {
                     available_accounts: avail.len(),
@@ -0,0 +1,199 @@
+use sha2::{Digest as _, Sha256};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+pub type Digest = [u8; 32];
+
+const OWNER_TAG: &[u8] = b"sirara:por:owner";
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+
+/// A node of a Merkle sum tree: its hash commits to its sum, so no subtree can claim
+/// less than the leaves under it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MerkleNode {
+    pub hash: Digest,
+    pub sum: i128,
+}
+
+impl MerkleNode {
+    /// Leaf for one owner's balance. Balances are liabilities owed, so never negative.
+    pub fn leaf(owner_hash: &Digest, balance_minor: i128) -> Result<Self, DomainError> {
+        if balance_minor < 0 {
+            return Err(DomainError::MerkleProofInvalid { reason: "leaf balance is negative".into() });
+        }
+        let mut h = Sha256::new();
+        h.update([LEAF_TAG]);
+        h.update(owner_hash);
+        h.update(balance_minor.to_be_bytes());
+        Ok(Self { hash: h.finalize().into(), sum: balance_minor })
+    }
+
+    pub fn parent(left: &MerkleNode, right: &MerkleNode) -> Result<Self, DomainError> {
+        if left.sum < 0 || right.sum < 0 {
+            return Err(DomainError::MerkleProofInvalid { reason: "node sum is negative".into() });
+        }
+        let sum = left
+            .sum
+            .checked_add(right.sum)
+            .ok_or_else(|| DomainError::MerkleProofInvalid { reason: "sum overflows".into() })?;
+        let mut h = Sha256::new();
+        h.update([NODE_TAG]);
+        h.update(left.hash);
+        h.update(left.sum.to_be_bytes());
+        h.update(right.hash);
+        h.update(right.sum.to_be_bytes());
+        Ok(Self { hash: h.finalize().into(), sum })
+    }
+}
+
+/// Hashes an owner id with the snapshot's salt, so the published tree does not reveal
+/// who holds what while each owner can still find their own leaf.
+pub fn hash_owner(salt: &[u8], owner_id: Uuid) -> Digest {
+    let mut h = Sha256::new();
+    h.update(OWNER_TAG);
+    h.update(salt);
+    h.update(owner_id.as_bytes());
+    h.finalize().into()
+}
+
+/// Which side of the running hash a proof's sibling sits on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SiblingSide {
+    Left,
+    Right,
+}
+
+impl SiblingSide {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            SiblingSide::Left => "LEFT",
+            SiblingSide::Right => "RIGHT",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "LEFT" => Ok(Self::Left),
+            "RIGHT" => Ok(Self::Right),
+            other => Err(DomainError::MerkleProofInvalid { reason: format!("unknown sibling side {other}") }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofStep {
+    pub side: SiblingSide,
+    pub sibling: MerkleNode,
+}
+
+/// The path from one leaf to the root. An owner recomputes their leaf from their own id,
+/// the snapshot's salt and their balance, then folds in the siblings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InclusionProof {
+    pub owner_hash: Digest,
+    pub balance_minor: i128,
+    pub steps: Vec<ProofStep>,
+}
+
+/// Merkle sum tree over leaves in the order given. A level with an odd node out carries
+/// that node up unchanged.
+#[derive(Debug, Clone)]
+pub struct MerkleSumTree {
+    leaves: Vec<(Digest, i128)>,
+    levels: Vec<Vec<MerkleNode>>,
+}
+
+impl MerkleSumTree {
+    pub fn build(leaves: Vec<(Digest, i128)>) -> Result<Self, DomainError> {
+        let mut level = leaves
+            .iter()
+            .map(|(owner_hash, balance)| MerkleNode::leaf(owner_hash, *balance))
+            .collect::<Result<Vec<_>, _>>()?;
+        if level.is_empty() {
+            // an empty tree commits to nothing owed
+            level.push(MerkleNode { hash: Sha256::digest([LEAF_TAG]).into(), sum: 0 });
+        }
+
+        let mut levels = vec![level];
+        while levels.last().is_some_and(|l| l.len() > 1) {
+            let below = levels.last().map(Vec::as_slice).unwrap_or_default();
+            let mut above = Vec::with_capacity(below.len().div_ceil(2));
+            for pair in below.chunks(2) {
+                above.push(match pair {
+                    [left, right] => MerkleNode::parent(left, right)?,
+                    [carried] => *carried,
+                    _ => unreachable!("chunks(2) yields one or two nodes"),
+                });
+            }
+            levels.push(above);
+        }
+
+        Ok(Self { leaves, levels })
+    }
+
+    pub fn root(&self) -> MerkleNode {
+        self.levels.last().and_then(|l| l.first()).copied().unwrap_or(MerkleNode { hash: [0; 32], sum: 0 })
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn position(&self, owner_hash: &Digest) -> Option<usize> {
+        self.leaves.iter().position(|(h, _)| h == owner_hash)
+    }
+
+    pub fn proof(&self, leaf_index: usize) -> Option<InclusionProof> {
+        let (owner_hash, balance_minor) = *self.leaves.get(leaf_index)?;
+        let mut steps = Vec::new();
+        let mut index = leaf_index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if let Some(node) = level.get(sibling) {
+                let side = if sibling < index { SiblingSide::Left } else { SiblingSide::Right };
+                steps.push(ProofStep { side, sibling: *node });
+            }
+            index /= 2;
+        }
+        Some(InclusionProof { owner_hash, balance_minor, steps })
+    }
+}
+
+/// Checks offline that `proof` leads to `root`: the recomputed hash must match and the
+/// sums on the path must add up to the published total. Needs nothing but the proof and
+/// the published root.
+pub fn verify_inclusion(root: &MerkleNode, proof: &InclusionProof) -> Result<(), DomainError> {
+    let mut node = MerkleNode::leaf(&proof.owner_hash, proof.balance_minor)?;
+    for step in &proof.steps {
+        node = match step.side {
+            SiblingSide::Left => MerkleNode::parent(&step.sibling, &node)?,
+            SiblingSide::Right => MerkleNode::parent(&node, &step.sibling)?,
+        };
+    }
+    if node != *root {
+        return Err(DomainError::MerkleProofInvalid { reason: "proof does not lead to the published root".into() });
+    }
+    Ok(())
+}
+
+/// [`verify_inclusion`] for an owner checking their own leaf: the proof's owner hash
+/// must also be theirs under the snapshot's salt.
+pub fn verify_owner_inclusion(
+    root: &MerkleNode,
+    owner_id: Uuid,
+    salt: &[u8],
+    proof: &InclusionProof,
+) -> Result<(), DomainError> {
+    if hash_owner(salt, owner_id) != proof.owner_hash {
+        return Err(DomainError::MerkleProofInvalid { reason: "proof is for a different owner".into() });
+    }
+    verify_inclusion(root, proof)
+}
+
+/// Parses a hex-encoded 32-byte digest, as published.
+pub fn parse_digest(s: &str) -> Result<Digest, DomainError> {
+    let invalid = || DomainError::MerkleProofInvalid { reason: format!("not a 32-byte hex digest: {s}") };
+    hex::decode(s).map_err(|_| invalid())?.try_into().map_err(|_| invalid())
+}
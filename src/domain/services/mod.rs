@@ -1,3 +1,26 @@
 
 mod tt;
 mod ledger_posting_service;
+mod fee_engine;
+mod financial_statements;
+mod value_dating;
+mod merkle_sum_tree;
+mod journal_chain;
+mod webhook_signature;
+
+pub use ledger_posting_service::{LedgerPostingService, PolicyValidatedJournal};
+pub use fee_engine::{
+    AppliedFee, FeeCalculation, FeeContext, FeeEngine, FeeRounding, FeeRule, FeeSchedule, FeeTier,
+};
+pub use financial_statements::{
+    BalanceSheet, FinancialStatements, IncomeStatement, MovementSummary, NodeFlows, NodeMovement,
+};
+pub use value_dating::{BalanceBasis, ValueDatingPolicy};
+pub use merkle_sum_tree::{
+    hash_owner, parse_digest, verify_inclusion, verify_owner_inclusion, Digest, InclusionProof, MerkleNode, MerkleSumTree, ProofStep,
+    SiblingSide,
+};
+pub use journal_chain::{
+    journal_hash, ChainBreak, ChainBreakKind, ChainHead, ChainLink, ChainVerification, ChainVerifier, GENESIS_HASH,
+};
+pub use webhook_signature::{sign_webhook, verify_webhook_signature};
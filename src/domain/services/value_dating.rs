@@ -0,0 +1,71 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::error::DomainError;
+
+/// Which timestamp decides whether a journal counts towards a point-in-time figure.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BalanceBasis {
+    /// When the journal was written to the ledger.
+    Posted,
+    /// When the journal takes economic effect (value date).
+    Effective,
+}
+
+impl BalanceBasis {
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            BalanceBasis::Posted => "POSTED",
+            BalanceBasis::Effective => "EFFECTIVE",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "POSTED" => Ok(Self::Posted),
+            "EFFECTIVE" => Ok(Self::Effective),
+            other => Err(DomainError::InvalidBalanceBasis { value: other.to_string() }),
+        }
+    }
+}
+
+/// How far a journal's effective date may sit from the time it is posted.
+#[derive(Debug, Clone, Copy)]
+pub struct ValueDatingPolicy {
+    max_backdate: Duration,
+    max_forward: Duration,
+}
+
+impl Default for ValueDatingPolicy {
+    fn default() -> Self {
+        Self { max_backdate: Duration::days(31), max_forward: Duration::minutes(5) }
+    }
+}
+
+impl ValueDatingPolicy {
+    pub fn new(max_backdate: Duration, max_forward: Duration) -> Self {
+        Self { max_backdate, max_forward }
+    }
+
+    /// `None` means "effective when posted" and is always allowed.
+    pub fn check(&self, effective_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Result<(), DomainError> {
+        let Some(at) = effective_at else {
+            return Ok(());
+        };
+
+        let earliest = now - self.max_backdate;
+        if at < earliest {
+            return Err(DomainError::EffectiveDateTooEarly {
+                effective_at: at.to_rfc3339(),
+                earliest: earliest.to_rfc3339(),
+            });
+        }
+        let latest = now + self.max_forward;
+        if at > latest {
+            return Err(DomainError::EffectiveDateTooLate {
+                effective_at: at.to_rfc3339(),
+                latest: latest.to_rfc3339(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use crate::domain::error::DomainError;
+
+/// Most decimal places a rate keeps; finer digits are truncated.
+pub const MAX_RATE_SCALE: u32 = 18;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Units of the quote asset per one unit of the base asset, as an exact decimal
+/// `mantissa * 10^-scale`. Always positive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExchangeRate {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl ExchangeRate {
+    pub fn new(mantissa: i128, scale: u32) -> Result<Self, DomainError> {
+        if mantissa <= 0 {
+            return Err(invalid("rate must be positive"));
+        }
+        let (mut mantissa, mut scale) = (mantissa, scale);
+        while scale > MAX_RATE_SCALE {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if mantissa == 0 {
+            return Err(invalid(format!("rate is below 1e-{MAX_RATE_SCALE}")));
+        }
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Parses a plain decimal such as `1523.45`.
+    pub fn parse(s: &str) -> Result<Self, DomainError> {
+        let s = s.trim();
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if (int.is_empty() && frac.is_empty())
+            || !int.chars().all(|c| c.is_ascii_digit())
+            || !frac.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid(format!("'{s}' is not a plain decimal")));
+        }
+        if frac.len() > MAX_RATE_SCALE as usize {
+            return Err(invalid(format!("at most {MAX_RATE_SCALE} decimal places")));
+        }
+        let mantissa: i128 = format!("{int}{frac}")
+            .parse()
+            .map_err(|_| invalid(format!("'{s}' is out of range")))?;
+        Self::new(mantissa, frac.len() as u32)
+    }
+
+    pub fn mantissa(&self) -> i128 { self.mantissa }
+    pub fn scale(&self) -> u32 { self.scale }
+
+    /// The rate worsened for the customer by `spread_bps`, truncated to the maximum scale.
+    pub fn less_spread(&self, spread_bps: u32) -> Result<Self, DomainError> {
+        let keep = BPS_DENOMINATOR - i128::from(spread_bps);
+        if keep <= 0 {
+            return Err(invalid("spread must be below 10000 bps"));
+        }
+        let mantissa = self.mantissa.checked_mul(keep).ok_or_else(|| invalid("rate overflow applying spread"))?;
+        Self::new(mantissa, self.scale + 4)
+    }
+
+    /// Converts `amount_minor` of the base asset into minor units of the quote asset,
+    /// rounding down.
+    pub fn convert(&self, amount_minor: i128, base_decimals: i16, quote_decimals: i16) -> Result<i128, DomainError> {
+        let overflow = || invalid("conversion overflows");
+        let product = amount_minor.checked_mul(self.mantissa).ok_or_else(overflow)?;
+
+        let exponent = i64::from(quote_decimals) - i64::from(base_decimals) - i64::from(self.scale);
+        if exponent >= 0 {
+            let factor = 10i128.checked_pow(exponent as u32).ok_or_else(overflow)?;
+            product.checked_mul(factor).ok_or_else(overflow)
+        } else {
+            match 10i128.checked_pow((-exponent) as u32) {
+                Some(divisor) => Ok(product.div_euclid(divisor)),
+                // smaller than one minor unit
+                None => Ok(0),
+            }
+        }
+    }
+}
+
+impl fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return f.write_str(&digits);
+        }
+        let padded = format!("{digits:0>width$}", width = scale + 1);
+        let (int, frac) = padded.split_at(padded.len() - scale);
+        write!(f, "{int}.{frac}")
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> DomainError {
+    DomainError::FxRateInvalid { reason: reason.into() }
+}
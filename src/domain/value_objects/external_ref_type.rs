@@ -7,6 +7,16 @@ pub enum ExternalRefType {
     Reversal,
     Fee,
     Settlement,
+    /// Correction for an earlier period, booked in a period that still accepts it.
+    PeriodAdjustment,
+    /// A step in a hold's lifecycle (place, capture, release, expire).
+    Hold,
+    /// Credit of an on-chain deposit once it has enough confirmations.
+    Deposit,
+    /// One leg of a currency conversion; the two legs share the quote id.
+    FxConversion,
+    /// Unrealised gain or loss on foreign holdings at a period's end, or its reversal.
+    FxRevaluation,
 }
 
 impl ExternalRefType {
@@ -17,6 +27,11 @@ impl ExternalRefType {
             ExternalRefType::Reversal => "REVERSAL",
             ExternalRefType::Fee => "FEE",
             ExternalRefType::Settlement => "SETTLEMENT",
+            ExternalRefType::PeriodAdjustment => "PERIOD_ADJUSTMENT",
+            ExternalRefType::Hold => "HOLD",
+            ExternalRefType::Deposit => "DEPOSIT",
+            ExternalRefType::FxConversion => "FX_CONVERSION",
+            ExternalRefType::FxRevaluation => "FX_REVALUATION",
         }
     }
     pub fn from_code(s: &str) -> Result<Self, DomainError> {
@@ -26,6 +41,11 @@ impl ExternalRefType {
             "REVERSAL" => Ok(Self::Reversal),
             "FEE" => Ok(Self::Fee),
             "SETTLEMENT" => Ok(Self::Settlement),
+            "PERIOD_ADJUSTMENT" => Ok(Self::PeriodAdjustment),
+            "HOLD" => Ok(Self::Hold),
+            "DEPOSIT" => Ok(Self::Deposit),
+            "FX_CONVERSION" => Ok(Self::FxConversion),
+            "FX_REVALUATION" => Ok(Self::FxRevaluation),
             other => Err(DomainError::InvalidExternalRefType {
                 value: other.to_string(),
             }),
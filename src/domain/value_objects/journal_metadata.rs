@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use crate::domain::error::DomainError;
+
+const MAX_ENTRIES: usize = 32;
+const MAX_KEY_LEN: usize = 64;
+const MAX_VALUE_LEN: usize = 256;
+const MAX_TAG_LEN: usize = 64;
+
+fn is_key_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '.' | '-')
+}
+
+/// Structured key/value data attached to a journal, e.g. `order_id=ord_123`.
+/// Keys are lowercase identifiers so lookups are exact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalMetadata(BTreeMap<String, String>);
+
+impl JournalMetadata {
+    pub fn new(entries: BTreeMap<String, String>) -> Result<Self, DomainError> {
+        if entries.len() > MAX_ENTRIES {
+            return Err(DomainError::JournalMetadataInvalid {
+                reason: format!("at most {MAX_ENTRIES} entries allowed"),
+            });
+        }
+        for (k, v) in &entries {
+            Self::validate_entry(k, v)?;
+        }
+        Ok(Self(entries))
+    }
+
+    fn validate_entry(key: &str, value: &str) -> Result<(), DomainError> {
+        if key.is_empty() || key.len() > MAX_KEY_LEN || !key.chars().all(is_key_char) {
+            return Err(DomainError::JournalMetadataInvalid {
+                reason: format!("key '{key}' must be 1-{MAX_KEY_LEN} chars of [a-z0-9_.-]"),
+            });
+        }
+        if value.len() > MAX_VALUE_LEN {
+            return Err(DomainError::JournalMetadataInvalid {
+                reason: format!("value for '{key}' exceeds {MAX_VALUE_LEN} bytes"),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_map(&self) -> &BTreeMap<String, String> {
+        &self.0
+    }
+}
+
+/// A label for grouping journals (`payroll`, `campaign:black-friday`), stored lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JournalTag(String);
+
+impl JournalTag {
+    pub fn new(s: impl Into<String>) -> Result<Self, DomainError> {
+        let s = s.into().trim().to_ascii_lowercase();
+        if s.is_empty() || s.len() > MAX_TAG_LEN || !s.chars().all(|c| is_key_char(c) || c == ':') {
+            return Err(DomainError::JournalTagInvalid { value: s });
+        }
+        Ok(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
@@ -10,7 +10,14 @@ pub use asset_code::AssetCode;
 mod external_ref;
 pub use external_ref::ExternalRef;
 
+mod exchange_rate;
+pub use exchange_rate::{ExchangeRate, MAX_RATE_SCALE};
+
+mod journal_metadata;
+pub use journal_metadata::{JournalMetadata, JournalTag};
+
 mod external_ref_type;
 mod asset;
 
 pub use external_ref_type::ExternalRefType;
+pub use asset::Asset;
@@ -0,0 +1,53 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::application::contracts::{SolvencyAlert, SolvencyAlertError, SolvencyAlertSink};
+
+#[derive(Debug, Default)]
+struct State {
+    alerts: Vec<SolvencyAlert>,
+    /// When set, alerts are refused with this message.
+    outage: Option<String>,
+}
+
+/// In-process alert sink for local runs and tests: keeps every alert it is given, once
+/// per report, like a real pager deduplicating on the report id.
+#[derive(Debug, Default)]
+pub struct LocalAlertRecorder {
+    state: Mutex<State>,
+}
+
+impl LocalAlertRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuses alerts with `message` until cleared with `None`.
+    pub fn set_outage(&self, message: Option<&str>) {
+        self.lock().outage = message.map(str::to_string);
+    }
+
+    pub fn alerts(&self) -> Vec<SolvencyAlert> {
+        self.lock().alerts.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        // a panic while holding the lock leaves a plain list behind; keep serving it
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl SolvencyAlertSink for LocalAlertRecorder {
+    async fn raise(&self, alert: &SolvencyAlert) -> Result<(), SolvencyAlertError> {
+        let mut state = self.lock();
+        if let Some(message) = &state.outage {
+            return Err(SolvencyAlertError::Undelivered { message: message.clone() });
+        }
+        if !state.alerts.iter().any(|a| a.report_id == alert.report_id) {
+            state.alerts.push(alert.clone());
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@ pub enum InfraError {
 
     #[error("configuration error")]
     Config(#[source] anyhow::Error),
+
+    #[error("io error")]
+    Io(#[source] std::io::Error),
 }
 
 impl From<sqlx::Error> for InfraError {
@@ -19,3 +22,6 @@ impl From<reqwest::Error> for InfraError {
 impl From<anyhow::Error> for InfraError {
     fn from(e: anyhow::Error) -> Self { InfraError::Config(e) }
 }
+impl From<std::io::Error> for InfraError {
+    fn from(e: std::io::Error) -> Self { InfraError::Io(e) }
+}
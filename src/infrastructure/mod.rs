@@ -1,2 +1,7 @@
 pub mod persistence;
+pub mod payout;
+pub mod alerts;
+pub mod notifications;
+pub mod webhooks;
 mod error;
+pub use error::InfraError;
@@ -0,0 +1,71 @@
+use thiserror::Error;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::application::contracts::BalanceChange;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum SubscriptionError {
+    /// The subscriber fell behind and `missed` changes were dropped; it should re-read the
+    /// balances it shows before carrying on.
+    #[error("balance subscriber lagged and missed {missed} changes")]
+    Lagged { missed: u64 },
+
+    /// The fan-out is gone; nothing more will arrive.
+    #[error("balance fan-out closed")]
+    Closed,
+}
+
+/// Hands every balance change to all in-process subscribers. Cheap to clone; clones share
+/// the same subscribers.
+#[derive(Debug, Clone)]
+pub struct BalanceFanout {
+    tx: broadcast::Sender<BalanceChange>,
+}
+
+impl BalanceFanout {
+    /// Each subscriber may fall up to `capacity` changes behind before it lags.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self { tx }
+    }
+
+    /// Returns how many subscribers were handed the change.
+    pub fn publish(&self, change: BalanceChange) -> usize {
+        // no subscribers is not an error: nobody is watching balances right now
+        self.tx.send(change).unwrap_or(0)
+    }
+
+    /// Publishes a `NOTIFY` payload as sent by the ledger.
+    pub fn publish_payload(&self, payload: &str) -> Result<usize, serde_json::Error> {
+        Ok(self.publish(serde_json::from_str(payload)?))
+    }
+
+    /// Changes from now on; with `owner_id`, only those of that owner's accounts.
+    pub fn subscribe(&self, owner_id: Option<Uuid>) -> BalanceSubscription {
+        BalanceSubscription { rx: self.tx.subscribe(), owner_id }
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+}
+
+pub struct BalanceSubscription {
+    rx: broadcast::Receiver<BalanceChange>,
+    owner_id: Option<Uuid>,
+}
+
+impl BalanceSubscription {
+    /// The next change this subscription wants, waiting until one is published.
+    pub async fn recv(&mut self) -> Result<BalanceChange, SubscriptionError> {
+        loop {
+            match self.rx.recv().await {
+                Ok(change) if self.owner_id.is_none_or(|o| change.is_owned_by(o)) => return Ok(change),
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(missed)) => return Err(SubscriptionError::Lagged { missed }),
+                Err(broadcast::error::RecvError::Closed) => return Err(SubscriptionError::Closed),
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::watch;
+
+use crate::application::contracts::BALANCE_CHANGED_CHANNEL;
+use crate::infrastructure::notifications::BalanceFanout;
+use crate::infrastructure::InfraError;
+
+/// Listens on the balance channel and hands each change to the fan-out.
+pub struct PgBalanceListener {
+    pool: PgPool,
+    fanout: BalanceFanout,
+}
+
+impl PgBalanceListener {
+    pub fn new(pool: PgPool, fanout: BalanceFanout) -> Self {
+        Self { pool, fanout }
+    }
+
+    /// Relays notifications until `shutdown` flips to true. If the connection drops it is
+    /// re-established, but changes committed meanwhile are not replayed: subscribers that
+    /// need every change read balances again after a reconnect.
+    pub async fn run(&self, mut shutdown: watch::Receiver<bool>) -> Result<(), InfraError> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(BALANCE_CHANGED_CHANNEL).await?;
+
+        while !*shutdown.borrow() {
+            tokio::select! {
+                notification = listener.recv() => {
+                    // payloads that do not parse were not sent by the ledger; skip them
+                    let _ = self.fanout.publish_payload(notification?.payload());
+                }
+                _ = shutdown.changed() => {}
+            }
+        }
+        Ok(())
+    }
+}
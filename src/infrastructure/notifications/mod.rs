@@ -0,0 +1,6 @@
+mod fanout;
+pub use fanout::{BalanceFanout, BalanceSubscription, SubscriptionError};
+mod listener;
+pub use listener::PgBalanceListener;
+mod websocket;
+pub use websocket::{serve_balance_socket, BalanceSocketAuthorizer, BALANCE_SOCKET_PATH};
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::{self, Message};
+use uuid::Uuid;
+
+use crate::infrastructure::notifications::{BalanceFanout, SubscriptionError};
+use crate::infrastructure::InfraError;
+
+pub const BALANCE_SOCKET_PATH: &str = "/balances";
+
+/// Resolves which owner a balance socket belongs to from its handshake request, e.g. by
+/// checking a session token in the `Authorization` header. Called inside the handshake,
+/// so it cannot await: look tokens up in something already in memory.
+pub trait BalanceSocketAuthorizer: Send + Sync {
+    /// The authenticated owner, or the reason the handshake is refused.
+    fn owner(&self, req: &Request) -> Result<Uuid, String>;
+}
+
+/// Serves `GET /balances` as a WebSocket that pushes each balance change of the owner
+/// `authorizer` resolves as a JSON text message, until `shutdown` flips to true.
+///
+/// A client that falls too far behind is closed with code 1013 and should re-read its
+/// balances before reconnecting.
+pub async fn serve_balance_socket(
+    listener: TcpListener,
+    fanout: BalanceFanout,
+    authorizer: Arc<dyn BalanceSocketAuthorizer>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<(), InfraError> {
+    while !*shutdown.borrow() {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, _) = accepted?;
+                let fanout = fanout.clone();
+                let authorizer = authorizer.clone();
+                let shutdown = shutdown.clone();
+                tokio::spawn(async move {
+                    // one client's broken connection is its own problem
+                    let _ = handle_connection(stream, fanout, authorizer, shutdown).await;
+                });
+            }
+            _ = shutdown.changed() => {}
+        }
+    }
+    Ok(())
+}
+
+fn owner_from_request(req: &Request, authorizer: &dyn BalanceSocketAuthorizer) -> Result<Uuid, (StatusCode, String)> {
+    if req.uri().path() != BALANCE_SOCKET_PATH {
+        return Err((StatusCode::NOT_FOUND, format!("unknown path {}", req.uri().path())));
+    }
+    authorizer.owner(req).map_err(|message| (StatusCode::UNAUTHORIZED, message))
+}
+
+fn close(code: CloseCode, reason: &str) -> Message {
+    Message::Close(Some(CloseFrame { code, reason: reason.into() }))
+}
+
+// the handshake callback's error type is fixed by tungstenite
+#[allow(clippy::result_large_err)]
+async fn handle_connection(
+    stream: TcpStream,
+    fanout: BalanceFanout,
+    authorizer: Arc<dyn BalanceSocketAuthorizer>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<(), tungstenite::Error> {
+    let mut owner = None;
+    let ws = tokio_tungstenite::accept_hdr_async(stream, |req: &Request, resp: Response| {
+        match owner_from_request(req, authorizer.as_ref()) {
+            Ok(o) => {
+                owner = Some(o);
+                Ok(resp)
+            }
+            Err((status, message)) => {
+                let mut err = ErrorResponse::new(Some(message));
+                *err.status_mut() = status;
+                Err(err)
+            }
+        }
+    })
+    .await?;
+    let Some(owner) = owner else {
+        return Ok(());
+    };
+
+    let mut subscription = fanout.subscribe(Some(owner));
+    let (mut outgoing, mut incoming) = ws.split();
+    loop {
+        tokio::select! {
+            change = subscription.recv() => match change {
+                Ok(change) => {
+                    let Ok(text) = serde_json::to_string(&change) else { continue };
+                    outgoing.send(Message::text(text)).await?;
+                }
+                Err(SubscriptionError::Lagged { .. }) => {
+                    outgoing.send(close(CloseCode::Again, "lagged")).await?;
+                    break;
+                }
+                Err(SubscriptionError::Closed) => {
+                    outgoing.send(close(CloseCode::Away, "closed")).await?;
+                    break;
+                }
+            },
+            message = incoming.next() => match message {
+                // pings are answered by the socket itself; anything else from the client is ignored
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+            _ = shutdown.changed() => {
+                outgoing.send(close(CloseCode::Away, "shutting down")).await?;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,2 @@
+mod simulator;
+pub use simulator::{LocalPayoutSimulator, SimulatedOutcome};
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::contracts::{PayoutInstruction, PayoutRail, PayoutRailError, PayoutStatus};
+
+/// How the simulator answers new submissions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedOutcome {
+    /// Accepted and left pending until settled with `confirm` or `fail`.
+    Pending,
+    /// Accepted and confirmed straight away.
+    Confirm,
+    /// Accepted, then failed with the reason.
+    Fail(String),
+    /// Refused at submission.
+    Reject(String),
+}
+
+#[derive(Debug, Default)]
+struct State {
+    outcome: Option<SimulatedOutcome>,
+    /// withdrawal id -> rail reference
+    references: HashMap<Uuid, String>,
+    statuses: HashMap<String, PayoutStatus>,
+    submissions: usize,
+}
+
+/// In-process payout rail for local runs and tests. Idempotent by withdrawal id like a
+/// real rail: resubmitting returns the first reference and does not pay again.
+#[derive(Debug, Default)]
+pub struct LocalPayoutSimulator {
+    state: Mutex<State>,
+}
+
+impl LocalPayoutSimulator {
+    pub fn new(outcome: SimulatedOutcome) -> Self {
+        Self { state: Mutex::new(State { outcome: Some(outcome), ..Default::default() }) }
+    }
+
+    pub fn set_outcome(&self, outcome: SimulatedOutcome) {
+        self.lock().outcome = Some(outcome);
+    }
+
+    /// Settles a pending payout as paid.
+    pub fn confirm(&self, rail_reference: &str) -> Result<(), PayoutRailError> {
+        self.settle(rail_reference, PayoutStatus::Confirmed)
+    }
+
+    /// Settles a pending payout as failed.
+    pub fn fail(&self, rail_reference: &str, reason: &str) -> Result<(), PayoutRailError> {
+        self.settle(rail_reference, PayoutStatus::Failed { reason: reason.to_string() })
+    }
+
+    /// Distinct payouts accepted so far.
+    pub fn submissions(&self) -> usize {
+        self.lock().submissions
+    }
+
+    fn settle(&self, rail_reference: &str, status: PayoutStatus) -> Result<(), PayoutRailError> {
+        let mut state = self.lock();
+        let current = state
+            .statuses
+            .get_mut(rail_reference)
+            .ok_or_else(|| PayoutRailError::UnknownReference { reference: rail_reference.to_string() })?;
+        if *current == PayoutStatus::Pending {
+            *current = status;
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        // a panic while holding the lock leaves plain maps behind; keep serving them
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl PayoutRail for LocalPayoutSimulator {
+    async fn submit(&self, instruction: &PayoutInstruction) -> Result<String, PayoutRailError> {
+        let mut state = self.lock();
+        let key = instruction.withdrawal_id.value();
+        if let Some(reference) = state.references.get(&key) {
+            return Ok(reference.clone());
+        }
+
+        let status = match state.outcome.clone().unwrap_or(SimulatedOutcome::Pending) {
+            SimulatedOutcome::Reject(reason) => return Err(PayoutRailError::Rejected { reason }),
+            SimulatedOutcome::Pending => PayoutStatus::Pending,
+            SimulatedOutcome::Confirm => PayoutStatus::Confirmed,
+            SimulatedOutcome::Fail(reason) => PayoutStatus::Failed { reason },
+        };
+
+        let reference = format!("sim_{}", Uuid::new_v4().simple());
+        state.references.insert(key, reference.clone());
+        state.statuses.insert(reference.clone(), status);
+        state.submissions += 1;
+        Ok(reference)
+    }
+
+    async fn status(&self, rail_reference: &str) -> Result<PayoutStatus, PayoutRailError> {
+        self.lock()
+            .statuses
+            .get(rail_reference)
+            .cloned()
+            .ok_or_else(|| PayoutRailError::UnknownReference { reference: rail_reference.to_string() })
+    }
+}
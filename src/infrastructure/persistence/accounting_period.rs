@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::AccountingPeriodRepository;
+use crate::domain::entities::{AccountingPeriod, NewAccountingPeriod};
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{AccountingPeriodRow, ClosingBalanceRow, NodeAmountRow};
+
+const PERIOD_COLUMNS: &str = "id, asset_id, name, starts_at, ends_at, status, status_changed_at, status_changed_by";
+
+pub struct PgAccountingPeriodRepository {
+    pool: PgPool,
+}
+
+impl PgAccountingPeriodRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountingPeriodRepository for PgAccountingPeriodRepository {
+    async fn insert(&self, spec: &NewAccountingPeriod) -> Result<AccountingPeriod, RepoError> {
+        let row = sqlx::query_as::<_, AccountingPeriodRow>(&format!(
+            r#"
+            INSERT INTO accounting_periods (asset_id, name, starts_at, ends_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING {PERIOD_COLUMNS}
+            "#
+        ))
+            .bind(spec.asset_id)
+            .bind(&spec.name)
+            .bind(spec.starts_at)
+            .bind(spec.ends_at)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain()
+    }
+
+    async fn find(&self, period_id: i64) -> Result<Option<AccountingPeriod>, RepoError> {
+        let row = sqlx::query_as::<_, AccountingPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM accounting_periods WHERE id = $1"
+        ))
+            .bind(period_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn list(&self, asset_id: i16) -> Result<Vec<AccountingPeriod>, RepoError> {
+        let rows = sqlx::query_as::<_, AccountingPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM accounting_periods WHERE asset_id = $1 ORDER BY starts_at"
+        ))
+            .bind(asset_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(|r| r.to_domain()).collect()
+    }
+
+    async fn lock_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period_id: i64,
+    ) -> Result<Option<AccountingPeriod>, RepoError> {
+        let row = sqlx::query_as::<_, AccountingPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM accounting_periods WHERE id = $1 FOR UPDATE"
+        ))
+            .bind(period_id)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn save_status_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period: &AccountingPeriod,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE accounting_periods
+            SET status = $2, status_changed_at = $3, status_changed_by = $4
+            WHERE id = $1
+            "#,
+        )
+            .bind(period.id())
+            .bind(period.status().as_code())
+            .bind(period.status_changed_at())
+            .bind(period.status_changed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn snapshot_closing_balances_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        period: &AccountingPeriod,
+    ) -> Result<u64, RepoError> {
+        let res = sqlx::query(
+            r#"
+            INSERT INTO period_closing_balances (period_id, account_id, coa_node_id, balance)
+            SELECT $1, a.id, a.coa_node_id, COALESCE(SUM(l.amount) FILTER (WHERE t.effective_at < $3), 0)
+            FROM ledger_accounts a
+            LEFT JOIN journal_lines l ON l.account_id = a.id
+            LEFT JOIN journal_transactions t ON t.id = l.journal_tx_id
+            WHERE a.asset_id = $2
+            GROUP BY a.id, a.coa_node_id
+            "#,
+        )
+            .bind(period.id())
+            .bind(period.asset_id())
+            .bind(period.ends_at())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(res.rows_affected())
+    }
+
+    async fn closing_balances(&self, period_id: i64) -> Result<HashMap<i64, i128>, RepoError> {
+        let rows = sqlx::query_as::<_, ClosingBalanceRow>(
+            r#"
+            SELECT account_id, balance
+            FROM period_closing_balances
+            WHERE period_id = $1
+            "#,
+        )
+            .bind(period_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = HashMap::with_capacity(rows.len());
+        for r in rows {
+            out.insert(r.account_id, bigdecimal_to_i128(&r.balance)?);
+        }
+        Ok(out)
+    }
+
+    async fn closing_balances_by_node(&self, period_id: i64) -> Result<HashMap<i64, i128>, RepoError> {
+        let rows = sqlx::query_as::<_, NodeAmountRow>(
+            r#"
+            SELECT coa_node_id, SUM(balance) AS amount
+            FROM period_closing_balances
+            WHERE period_id = $1
+            GROUP BY coa_node_id
+            "#,
+        )
+            .bind(period_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = HashMap::with_capacity(rows.len());
+        for r in rows {
+            out.insert(r.coa_node_id, bigdecimal_to_i128(&r.amount)?);
+        }
+        Ok(out)
+    }
+}
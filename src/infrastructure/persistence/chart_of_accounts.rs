@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::application::contracts::repository::ChartOfAccountsRepository;
+use crate::domain::entities::ChartOfAccounts;
+use crate::domain::repository::RepoError;
+use crate::domain::services::{BalanceBasis, NodeFlows};
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{CoaNodeRow, NodeAmountRow, NodeFlowsRow};
+
+pub struct PgChartOfAccountsRepository {
+    pool: PgPool,
+}
+
+impl PgChartOfAccountsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn dated_by(basis: BalanceBasis) -> &'static str {
+        match basis {
+            BalanceBasis::Posted => "t.created_at",
+            BalanceBasis::Effective => "t.effective_at",
+        }
+    }
+
+    fn to_map(rows: Vec<NodeAmountRow>) -> Result<HashMap<i64, i128>, RepoError> {
+        let mut out = HashMap::with_capacity(rows.len());
+        for r in rows {
+            out.insert(r.coa_node_id, bigdecimal_to_i128(&r.amount)?);
+        }
+        Ok(out)
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountsRepository for PgChartOfAccountsRepository {
+    async fn load_chart(&self) -> Result<ChartOfAccounts, RepoError> {
+        let rows = sqlx::query_as::<_, CoaNodeRow>(
+            r#"
+            SELECT id, code, name, gl_class, normal_balance, parent_id
+            FROM coa_nodes
+            ORDER BY code
+            "#,
+        )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let nodes = rows.iter().map(|r| r.to_domain()).collect::<Result<Vec<_>, _>>()?;
+        ChartOfAccounts::new(nodes).map_err(|e| RepoError::Integrity { message: e.to_string() })
+    }
+
+    async fn balances_by_node(&self, asset_id: i16) -> Result<HashMap<i64, i128>, RepoError> {
+        let rows = sqlx::query_as::<_, NodeAmountRow>(
+            r#"
+            SELECT a.coa_node_id, SUM(b.balance) AS amount
+            FROM ledger_accounts a
+            JOIN ledger_account_balances b ON b.account_id = a.id
+            WHERE a.asset_id = $1
+            GROUP BY a.coa_node_id
+            "#,
+        )
+            .bind(asset_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::to_map(rows)
+    }
+
+    async fn balances_by_node_as_of(
+        &self,
+        asset_id: i16,
+        at: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, i128>, RepoError> {
+        let sql = format!(
+            r#"
+            SELECT a.coa_node_id, SUM(l.amount) AS amount
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            JOIN ledger_accounts a ON a.id = l.account_id
+            WHERE a.asset_id = $1
+              AND {dated} < $2
+            GROUP BY a.coa_node_id
+            "#,
+            dated = Self::dated_by(basis),
+        );
+        let rows = sqlx::query_as::<_, NodeAmountRow>(&sql)
+            .bind(asset_id)
+            .bind(at)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::to_map(rows)
+    }
+
+    async fn movements_by_node(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, i128>, RepoError> {
+        let sql = format!(
+            r#"
+            SELECT a.coa_node_id, SUM(l.amount) AS amount
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            JOIN ledger_accounts a ON a.id = l.account_id
+            WHERE a.asset_id = $1
+              AND {dated} >= $2
+              AND {dated} < $3
+            GROUP BY a.coa_node_id
+            "#,
+            dated = Self::dated_by(basis),
+        );
+        let rows = sqlx::query_as::<_, NodeAmountRow>(&sql)
+            .bind(asset_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::to_map(rows)
+    }
+
+    async fn flows_by_node(
+        &self,
+        asset_id: i16,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<HashMap<i64, NodeFlows>, RepoError> {
+        let sql = format!(
+            r#"
+            SELECT a.coa_node_id,
+                   COALESCE(SUM(l.amount) FILTER (WHERE l.amount > 0), 0) AS inflow,
+                   COALESCE(SUM(l.amount) FILTER (WHERE l.amount < 0), 0) AS outflow
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            JOIN ledger_accounts a ON a.id = l.account_id
+            WHERE a.asset_id = $1
+              AND {dated} >= $2
+              AND {dated} < $3
+            GROUP BY a.coa_node_id
+            "#,
+            dated = Self::dated_by(basis),
+        );
+        let rows = sqlx::query_as::<_, NodeFlowsRow>(&sql)
+            .bind(asset_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = HashMap::with_capacity(rows.len());
+        for r in rows {
+            out.insert(
+                r.coa_node_id,
+                NodeFlows {
+                    inflow_minor: bigdecimal_to_i128(&r.inflow)?,
+                    outflow_minor: bigdecimal_to_i128(&r.outflow)?,
+                },
+            );
+        }
+        Ok(out)
+    }
+
+    async fn assign_account(&self, account_id: i64, coa_node_id: i64) -> Result<(), RepoError> {
+        let n = sqlx::query(r#"UPDATE ledger_accounts SET coa_node_id = $2 WHERE id = $1"#)
+            .bind(account_id)
+            .bind(coa_node_id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx)?
+            .rows_affected();
+
+        if n == 0 {
+            return Err(RepoError::NotFound {
+                entity: format!("ledger_account id={account_id}"),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::application::contracts::repository::DepositRepository;
+use crate::domain::aggregate::{DepositIntent, NewDepositIntent};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::DepositIntentRow;
+
+const DEPOSIT_COLUMNS: &str = "id, public_id, owner_id, asset_id, amount, tx_reference, required_confirmations, \
+     source, confirmations, status, journal_tx_id, created_by, confirmed_at, credited_at";
+
+pub struct PgDepositRepository {
+    pool: PgPool,
+}
+
+impl PgDepositRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<DepositIntent>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let row = sqlx::query_as::<_, DepositIntentRow>(&format!(
+            "SELECT {DEPOSIT_COLUMNS} FROM deposit_intents WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(DepositIntentRow::to_domain).transpose()
+    }
+}
+
+/// Confirmation counts are stored as INTEGER; anything past it is long past any threshold.
+fn count_to_i32(count: u32) -> i32 {
+    i32::try_from(count).unwrap_or(i32::MAX)
+}
+
+#[async_trait]
+impl DepositRepository for PgDepositRepository {
+    async fn insert(&self, spec: &NewDepositIntent) -> Result<DepositIntent, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO deposit_intents
+                (public_id, owner_id, asset_id, amount, tx_reference, required_confirmations, source,
+                 confirmations, created_by, confirmed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, now())
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+            .bind(spec.public_id.value())
+            .bind(spec.owner_id)
+            .bind(spec.asset_id)
+            .bind(i128_to_bigdecimal(spec.amount_minor))
+            .bind(&spec.tx_reference)
+            .bind(count_to_i32(spec.required_confirmations))
+            .bind(spec.source.as_code())
+            .bind(count_to_i32(spec.confirmations))
+            .bind(&spec.created_by)
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        let by_transfer = sqlx::query_as::<_, DepositIntentRow>(&format!(
+            "SELECT {DEPOSIT_COLUMNS} FROM deposit_intents WHERE asset_id = $1 AND tx_reference = $2"
+        ))
+            .bind(spec.asset_id)
+            .bind(&spec.tx_reference)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+        if let Some(row) = by_transfer {
+            return row.to_domain();
+        }
+
+        // the public id is taken by another transfer; the caller decides what that means
+        Self::load(&mut conn, spec.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("deposit public_id={}", spec.public_id.value()) })
+    }
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<DepositIntent>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<DepositIntent>, RepoError> {
+        Self::load(tx, public_id, true).await
+    }
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, deposit: &DepositIntent) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE deposit_intents
+            SET confirmations = $2,
+                status = $3,
+                journal_tx_id = $4,
+                confirmed_at = $5,
+                credited_at = $6
+            WHERE id = $1
+            "#,
+        )
+            .bind(deposit.id())
+            .bind(count_to_i32(deposit.confirmations()))
+            .bind(deposit.status().as_code())
+            .bind(deposit.journal_id())
+            .bind(deposit.confirmed_at())
+            .bind(deposit.credited_at())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn list_pending(&self, owner_id: Uuid, asset_id: Option<i16>) -> Result<Vec<DepositIntent>, RepoError> {
+        let rows = sqlx::query_as::<_, DepositIntentRow>(&format!(
+            r#"
+            SELECT {DEPOSIT_COLUMNS}
+            FROM deposit_intents
+            WHERE owner_id = $1
+              AND status = 'PENDING'
+              AND ($2::SMALLINT IS NULL OR asset_id = $2)
+            ORDER BY created_at, id
+            "#
+        ))
+            .bind(owner_id)
+            .bind(asset_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(DepositIntentRow::to_domain).collect()
+    }
+}
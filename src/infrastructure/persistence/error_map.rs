@@ -17,7 +17,7 @@ pub fn map_sqlx(e: sqlx::Error) -> RepoError {
             let code = code_owned.as_deref().unwrap_or("");
 
             match code {
-                "23505" => RepoError::Conflict {
+                "23505" | "23P01" => RepoError::Conflict {
                     message: db_err.to_string(),
                 },
                 "23503" | "23514" | "23502" => RepoError::Integrity {
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::application::contracts::repository::FeeScheduleRepository;
+use crate::domain::repository::RepoError;
+use crate::domain::services::FeeSchedule;
+use crate::domain::value_objects::ExternalRefType;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::models::{FeeRuleRow, FeeRuleTierRow};
+
+pub struct PgFeeScheduleRepository {
+    pool: PgPool,
+}
+
+impl PgFeeScheduleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeeScheduleRepository for PgFeeScheduleRepository {
+    async fn load_schedule(
+        &self,
+        asset_id: i16,
+        posting_kind: ExternalRefType,
+    ) -> Result<FeeSchedule, RepoError> {
+        let rules = sqlx::query_as::<_, FeeRuleRow>(
+            r#"
+            SELECT id, asset_id, posting_kind, calculation, flat_amount, rate_bps, min_fee, max_fee, rounding
+            FROM fee_rules
+            WHERE asset_id = $1 AND posting_kind = $2 AND is_active
+            ORDER BY id
+            "#,
+        )
+            .bind(asset_id)
+            .bind(posting_kind.as_code())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        if rules.is_empty() {
+            return Ok(FeeSchedule::default());
+        }
+
+        let rule_ids: Vec<i64> = rules.iter().map(|r| r.id).collect();
+        let tiers = sqlx::query_as::<_, FeeRuleTierRow>(
+            r#"
+            SELECT fee_rule_id, up_to, rate_bps, flat_amount
+            FROM fee_rule_tiers
+            WHERE fee_rule_id = ANY($1)
+            ORDER BY fee_rule_id, position
+            "#,
+        )
+            .bind(&rule_ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = Vec::with_capacity(rules.len());
+        for r in &rules {
+            out.push(r.to_domain(&tiers)?);
+        }
+
+        FeeSchedule::new(out).map_err(|e| RepoError::Integrity { message: e.to_string() })
+    }
+}
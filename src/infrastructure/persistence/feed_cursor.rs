@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::application::contracts::repository::FeedCursorRepository;
+use crate::domain::aggregate::FeedCursor;
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::models::FeedCursorRow;
+
+const CURSOR_COLUMNS: &str = "consumer, position, created_at, updated_at";
+
+pub struct PgFeedCursorRepository {
+    pool: PgPool,
+}
+
+impl PgFeedCursorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeedCursorRepository for PgFeedCursorRepository {
+    async fn insert(&self, cursor: &FeedCursor) -> Result<FeedCursor, RepoError> {
+        let inserted = sqlx::query_as::<_, FeedCursorRow>(&format!(
+            r#"
+            INSERT INTO feed_cursors (consumer, position, created_at, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (consumer) DO NOTHING
+            RETURNING {CURSOR_COLUMNS}
+            "#
+        ))
+            .bind(cursor.consumer())
+            .bind(cursor.position())
+            .bind(cursor.created_at())
+            .bind(cursor.updated_at())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        match inserted {
+            Some(row) => row.to_domain(),
+            None => self.find(cursor.consumer()).await?.ok_or_else(|| RepoError::NotFound {
+                entity: format!("feed_cursor consumer={}", cursor.consumer()),
+            }),
+        }
+    }
+
+    async fn find(&self, consumer: &str) -> Result<Option<FeedCursor>, RepoError> {
+        let row = sqlx::query_as::<_, FeedCursorRow>(&format!(
+            "SELECT {CURSOR_COLUMNS} FROM feed_cursors WHERE consumer = $1"
+        ))
+            .bind(consumer)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn list(&self, limit: usize, offset: usize) -> Result<Vec<FeedCursor>, RepoError> {
+        let rows = sqlx::query_as::<_, FeedCursorRow>(&format!(
+            r#"
+            SELECT {CURSOR_COLUMNS}
+            FROM feed_cursors
+            ORDER BY consumer
+            LIMIT $1 OFFSET $2
+            "#
+        ))
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(FeedCursorRow::to_domain).collect()
+    }
+
+    async fn save_position(&self, cursor: &FeedCursor) -> Result<FeedCursor, RepoError> {
+        let saved = sqlx::query_as::<_, FeedCursorRow>(&format!(
+            r#"
+            UPDATE feed_cursors
+            SET position = $2, updated_at = $3
+            WHERE consumer = $1 AND position <= $2
+            RETURNING {CURSOR_COLUMNS}
+            "#
+        ))
+            .bind(cursor.consumer())
+            .bind(cursor.position())
+            .bind(cursor.updated_at())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        match saved {
+            Some(row) => row.to_domain(),
+            None => match self.find(cursor.consumer()).await? {
+                None => Err(RepoError::NotFound { entity: format!("feed_cursor consumer={}", cursor.consumer()) }),
+                Some(stored) => Err(RepoError::Conflict {
+                    message: format!(
+                        "feed cursor {} is already at {}, past {}",
+                        cursor.consumer(),
+                        stored.position(),
+                        cursor.position()
+                    ),
+                }),
+            },
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::FxRepository;
+use crate::domain::aggregate::{FxQuote, FxRate, NewFxQuote, NewFxRate};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::{i128_to_bigdecimal, rate_to_bigdecimal};
+use crate::infrastructure::persistence::models::{FxQuoteRow, FxRateRow};
+
+const RATE_COLUMNS: &str = "id, base_asset_id, quote_asset_id, rate, valid_from, valid_to, source, created_by";
+
+const QUOTE_COLUMNS: &str = "id, public_id, owner_id, from_asset_id, to_asset_id, rate_id, mid_rate, spread_bps, \
+     applied_rate, from_amount, to_amount, liquidity, expires_at, status, sell_journal_id, buy_journal_id, \
+     created_by, executed_at, executed_by";
+
+pub struct PgFxRepository {
+    pool: PgPool,
+}
+
+impl PgFxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load_quote(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<FxQuote>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let row = sqlx::query_as::<_, FxQuoteRow>(&format!(
+            "SELECT {QUOTE_COLUMNS} FROM fx_quotes WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(FxQuoteRow::to_domain).transpose()
+    }
+}
+
+#[async_trait]
+impl FxRepository for PgFxRepository {
+    async fn insert_rate(&self, spec: &NewFxRate) -> Result<FxRate, RepoError> {
+        let row = sqlx::query_as::<_, FxRateRow>(&format!(
+            r#"
+            INSERT INTO fx_rates (base_asset_id, quote_asset_id, rate, valid_from, valid_to, source, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {RATE_COLUMNS}
+            "#
+        ))
+            .bind(spec.base_asset_id)
+            .bind(spec.quote_asset_id)
+            .bind(rate_to_bigdecimal(spec.rate))
+            .bind(spec.valid_from)
+            .bind(spec.valid_to)
+            .bind(&spec.source)
+            .bind(&spec.created_by)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain()
+    }
+
+    async fn rate_at(
+        &self,
+        base_asset_id: i16,
+        quote_asset_id: i16,
+        at: DateTime<Utc>,
+    ) -> Result<Option<FxRate>, RepoError> {
+        let row = sqlx::query_as::<_, FxRateRow>(&format!(
+            r#"
+            SELECT {RATE_COLUMNS}
+            FROM fx_rates
+            WHERE base_asset_id = $1
+              AND quote_asset_id = $2
+              AND valid_from <= $3
+              AND (valid_to IS NULL OR valid_to > $3)
+            ORDER BY valid_from DESC, id DESC
+            LIMIT 1
+            "#
+        ))
+            .bind(base_asset_id)
+            .bind(quote_asset_id)
+            .bind(at)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(FxRateRow::to_domain).transpose()
+    }
+
+    async fn list_rates(&self, base_asset_id: i16, quote_asset_id: i16, limit: usize) -> Result<Vec<FxRate>, RepoError> {
+        let rows = sqlx::query_as::<_, FxRateRow>(&format!(
+            r#"
+            SELECT {RATE_COLUMNS}
+            FROM fx_rates
+            WHERE base_asset_id = $1 AND quote_asset_id = $2
+            ORDER BY valid_from DESC, id DESC
+            LIMIT $3
+            "#
+        ))
+            .bind(base_asset_id)
+            .bind(quote_asset_id)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(FxRateRow::to_domain).collect()
+    }
+
+    async fn insert_quote(&self, spec: &NewFxQuote) -> Result<FxQuote, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO fx_quotes
+                (public_id, owner_id, from_asset_id, to_asset_id, rate_id, mid_rate, spread_bps, applied_rate,
+                 from_amount, to_amount, liquidity, expires_at, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (public_id) DO NOTHING
+            "#,
+        )
+            .bind(spec.public_id.value())
+            .bind(spec.owner_id)
+            .bind(spec.from_asset_id)
+            .bind(spec.to_asset_id)
+            .bind(spec.rate_id)
+            .bind(rate_to_bigdecimal(spec.mid_rate))
+            .bind(spec.spread_bps as i32)
+            .bind(rate_to_bigdecimal(spec.applied_rate))
+            .bind(i128_to_bigdecimal(spec.from_amount_minor))
+            .bind(i128_to_bigdecimal(spec.to_amount_minor))
+            .bind(spec.liquidity.as_code())
+            .bind(spec.expires_at)
+            .bind(&spec.created_by)
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::load_quote(&mut conn, spec.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("fx_quote public_id={}", spec.public_id.value()) })
+    }
+
+    async fn find_quote(&self, public_id: PublicId) -> Result<Option<FxQuote>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load_quote(&mut conn, public_id, false).await
+    }
+
+    async fn lock_quote_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<FxQuote>, RepoError> {
+        Self::load_quote(tx, public_id, true).await
+    }
+
+    async fn save_quote_tx(&self, tx: &mut Transaction<'_, Postgres>, quote: &FxQuote) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE fx_quotes
+            SET status = $2,
+                sell_journal_id = $3,
+                buy_journal_id = $4,
+                executed_at = $5,
+                executed_by = $6
+            WHERE id = $1
+            "#,
+        )
+            .bind(quote.id())
+            .bind(quote.status().as_code())
+            .bind(quote.sell_journal_id())
+            .bind(quote.buy_journal_id())
+            .bind(quote.executed_at())
+            .bind(quote.executed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+}
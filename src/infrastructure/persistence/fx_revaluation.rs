@@ -0,0 +1,257 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::FxRevaluationRepository;
+use crate::domain::aggregate::{FxRevaluation, NewFxRevaluation, RevaluationPosition, REVALUED_ACCOUNT_TYPES};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::{i128_to_bigdecimal, rate_to_bigdecimal};
+use crate::infrastructure::persistence::models::{FxRevaluationLineRow, FxRevaluationRow, RevaluationPositionRow};
+
+const RUN_COLUMNS: &str =
+    "id, public_id, reporting_asset_id, period_id, valued_at, status, created_by, created_at, reversed_at, reversed_by";
+
+pub struct PgFxRevaluationRepository {
+    pool: PgPool,
+}
+
+impl PgFxRevaluationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn with_lines(conn: &mut PgConnection, row: FxRevaluationRow) -> Result<FxRevaluation, RepoError> {
+        let lines = sqlx::query_as::<_, FxRevaluationLineRow>(
+            r#"
+            SELECT asset_id, balance, opening_rate_id, opening_rate, closing_rate_id, closing_rate,
+                   opening_value, closing_value, gain, journal_id, reversal_journal_id
+            FROM fx_revaluation_lines
+            WHERE revaluation_id = $1
+            ORDER BY asset_id
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain(&lines)
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<FxRevaluation>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let Some(row) = sqlx::query_as::<_, FxRevaluationRow>(&format!(
+            "SELECT {RUN_COLUMNS} FROM fx_revaluations WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::with_lines(conn, row).await?))
+    }
+}
+
+#[async_trait]
+impl FxRevaluationRepository for PgFxRevaluationRepository {
+    async fn positions_at(&self, reporting_asset_id: i16, at: DateTime<Utc>) -> Result<Vec<RevaluationPosition>, RepoError> {
+        let account_types: Vec<&str> = REVALUED_ACCOUNT_TYPES.iter().map(|t| t.as_str()).collect();
+        let rows = sqlx::query_as::<_, RevaluationPositionRow>(
+            r#"
+            SELECT l.asset_id, a.decimals, SUM(l.amount) AS balance
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            JOIN ledger_accounts la ON la.id = l.account_id
+            JOIN assets a ON a.id = l.asset_id
+            WHERE la.account_type = ANY($2::text[])
+              AND l.asset_id <> $1
+              AND t.effective_at < $3
+            GROUP BY l.asset_id, a.decimals
+            HAVING SUM(l.amount) <> 0
+            ORDER BY l.asset_id
+            "#,
+        )
+            .bind(reporting_asset_id)
+            .bind(&account_types)
+            .bind(at)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(RevaluationPositionRow::to_domain).collect()
+    }
+
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewFxRevaluation,
+    ) -> Result<Option<FxRevaluation>, RepoError> {
+        let Some(row) = sqlx::query_as::<_, FxRevaluationRow>(&format!(
+            r#"
+            INSERT INTO fx_revaluations (public_id, reporting_asset_id, period_id, valued_at, created_by)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (period_id) WHERE status = 'POSTED' DO NOTHING
+            RETURNING {RUN_COLUMNS}
+            "#
+        ))
+            .bind(spec.public_id.value())
+            .bind(spec.reporting_asset_id)
+            .bind(spec.period_id)
+            .bind(spec.valued_at)
+            .bind(&spec.created_by)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        let n = spec.lines.len();
+        let mut asset_ids = Vec::with_capacity(n);
+        let mut balances: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut opening_rate_ids = Vec::with_capacity(n);
+        let mut opening_rates: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut closing_rate_ids = Vec::with_capacity(n);
+        let mut closing_rates: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut opening_values: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut closing_values: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut gains: Vec<BigDecimal> = Vec::with_capacity(n);
+        let mut journal_ids: Vec<Option<i64>> = Vec::with_capacity(n);
+        for l in &spec.lines {
+            asset_ids.push(l.asset_id);
+            balances.push(i128_to_bigdecimal(l.balance_minor));
+            opening_rate_ids.push(l.opening_rate_id);
+            opening_rates.push(rate_to_bigdecimal(l.opening_rate));
+            closing_rate_ids.push(l.closing_rate_id);
+            closing_rates.push(rate_to_bigdecimal(l.closing_rate));
+            opening_values.push(i128_to_bigdecimal(l.opening_value_minor));
+            closing_values.push(i128_to_bigdecimal(l.closing_value_minor));
+            gains.push(i128_to_bigdecimal(l.gain_minor));
+            journal_ids.push(l.journal_id);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO fx_revaluation_lines
+                (revaluation_id, asset_id, balance, opening_rate_id, opening_rate, closing_rate_id, closing_rate,
+                 opening_value, closing_value, gain, journal_id)
+            SELECT $1, x.asset_id, x.balance, x.opening_rate_id, x.opening_rate, x.closing_rate_id, x.closing_rate,
+                   x.opening_value, x.closing_value, x.gain, x.journal_id
+            FROM UNNEST($2::smallint[], $3::numeric[], $4::bigint[], $5::numeric[], $6::bigint[], $7::numeric[],
+                        $8::numeric[], $9::numeric[], $10::numeric[], $11::bigint[])
+                AS x(asset_id, balance, opening_rate_id, opening_rate, closing_rate_id, closing_rate,
+                     opening_value, closing_value, gain, journal_id)
+            "#,
+        )
+            .bind(row.id)
+            .bind(&asset_ids)
+            .bind(&balances)
+            .bind(&opening_rate_ids)
+            .bind(&opening_rates)
+            .bind(&closing_rate_ids)
+            .bind(&closing_rates)
+            .bind(&opening_values)
+            .bind(&closing_values)
+            .bind(&gains)
+            .bind(&journal_ids)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(Some(Self::with_lines(tx, row).await?))
+    }
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<FxRevaluation>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn find_posted_for_period(&self, period_id: i64) -> Result<Option<FxRevaluation>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        let Some(row) = sqlx::query_as::<_, FxRevaluationRow>(&format!(
+            "SELECT {RUN_COLUMNS} FROM fx_revaluations WHERE period_id = $1 AND status = 'POSTED'"
+        ))
+            .bind(period_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::with_lines(&mut conn, row).await?))
+    }
+
+    async fn list_for_period(&self, period_id: i64) -> Result<Vec<FxRevaluation>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        let rows = sqlx::query_as::<_, FxRevaluationRow>(&format!(
+            "SELECT {RUN_COLUMNS} FROM fx_revaluations WHERE period_id = $1 ORDER BY created_at DESC, id DESC"
+        ))
+            .bind(period_id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut runs = Vec::with_capacity(rows.len());
+        for row in rows {
+            runs.push(Self::with_lines(&mut conn, row).await?);
+        }
+        Ok(runs)
+    }
+
+    async fn lock_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<FxRevaluation>, RepoError> {
+        Self::load(tx, public_id, true).await
+    }
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, run: &FxRevaluation) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE fx_revaluations
+            SET status = $2,
+                reversed_at = $3,
+                reversed_by = $4
+            WHERE id = $1
+            "#,
+        )
+            .bind(run.id())
+            .bind(run.status().as_code())
+            .bind(run.reversed_at())
+            .bind(run.reversed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let (asset_ids, reversal_ids): (Vec<i16>, Vec<Option<i64>>) =
+            run.lines().iter().map(|l| (l.asset_id, l.reversal_journal_id)).unzip();
+        sqlx::query(
+            r#"
+            UPDATE fx_revaluation_lines l
+            SET reversal_journal_id = x.reversal_journal_id
+            FROM UNNEST($2::smallint[], $3::bigint[]) AS x(asset_id, reversal_journal_id)
+            WHERE l.revaluation_id = $1 AND l.asset_id = x.asset_id
+            "#,
+        )
+            .bind(run.id())
+            .bind(&asset_ids)
+            .bind(&reversal_ids)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+}
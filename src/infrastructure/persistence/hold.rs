@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::application::contracts::repository::HoldRepository;
+use crate::domain::aggregate::{Hold, HoldJournal, NewHold};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{HoldJournalRow, HoldRow};
+
+const HOLD_COLUMNS: &str = "id, public_id, owner_id, asset_id, available_account_id, locked_account_id, amount, \
+     captured_amount, released_amount, status, expires_at, description, created_by, journal_count, \
+     status_changed_at, status_changed_by";
+
+pub struct PgHoldRepository {
+    pool: PgPool,
+}
+
+impl PgHoldRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<Hold>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let row = sqlx::query_as::<_, HoldRow>(&format!(
+            "SELECT {HOLD_COLUMNS} FROM holds WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(HoldRow::to_domain).transpose()
+    }
+}
+
+fn to_i32(v: u32, name: &str) -> Result<i32, RepoError> {
+    i32::try_from(v).map_err(|_| RepoError::Integrity { message: format!("{name} out of range: {v}") })
+}
+
+#[async_trait]
+impl HoldRepository for PgHoldRepository {
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewHold,
+    ) -> Result<Option<Hold>, RepoError> {
+        let row = sqlx::query_as::<_, HoldRow>(&format!(
+            r#"
+            INSERT INTO holds
+                (public_id, owner_id, asset_id, available_account_id, locked_account_id, amount,
+                 expires_at, description, created_by, journal_count)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 1)
+            ON CONFLICT (public_id) DO NOTHING
+            RETURNING {HOLD_COLUMNS}
+            "#
+        ))
+            .bind(spec.public_id.value())
+            .bind(spec.owner_id)
+            .bind(spec.asset_id)
+            .bind(spec.available_account_id)
+            .bind(spec.locked_account_id)
+            .bind(i128_to_bigdecimal(spec.amount_minor))
+            .bind(spec.expires_at)
+            .bind(&spec.description)
+            .bind(&spec.created_by)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(HoldRow::to_domain).transpose()
+    }
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<Hold>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<Hold>, RepoError> {
+        Self::load(tx, public_id, true).await
+    }
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, hold: &Hold) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE holds
+            SET captured_amount = $2,
+                released_amount = $3,
+                status = $4,
+                journal_count = $5,
+                status_changed_at = $6,
+                status_changed_by = $7
+            WHERE id = $1
+            "#,
+        )
+            .bind(hold.id())
+            .bind(i128_to_bigdecimal(hold.captured_minor()))
+            .bind(i128_to_bigdecimal(hold.released_minor()))
+            .bind(hold.status().as_code())
+            .bind(to_i32(hold.journal_count(), "journal_count")?)
+            .bind(hold.status_changed_at())
+            .bind(hold.status_changed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn record_journal_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        hold: &Hold,
+        journal: &HoldJournal,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            INSERT INTO hold_journals
+                (hold_id, seq, action, amount, journal_tx_id, counterparty_account_id, actor, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+            .bind(hold.id())
+            .bind(to_i32(journal.seq, "seq")?)
+            .bind(journal.action.as_code())
+            .bind(i128_to_bigdecimal(journal.amount_minor))
+            .bind(journal.journal_id)
+            .bind(journal.counterparty_account_id)
+            .bind(&journal.actor)
+            .bind(journal.created_at)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn list_active(&self, owner_id: Uuid, asset_id: Option<i16>) -> Result<Vec<Hold>, RepoError> {
+        let rows = sqlx::query_as::<_, HoldRow>(&format!(
+            r#"
+            SELECT {HOLD_COLUMNS}
+            FROM holds
+            WHERE owner_id = $1 AND status = 'ACTIVE'
+              AND ($2::smallint IS NULL OR asset_id = $2)
+            ORDER BY created_at, id
+            "#
+        ))
+            .bind(owner_id)
+            .bind(asset_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(HoldRow::to_domain).collect()
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<PublicId>, RepoError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT public_id
+            FROM holds
+            WHERE status = 'ACTIVE' AND expires_at <= $1
+            ORDER BY expires_at, id
+            LIMIT $2
+            "#,
+        )
+            .bind(now)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(ids.into_iter().map(PublicId::new).collect())
+    }
+
+    async fn journals(&self, public_id: PublicId) -> Result<Vec<HoldJournal>, RepoError> {
+        let rows = sqlx::query_as::<_, HoldJournalRow>(
+            r#"
+            SELECT j.seq, j.action, j.amount, j.journal_tx_id, j.counterparty_account_id, j.actor, j.created_at
+            FROM hold_journals j
+            JOIN holds h ON h.id = j.hold_id
+            WHERE h.public_id = $1
+            ORDER BY j.seq
+            "#,
+        )
+            .bind(public_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(HoldJournalRow::to_domain).collect()
+    }
+}
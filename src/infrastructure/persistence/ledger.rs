@@ -1,22 +1,35 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
-use sqlx::{PgPool, Postgres, Transaction};
-
-use crate::domain::aggregate::{PostedJournal, ValidatedJournal};
-use crate::domain::entities::{AccountType, LedgerAccount};
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{Acquire, PgPool, Postgres, Transaction};
+
+use crate::domain::aggregate::{JournalLine, PostedJournal, ValidatedJournal};
+use crate::domain::entities::{
+    AccountingPeriod, AccountStatusChange, AccountStatusTimeline, AccountType, LedgerAccount, OwnerType, PostingDirection,
+    StatusReasonCode,
+};
+use crate::domain::error::DomainError;
 use crate::domain::repository::{LedgerRepository, NewLedgerAccountSpec, RepoError};
-use crate::domain::value_objects::{ExternalRef, ExternalRefType};
+use crate::domain::services::{journal_hash, BalanceBasis, ChainHead, ChainLink};
+use crate::domain::value_objects::{Asset, AssetCode, ExternalRef, ExternalRefType};
 
 use crate::infrastructure::persistence::error_map::map_sqlx;
-use crate::infrastructure::persistence::mappers::{i128_to_bigdecimal, map_posted_journal};
-use crate::infrastructure::persistence::models::{JournalLineRow, JournalTxRow, LedgerAccountRow};
-use crate::application::contracts::repository::LedgerRepositoryTx;
+use crate::infrastructure::persistence::mappers::{bigdecimal_to_i128, i128_to_bigdecimal, map_posted_journal};
+use crate::infrastructure::persistence::models::{
+    AccountingPeriodRow, AccountStatusHistoryRow, AssetRow, ChainHeadRow, ChainLinkRow, JournalLineRow, JournalTxRow,
+    LedgerAccountRow,
+};
+use crate::application::contracts::{BalanceChange, BALANCE_CHANGED_CHANNEL};
+use crate::application::contracts::repository::{
+    AccountStatusRepository, JournalChainRepository, JournalFilter, JournalQueryRepository, LedgerRepositoryTx,
+};
 
 //type BoxFut<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
 pub struct PgLedgerRepository {
-    pool: PgPool,
+    pub(super) pool: PgPool,
 }
 
 impl PgLedgerRepository {
@@ -43,14 +56,41 @@ impl PgLedgerRepository {
         }
     }*/
 
-    fn is_spendable_bucket(t: AccountType) -> bool {
+    pub(super) fn is_spendable_bucket(t: AccountType) -> bool {
         matches!(
             t,
             AccountType::UserAvailable | AccountType::TreasuryAvailable | AccountType::InventoryAvailable
         )
     }
 
-    async fn fetch_accounts_for_update(
+    async fn insert_status_history(
+        tx: &mut Transaction<'_, Postgres>,
+        change: &AccountStatusChange,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            INSERT INTO account_status_history
+                (account_id, from_status, to_status, was_active, is_active, reason, note, actor, changed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+            .bind(change.account_id)
+            .bind(change.from.as_code())
+            .bind(change.to.as_code())
+            .bind(change.was_active)
+            .bind(change.is_active)
+            .bind(change.reason.as_code())
+            .bind(&change.note)
+            .bind(&change.actor)
+            .bind(change.at)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    pub(super) async fn fetch_accounts_for_update(
         tx: &mut Transaction<'_, Postgres>,
         account_ids: &[i64],
     ) -> Result<Vec<LedgerAccountRow>, RepoError> {
@@ -60,7 +100,7 @@ impl PgLedgerRepository {
 
         let rows = sqlx::query_as::<_, LedgerAccountRow>(
             r#"
-            SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active
+            SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active, status
             FROM ledger_accounts
             WHERE id = ANY($1)
             ORDER BY id
@@ -75,6 +115,78 @@ impl PgLedgerRepository {
         Ok(rows)
     }
 
+    /// Locks the accounts in id order and requires every one of them to exist and be active.
+    pub(super) async fn lock_active_accounts_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        account_ids: &[i64],
+    ) -> Result<HashMap<i64, LedgerAccount>, RepoError> {
+        let locked_rows = Self::fetch_accounts_for_update(tx, account_ids).await?;
+        if locked_rows.len() != account_ids.len() {
+            return Err(RepoError::NotFound {
+                entity: "one or more ledger accounts missing".into(),
+            });
+        }
+
+        let mut locked = HashMap::with_capacity(locked_rows.len());
+        for r in locked_rows {
+            let acct = r.to_domain()?;
+            if !acct.is_active() {
+                return Err(RepoError::Integrity {
+                    message: format!("ledger account is inactive (account_id={})", acct.id()),
+                });
+            }
+            locked.insert(acct.id(), acct);
+        }
+        Ok(locked)
+    }
+
+    /// Checks net deltas against the locked accounts: each account's status must permit
+    /// the direction of its delta, and an outflow from a spendable bucket must leave at
+    /// least what pending journals still reserve on it.
+    pub(super) fn check_deltas(
+        locked: &HashMap<i64, LedgerAccount>,
+        current: &HashMap<i64, i128>,
+        reserved: &HashMap<i64, i128>,
+        delta: &HashMap<i64, i128>,
+    ) -> Result<(), RepoError> {
+        for (account_id, d) in delta {
+            let acct = locked.get(account_id).ok_or_else(|| RepoError::NotFound {
+                entity: format!("ledger_account id={account_id}"),
+            })?;
+
+            // Re-check lifecycle status under lock; net-zero legs do not move funds.
+            if *d != 0 {
+                let direction = if *d < 0 { PostingDirection::Outflow } else { PostingDirection::Inflow };
+                if !acct.status().permits(direction) {
+                    return Err(RepoError::Conflict {
+                        message: format!(
+                            "account status {} blocks {} postings (account_id={account_id})",
+                            acct.status().as_code(),
+                            direction.as_str(),
+                        ),
+                    });
+                }
+            }
+
+            if Self::is_spendable_bucket(acct.account_type()) {
+                let cur = *current.get(account_id).unwrap_or(&0);
+                let held = *reserved.get(account_id).unwrap_or(&0);
+                let next = cur.checked_add(*d).ok_or_else(|| RepoError::Integrity {
+                    message: format!("balance overflow (account_id={account_id})"),
+                })?;
+
+                if next < 0 || (*d < 0 && next < held) {
+                    return Err(RepoError::Conflict {
+                        message: format!(
+                            "insufficient funds (account_id={account_id}, current={cur}, reserved={held}, delta={d})"
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     async fn insert_or_get_tx_id(
         tx: &mut Transaction<'_, Postgres>,
         posting: &ValidatedJournal,
@@ -82,8 +194,9 @@ impl PgLedgerRepository {
         let inserted = sqlx::query_scalar::<_, i64>(
             r#"
             INSERT INTO journal_transactions
-                (public_id, external_ref, external_ref_type, description, created_by)
-            VALUES ($1, $2, $3, $4, $5)
+                (public_id, external_ref, external_ref_type, description, created_by, fee_rule_ids, metadata, tags,
+                 effective_at, multi_asset)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, COALESCE($9, now()), $10)
             ON CONFLICT (external_ref_type, external_ref) DO NOTHING
             RETURNING id
             "#,
@@ -93,6 +206,11 @@ impl PgLedgerRepository {
             .bind(posting.external_ref_type.as_code())
             .bind(&posting.description)
             .bind(&posting.created_by)
+            .bind(&posting.fee_rule_ids)
+            .bind(Json(posting.metadata.as_map()))
+            .bind(posting.tags.iter().map(|t| t.as_str()).collect::<Vec<_>>())
+            .bind(posting.effective_at)
+            .bind(posting.multi_asset)
             .fetch_optional(&mut **tx)
             .await
             .map_err(map_sqlx)?;
@@ -117,6 +235,32 @@ impl PgLedgerRepository {
         Ok(existing)
     }
 
+    async fn period_for_journal_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        tx_id: i64,
+        asset_id: i16,
+    ) -> Result<Option<AccountingPeriod>, RepoError> {
+        let row = sqlx::query_as::<_, AccountingPeriodRow>(
+            r#"
+            SELECT p.id, p.asset_id, p.name, p.starts_at, p.ends_at, p.status,
+                   p.status_changed_at, p.status_changed_by
+            FROM accounting_periods p
+            JOIN journal_transactions t ON t.id = $1
+            WHERE p.asset_id = $2
+              AND p.starts_at <= t.effective_at
+              AND t.effective_at < p.ends_at
+            FOR SHARE OF p
+            "#,
+        )
+            .bind(tx_id)
+            .bind(asset_id)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
     async fn tx_has_lines(tx: &mut Transaction<'_, Postgres>, tx_id: i64) -> Result<bool, RepoError> {
         let has = sqlx::query_scalar::<_, bool>(
             r#"SELECT EXISTS (SELECT 1 FROM journal_lines WHERE journal_tx_id = $1)"#,
@@ -135,7 +279,8 @@ impl PgLedgerRepository {
     ) -> Result<PostedJournal, RepoError> {
         let header = sqlx::query_as::<_, JournalTxRow>(
             r#"
-            SELECT id, public_id, external_ref_type, external_ref, description, created_by
+            SELECT id, public_id, external_ref_type, external_ref, description, created_by, fee_rule_ids,
+                   metadata, tags, effective_at, created_at, multi_asset, ledger_seq
             FROM journal_transactions
             WHERE id = $1
             "#,
@@ -147,7 +292,7 @@ impl PgLedgerRepository {
 
         let lines = sqlx::query_as::<_, JournalLineRow>(
             r#"
-            SELECT account_id, amount
+            SELECT account_id, asset_id, amount, memo
             FROM journal_lines
             WHERE journal_tx_id = $1
             ORDER BY id ASC
@@ -158,23 +303,105 @@ impl PgLedgerRepository {
             .await
             .map_err(map_sqlx)?;
 
-        let asset_id = if let Some(first) = lines.first() {
-            sqlx::query_scalar::<_, i16>("SELECT asset_id FROM ledger_accounts WHERE id = $1")
-                .bind(first.account_id)
-                .fetch_one(&mut **tx)
-                .await
-                .map_err(map_sqlx)?
-        } else {
-            0
+        map_posted_journal(header, lines)
+    }
+
+    /// Locks the ledger sequence and the journal chain head, in that order. Every posting
+    /// takes them before any account or balance row, so a transaction posting several
+    /// journals (an FX trade, a revaluation run) cannot hold them while waiting for
+    /// accounts that a concurrent posting has locked on its way to the same rows.
+    ///
+    /// Refuses to post while journals from before the chain are still unlinked: they have
+    /// to be chained first for chain order to match posting order.
+    async fn lock_ledger_heads_tx(tx: &mut Transaction<'_, Postgres>) -> Result<(), RepoError> {
+        sqlx::query("SELECT 1 FROM ledger_sequence WHERE id FOR UPDATE")
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+        let backfilled = sqlx::query_scalar::<_, bool>("SELECT backfilled FROM journal_chain_head WHERE id FOR UPDATE")
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+        if !backfilled {
+            return Err(RepoError::Conflict {
+                message: "journal chain backfill has not finished; postings resume once every earlier journal is linked"
+                    .into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Takes the next ledger sequence number for the journal. The counter row stays locked
+    /// until commit, so numbers are handed out in commit order and a rollback returns its
+    /// number to the next posting.
+    async fn assign_ledger_seq_tx(tx: &mut Transaction<'_, Postgres>, tx_id: i64) -> Result<i64, RepoError> {
+        let seq = sqlx::query_scalar::<_, i64>(
+            "UPDATE ledger_sequence SET last_seq = last_seq + 1 WHERE id RETURNING last_seq",
+        )
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        sqlx::query("UPDATE journal_transactions SET ledger_seq = $1 WHERE id = $2")
+            .bind(seq)
+            .bind(tx_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(seq)
+    }
+
+    /// Links `journal` after the chain head. Postings lock the head up front and hold it
+    /// until commit, so links follow commit order.
+    async fn append_chain_link_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        journal: &PostedJournal,
+    ) -> Result<ChainLink, RepoError> {
+        let head = sqlx::query_as::<_, ChainHeadRow>(
+            "SELECT position, entry_hash FROM journal_chain_head WHERE id FOR UPDATE",
+        )
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(map_sqlx)?
+            .to_domain()?;
+
+        let link = ChainLink {
+            position: head.position + 1,
+            journal_id: journal.db_id,
+            prev_hash: head.entry_hash,
+            entry_hash: journal_hash(&head.entry_hash, journal),
         };
 
-        map_posted_journal(header, lines, asset_id)
+        sqlx::query(
+            r#"
+            INSERT INTO journal_chain (position, journal_tx_id, prev_hash, entry_hash)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+            .bind(link.position)
+            .bind(link.journal_id)
+            .bind(link.prev_hash.as_slice())
+            .bind(link.entry_hash.as_slice())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        sqlx::query("UPDATE journal_chain_head SET position = $1, entry_hash = $2 WHERE id")
+            .bind(link.position)
+            .bind(link.entry_hash.as_slice())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(link)
     }
 
     async fn load_posted_by_tx_id(pool: &PgPool, tx_id: i64) -> Result<PostedJournal, RepoError> {
         let header = sqlx::query_as::<_, JournalTxRow>(
             r#"
-            SELECT id, public_id, external_ref_type, external_ref, description, created_by
+            SELECT id, public_id, external_ref_type, external_ref, description, created_by, fee_rule_ids,
+                   metadata, tags, effective_at, created_at, multi_asset, ledger_seq
             FROM journal_transactions
             WHERE id = $1
             "#,
@@ -186,7 +413,7 @@ impl PgLedgerRepository {
 
         let lines = sqlx::query_as::<_, JournalLineRow>(
             r#"
-            SELECT account_id, amount
+            SELECT account_id, asset_id, amount, memo
             FROM journal_lines
             WHERE journal_tx_id = $1
             ORDER BY id ASC
@@ -197,36 +424,30 @@ impl PgLedgerRepository {
             .await
             .map_err(map_sqlx)?;
 
-        let asset_id = if let Some(first) = lines.first() {
-            sqlx::query_scalar::<_, i16>("SELECT asset_id FROM ledger_accounts WHERE id = $1")
-                .bind(first.account_id)
-                .fetch_one(pool)
-                .await
-                .map_err(map_sqlx)?
-        } else {
-            0
-        };
-
-        map_posted_journal(header, lines, asset_id)
+        map_posted_journal(header, lines)
     }
 
     async fn bulk_insert_lines(
         tx: &mut Transaction<'_, Postgres>,
         tx_id: i64,
         account_ids: &[i64],
+        asset_ids: &[i16],
         amounts: &[BigDecimal],
+        memos: &[Option<String>],
     ) -> Result<u64, RepoError> {
         let res = sqlx::query(
             r#"
-        INSERT INTO journal_lines (journal_tx_id, account_id, amount)
-        SELECT $1, x.account_id, x.amount
-        FROM UNNEST($2::bigint[], $3::numeric[]) AS x(account_id, amount)
+        INSERT INTO journal_lines (journal_tx_id, account_id, asset_id, amount, memo)
+        SELECT $1, x.account_id, x.asset_id, x.amount, x.memo
+        FROM UNNEST($2::bigint[], $3::smallint[], $4::numeric[], $5::text[]) AS x(account_id, asset_id, amount, memo)
         ON CONFLICT (journal_tx_id, account_id) DO NOTHING
         "#,
         )
             .bind(tx_id)
             .bind(account_ids)
+            .bind(asset_ids)
             .bind(amounts)
+            .bind(memos)
             .execute(&mut **tx)
             .await
             .map_err(map_sqlx)?;
@@ -235,7 +456,7 @@ impl PgLedgerRepository {
     }
 
 
-    fn numeric0_to_i128_strict(v: &BigDecimal, account_id: i64) -> Result<i128, RepoError> {
+    pub(super) fn numeric0_to_i128_strict(v: &BigDecimal, account_id: i64) -> Result<i128, RepoError> {
         let s = v.to_string();
         if s.contains('.') {
             return Err(RepoError::Integrity {
@@ -247,7 +468,7 @@ impl PgLedgerRepository {
         })
     }
 
-    async fn lock_and_fetch_balances(
+    pub(super) async fn lock_and_fetch_balances(
         tx: &mut Transaction<'_, Postgres>,
         account_ids: &[i64],
     ) -> Result<HashMap<i64, i128>, RepoError> {
@@ -290,7 +511,35 @@ impl PgLedgerRepository {
         Ok(out)
     }
 
-    async fn ensure_single_asset(
+    /// Amounts pending journals reserve on the accounts; callers hold the balance row locks.
+    pub(super) async fn fetch_pending_outflows_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        account_ids: &[i64],
+    ) -> Result<HashMap<i64, i128>, RepoError> {
+        #[derive(sqlx::FromRow)]
+        struct ReservedRow {
+            account_id: i64,
+            pending_outflow: BigDecimal,
+        }
+
+        let rows = sqlx::query_as::<_, ReservedRow>(
+            r#"
+            SELECT account_id, pending_outflow
+            FROM ledger_account_balances
+            WHERE account_id = ANY($1)
+            "#,
+        )
+            .bind(account_ids)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter()
+            .map(|r| Ok((r.account_id, Self::numeric0_to_i128_strict(&r.pending_outflow, r.account_id)?)))
+            .collect()
+    }
+
+    pub(super) async fn ensure_single_asset(
         tx: &mut Transaction<'_, Postgres>,
         account_ids: &[i64],
     ) -> Result<i16, RepoError> {
@@ -334,8 +583,54 @@ impl PgLedgerRepository {
 
         Ok(asset_id)
     }
+
+    /// Checks a multi-asset posting against the accounts' stored assets: each asset's
+    /// lines must net to zero. Returns the assets touched, ascending.
+    async fn ensure_balanced_per_asset(
+        tx: &mut Transaction<'_, Postgres>,
+        lines: &[JournalLine],
+    ) -> Result<Vec<i16>, RepoError> {
+        let account_ids: Vec<i64> = lines.iter().map(|l| l.account_id).collect();
+        let assets: HashMap<i64, i16> = sqlx::query_as::<_, (i64, i16)>(
+            r#"
+            SELECT id, asset_id
+            FROM ledger_accounts
+            WHERE id = ANY($1)
+            "#,
+        )
+            .bind(&account_ids)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(map_sqlx)?
+            .into_iter()
+            .collect();
+
+        let mut net: BTreeMap<i16, i128> = BTreeMap::new();
+        for l in lines {
+            let asset_id = *assets.get(&l.account_id).ok_or_else(|| RepoError::NotFound {
+                entity: format!("ledger_account id={}", l.account_id),
+            })?;
+            if asset_id != l.asset_id {
+                return Err(RepoError::Integrity {
+                    message: format!("journal line asset does not match its account (account_id={})", l.account_id),
+                });
+            }
+            *net.entry(asset_id).or_insert(0) += l.amount.minor();
+        }
+
+        if let Some((asset_id, _)) = net.iter().find(|(_, n)| **n != 0) {
+            return Err(RepoError::Integrity {
+                message: format!("multi-asset posting does not balance in asset_id={asset_id}"),
+            });
+        }
+        Ok(net.into_keys().collect())
+    }
+    /// Applies the posting's deltas to the running balances and publishes each account's
+    /// new balance on the balance channel. Postgres holds notifications until commit, so
+    /// listeners never see a balance from a posting that rolled back.
     async fn apply_balance_deltas(
         tx: &mut Transaction<'_, Postgres>,
+        journal_id: i64,
         delta: &HashMap<i64, i128>,
     ) -> Result<(), RepoError> {
         if delta.is_empty() {
@@ -350,23 +645,25 @@ impl PgLedgerRepository {
             deltas.push(i128_to_bigdecimal(*d));
         }
 
-        let res = sqlx::query(
+        let updated = sqlx::query_as::<_, (i64, String, Option<uuid::Uuid>, i16, BigDecimal)>(
             r#"
         UPDATE ledger_account_balances b
         SET balance = b.balance + x.delta,
             updated_at = now()
-        FROM UNNEST($1::bigint[], $2::numeric[]) AS x(account_id, delta)
+        FROM UNNEST($1::bigint[], $2::numeric[]) AS x(account_id, delta), ledger_accounts a
         WHERE b.account_id = x.account_id
+          AND a.id = b.account_id
+        RETURNING b.account_id, a.owner_type, a.owner_id, a.asset_id, b.balance
         "#,
         )
             .bind(&ids)
             .bind(&deltas)
-            .execute(&mut **tx)
+            .fetch_all(&mut **tx)
             .await
             .map_err(map_sqlx)?;
 
-        let expected = ids.len() as u64;
-        let actual = res.rows_affected();
+        let expected = ids.len();
+        let actual = updated.len();
 
         if actual != expected {
             return Err(RepoError::Integrity {
@@ -377,18 +674,45 @@ impl PgLedgerRepository {
             });
         }
 
+        let mut payloads: Vec<String> = Vec::with_capacity(updated.len());
+        for (account_id, owner_type, owner_id, asset_id, balance) in updated {
+            let change = BalanceChange {
+                account_id,
+                owner_type,
+                owner_id: owner_id.map(|id| id.to_string()),
+                asset_id,
+                balance_minor: bigdecimal_to_i128(&balance)?,
+                journal_id,
+            };
+            payloads.push(serde_json::to_string(&change).map_err(|e| RepoError::Unexpected {
+                message: format!("balance notification for account_id={account_id}: {e}"),
+            })?);
+        }
+
+        sqlx::query("SELECT pg_notify($1, p) FROM UNNEST($2::text[]) AS p")
+            .bind(BALANCE_CHANGED_CHANNEL)
+            .bind(&payloads)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
         Ok(())
     }
 }
     #[async_trait]
 impl LedgerRepository for PgLedgerRepository {
     async fn create_account(&self, spec: NewLedgerAccountSpec) -> Result<LedgerAccount, RepoError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+
         let row = sqlx::query_as::<_, LedgerAccountRow>(
             r#"
             INSERT INTO ledger_accounts
-                (public_id, owner_type, owner_id, account_type, asset_id, is_active)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, public_id, owner_type, owner_id, account_type, asset_id, is_active
+                (public_id, owner_type, owner_id, account_type, asset_id, is_active, coa_node_id)
+            VALUES (
+                $1, $2, $3, $4, $5, $6,
+                COALESCE($7, (SELECT coa_node_id FROM coa_account_type_defaults WHERE account_type = $4))
+            )
+            RETURNING id, public_id, owner_type, owner_id, account_type, asset_id, is_active, status
             "#,
         )
             .bind(spec.public_id.value())
@@ -402,14 +726,18 @@ impl LedgerRepository for PgLedgerRepository {
                 AccountType::UserAvailable => "USER_AVAILABLE",
                 AccountType::UserLocked => "USER_LOCKED",
                 AccountType::PlatformClearing => "PLATFORM_CLEARING",
+                AccountType::PlatformRevenue => "PLATFORM_REVENUE",
                 AccountType::TreasuryAvailable => "TREASURY_AVAILABLE",
                 AccountType::TreasuryLocked => "TREASURY_LOCKED",
                 AccountType::InventoryAvailable => "INVENTORY_AVAILABLE",
                 AccountType::InventoryLocked => "INVENTORY_LOCKED",
+                AccountType::TreasuryRevaluation => "TREASURY_REVALUATION",
+                AccountType::PlatformUnrealisedFx => "PLATFORM_UNREALISED_FX",
             })
             .bind(spec.asset_id)
             .bind(spec.is_active)
-            .fetch_one(&self.pool)
+            .bind(spec.coa_node_id)
+            .fetch_one(&mut *tx)
             .await
             .map_err(map_sqlx)?;
 
@@ -423,27 +751,50 @@ impl LedgerRepository for PgLedgerRepository {
             "#,
         )
             .bind(row.id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(map_sqlx)?;
 
-        row.to_domain()
+        let account = row.to_domain()?;
+        Self::insert_status_history(
+            &mut tx,
+            &AccountStatusChange {
+                account_id: account.id(),
+                from: account.status(),
+                to: account.status(),
+                was_active: account.is_active(),
+                is_active: account.is_active(),
+                reason: StatusReasonCode::AccountOpened,
+                note: None,
+                actor: "system".to_string(),
+                at: Utc::now(),
+            },
+        )
+            .await?;
+
+        tx.commit().await.map_err(map_sqlx)?;
+        Ok(account)
     }
 
-    async fn set_account_active(&self, account_id: i64, active: bool) -> Result<(), RepoError> {
-        let n = sqlx::query(r#"UPDATE ledger_accounts SET is_active = $2 WHERE id = $1"#)
-            .bind(account_id)
-            .bind(active)
-            .execute(&self.pool)
-            .await
-            .map_err(map_sqlx)?
-            .rows_affected();
+    async fn set_account_active(
+        &self,
+        account_id: i64,
+        active: bool,
+        reason: StatusReasonCode,
+        actor: &str,
+    ) -> Result<(), RepoError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
 
-        if n == 0 {
-            return Err(RepoError::NotFound {
-                entity: format!("ledger_account id={account_id}"),
-            });
-        }
+        let (mut account, _) = self.lock_account_tx(&mut tx, account_id).await?.ok_or_else(|| RepoError::NotFound {
+            entity: format!("ledger_account id={account_id}"),
+        })?;
+
+        let change = account
+            .set_active(active, reason, None, actor, Utc::now())
+            .map_err(|e| RepoError::Conflict { message: e.to_string() })?;
+
+        self.save_status_change_tx(&mut tx, &change).await?;
+        tx.commit().await.map_err(map_sqlx)?;
         Ok(())
     }
 
@@ -454,7 +805,7 @@ impl LedgerRepository for PgLedgerRepository {
 
         let rows = sqlx::query_as::<_, LedgerAccountRow>(
             r#"
-            SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active
+            SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active, status
             FROM ledger_accounts
             WHERE id = ANY($1)
             "#,
@@ -471,6 +822,52 @@ impl LedgerRepository for PgLedgerRepository {
         Ok(out)
     }
 
+    async fn find_account(
+        &self,
+        owner_type: OwnerType,
+        owner_id: Option<uuid::Uuid>,
+        account_type: AccountType,
+        asset_id: i16,
+    ) -> Result<Option<LedgerAccount>, RepoError> {
+        let row = sqlx::query_as::<_, LedgerAccountRow>(
+            r#"
+            SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active, status
+            FROM ledger_accounts
+            WHERE owner_type = $1
+              AND owner_id IS NOT DISTINCT FROM $2
+              AND account_type = $3
+              AND asset_id = $4
+            ORDER BY id
+            LIMIT 1
+            "#,
+        )
+            .bind(owner_type.as_str())
+            .bind(owner_id)
+            .bind(account_type.as_str())
+            .bind(asset_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn find_asset_by_code(&self, code: &AssetCode) -> Result<Option<Asset>, RepoError> {
+        let row = sqlx::query_as::<_, AssetRow>(
+            r#"
+            SELECT id, code, decimals, is_active
+            FROM assets
+            WHERE code = $1
+            "#,
+        )
+            .bind(code.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
     async fn find_posted_by_external_ref(
         &self,
         external_ref_type: ExternalRefType,
@@ -589,19 +986,21 @@ impl LedgerRepository for PgLedgerRepository {
     }*/
 }
 
-#[async_trait]
-impl LedgerRepositoryTx for PgLedgerRepository {
-    async fn insert_posting_atomic_tx(
+impl PgLedgerRepository {
+    async fn post_journal_tx(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         posting: ValidatedJournal,
-    ) -> Result<PostedJournal, RepoError> {
+    ) -> Result<Result<PostedJournal, DomainError>, RepoError> {
+        // 0) Ledger-wide locks first, before any row this posting touches
+        Self::lock_ledger_heads_tx(tx).await?;
+
         // 1) Idempotent header insert/get
         let tx_id = Self::insert_or_get_tx_id(tx, &posting).await?;
 
         // If already posted (lines exist), return existing
         if Self::tx_has_lines(tx, tx_id).await? {
-            return Self::load_posted_by_tx_id_tx(tx, tx_id).await;
+            return Self::load_posted_by_tx_id_tx(tx, tx_id).await.map(Ok);
         }
 
         // 2) Lock affected accounts in stable order
@@ -609,29 +1008,35 @@ impl LedgerRepositoryTx for PgLedgerRepository {
         account_ids.sort_unstable();
         account_ids.dedup();
 
-        let locked_rows = Self::fetch_accounts_for_update(tx, &account_ids).await?;
-        if locked_rows.len() != account_ids.len() {
-            return Err(RepoError::NotFound {
-                entity: "one or more ledger accounts missing".into(),
-            });
-        }
+        let locked = Self::lock_active_accounts_tx(tx, &account_ids).await?;
 
-        let mut locked: std::collections::HashMap<i64, LedgerAccount> =
-            std::collections::HashMap::with_capacity(locked_rows.len());
+        let asset_ids = if posting.multi_asset {
+            Self::ensure_balanced_per_asset(tx, &posting.lines).await?
+        } else {
+            vec![Self::ensure_single_asset(tx, &account_ids).await?]
+        };
 
-        for r in locked_rows {
-            let acct = r.to_domain()?;
-            if !acct.is_active() {
-                return Err(RepoError::Integrity {
-                    message: format!("ledger account is inactive (account_id={})", acct.id()),
-                });
+        // The accounting period covering the value date must still accept this journal,
+        // in every asset it touches. The share lock makes a concurrent close wait until
+        // this posting commits.
+        for asset_id in asset_ids {
+            if let Some(period) = Self::period_for_journal_tx(tx, tx_id, asset_id).await?
+                && let Err(e) = period.ensure_accepts(posting.external_ref_type)
+            {
+                return Ok(Err(e));
             }
-            locked.insert(acct.id(), acct);
         }
 
-        let _asset_id = Self::ensure_single_asset(tx, &account_ids).await?;
+        // Backdated journals must also have been allowed by each account's status on the value date.
+        if let Some(at) = posting.effective_at.filter(|at| *at < Utc::now()) {
+            let timelines = self.status_timelines_tx(tx, &account_ids).await?;
+            if let Err(e) = posting.ensure_status_at(&timelines, at) {
+                return Ok(Err(e));
+            }
+        }
 
         let current = Self::lock_and_fetch_balances(tx, &account_ids).await?;
+        let reserved = Self::fetch_pending_outflows_tx(tx, &account_ids).await?;
 
         let mut delta: std::collections::HashMap<i64, i128> =
             std::collections::HashMap::with_capacity(posting.lines.len());
@@ -643,41 +1048,27 @@ impl LedgerRepositoryTx for PgLedgerRepository {
             })?;
         }
 
-        for (account_id, d) in &delta {
-            let acct = locked.get(account_id).ok_or_else(|| RepoError::NotFound {
-                entity: format!("ledger_account id={account_id}"),
-            })?;
-
-            if Self::is_spendable_bucket(acct.account_type()) {
-                let cur = *current.get(account_id).unwrap_or(&0);
-                let next = cur.checked_add(*d).ok_or_else(|| RepoError::Integrity {
-                    message: format!("balance overflow (account_id={account_id})"),
-                })?;
-
-                if next < 0 {
-                    return Err(RepoError::Conflict {
-                        message: format!(
-                            "insufficient funds (account_id={account_id}, current={cur}, delta={d})"
-                        ),
-                    });
-                }
-            }
-        }
+        Self::check_deltas(&locked, &current, &reserved, &delta)?;
 
         let mut line_account_ids = Vec::with_capacity(posting.lines.len());
+        let mut line_asset_ids = Vec::with_capacity(posting.lines.len());
         let mut line_amounts = Vec::with_capacity(posting.lines.len());
+        let mut line_memos = Vec::with_capacity(posting.lines.len());
         for l in &posting.lines {
             line_account_ids.push(l.account_id);
+            line_asset_ids.push(l.asset_id);
             line_amounts.push(i128_to_bigdecimal(l.amount.minor()));
+            line_memos.push(l.memo.clone());
         }
 
         // 8) Bulk insert journal lines (idempotent w/ unique (tx_id, account_id))
-        let inserted = Self::bulk_insert_lines(tx, tx_id, &line_account_ids, &line_amounts).await?;
+        let inserted =
+            Self::bulk_insert_lines(tx, tx_id, &line_account_ids, &line_asset_ids, &line_amounts, &line_memos).await?;
 
         // If 0 inserted, someone else already posted (idempotent replay / concurrent winner)
         // Return the existing posted journal without applying deltas again.
         if inserted == 0 {
-            return Self::load_posted_by_tx_id_tx(tx, tx_id).await;
+            return Self::load_posted_by_tx_id_tx(tx, tx_id).await.map(Ok);
         }
 
         // Safety: partial insert should never happen if your ValidatedJournal is compressed,
@@ -693,10 +1084,14 @@ impl LedgerRepositoryTx for PgLedgerRepository {
         }
 
         // 9) Update running balances ONLY if we inserted lines now
-        Self::apply_balance_deltas(tx, &delta).await?;
+        Self::apply_balance_deltas(tx, tx_id, &delta).await?;
 
-        // 10) Return posted journal
-        Self::load_posted_by_tx_id_tx(tx, tx_id).await
+        // 10) Number it in the ledger sequence, link it into the journal hash chain and
+        //     return the posted journal
+        Self::assign_ledger_seq_tx(tx, tx_id).await?;
+        let posted = Self::load_posted_by_tx_id_tx(tx, tx_id).await?;
+        Self::append_chain_link_tx(tx, &posted).await?;
+        Ok(Ok(posted))
 
 
         /*
@@ -706,4 +1101,322 @@ impl LedgerRepositoryTx for PgLedgerRepository {
 
         Self::load_posted_by_tx_id_tx(tx, tx_id).await*/
     }
-}
\ No newline at end of file
+}
+
+#[async_trait]
+impl LedgerRepositoryTx for PgLedgerRepository {
+    async fn insert_posting_atomic_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        posting: ValidatedJournal,
+    ) -> Result<Result<PostedJournal, DomainError>, RepoError> {
+        // The caller commits after a rejected posting too, so post under a savepoint and
+        // drop the header this posting may already have written.
+        let mut savepoint = tx.begin().await.map_err(map_sqlx)?;
+        let posted = self.post_journal_tx(&mut savepoint, posting).await?;
+        if posted.is_ok() {
+            savepoint.commit().await.map_err(map_sqlx)?;
+        } else {
+            savepoint.rollback().await.map_err(map_sqlx)?;
+        }
+        Ok(posted)
+    }
+}
+#[async_trait]
+impl AccountStatusRepository for PgLedgerRepository {
+    async fn lock_account_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        account_id: i64,
+    ) -> Result<Option<(LedgerAccount, i128)>, RepoError> {
+        let Some(row) = Self::fetch_accounts_for_update(tx, &[account_id]).await?.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let balances = Self::lock_and_fetch_balances(tx, &[account_id]).await?;
+        let balance = *balances.get(&account_id).unwrap_or(&0);
+
+        Ok(Some((row.to_domain()?, balance)))
+    }
+
+    async fn save_status_change_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        change: &AccountStatusChange,
+    ) -> Result<(), RepoError> {
+        let n = sqlx::query(
+            r#"
+            UPDATE ledger_accounts
+            SET status = $2,
+                is_active = $3,
+                status_reason = $4,
+                status_note = $5,
+                status_changed_by = $6,
+                status_changed_at = $7
+            WHERE id = $1 AND status = $8 AND is_active = $9
+            "#,
+        )
+            .bind(change.account_id)
+            .bind(change.to.as_code())
+            .bind(change.is_active)
+            .bind(change.reason.as_code())
+            .bind(&change.note)
+            .bind(&change.actor)
+            .bind(change.at)
+            .bind(change.from.as_code())
+            .bind(change.was_active)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?
+            .rows_affected();
+
+        if n == 0 {
+            return Err(RepoError::Conflict {
+                message: format!(
+                    "account status changed concurrently (account_id={}, expected {})",
+                    change.account_id,
+                    change.from.as_code()
+                ),
+            });
+        }
+
+        Self::insert_status_history(tx, change).await
+    }
+
+    async fn status_timeline(&self, account_id: i64) -> Result<AccountStatusTimeline, RepoError> {
+        let rows = sqlx::query_as::<_, AccountStatusHistoryRow>(STATUS_HISTORY_SQL)
+            .bind(&[account_id][..])
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let entries = rows.iter().map(|r| r.to_domain()).collect::<Result<Vec<_>, _>>()?;
+        Ok(AccountStatusTimeline::new(account_id, entries))
+    }
+
+    async fn status_timelines_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        account_ids: &[i64],
+    ) -> Result<HashMap<i64, AccountStatusTimeline>, RepoError> {
+        let rows = sqlx::query_as::<_, AccountStatusHistoryRow>(STATUS_HISTORY_SQL)
+            .bind(account_ids)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut grouped: HashMap<i64, Vec<AccountStatusChange>> = HashMap::new();
+        for r in rows {
+            grouped.entry(r.account_id).or_default().push(r.to_domain()?);
+        }
+
+        Ok(grouped
+            .into_iter()
+            .map(|(id, entries)| (id, AccountStatusTimeline::new(id, entries)))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl JournalQueryRepository for PgLedgerRepository {
+    async fn list_journals(&self, filter: &JournalFilter) -> Result<Vec<PostedJournal>, RepoError> {
+        // empty metadata object / tag array contain nothing, so they match every journal
+        let ids = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT id
+            FROM journal_transactions
+            WHERE ($1::text IS NULL OR external_ref_type = $1)
+              AND ($2::text IS NULL OR created_by = $2)
+              AND metadata @> $3
+              AND tags @> $4
+            ORDER BY id DESC
+            LIMIT $5 OFFSET $6
+            "#,
+        )
+            .bind(filter.external_ref_type.map(|t| t.as_code()))
+            .bind(&filter.created_by)
+            .bind(Json(filter.metadata.as_map()))
+            .bind(filter.tags.iter().map(|t| t.as_str()).collect::<Vec<_>>())
+            .bind(filter.limit as i64)
+            .bind(filter.offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = Vec::with_capacity(ids.len());
+        for id in ids {
+            out.push(Self::load_posted_by_tx_id(&self.pool, id).await?);
+        }
+        Ok(out)
+    }
+
+    async fn balance_as_of(
+        &self,
+        account_id: i64,
+        at: DateTime<Utc>,
+        basis: BalanceBasis,
+    ) -> Result<i128, RepoError> {
+        let dated = match basis {
+            BalanceBasis::Posted => "t.created_at",
+            BalanceBasis::Effective => "t.effective_at",
+        };
+        let sql = format!(
+            r#"
+            SELECT COALESCE(SUM(l.amount), 0)
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            WHERE l.account_id = $1
+              AND {dated} < $2
+            "#
+        );
+        let total = sqlx::query_scalar::<_, BigDecimal>(&sql)
+            .bind(account_id)
+            .bind(at)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::numeric0_to_i128_strict(&total, account_id)
+    }
+
+    async fn latest_sequence(&self) -> Result<i64, RepoError> {
+        sqlx::query_scalar::<_, i64>("SELECT last_seq FROM ledger_sequence WHERE id")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)
+    }
+
+    async fn journals_after_sequence(&self, after: i64, limit: usize) -> Result<Vec<PostedJournal>, RepoError> {
+        let ids = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT id
+            FROM journal_transactions
+            WHERE ledger_seq > $1
+            ORDER BY ledger_seq
+            LIMIT $2
+            "#,
+        )
+            .bind(after)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = Vec::with_capacity(ids.len());
+        for id in ids {
+            out.push(Self::load_posted_by_tx_id(&self.pool, id).await?);
+        }
+        Ok(out)
+    }
+
+    async fn balance_as_of_sequence(&self, account_id: i64, sequence: i64) -> Result<i128, RepoError> {
+        let total = sqlx::query_scalar::<_, BigDecimal>(
+            r#"
+            SELECT COALESCE(SUM(l.amount), 0)
+            FROM journal_lines l
+            JOIN journal_transactions t ON t.id = l.journal_tx_id
+            WHERE l.account_id = $1
+              AND t.ledger_seq <= $2
+            "#,
+        )
+            .bind(account_id)
+            .bind(sequence)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::numeric0_to_i128_strict(&total, account_id)
+    }
+}
+
+const STATUS_HISTORY_SQL: &str = r#"
+    SELECT account_id, from_status, to_status, was_active, is_active, reason, note, actor, changed_at
+    FROM account_status_history
+    WHERE account_id = ANY($1)
+    ORDER BY account_id, changed_at, id
+"#;
+
+#[async_trait]
+impl JournalChainRepository for PgLedgerRepository {
+    async fn chain_head(&self) -> Result<ChainHead, RepoError> {
+        sqlx::query_as::<_, ChainHeadRow>("SELECT position, entry_hash FROM journal_chain_head WHERE id")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?
+            .to_domain()
+    }
+
+    async fn chain_links(
+        &self,
+        after_position: i64,
+        up_to_position: i64,
+        limit: usize,
+    ) -> Result<Vec<(ChainLink, Option<PostedJournal>)>, RepoError> {
+        let rows = sqlx::query_as::<_, ChainLinkRow>(
+            r#"
+            SELECT c.position, c.journal_tx_id, c.prev_hash, c.entry_hash, (t.id IS NOT NULL) AS journal_exists
+            FROM journal_chain c
+            LEFT JOIN journal_transactions t ON t.id = c.journal_tx_id
+            WHERE c.position > $1 AND c.position <= $2
+            ORDER BY c.position
+            LIMIT $3
+            "#,
+        )
+            .bind(after_position)
+            .bind(up_to_position)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            let journal = match row.journal_exists {
+                true => Some(Self::load_posted_by_tx_id(&self.pool, row.journal_tx_id).await?),
+                false => None,
+            };
+            out.push((row.to_domain()?, journal));
+        }
+        Ok(out)
+    }
+
+    async fn chain_unlinked(&self, limit: usize) -> Result<usize, RepoError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+
+        // taken first so a concurrent backfill waits instead of picking the same journals
+        sqlx::query("SELECT 1 FROM journal_chain_head WHERE id FOR UPDATE")
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let ids = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT t.id
+            FROM journal_transactions t
+            WHERE NOT EXISTS (SELECT 1 FROM journal_chain c WHERE c.journal_tx_id = t.id)
+              AND EXISTS (SELECT 1 FROM journal_lines l WHERE l.journal_tx_id = t.id)
+            ORDER BY t.ledger_seq
+            LIMIT $1
+            "#,
+        )
+            .bind(limit as i64)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        for id in &ids {
+            let posted = Self::load_posted_by_tx_id_tx(&mut tx, *id).await?;
+            Self::append_chain_link_tx(&mut tx, &posted).await?;
+        }
+        if ids.len() < limit {
+            // nothing older is left, so postings may link after it
+            sqlx::query("UPDATE journal_chain_head SET backfilled = TRUE WHERE id AND NOT backfilled")
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx)?;
+        Ok(ids.len())
+    }
+}
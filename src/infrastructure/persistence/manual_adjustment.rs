@@ -0,0 +1,246 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::ManualAdjustmentRepository;
+use crate::domain::aggregate::{AdjustmentAuditEntry, ManualAdjustment, NewManualAdjustment};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{
+    ManualAdjustmentAuditRow, ManualAdjustmentLineRow, ManualAdjustmentRow,
+};
+
+pub struct PgManualAdjustmentRepository {
+    pool: PgPool,
+}
+
+impl PgManualAdjustmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<ManualAdjustment>, RepoError> {
+        let sql = if for_update {
+            r#"
+            SELECT id, public_id, external_ref, description, created_by, asset_id, gross_amount,
+                   status, required_approvals, rejection_reason, posted_journal_tx_id, effective_at,
+                   metadata, tags
+            FROM manual_adjustments
+            WHERE public_id = $1
+            FOR UPDATE
+            "#
+        } else {
+            r#"
+            SELECT id, public_id, external_ref, description, created_by, asset_id, gross_amount,
+                   status, required_approvals, rejection_reason, posted_journal_tx_id, effective_at,
+                   metadata, tags
+            FROM manual_adjustments
+            WHERE public_id = $1
+            "#
+        };
+
+        let Some(row) = sqlx::query_as::<_, ManualAdjustmentRow>(sql)
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        let lines = sqlx::query_as::<_, ManualAdjustmentLineRow>(
+            r#"
+            SELECT account_id, amount, memo
+            FROM manual_adjustment_lines
+            WHERE adjustment_id = $1
+            ORDER BY position
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        let approvals = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT approver
+            FROM manual_adjustment_approvals
+            WHERE adjustment_id = $1
+            ORDER BY approved_at, approver
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(Some(row.to_domain(&lines, approvals)?))
+    }
+
+    async fn append_audit(
+        conn: &mut PgConnection,
+        adjustment_id: i64,
+        entries: &[AdjustmentAuditEntry],
+    ) -> Result<(), RepoError> {
+        for e in entries {
+            sqlx::query(
+                r#"
+                INSERT INTO manual_adjustment_audit (adjustment_id, action, actor, reason, at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+                .bind(adjustment_id)
+                .bind(e.action.as_code())
+                .bind(&e.actor)
+                .bind(&e.reason)
+                .bind(e.at)
+                .execute(&mut *conn)
+                .await
+                .map_err(map_sqlx)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ManualAdjustmentRepository for PgManualAdjustmentRepository {
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<ManualAdjustment>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn audit_trail(&self, public_id: PublicId) -> Result<Vec<AdjustmentAuditEntry>, RepoError> {
+        let rows = sqlx::query_as::<_, ManualAdjustmentAuditRow>(
+            r#"
+            SELECT a.action, a.actor, a.reason, a.at
+            FROM manual_adjustment_audit a
+            JOIN manual_adjustments m ON m.id = a.adjustment_id
+            WHERE m.public_id = $1
+            ORDER BY a.id
+            "#,
+        )
+            .bind(public_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(|r| r.to_domain()).collect()
+    }
+
+    async fn insert_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewManualAdjustment,
+    ) -> Result<ManualAdjustment, RepoError> {
+        let draft = &spec.draft;
+
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO manual_adjustments
+                (public_id, external_ref, description, created_by, asset_id, gross_amount, required_approvals,
+                 effective_at, metadata, tags)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id
+            "#,
+        )
+            .bind(draft.public_id.value())
+            .bind(draft.external_ref.as_str())
+            .bind(&draft.description)
+            .bind(&draft.created_by)
+            .bind(spec.asset_id)
+            .bind(i128_to_bigdecimal(spec.gross_amount_minor))
+            .bind(spec.required_approvals as i16)
+            .bind(draft.effective_at)
+            .bind(Json(draft.metadata.as_map()))
+            .bind(draft.tags().iter().map(|t| t.as_str()).collect::<Vec<_>>())
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let positions: Vec<i16> = (0..draft.lines().len() as i16).collect();
+        let account_ids: Vec<i64> = draft.lines().iter().map(|l| l.account_id).collect();
+        let amounts: Vec<BigDecimal> = draft.lines().iter().map(|l| i128_to_bigdecimal(l.amount.minor())).collect();
+        let memos: Vec<Option<String>> = draft.lines().iter().map(|l| l.memo.clone()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO manual_adjustment_lines (adjustment_id, position, account_id, amount, memo)
+            SELECT $1, x.position, x.account_id, x.amount, x.memo
+            FROM UNNEST($2::smallint[], $3::bigint[], $4::numeric[], $5::text[])
+                AS x(position, account_id, amount, memo)
+            "#,
+        )
+            .bind(id)
+            .bind(&positions)
+            .bind(&account_ids)
+            .bind(&amounts)
+            .bind(&memos)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::append_audit(&mut *tx, id, std::slice::from_ref(&spec.submitted)).await?;
+
+        Self::load(&mut *tx, draft.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("manual_adjustment id={id}") })
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<ManualAdjustment>, RepoError> {
+        Self::load(&mut *tx, public_id, true).await
+    }
+
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        adjustment: &mut ManualAdjustment,
+    ) -> Result<(), RepoError> {
+        let audit = adjustment.take_audit();
+
+        sqlx::query(
+            r#"
+            UPDATE manual_adjustments
+            SET status = $2,
+                rejection_reason = $3,
+                posted_journal_tx_id = $4,
+                updated_at = now()
+            WHERE id = $1
+            "#,
+        )
+            .bind(adjustment.id())
+            .bind(adjustment.status().as_code())
+            .bind(adjustment.rejection_reason())
+            .bind(adjustment.posted_journal_id())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        for e in audit.iter().filter(|e| e.action == crate::domain::aggregate::AdjustmentAction::Approved) {
+            sqlx::query(
+                r#"
+                INSERT INTO manual_adjustment_approvals (adjustment_id, approver, approved_at)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+                .bind(adjustment.id())
+                .bind(&e.actor)
+                .bind(e.at)
+                .execute(&mut **tx)
+                .await
+                .map_err(map_sqlx)?;
+        }
+
+        Self::append_audit(&mut *tx, adjustment.id(), &audit).await
+    }
+}
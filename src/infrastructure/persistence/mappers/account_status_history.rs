@@ -0,0 +1,23 @@
+use crate::domain::entities::{AccountStatus, AccountStatusChange, StatusReasonCode};
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::models::AccountStatusHistoryRow;
+
+impl AccountStatusHistoryRow {
+    pub fn to_domain(&self) -> Result<AccountStatusChange, RepoError> {
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid account status history in db (account_id={}): {e}", self.account_id),
+        };
+
+        Ok(AccountStatusChange {
+            account_id: self.account_id,
+            from: AccountStatus::from_code(&self.from_status).map_err(integrity)?,
+            to: AccountStatus::from_code(&self.to_status).map_err(integrity)?,
+            was_active: self.was_active,
+            is_active: self.is_active,
+            reason: StatusReasonCode::from_code(&self.reason).map_err(integrity)?,
+            note: self.note.clone(),
+            actor: self.actor.clone(),
+            at: self.changed_at,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::entities::{AccountingPeriod, PeriodStatus};
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::models::AccountingPeriodRow;
+
+impl AccountingPeriodRow {
+    pub fn to_domain(&self) -> Result<AccountingPeriod, RepoError> {
+        let status = PeriodStatus::from_code(&self.status).map_err(|e| RepoError::Integrity {
+            message: format!("invalid accounting period in db (period_id={}): {e}", self.id),
+        })?;
+
+        Ok(AccountingPeriod::restore(
+            self.id,
+            self.asset_id,
+            self.name.clone(),
+            self.starts_at,
+            self.ends_at,
+            status,
+            self.status_changed_at,
+            self.status_changed_by.clone(),
+        ))
+    }
+}
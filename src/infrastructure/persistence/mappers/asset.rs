@@ -0,0 +1,13 @@
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::{Asset, AssetCode};
+
+use crate::infrastructure::persistence::models::AssetRow;
+
+impl AssetRow {
+    pub fn to_domain(&self) -> Result<Asset, RepoError> {
+        let code = AssetCode::new(self.code.clone()).map_err(|e| RepoError::Integrity {
+            message: format!("invalid asset code in db (asset_id={}): {e}", self.id),
+        })?;
+        Ok(Asset::new(self.id, code, self.decimals, self.is_active))
+    }
+}
@@ -0,0 +1,20 @@
+use crate::domain::entities::{CoaNode, GlClass, NormalBalance};
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::models::CoaNodeRow;
+
+impl CoaNodeRow {
+    pub fn to_domain(&self) -> Result<CoaNode, RepoError> {
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid coa node in db (coa_node_id={}): {e}", self.id),
+        };
+
+        Ok(CoaNode {
+            id: self.id,
+            code: self.code.clone(),
+            name: self.name.clone(),
+            gl_class: GlClass::from_code(&self.gl_class).map_err(integrity)?,
+            normal_balance: NormalBalance::from_code(&self.normal_balance).map_err(integrity)?,
+            parent_id: self.parent_id,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::aggregate::{DepositIntent, DepositSource, DepositStatus, NewDepositIntent};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::DepositIntentRow;
+
+impl DepositIntentRow {
+    pub fn to_domain(&self) -> Result<DepositIntent, RepoError> {
+        let integrity = |e: String| RepoError::Integrity {
+            message: format!("invalid deposit intent in db (deposit_id={}): {e}", self.id),
+        };
+        let status = DepositStatus::from_code(&self.status).map_err(|e| integrity(e.to_string()))?;
+        let source = DepositSource::from_code(&self.source).map_err(|e| integrity(e.to_string()))?;
+        let required_confirmations =
+            u32::try_from(self.required_confirmations).map_err(|e| integrity(e.to_string()))?;
+        let confirmations = u32::try_from(self.confirmations).map_err(|e| integrity(e.to_string()))?;
+
+        let spec = NewDepositIntent {
+            public_id: PublicId::new(self.public_id),
+            owner_id: self.owner_id,
+            asset_id: self.asset_id,
+            amount_minor: bigdecimal_to_i128(&self.amount)?,
+            tx_reference: self.tx_reference.clone(),
+            required_confirmations,
+            source,
+            confirmations,
+            created_by: self.created_by.clone(),
+        };
+
+        Ok(DepositIntent::restore(
+            self.id,
+            spec,
+            confirmations,
+            status,
+            self.journal_tx_id,
+            self.confirmed_at,
+            self.credited_at,
+        ))
+    }
+}
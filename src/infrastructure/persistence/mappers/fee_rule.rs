@@ -0,0 +1,57 @@
+use crate::domain::repository::RepoError;
+use crate::domain::services::{FeeCalculation, FeeRounding, FeeRule, FeeTier};
+use crate::domain::value_objects::ExternalRefType;
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{FeeRuleRow, FeeRuleTierRow};
+
+impl FeeRuleRow {
+    /// `tiers` must belong to this rule and be ordered by bracket position.
+    pub fn to_domain(&self, tiers: &[FeeRuleTierRow]) -> Result<FeeRule, RepoError> {
+        let id = self.id;
+        let integrity = |what: &str| RepoError::Integrity {
+            message: format!("invalid {what} for fee_rule_id={id}"),
+        };
+
+        let posting_kind = ExternalRefType::from_code(&self.posting_kind)
+            .map_err(|e| RepoError::Integrity { message: format!("fee_rule_id={id}: {e}") })?;
+        let rounding = FeeRounding::from_code(&self.rounding)
+            .map_err(|e| RepoError::Integrity { message: format!("fee_rule_id={id}: {e}") })?;
+
+        let rate_bps = |v: Option<i32>| -> Result<u32, RepoError> {
+            v.and_then(|r| u32::try_from(r).ok()).ok_or_else(|| integrity("rate_bps"))
+        };
+
+        let calculation = match self.calculation.as_str() {
+            "FLAT" => FeeCalculation::Flat {
+                amount_minor: bigdecimal_to_i128(self.flat_amount.as_ref().ok_or_else(|| integrity("flat_amount"))?)?,
+            },
+            "PERCENTAGE" => FeeCalculation::Percentage { rate_bps: rate_bps(self.rate_bps)? },
+            "TIERED" => {
+                let mut out = Vec::with_capacity(tiers.len());
+                for t in tiers.iter().filter(|t| t.fee_rule_id == id) {
+                    out.push(FeeTier {
+                        up_to_minor: t.up_to.as_ref().map(bigdecimal_to_i128).transpose()?,
+                        rate_bps: rate_bps(Some(t.rate_bps))?,
+                        flat_minor: bigdecimal_to_i128(&t.flat_amount)?,
+                    });
+                }
+                FeeCalculation::Tiered { tiers: out }
+            }
+            other => {
+                return Err(RepoError::Integrity {
+                    message: format!("unknown fee calculation={other} for fee_rule_id={id}"),
+                })
+            }
+        };
+
+        Ok(FeeRule {
+            id,
+            asset_id: self.asset_id,
+            posting_kind,
+            calculation,
+            min_fee_minor: self.min_fee.as_ref().map(bigdecimal_to_i128).transpose()?,
+            max_fee_minor: self.max_fee.as_ref().map(bigdecimal_to_i128).transpose()?,
+            rounding,
+        })
+    }
+}
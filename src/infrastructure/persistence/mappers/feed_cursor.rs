@@ -0,0 +1,12 @@
+use crate::domain::aggregate::FeedCursor;
+use crate::domain::repository::RepoError;
+use crate::infrastructure::persistence::models::FeedCursorRow;
+
+impl FeedCursorRow {
+    pub fn to_domain(&self) -> Result<FeedCursor, RepoError> {
+        let consumer = FeedCursor::normalize(self.consumer.as_str()).map_err(|e| RepoError::Integrity {
+            message: format!("invalid feed cursor in db (consumer={}): {e}", self.consumer),
+        })?;
+        Ok(FeedCursor::restore(consumer, self.position, self.created_at, self.updated_at))
+    }
+}
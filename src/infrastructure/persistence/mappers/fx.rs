@@ -0,0 +1,81 @@
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{BigDecimal, ToPrimitive};
+
+use crate::domain::aggregate::{FxLiquidity, FxQuote, FxQuoteStatus, FxRate, NewFxQuote, NewFxRate};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::{ExchangeRate, PublicId};
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{FxQuoteRow, FxRateRow};
+
+pub fn rate_to_bigdecimal(rate: ExchangeRate) -> BigDecimal {
+    BigDecimal::new(BigInt::from(rate.mantissa()), i64::from(rate.scale()))
+}
+
+pub fn bigdecimal_to_rate(d: &BigDecimal) -> Result<ExchangeRate, RepoError> {
+    let integrity = |reason: String| RepoError::Integrity { message: format!("invalid fx rate in db: {reason}") };
+
+    let (digits, scale) = d.normalized().into_bigint_and_exponent();
+    let mantissa = digits.to_i128().ok_or_else(|| integrity("out of range".into()))?;
+    // a negative scale is a whole number with trailing zeros folded into the exponent
+    let (mantissa, scale) = if scale < 0 {
+        let factor = 10i128.checked_pow(scale.unsigned_abs() as u32).ok_or_else(|| integrity("out of range".into()))?;
+        (mantissa.checked_mul(factor).ok_or_else(|| integrity("out of range".into()))?, 0)
+    } else {
+        (mantissa, scale as u32)
+    };
+    ExchangeRate::new(mantissa, scale).map_err(|e| integrity(e.to_string()))
+}
+
+impl FxRateRow {
+    pub fn to_domain(&self) -> Result<FxRate, RepoError> {
+        Ok(FxRate::restore(
+            self.id,
+            NewFxRate {
+                base_asset_id: self.base_asset_id,
+                quote_asset_id: self.quote_asset_id,
+                rate: bigdecimal_to_rate(&self.rate)?,
+                valid_from: self.valid_from,
+                valid_to: self.valid_to,
+                source: self.source.clone(),
+                created_by: self.created_by.clone(),
+            },
+        ))
+    }
+}
+
+impl FxQuoteRow {
+    pub fn to_domain(&self) -> Result<FxQuote, RepoError> {
+        let integrity = |e: String| RepoError::Integrity {
+            message: format!("invalid fx quote in db (fx_quote_id={}): {e}", self.id),
+        };
+        let status = FxQuoteStatus::from_code(&self.status).map_err(|e| integrity(e.to_string()))?;
+        let liquidity = FxLiquidity::from_code(&self.liquidity).map_err(|e| integrity(e.to_string()))?;
+        let spread_bps = u32::try_from(self.spread_bps).map_err(|e| integrity(e.to_string()))?;
+
+        let spec = NewFxQuote {
+            public_id: PublicId::new(self.public_id),
+            owner_id: self.owner_id,
+            from_asset_id: self.from_asset_id,
+            to_asset_id: self.to_asset_id,
+            rate_id: self.rate_id,
+            mid_rate: bigdecimal_to_rate(&self.mid_rate)?,
+            spread_bps,
+            applied_rate: bigdecimal_to_rate(&self.applied_rate)?,
+            from_amount_minor: bigdecimal_to_i128(&self.from_amount)?,
+            to_amount_minor: bigdecimal_to_i128(&self.to_amount)?,
+            liquidity,
+            expires_at: self.expires_at,
+            created_by: self.created_by.clone(),
+        };
+
+        Ok(FxQuote::restore(
+            self.id,
+            spec,
+            status,
+            self.sell_journal_id,
+            self.buy_journal_id,
+            self.executed_at,
+            self.executed_by.clone(),
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::aggregate::{
+    FxRevaluation, FxRevaluationStatus, NewFxRevaluation, RevaluationLine, RevaluationPosition,
+};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::{bigdecimal_to_i128, bigdecimal_to_rate};
+use crate::infrastructure::persistence::models::{FxRevaluationLineRow, FxRevaluationRow, RevaluationPositionRow};
+
+impl FxRevaluationLineRow {
+    pub fn to_domain(&self) -> Result<RevaluationLine, RepoError> {
+        Ok(RevaluationLine {
+            asset_id: self.asset_id,
+            balance_minor: bigdecimal_to_i128(&self.balance)?,
+            opening_rate_id: self.opening_rate_id,
+            opening_rate: bigdecimal_to_rate(&self.opening_rate)?,
+            closing_rate_id: self.closing_rate_id,
+            closing_rate: bigdecimal_to_rate(&self.closing_rate)?,
+            opening_value_minor: bigdecimal_to_i128(&self.opening_value)?,
+            closing_value_minor: bigdecimal_to_i128(&self.closing_value)?,
+            gain_minor: bigdecimal_to_i128(&self.gain)?,
+            journal_id: self.journal_id,
+            reversal_journal_id: self.reversal_journal_id,
+        })
+    }
+}
+
+impl FxRevaluationRow {
+    pub fn to_domain(&self, lines: &[FxRevaluationLineRow]) -> Result<FxRevaluation, RepoError> {
+        let status = FxRevaluationStatus::from_code(&self.status).map_err(|e| RepoError::Integrity {
+            message: format!("invalid fx revaluation in db (fx_revaluation_id={}): {e}", self.id),
+        })?;
+
+        let spec = NewFxRevaluation {
+            public_id: PublicId::new(self.public_id),
+            reporting_asset_id: self.reporting_asset_id,
+            period_id: self.period_id,
+            valued_at: self.valued_at,
+            lines: lines.iter().map(FxRevaluationLineRow::to_domain).collect::<Result<_, _>>()?,
+            created_by: self.created_by.clone(),
+        };
+
+        Ok(FxRevaluation::restore(
+            self.id,
+            spec,
+            status,
+            self.created_at,
+            self.reversed_at,
+            self.reversed_by.clone(),
+        ))
+    }
+}
+
+impl RevaluationPositionRow {
+    pub fn to_domain(&self) -> Result<RevaluationPosition, RepoError> {
+        Ok(RevaluationPosition {
+            asset_id: self.asset_id,
+            decimals: self.decimals,
+            balance_minor: bigdecimal_to_i128(&self.balance)?,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::aggregate::{Hold, HoldAction, HoldJournal, HoldStatus, NewHold};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{HoldJournalRow, HoldRow};
+
+impl HoldRow {
+    pub fn to_domain(&self) -> Result<Hold, RepoError> {
+        let id = self.id;
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid hold in db (hold_id={id}): {e}"),
+        };
+
+        let spec = NewHold {
+            public_id: PublicId::new(self.public_id),
+            owner_id: self.owner_id,
+            asset_id: self.asset_id,
+            available_account_id: self.available_account_id,
+            locked_account_id: self.locked_account_id,
+            amount_minor: bigdecimal_to_i128(&self.amount)?,
+            expires_at: self.expires_at,
+            description: self.description.clone(),
+            created_by: self.created_by.clone(),
+        };
+
+        Ok(Hold::restore(
+            id,
+            spec,
+            bigdecimal_to_i128(&self.captured_amount)?,
+            bigdecimal_to_i128(&self.released_amount)?,
+            HoldStatus::from_code(&self.status).map_err(integrity)?,
+            u32::try_from(self.journal_count).map_err(|_| RepoError::Integrity {
+                message: format!("invalid journal_count in holds (hold_id={id}): {}", self.journal_count),
+            })?,
+            self.status_changed_at,
+            self.status_changed_by.clone(),
+        ))
+    }
+}
+
+impl HoldJournalRow {
+    pub fn to_domain(&self) -> Result<HoldJournal, RepoError> {
+        Ok(HoldJournal {
+            seq: u32::try_from(self.seq).map_err(|_| RepoError::Integrity {
+                message: format!("invalid seq in hold_journals: {}", self.seq),
+            })?,
+            action: HoldAction::from_code(&self.action)
+                .map_err(|e| RepoError::Integrity { message: format!("invalid hold journal in db: {e}") })?,
+            amount_minor: bigdecimal_to_i128(&self.amount)?,
+            journal_id: self.journal_tx_id,
+            counterparty_account_id: self.counterparty_account_id,
+            actor: self.actor.clone(),
+            created_at: self.created_at,
+        })
+    }
+}
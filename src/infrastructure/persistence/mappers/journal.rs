@@ -3,7 +3,7 @@ use num_traits::ToPrimitive;
 
 use crate::domain::aggregate::{JournalLine, PostedJournal};
 use crate::domain::repository::RepoError;
-use crate::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
 use crate::infrastructure::persistence::models::{JournalLineRow, JournalTxRow};
 
 pub fn bigdecimal_to_i128(d: &BigDecimal) -> Result<i128, RepoError> {
@@ -19,7 +19,6 @@ pub fn i128_to_bigdecimal(v: i128) -> BigDecimal {
 pub fn map_posted_journal(
     header: JournalTxRow,
     lines: Vec<JournalLineRow>,
-    asset_id: i16,
 ) -> Result<PostedJournal, RepoError> {
     let tx_id = header.id;
 
@@ -34,6 +33,23 @@ pub fn map_posted_journal(
         message: format!("invalid external_ref in db (tx_id={tx_id}): {e}"),
     })?;
 
+    let metadata = JournalMetadata::new(header.metadata.0).map_err(|e| RepoError::Integrity {
+        message: format!("invalid journal metadata in db (tx_id={tx_id}): {e}"),
+    })?;
+
+    let tags = header
+        .tags
+        .into_iter()
+        .map(JournalTag::new)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| RepoError::Integrity {
+            message: format!("invalid journal tag in db (tx_id={tx_id}): {e}"),
+        })?;
+
+    let sequence = header.ledger_seq.ok_or_else(|| RepoError::Integrity {
+        message: format!("posted journal has no ledger sequence (tx_id={tx_id})"),
+    })?;
+
     let mut out_lines: Vec<JournalLine> = Vec::with_capacity(lines.len());
 
     for l in lines {
@@ -51,17 +67,29 @@ pub fn map_posted_journal(
             ),
         })?;
 
-        out_lines.push(JournalLine { account_id, amount: money });
+        out_lines.push(JournalLine { account_id, asset_id: l.asset_id, amount: money, memo: l.memo });
     }
 
+    let asset_id = match out_lines.split_first() {
+        Some((first, rest)) if rest.iter().all(|l| l.asset_id == first.asset_id) => Some(first.asset_id),
+        _ => None,
+    };
+
     Ok(PostedJournal {
         db_id: header.id,
+        sequence,
         public_id: PublicId::new(header.public_id),
         external_ref_type: ext_type,
         external_ref,
         description: header.description,
         created_by: header.created_by,
+        metadata,
+        tags,
+        effective_at: header.effective_at,
+        posted_at: header.created_at,
+        multi_asset: header.multi_asset,
         asset_id,
         lines: out_lines,
+        fee_rule_ids: header.fee_rule_ids,
     })
 }
@@ -0,0 +1,21 @@
+use crate::domain::repository::RepoError;
+use crate::domain::services::{ChainHead, ChainLink};
+use crate::infrastructure::persistence::mappers::to_digest;
+use crate::infrastructure::persistence::models::{ChainHeadRow, ChainLinkRow};
+
+impl ChainLinkRow {
+    pub fn to_domain(&self) -> Result<ChainLink, RepoError> {
+        Ok(ChainLink {
+            position: self.position,
+            journal_id: self.journal_tx_id,
+            prev_hash: to_digest(&self.prev_hash, "journal chain prev hash")?,
+            entry_hash: to_digest(&self.entry_hash, "journal chain entry hash")?,
+        })
+    }
+}
+
+impl ChainHeadRow {
+    pub fn to_domain(&self) -> Result<ChainHead, RepoError> {
+        Ok(ChainHead { position: self.position, entry_hash: to_digest(&self.entry_hash, "journal chain head hash")? })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use crate::domain::entities::{AccountStatus, AccountType, LedgerAccount, OwnerType};
 use crate::domain::repository::RepoError;
 use crate::domain::value_objects::PublicId;
 
@@ -21,10 +21,13 @@ impl LedgerAccountRow {
             "USER_AVAILABLE" => AccountType::UserAvailable,
             "USER_LOCKED" => AccountType::UserLocked,
             "PLATFORM_CLEARING" => AccountType::PlatformClearing,
+            "PLATFORM_REVENUE" => AccountType::PlatformRevenue,
             "TREASURY_AVAILABLE" => AccountType::TreasuryAvailable,
             "TREASURY_LOCKED" => AccountType::TreasuryLocked,
             "INVENTORY_AVAILABLE" => AccountType::InventoryAvailable,
             "INVENTORY_LOCKED" => AccountType::InventoryLocked,
+            "TREASURY_REVALUATION" => AccountType::TreasuryRevaluation,
+            "PLATFORM_UNREALISED_FX" => AccountType::PlatformUnrealisedFx,
             other => {
                 return Err(RepoError::Integrity {
                     message: format!("unknown account_type={other} for ledger_account_id={}", self.id),
@@ -32,6 +35,10 @@ impl LedgerAccountRow {
             }
         };
 
+        let status = AccountStatus::from_code(&self.status).map_err(|e| RepoError::Integrity {
+            message: format!("{e} for ledger_account_id={}", self.id),
+        })?;
+
         Ok(LedgerAccount::new(
             self.id,
             PublicId::new(self.public_id),
@@ -40,6 +47,7 @@ impl LedgerAccountRow {
             account_type,
             self.asset_id,
             self.is_active,
-        ))
+        )
+            .with_status(status))
     }
 }
@@ -0,0 +1,70 @@
+use crate::domain::aggregate::{
+    AdjustmentAction, AdjustmentAuditEntry, AdjustmentStatus, JournalDraft, ManualAdjustment,
+};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{
+    ManualAdjustmentAuditRow, ManualAdjustmentLineRow, ManualAdjustmentRow,
+};
+
+impl ManualAdjustmentRow {
+    pub fn to_domain(
+        &self,
+        lines: &[ManualAdjustmentLineRow],
+        approvals: Vec<String>,
+    ) -> Result<ManualAdjustment, RepoError> {
+        let id = self.id;
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid manual adjustment in db (adjustment_id={id}): {e}"),
+        };
+
+        let mut draft = JournalDraft::new(
+            PublicId::new(self.public_id),
+            ExternalRefType::ManualAdjustment,
+            ExternalRef::new(self.external_ref.clone()).map_err(integrity)?,
+            self.created_by.clone(),
+            self.description.clone(),
+        )
+            .map_err(integrity)?;
+        draft.effective_at = self.effective_at;
+        draft.metadata = JournalMetadata::new(self.metadata.0.clone()).map_err(integrity)?;
+        for t in &self.tags {
+            draft.add_tag(JournalTag::new(t.clone()).map_err(integrity)?);
+        }
+
+        for l in lines {
+            let minor = bigdecimal_to_i128(&l.amount)?;
+            draft.add_line_with_memo(l.account_id, Money::from_signed_minor(minor).map_err(integrity)?, l.memo.clone());
+        }
+
+        let required_approvals = u16::try_from(self.required_approvals).map_err(|_| RepoError::Integrity {
+            message: format!("invalid required_approvals for adjustment_id={id}"),
+        })?;
+
+        Ok(ManualAdjustment::restore(
+            id,
+            draft,
+            self.asset_id,
+            bigdecimal_to_i128(&self.gross_amount)?,
+            AdjustmentStatus::from_code(&self.status).map_err(integrity)?,
+            required_approvals,
+            approvals,
+            self.rejection_reason.clone(),
+            self.posted_journal_tx_id,
+        ))
+    }
+}
+
+impl ManualAdjustmentAuditRow {
+    pub fn to_domain(&self) -> Result<AdjustmentAuditEntry, RepoError> {
+        Ok(AdjustmentAuditEntry {
+            action: AdjustmentAction::from_code(&self.action).map_err(|e| RepoError::Integrity {
+                message: format!("invalid manual adjustment audit action in db: {e}"),
+            })?,
+            actor: self.actor.clone(),
+            reason: self.reason.clone(),
+            at: self.at,
+        })
+    }
+}
@@ -1,6 +1,27 @@
 mod ledger_account;
+mod asset;
 mod journal;
+mod fee_rule;
+mod manual_adjustment;
+mod account_status_history;
+mod coa_node;
+mod accounting_period;
+mod scheduled_journal;
+mod pending_journal;
+mod hold;
+mod withdrawal;
+mod deposit;
+mod fx;
+mod fx_revaluation;
+mod reserve_snapshot;
+mod solvency_report;
+mod journal_chain;
+mod feed_cursor;
+mod webhook;
 pub use self::journal::{
+    bigdecimal_to_i128,
     i128_to_bigdecimal,
     map_posted_journal,
-};
\ No newline at end of file
+};
+pub use self::fx::{bigdecimal_to_rate, rate_to_bigdecimal};
+pub use self::reserve_snapshot::to_digest;
@@ -0,0 +1,55 @@
+use crate::domain::aggregate::{AvailableBalance, JournalDraft, PendingJournal, PendingStatus};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{AvailableBalanceRow, PendingJournalLineRow, PendingJournalRow};
+
+impl PendingJournalRow {
+    pub fn to_domain(&self, lines: &[PendingJournalLineRow]) -> Result<PendingJournal, RepoError> {
+        let id = self.id;
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid pending journal in db (pending_id={id}): {e}"),
+        };
+
+        let mut draft = JournalDraft::new(
+            PublicId::new(self.public_id),
+            ExternalRefType::from_code(&self.external_ref_type).map_err(integrity)?,
+            ExternalRef::new(self.external_ref.clone()).map_err(integrity)?,
+            self.created_by.clone(),
+            self.description.clone(),
+        )
+            .map_err(integrity)?;
+        draft.metadata = JournalMetadata::new(self.metadata.0.clone()).map_err(integrity)?;
+        for t in &self.tags {
+            draft.add_tag(JournalTag::new(t.clone()).map_err(integrity)?);
+        }
+        for l in lines {
+            let minor = bigdecimal_to_i128(&l.amount)?;
+            draft.add_line_with_memo(l.account_id, Money::from_signed_minor(minor).map_err(integrity)?, l.memo.clone());
+        }
+
+        Ok(PendingJournal::restore(
+            id,
+            draft,
+            self.asset_id,
+            PendingStatus::from_code(&self.status).map_err(integrity)?,
+            self.expires_at,
+            self.committed_amount.as_ref().map(bigdecimal_to_i128).transpose()?,
+            self.posted_journal_tx_id,
+            self.status_changed_at,
+            self.status_changed_by.clone(),
+        ))
+    }
+}
+
+impl AvailableBalanceRow {
+    pub fn to_domain(&self) -> Result<AvailableBalance, RepoError> {
+        Ok(AvailableBalance {
+            account_id: self.account_id,
+            asset_id: self.asset_id,
+            posted_minor: bigdecimal_to_i128(&self.balance)?,
+            pending_inflow_minor: bigdecimal_to_i128(&self.pending_inflow)?,
+            pending_outflow_minor: bigdecimal_to_i128(&self.pending_outflow)?,
+        })
+    }
+}
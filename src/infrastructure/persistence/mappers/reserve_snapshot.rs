@@ -0,0 +1,44 @@
+use crate::domain::aggregate::{NewReserveSnapshot, OwnerLiability, ReserveLeaf, ReserveSnapshot};
+use crate::domain::repository::RepoError;
+use crate::domain::services::{Digest, MerkleNode};
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{OwnerLiabilityRow, ReserveLeafRow, ReserveSnapshotRow};
+
+pub fn to_digest(bytes: &[u8], what: &str) -> Result<Digest, RepoError> {
+    bytes.try_into().map_err(|_| RepoError::Integrity { message: format!("{what} in db is not a 32-byte digest") })
+}
+
+impl ReserveLeafRow {
+    pub fn to_domain(&self) -> Result<ReserveLeaf, RepoError> {
+        Ok(ReserveLeaf {
+            owner_id: self.owner_id,
+            owner_hash: to_digest(&self.owner_hash, "reserve leaf owner hash")?,
+            balance_minor: bigdecimal_to_i128(&self.balance)?,
+        })
+    }
+}
+
+impl ReserveSnapshotRow {
+    pub fn to_domain(&self, leaves: &[ReserveLeafRow]) -> Result<ReserveSnapshot, RepoError> {
+        let spec = NewReserveSnapshot {
+            public_id: PublicId::new(self.public_id),
+            asset_id: self.asset_id,
+            taken_at: self.taken_at,
+            salt: self.salt.clone(),
+            leaves: leaves.iter().map(ReserveLeafRow::to_domain).collect::<Result<_, _>>()?,
+            root: MerkleNode {
+                hash: to_digest(&self.root_hash, "reserve snapshot root hash")?,
+                sum: bigdecimal_to_i128(&self.total)?,
+            },
+            created_by: self.created_by.clone(),
+        };
+        Ok(ReserveSnapshot::restore(self.id, spec))
+    }
+}
+
+impl OwnerLiabilityRow {
+    pub fn to_domain(&self) -> Result<OwnerLiability, RepoError> {
+        Ok(OwnerLiability { owner_id: self.owner_id, balance_minor: bigdecimal_to_i128(&self.balance)? })
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::aggregate::{JournalDraft, Recurrence, ScheduleStatus, ScheduledJournal, ScheduledJournalRun};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{
+    ScheduledJournalLineRow, ScheduledJournalRow, ScheduledJournalRunRow,
+};
+
+impl ScheduledJournalRow {
+    pub fn to_domain(&self, lines: &[ScheduledJournalLineRow]) -> Result<ScheduledJournal, RepoError> {
+        let id = self.id;
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid scheduled journal in db (schedule_id={id}): {e}"),
+        };
+        let counter = |name: &str, v: i32| {
+            u32::try_from(v).map_err(|_| RepoError::Integrity {
+                message: format!("invalid {name} for schedule_id={id}"),
+            })
+        };
+
+        let mut template = JournalDraft::new(
+            PublicId::new(self.public_id),
+            ExternalRefType::from_code(&self.external_ref_type).map_err(integrity)?,
+            ExternalRef::new(self.external_ref.clone()).map_err(integrity)?,
+            self.created_by.clone(),
+            self.description.clone(),
+        )
+            .map_err(integrity)?;
+        template.metadata = JournalMetadata::new(self.metadata.0.clone()).map_err(integrity)?;
+        template.multi_asset = self.multi_asset;
+        for t in &self.tags {
+            template.add_tag(JournalTag::new(t.clone()).map_err(integrity)?);
+        }
+        for l in lines {
+            let minor = bigdecimal_to_i128(&l.amount)?;
+            template.add_line_with_memo(l.account_id, Money::from_signed_minor(minor).map_err(integrity)?, l.memo.clone());
+        }
+
+        Ok(ScheduledJournal::restore(
+            id,
+            template,
+            Recurrence::from_parts(&self.recurrence, self.interval_seconds).map_err(integrity)?,
+            self.starts_at,
+            self.ends_at,
+            self.max_occurrences.map(|m| counter("max_occurrences", m)).transpose()?,
+            ScheduleStatus::from_code(&self.status).map_err(integrity)?,
+            counter("next_occurrence", self.next_occurrence)?,
+            self.next_run_at,
+            self.due_at,
+            counter("posted_count", self.posted_count)?,
+            counter("consecutive_failures", self.consecutive_failures)?,
+            self.last_error.clone(),
+            self.status_changed_at,
+            self.status_changed_by.clone(),
+        ))
+    }
+}
+
+impl ScheduledJournalRunRow {
+    pub fn to_domain(&self) -> Result<ScheduledJournalRun, RepoError> {
+        Ok(ScheduledJournalRun {
+            occurrence: u32::try_from(self.occurrence).map_err(|_| RepoError::Integrity {
+                message: format!("invalid occurrence in scheduled_journal_runs: {}", self.occurrence),
+            })?,
+            scheduled_for: self.scheduled_for,
+            journal_id: self.journal_tx_id,
+            posted_at: self.posted_at,
+        })
+    }
+}
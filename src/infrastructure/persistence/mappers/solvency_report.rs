@@ -0,0 +1,47 @@
+use crate::domain::aggregate::{NewSolvencyReport, SolvencyBalances, SolvencyReport, SolvencyStatus, SolvencyTrigger};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::{SolvencyBalancesRow, SolvencyReportRow};
+
+impl SolvencyBalancesRow {
+    pub fn to_domain(&self) -> Result<SolvencyBalances, RepoError> {
+        Ok(SolvencyBalances {
+            user_available: bigdecimal_to_i128(&self.user_available)?,
+            user_locked: bigdecimal_to_i128(&self.user_locked)?,
+            treasury_available: bigdecimal_to_i128(&self.treasury_available)?,
+            treasury_locked: bigdecimal_to_i128(&self.treasury_locked)?,
+            platform_clearing: bigdecimal_to_i128(&self.platform_clearing)?,
+        })
+    }
+}
+
+impl SolvencyReportRow {
+    pub fn to_domain(&self) -> Result<SolvencyReport, RepoError> {
+        let integrity = |e: crate::domain::error::DomainError| RepoError::Integrity {
+            message: format!("invalid solvency report in db (solvency_report_id={}): {e}", self.id),
+        };
+        let trigger = SolvencyTrigger::from_code(&self.trigger).map_err(integrity)?;
+        let status = SolvencyStatus::from_code(&self.status).map_err(integrity)?;
+
+        let spec = NewSolvencyReport {
+            public_id: PublicId::new(self.public_id),
+            asset_id: self.asset_id,
+            as_of: self.as_of,
+            trigger,
+            balances: SolvencyBalances {
+                user_available: bigdecimal_to_i128(&self.user_available)?,
+                user_locked: bigdecimal_to_i128(&self.user_locked)?,
+                treasury_available: bigdecimal_to_i128(&self.treasury_available)?,
+                treasury_locked: bigdecimal_to_i128(&self.treasury_locked)?,
+                platform_clearing: bigdecimal_to_i128(&self.platform_clearing)?,
+            },
+            liabilities_minor: bigdecimal_to_i128(&self.liabilities)?,
+            holdings_minor: bigdecimal_to_i128(&self.holdings)?,
+            coverage_bps: self.coverage_bps.as_ref().map(bigdecimal_to_i128).transpose()?,
+            status,
+            requested_by: self.requested_by.clone(),
+        };
+        Ok(SolvencyReport::restore(self.id, spec, self.alerted_at))
+    }
+}
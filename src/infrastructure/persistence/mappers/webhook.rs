@@ -0,0 +1,81 @@
+use crate::domain::aggregate::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookAttempt, WebhookDelivery, WebhookDeliveryState,
+    WebhookDeliveryStatus, WebhookEventType, WebhookSubscription,
+};
+use crate::domain::error::DomainError;
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::models::{WebhookAttemptRow, WebhookDeliveryRow, WebhookSubscriptionRow};
+
+fn count(value: i32, what: &str, id: i64) -> Result<u32, RepoError> {
+    u32::try_from(value).map_err(|_| RepoError::Integrity {
+        message: format!("negative {what} in db (webhook_delivery_id={id}): {value}"),
+    })
+}
+
+impl WebhookSubscriptionRow {
+    pub fn to_domain(&self) -> Result<WebhookSubscription, RepoError> {
+        let event_types = self
+            .event_types
+            .iter()
+            .map(|t| WebhookEventType::from_code(t))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepoError::Integrity {
+                message: format!("invalid webhook subscription in db (webhook_subscription_id={}): {e}", self.id),
+            })?;
+
+        let spec = NewWebhookSubscription {
+            public_id: PublicId::new(self.public_id),
+            owner_id: self.owner_id,
+            url: self.url.clone(),
+            secret: self.secret.clone(),
+            event_types,
+            created_by: self.created_by.clone(),
+            created_at: self.created_at,
+        };
+        Ok(WebhookSubscription::restore(self.id, spec, self.active, self.updated_at))
+    }
+}
+
+impl WebhookDeliveryRow {
+    pub fn to_domain(&self) -> Result<WebhookDelivery, RepoError> {
+        let integrity = |e: DomainError| RepoError::Integrity {
+            message: format!("invalid webhook delivery in db (webhook_delivery_id={}): {e}", self.id),
+        };
+        let spec = NewWebhookDelivery {
+            public_id: PublicId::new(self.public_id),
+            subscription_id: self.subscription_id,
+            event_id: self.event_id.clone(),
+            event_type: WebhookEventType::from_code(&self.event_type).map_err(integrity)?,
+            payload: self.payload.clone(),
+            created_at: self.created_at,
+        };
+        let state = WebhookDeliveryState {
+            status: WebhookDeliveryStatus::from_code(&self.status).map_err(integrity)?,
+            attempts: count(self.attempts, "attempts", self.id)?,
+            failures: count(self.failures, "failures", self.id)?,
+            next_attempt_at: self.next_attempt_at,
+            last_error: self.last_error.clone(),
+            delivered_at: self.delivered_at,
+        };
+        Ok(WebhookDelivery::restore(self.id, spec, state))
+    }
+}
+
+impl WebhookAttemptRow {
+    pub fn to_domain(&self) -> Result<WebhookAttempt, RepoError> {
+        let status_code = self
+            .status_code
+            .map(u16::try_from)
+            .transpose()
+            .map_err(|_| RepoError::Integrity {
+                message: format!("invalid status code in db (webhook_delivery_id={}): {:?}", self.delivery_id, self.status_code),
+            })?;
+        Ok(WebhookAttempt {
+            attempt: count(self.attempt, "attempt", self.delivery_id)?,
+            attempted_at: self.attempted_at,
+            status_code,
+            error: self.error.clone(),
+        })
+    }
+}
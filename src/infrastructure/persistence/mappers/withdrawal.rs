@@ -0,0 +1,32 @@
+use crate::domain::aggregate::{NewWithdrawal, Withdrawal, WithdrawalStatus};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::mappers::journal::bigdecimal_to_i128;
+use crate::infrastructure::persistence::models::WithdrawalRow;
+
+impl WithdrawalRow {
+    pub fn to_domain(&self) -> Result<Withdrawal, RepoError> {
+        let status = WithdrawalStatus::from_code(&self.status).map_err(|e| RepoError::Integrity {
+            message: format!("invalid withdrawal in db (withdrawal_id={}): {e}", self.id),
+        })?;
+
+        let spec = NewWithdrawal {
+            public_id: PublicId::new(self.public_id),
+            owner_id: self.owner_id,
+            asset_id: self.asset_id,
+            amount_minor: bigdecimal_to_i128(&self.amount)?,
+            destination: self.destination.clone(),
+            created_by: self.created_by.clone(),
+        };
+
+        Ok(Withdrawal::restore(
+            self.id,
+            spec,
+            status,
+            self.rail_reference.clone(),
+            self.failure_reason.clone(),
+            self.status_changed_at,
+            self.status_changed_by.clone(),
+        ))
+    }
+}
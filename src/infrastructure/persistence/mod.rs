@@ -1,6 +1,21 @@
 pub mod ledger;
+pub mod fee_schedule;
+pub mod manual_adjustment;
+pub mod chart_of_accounts;
+pub mod accounting_period;
+pub mod scheduled_journal;
+mod pending_journal;
+pub mod hold;
+pub mod withdrawal;
+pub mod deposit;
+pub mod fx;
+pub mod fx_revaluation;
+pub mod reserve_snapshot;
+pub mod solvency_report;
+pub mod feed_cursor;
+pub mod webhook;
 mod postgres;
 mod mappers;
 pub mod models;
 mod error_map;
-mod uow;
\ No newline at end of file
+mod uow;
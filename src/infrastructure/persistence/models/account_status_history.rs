@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountStatusHistoryRow {
+    pub account_id: i64,
+    pub from_status: String,
+    pub to_status: String,
+    pub was_active: bool,
+    pub is_active: bool,
+    pub reason: String,
+    pub note: Option<String>,
+    pub actor: String,
+    pub changed_at: DateTime<Utc>,
+}
@@ -0,0 +1,21 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountingPeriodRow {
+    pub id: i64,
+    pub asset_id: i16,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: String, // 'OPEN' | 'SOFT_CLOSED' | 'CLOSED'
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ClosingBalanceRow {
+    pub account_id: i64,
+    pub balance: BigDecimal,
+}
@@ -0,0 +1,9 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AssetRow {
+    pub id: i16,
+    pub code: String,
+    pub decimals: i16,
+    pub is_active: bool,
+}
@@ -0,0 +1,27 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct CoaNodeRow {
+    pub id: i64,
+    pub code: String,
+    pub name: String,
+    pub gl_class: String,       // 'ASSET' | 'LIABILITY' | 'EQUITY' | 'REVENUE' | 'EXPENSE'
+    pub normal_balance: String, // 'DEBIT' | 'CREDIT'
+    pub parent_id: Option<i64>,
+}
+
+/// A ledger amount aggregated per chart node.
+#[derive(Debug, Clone, FromRow)]
+pub struct NodeAmountRow {
+    pub coa_node_id: i64,
+    pub amount: BigDecimal,
+}
+
+/// Positive and negative ledger lines summed separately per chart node.
+#[derive(Debug, Clone, FromRow)]
+pub struct NodeFlowsRow {
+    pub coa_node_id: i64,
+    pub inflow: BigDecimal,
+    pub outflow: BigDecimal,
+}
@@ -0,0 +1,22 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DepositIntentRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub amount: BigDecimal,
+    pub tx_reference: String,
+    pub required_confirmations: i32,
+    pub source: String, // 'PLATFORM_CLEARING' | 'TREASURY'
+    pub confirmations: i32,
+    pub status: String, // 'PENDING' | 'CREDITED'
+    pub journal_tx_id: Option<i64>,
+    pub created_by: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub credited_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,23 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FeeRuleRow {
+    pub id: i64,
+    pub asset_id: i16,
+    pub posting_kind: String, // external_ref_type code
+    pub calculation: String,  // 'FLAT' | 'PERCENTAGE' | 'TIERED'
+    pub flat_amount: Option<BigDecimal>,
+    pub rate_bps: Option<i32>,
+    pub min_fee: Option<BigDecimal>,
+    pub max_fee: Option<BigDecimal>,
+    pub rounding: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FeeRuleTierRow {
+    pub fee_rule_id: i64,
+    pub up_to: Option<BigDecimal>,
+    pub rate_bps: i32,
+    pub flat_amount: BigDecimal,
+}
@@ -0,0 +1,39 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FxRateRow {
+    pub id: i64,
+    pub base_asset_id: i16,
+    pub quote_asset_id: i16,
+    pub rate: BigDecimal,
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+    pub source: String,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FxQuoteRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub owner_id: Uuid,
+    pub from_asset_id: i16,
+    pub to_asset_id: i16,
+    pub rate_id: i64,
+    pub mid_rate: BigDecimal,
+    pub spread_bps: i32,
+    pub applied_rate: BigDecimal,
+    pub from_amount: BigDecimal,
+    pub to_amount: BigDecimal,
+    pub liquidity: String, // 'TREASURY' | 'INVENTORY'
+    pub expires_at: DateTime<Utc>,
+    pub status: String, // 'OPEN' | 'EXECUTED'
+    pub sell_journal_id: Option<i64>,
+    pub buy_journal_id: Option<i64>,
+    pub created_by: String,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub executed_by: Option<String>,
+}
@@ -0,0 +1,40 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FxRevaluationRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub reporting_asset_id: i16,
+    pub period_id: i64,
+    pub valued_at: DateTime<Utc>,
+    pub status: String, // 'POSTED' | 'REVERSED'
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub reversed_at: Option<DateTime<Utc>>,
+    pub reversed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FxRevaluationLineRow {
+    pub asset_id: i16,
+    pub balance: BigDecimal,
+    pub opening_rate_id: i64,
+    pub opening_rate: BigDecimal,
+    pub closing_rate_id: i64,
+    pub closing_rate: BigDecimal,
+    pub opening_value: BigDecimal,
+    pub closing_value: BigDecimal,
+    pub gain: BigDecimal,
+    pub journal_id: Option<i64>,
+    pub reversal_journal_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RevaluationPositionRow {
+    pub asset_id: i16,
+    pub decimals: i16,
+    pub balance: BigDecimal,
+}
@@ -0,0 +1,35 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct HoldRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub available_account_id: i64,
+    pub locked_account_id: i64,
+    pub amount: BigDecimal,
+    pub captured_amount: BigDecimal,
+    pub released_amount: BigDecimal,
+    pub status: String, // 'ACTIVE' | 'CAPTURED' | 'RELEASED' | 'EXPIRED'
+    pub expires_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub journal_count: i32,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct HoldJournalRow {
+    pub seq: i32,
+    pub action: String,
+    pub amount: BigDecimal,
+    pub journal_tx_id: i64,
+    pub counterparty_account_id: i64,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,16 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChainLinkRow {
+    pub position: i64,
+    pub journal_tx_id: i64,
+    pub prev_hash: Vec<u8>,
+    pub entry_hash: Vec<u8>,
+    pub journal_exists: bool,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChainHeadRow {
+    pub position: i64,
+    pub entry_hash: Vec<u8>,
+}
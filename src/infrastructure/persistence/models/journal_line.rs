@@ -4,5 +4,7 @@ use sqlx::FromRow;
 #[derive(Debug, Clone, FromRow)]
 pub struct JournalLineRow {
     pub account_id: i64,
+    pub asset_id: i16,
     pub amount: BigDecimal, // numeric(38,0)
+    pub memo: Option<String>,
 }
@@ -1,4 +1,8 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
 use sqlx::FromRow;
+use sqlx::types::Json;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, FromRow)]
@@ -9,4 +13,12 @@ pub struct JournalTxRow {
     pub external_ref: String,
     pub description: Option<String>,
     pub created_by: String,
+    pub fee_rule_ids: Vec<i64>,
+    pub metadata: Json<BTreeMap<String, String>>,
+    pub tags: Vec<String>,
+    pub effective_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub multi_asset: bool,
+    /// Set inside the posting transaction, so never null once committed.
+    pub ledger_seq: Option<i64>,
 }
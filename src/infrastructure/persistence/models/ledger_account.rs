@@ -10,4 +10,5 @@ pub struct LedgerAccountRow {
     pub account_type: String, // 'USER_AVAILABLE' etc
     pub asset_id: i16,
     pub is_active: bool,
+    pub status: String,       // 'ACTIVE' | 'FROZEN' | 'CREDIT_ONLY' | 'DEBIT_ONLY' | 'CLOSED'
 }
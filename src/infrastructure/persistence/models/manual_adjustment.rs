@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ManualAdjustmentRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub asset_id: i16,
+    pub gross_amount: BigDecimal,
+    pub status: String, // 'PENDING_APPROVAL' | 'REJECTED' | 'POSTED'
+    pub required_approvals: i16,
+    pub rejection_reason: Option<String>,
+    pub posted_journal_tx_id: Option<i64>,
+    pub effective_at: Option<DateTime<Utc>>,
+    pub metadata: Json<BTreeMap<String, String>>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ManualAdjustmentLineRow {
+    pub account_id: i64,
+    pub amount: BigDecimal,
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ManualAdjustmentAuditRow {
+    pub action: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub at: DateTime<Utc>,
+}
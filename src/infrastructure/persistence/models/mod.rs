@@ -1,9 +1,45 @@
 mod ledger_account;
+mod asset;
 mod journal_tx;
 mod journal_line;
+mod fee_rule;
+mod manual_adjustment;
+mod account_status_history;
+mod coa_node;
+mod accounting_period;
+mod scheduled_journal;
+mod pending_journal;
+mod hold;
+mod withdrawal;
+mod deposit;
+mod fx;
+mod fx_revaluation;
+mod reserve_snapshot;
+mod solvency_report;
+mod journal_chain;
+mod feed_cursor;
+mod webhook;
 
 pub use self::{
     journal_line::JournalLineRow,
     journal_tx::JournalTxRow,
     ledger_account::LedgerAccountRow,
-};
\ No newline at end of file
+    asset::AssetRow,
+    fee_rule::{FeeRuleRow, FeeRuleTierRow},
+    manual_adjustment::{ManualAdjustmentAuditRow, ManualAdjustmentLineRow, ManualAdjustmentRow},
+    account_status_history::AccountStatusHistoryRow,
+    coa_node::{CoaNodeRow, NodeAmountRow, NodeFlowsRow},
+    accounting_period::{AccountingPeriodRow, ClosingBalanceRow},
+    scheduled_journal::{ScheduledJournalLineRow, ScheduledJournalRow, ScheduledJournalRunRow},
+    pending_journal::{AvailableBalanceRow, PendingJournalLineRow, PendingJournalRow},
+    hold::{HoldJournalRow, HoldRow},
+    withdrawal::WithdrawalRow,
+    deposit::DepositIntentRow,
+    fx::{FxQuoteRow, FxRateRow},
+    fx_revaluation::{FxRevaluationLineRow, FxRevaluationRow, RevaluationPositionRow},
+    reserve_snapshot::{OwnerLiabilityRow, ReserveLeafRow, ReserveSnapshotRow},
+    solvency_report::{SolvencyBalancesRow, SolvencyReportRow},
+    journal_chain::{ChainHeadRow, ChainLinkRow},
+    feed_cursor::FeedCursorRow,
+    webhook::{WebhookAttemptRow, WebhookDeliveryRow, WebhookSubscriptionRow},
+};
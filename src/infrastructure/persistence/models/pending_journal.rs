@@ -0,0 +1,42 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PendingJournalRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub external_ref_type: String,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub metadata: Json<BTreeMap<String, String>>,
+    pub tags: Vec<String>,
+    pub asset_id: i16,
+    pub status: String, // 'PENDING' | 'COMMITTED' | 'VOIDED' | 'EXPIRED'
+    pub expires_at: DateTime<Utc>,
+    pub committed_amount: Option<BigDecimal>,
+    pub posted_journal_tx_id: Option<i64>,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PendingJournalLineRow {
+    pub account_id: i64,
+    pub amount: BigDecimal,
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AvailableBalanceRow {
+    pub account_id: i64,
+    pub asset_id: i16,
+    pub balance: BigDecimal,
+    pub pending_inflow: BigDecimal,
+    pub pending_outflow: BigDecimal,
+}
@@ -0,0 +1,29 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReserveSnapshotRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub asset_id: i16,
+    pub taken_at: DateTime<Utc>,
+    pub salt: Vec<u8>,
+    pub root_hash: Vec<u8>,
+    pub total: BigDecimal,
+    pub created_by: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReserveLeafRow {
+    pub owner_id: Uuid,
+    pub owner_hash: Vec<u8>,
+    pub balance: BigDecimal,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OwnerLiabilityRow {
+    pub owner_id: Uuid,
+    pub balance: BigDecimal,
+}
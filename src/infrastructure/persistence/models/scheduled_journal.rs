@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ScheduledJournalRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub external_ref_type: String,
+    pub external_ref: String,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub metadata: Json<BTreeMap<String, String>>,
+    pub tags: Vec<String>,
+    pub multi_asset: bool,
+    pub recurrence: String, // 'ONCE' | 'INTERVAL' | 'MONTHLY'
+    pub interval_seconds: Option<i64>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub max_occurrences: Option<i32>,
+    pub status: String, // 'ACTIVE' | 'PAUSED' | 'COMPLETED' | 'CANCELLED'
+    pub next_occurrence: i32,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub posted_count: i32,
+    pub consecutive_failures: i32,
+    pub last_error: Option<String>,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ScheduledJournalLineRow {
+    pub account_id: i64,
+    pub amount: BigDecimal,
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ScheduledJournalRunRow {
+    pub occurrence: i32,
+    pub scheduled_for: DateTime<Utc>,
+    pub journal_tx_id: i64,
+    pub posted_at: DateTime<Utc>,
+}
@@ -0,0 +1,33 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SolvencyReportRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub asset_id: i16,
+    pub as_of: DateTime<Utc>,
+    pub trigger: String,
+    pub status: String,
+    pub user_available: BigDecimal,
+    pub user_locked: BigDecimal,
+    pub treasury_available: BigDecimal,
+    pub treasury_locked: BigDecimal,
+    pub platform_clearing: BigDecimal,
+    pub liabilities: BigDecimal,
+    pub holdings: BigDecimal,
+    pub coverage_bps: Option<BigDecimal>,
+    pub requested_by: String,
+    pub alerted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SolvencyBalancesRow {
+    pub user_available: BigDecimal,
+    pub user_locked: BigDecimal,
+    pub treasury_available: BigDecimal,
+    pub treasury_locked: BigDecimal,
+    pub platform_clearing: BigDecimal,
+}
@@ -0,0 +1,20 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WithdrawalRow {
+    pub id: i64,
+    pub public_id: Uuid,
+    pub owner_id: Uuid,
+    pub asset_id: i16,
+    pub amount: BigDecimal,
+    pub destination: String,
+    pub status: String, // 'REQUESTED' | 'HELD' | 'SUBMITTED' | 'CONFIRMED' | 'FAILED'
+    pub rail_reference: Option<String>,
+    pub failure_reason: Option<String>,
+    pub created_by: String,
+    pub status_changed_at: Option<DateTime<Utc>>,
+    pub status_changed_by: Option<String>,
+}
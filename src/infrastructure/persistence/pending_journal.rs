@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{PgConnection, Postgres, Transaction};
+
+use crate::application::contracts::repository::PendingJournalRepository;
+use crate::domain::aggregate::{AvailableBalance, NewPendingJournal, PendingJournal};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::ledger::PgLedgerRepository;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{AvailableBalanceRow, PendingJournalLineRow, PendingJournalRow};
+
+const PENDING_COLUMNS: &str = "id, public_id, external_ref_type, external_ref, description, created_by, metadata, \
+     tags, asset_id, status, expires_at, committed_amount, posted_journal_tx_id, status_changed_at, status_changed_by";
+
+impl PgLedgerRepository {
+    async fn pending_with_lines(
+        conn: &mut PgConnection,
+        row: PendingJournalRow,
+    ) -> Result<PendingJournal, RepoError> {
+        let lines = sqlx::query_as::<_, PendingJournalLineRow>(
+            r#"
+            SELECT account_id, amount, memo
+            FROM pending_journal_lines
+            WHERE pending_id = $1
+            ORDER BY position
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain(&lines)
+    }
+
+    async fn load_pending(
+        conn: &mut PgConnection,
+        filter: &str,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<PendingJournal>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let Some(row) = sqlx::query_as::<_, PendingJournalRow>(&format!(
+            "SELECT {PENDING_COLUMNS} FROM pending_journals WHERE {filter} {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::pending_with_lines(conn, row).await?))
+    }
+
+    /// Adds (`sign` = 1) or removes (`sign` = -1) a reservation on the pending columns;
+    /// callers hold the balance row locks.
+    async fn adjust_reservations_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        reservation: &HashMap<i64, i128>,
+        sign: i128,
+    ) -> Result<(), RepoError> {
+        let mut account_ids = Vec::with_capacity(reservation.len());
+        let mut inflows: Vec<BigDecimal> = Vec::with_capacity(reservation.len());
+        let mut outflows: Vec<BigDecimal> = Vec::with_capacity(reservation.len());
+        for (account_id, d) in reservation {
+            account_ids.push(*account_id);
+            inflows.push(i128_to_bigdecimal(sign * (*d).max(0)));
+            outflows.push(i128_to_bigdecimal(sign * (-*d).max(0)));
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE ledger_account_balances b
+            SET pending_inflow  = b.pending_inflow + x.inflow,
+                pending_outflow = b.pending_outflow + x.outflow
+            FROM UNNEST($1::bigint[], $2::numeric[], $3::numeric[]) AS x(account_id, inflow, outflow)
+            WHERE b.account_id = x.account_id
+            "#,
+        )
+            .bind(&account_ids)
+            .bind(&inflows)
+            .bind(&outflows)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    fn sorted_account_ids(reservation: &HashMap<i64, i128>) -> Vec<i64> {
+        let mut ids: Vec<i64> = reservation.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+#[async_trait]
+impl PendingJournalRepository for PgLedgerRepository {
+    async fn authorize_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        spec: &NewPendingJournal,
+    ) -> Result<PendingJournal, RepoError> {
+        let d = &spec.draft;
+
+        let posted = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM journal_transactions
+                WHERE external_ref_type = $1 AND external_ref = $2
+            )
+            "#,
+        )
+            .bind(d.external_ref_type.as_code())
+            .bind(d.external_ref.as_str())
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+        if posted {
+            return Err(RepoError::Conflict {
+                message: format!("external ref already posted ({}:{})", d.external_ref_type.as_code(), d.external_ref.as_str()),
+            });
+        }
+
+        // Same idempotency rule as the journal header: a replayed ref gets the stored row back.
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO pending_journals
+                (public_id, external_ref_type, external_ref, description, created_by, metadata, tags,
+                 asset_id, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (external_ref_type, external_ref) DO NOTHING
+            RETURNING id
+            "#,
+        )
+            .bind(d.public_id.value())
+            .bind(d.external_ref_type.as_code())
+            .bind(d.external_ref.as_str())
+            .bind(&d.description)
+            .bind(&d.created_by)
+            .bind(Json(d.metadata.as_map()))
+            .bind(d.tags().iter().map(|g| g.as_str()).collect::<Vec<_>>())
+            .bind(spec.asset_id)
+            .bind(spec.expires_at)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let Some(id) = id else {
+            let row = sqlx::query_as::<_, PendingJournalRow>(&format!(
+                "SELECT {PENDING_COLUMNS} FROM pending_journals WHERE external_ref_type = $1 AND external_ref = $2"
+            ))
+                .bind(d.external_ref_type.as_code())
+                .bind(d.external_ref.as_str())
+                .fetch_one(&mut **tx)
+                .await
+                .map_err(map_sqlx)?;
+            return Self::pending_with_lines(tx, row).await;
+        };
+
+        let positions: Vec<i16> = (0..d.lines().len() as i16).collect();
+        let line_accounts: Vec<i64> = d.lines().iter().map(|l| l.account_id).collect();
+        let amounts: Vec<BigDecimal> = d.lines().iter().map(|l| i128_to_bigdecimal(l.amount.minor())).collect();
+        let memos: Vec<Option<String>> = d.lines().iter().map(|l| l.memo.clone()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO pending_journal_lines (pending_id, position, account_id, amount, memo)
+            SELECT $1, x.position, x.account_id, x.amount, x.memo
+            FROM UNNEST($2::smallint[], $3::bigint[], $4::numeric[], $5::text[])
+                AS x(position, account_id, amount, memo)
+            "#,
+        )
+            .bind(id)
+            .bind(&positions)
+            .bind(&line_accounts)
+            .bind(&amounts)
+            .bind(&memos)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let pending = Self::load_pending(tx, "public_id = $1", d.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("pending_journal id={id}") })?;
+
+        // Reserve under the same locks and checks a posting of the full amount would take.
+        let reservation = pending.reservation();
+        let account_ids = Self::sorted_account_ids(&reservation);
+        let locked = Self::lock_active_accounts_tx(tx, &account_ids).await?;
+        Self::ensure_single_asset(tx, &account_ids).await?;
+        let current = Self::lock_and_fetch_balances(tx, &account_ids).await?;
+        let reserved = Self::fetch_pending_outflows_tx(tx, &account_ids).await?;
+        Self::check_deltas(&locked, &current, &reserved, &reservation)?;
+        Self::adjust_reservations_tx(tx, &reservation, 1).await?;
+
+        Ok(pending)
+    }
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<PendingJournal>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load_pending(&mut conn, "public_id = $1", public_id, false).await
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<PendingJournal>, RepoError> {
+        Self::load_pending(tx, "public_id = $1", public_id, true).await
+    }
+
+    async fn lock_expired_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<PendingJournal>, RepoError> {
+        let rows = sqlx::query_as::<_, PendingJournalRow>(&format!(
+            r#"
+            SELECT {PENDING_COLUMNS}
+            FROM pending_journals
+            WHERE status = 'PENDING' AND expires_at <= $1
+            ORDER BY expires_at, id
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+            "#
+        ))
+            .bind(now)
+            .bind(limit as i64)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(Self::pending_with_lines(tx, row).await?);
+        }
+        Ok(out)
+    }
+
+    async fn release_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pending: &PendingJournal,
+    ) -> Result<(), RepoError> {
+        // Account rows first, then balances: the order postings lock in. Releasing does not
+        // depend on account status, so frozen or closed accounts still get their funds back.
+        let reservation = pending.reservation();
+        let account_ids = Self::sorted_account_ids(&reservation);
+        Self::fetch_accounts_for_update(tx, &account_ids).await?;
+        Self::lock_and_fetch_balances(tx, &account_ids).await?;
+        Self::adjust_reservations_tx(tx, &reservation, -1).await
+    }
+
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pending: &PendingJournal,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE pending_journals
+            SET status = $2,
+                committed_amount = $3,
+                posted_journal_tx_id = $4,
+                status_changed_at = $5,
+                status_changed_by = $6
+            WHERE id = $1
+            "#,
+        )
+            .bind(pending.id())
+            .bind(pending.status().as_code())
+            .bind(pending.committed_amount_minor().map(i128_to_bigdecimal))
+            .bind(pending.posted_journal_id())
+            .bind(pending.status_changed_at())
+            .bind(pending.status_changed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn available_balance(&self, account_id: i64) -> Result<Option<AvailableBalance>, RepoError> {
+        let row = sqlx::query_as::<_, AvailableBalanceRow>(
+            r#"
+            SELECT b.account_id, a.asset_id, b.balance, b.pending_inflow, b.pending_outflow
+            FROM ledger_account_balances b
+            JOIN ledger_accounts a ON a.id = b.account_id
+            WHERE b.account_id = $1
+            "#,
+        )
+            .bind(account_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(AvailableBalanceRow::to_domain).transpose()
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use sqlx::{PgConnection, PgPool};
+
+use crate::application::contracts::repository::ReserveSnapshotRepository;
+use crate::domain::aggregate::{NewReserveSnapshot, OwnerLiability, ReserveSnapshot, LIABILITY_ACCOUNT_TYPES};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{OwnerLiabilityRow, ReserveLeafRow, ReserveSnapshotRow};
+
+const SNAPSHOT_COLUMNS: &str = "id, public_id, asset_id, taken_at, salt, root_hash, total, created_by";
+
+pub struct PgReserveSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PgReserveSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn with_leaves(conn: &mut PgConnection, row: ReserveSnapshotRow) -> Result<ReserveSnapshot, RepoError> {
+        let leaves = sqlx::query_as::<_, ReserveLeafRow>(
+            r#"
+            SELECT owner_id, owner_hash, balance
+            FROM reserve_snapshot_leaves
+            WHERE snapshot_id = $1
+            ORDER BY position
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain(&leaves)
+    }
+
+    async fn load(conn: &mut PgConnection, public_id: PublicId) -> Result<Option<ReserveSnapshot>, RepoError> {
+        let Some(row) = sqlx::query_as::<_, ReserveSnapshotRow>(&format!(
+            "SELECT {SNAPSHOT_COLUMNS} FROM reserve_snapshots WHERE public_id = $1"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::with_leaves(conn, row).await?))
+    }
+}
+
+#[async_trait]
+impl ReserveSnapshotRepository for PgReserveSnapshotRepository {
+    async fn owner_liabilities(&self, asset_id: i16) -> Result<Vec<OwnerLiability>, RepoError> {
+        let account_types: Vec<&str> = LIABILITY_ACCOUNT_TYPES.iter().map(|t| t.as_str()).collect();
+        let rows = sqlx::query_as::<_, OwnerLiabilityRow>(
+            r#"
+            SELECT a.owner_id, SUM(b.balance) AS balance
+            FROM ledger_accounts a
+            JOIN ledger_account_balances b ON b.account_id = a.id
+            WHERE a.asset_id = $1
+              AND a.owner_type = 'USER'
+              AND a.owner_id IS NOT NULL
+              AND a.account_type = ANY($2::text[])
+            GROUP BY a.owner_id
+            ORDER BY a.owner_id
+            "#,
+        )
+            .bind(asset_id)
+            .bind(&account_types)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(OwnerLiabilityRow::to_domain).collect()
+    }
+
+    async fn insert(&self, spec: &NewReserveSnapshot) -> Result<ReserveSnapshot, RepoError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO reserve_snapshots (public_id, asset_id, taken_at, salt, root_hash, total, leaf_count, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (public_id) DO NOTHING
+            RETURNING id
+            "#,
+        )
+            .bind(spec.public_id.value())
+            .bind(spec.asset_id)
+            .bind(spec.taken_at)
+            .bind(&spec.salt)
+            .bind(spec.root.hash.as_slice())
+            .bind(i128_to_bigdecimal(spec.root.sum))
+            .bind(i32::try_from(spec.leaves.len()).map_err(|_| RepoError::Integrity {
+                message: format!("too many reserve leaves: {}", spec.leaves.len()),
+            })?)
+            .bind(&spec.created_by)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        if let Some(id) = id {
+            let positions: Vec<i32> = (0..spec.leaves.len() as i32).collect();
+            let owner_ids: Vec<uuid::Uuid> = spec.leaves.iter().map(|l| l.owner_id).collect();
+            let owner_hashes: Vec<Vec<u8>> = spec.leaves.iter().map(|l| l.owner_hash.to_vec()).collect();
+            let balances: Vec<BigDecimal> = spec.leaves.iter().map(|l| i128_to_bigdecimal(l.balance_minor)).collect();
+
+            sqlx::query(
+                r#"
+                INSERT INTO reserve_snapshot_leaves (snapshot_id, position, owner_id, owner_hash, balance)
+                SELECT $1, x.position, x.owner_id, x.owner_hash, x.balance
+                FROM UNNEST($2::int[], $3::uuid[], $4::bytea[], $5::numeric[])
+                    AS x(position, owner_id, owner_hash, balance)
+                "#,
+            )
+                .bind(id)
+                .bind(&positions)
+                .bind(&owner_ids)
+                .bind(&owner_hashes)
+                .bind(&balances)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx)?;
+        }
+
+        let snapshot = Self::load(&mut tx, spec.public_id)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("reserve_snapshot public_id={}", spec.public_id.value()) })?;
+
+        tx.commit().await.map_err(map_sqlx)?;
+        Ok(snapshot)
+    }
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<ReserveSnapshot>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id).await
+    }
+
+    async fn latest(&self, asset_id: i16) -> Result<Option<ReserveSnapshot>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        let Some(row) = sqlx::query_as::<_, ReserveSnapshotRow>(&format!(
+            "SELECT {SNAPSHOT_COLUMNS} FROM reserve_snapshots WHERE asset_id = $1 ORDER BY taken_at DESC, id DESC LIMIT 1"
+        ))
+            .bind(asset_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::with_leaves(&mut conn, row).await?))
+    }
+}
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::Duration;
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::ScheduledJournalRepository;
+use crate::domain::aggregate::{NewScheduledJournal, ScheduleStatus, ScheduledJournal, ScheduledJournalRun};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{
+    ScheduledJournalLineRow, ScheduledJournalRow, ScheduledJournalRunRow,
+};
+
+const SCHEDULE_COLUMNS: &str = "id, public_id, external_ref_type, external_ref, description, created_by, metadata, \
+     tags, multi_asset, recurrence, interval_seconds, starts_at, ends_at, max_occurrences, status, next_occurrence, \
+     next_run_at, due_at, posted_count, consecutive_failures, last_error, status_changed_at, status_changed_by";
+
+pub struct PgScheduledJournalRepository {
+    pool: PgPool,
+}
+
+impl PgScheduledJournalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn with_lines(
+        conn: &mut PgConnection,
+        row: ScheduledJournalRow,
+    ) -> Result<ScheduledJournal, RepoError> {
+        let lines = sqlx::query_as::<_, ScheduledJournalLineRow>(
+            r#"
+            SELECT account_id, amount, memo
+            FROM scheduled_journal_lines
+            WHERE schedule_id = $1
+            ORDER BY position
+            "#,
+        )
+            .bind(row.id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain(&lines)
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<ScheduledJournal>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let Some(row) = sqlx::query_as::<_, ScheduledJournalRow>(&format!(
+            "SELECT {SCHEDULE_COLUMNS} FROM scheduled_journals WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::with_lines(conn, row).await?))
+    }
+}
+
+fn to_i32(v: u32, name: &str) -> Result<i32, RepoError> {
+    i32::try_from(v).map_err(|_| RepoError::Integrity { message: format!("{name} out of range: {v}") })
+}
+
+#[async_trait]
+impl ScheduledJournalRepository for PgScheduledJournalRepository {
+    async fn insert(&self, spec: &NewScheduledJournal) -> Result<ScheduledJournal, RepoError> {
+        let t = &spec.template;
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO scheduled_journals
+                (public_id, external_ref_type, external_ref, description, created_by, metadata, tags,
+                 recurrence, interval_seconds, starts_at, ends_at, max_occurrences, next_run_at, due_at,
+                 multi_asset)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $10, $10, $13)
+            RETURNING id
+            "#,
+        )
+            .bind(t.public_id.value())
+            .bind(t.external_ref_type.as_code())
+            .bind(t.external_ref.as_str())
+            .bind(&t.description)
+            .bind(&t.created_by)
+            .bind(Json(t.metadata.as_map()))
+            .bind(t.tags().iter().map(|g| g.as_str()).collect::<Vec<_>>())
+            .bind(spec.recurrence.as_code())
+            .bind(spec.recurrence.interval_seconds())
+            .bind(spec.starts_at)
+            .bind(spec.ends_at)
+            .bind(spec.max_occurrences.map(|m| to_i32(m, "max_occurrences")).transpose()?)
+            .bind(t.multi_asset)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let positions: Vec<i16> = (0..t.lines().len() as i16).collect();
+        let account_ids: Vec<i64> = t.lines().iter().map(|l| l.account_id).collect();
+        let amounts: Vec<BigDecimal> = t.lines().iter().map(|l| i128_to_bigdecimal(l.amount.minor())).collect();
+        let memos: Vec<Option<String>> = t.lines().iter().map(|l| l.memo.clone()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO scheduled_journal_lines (schedule_id, position, account_id, amount, memo)
+            SELECT $1, x.position, x.account_id, x.amount, x.memo
+            FROM UNNEST($2::smallint[], $3::bigint[], $4::numeric[], $5::text[])
+                AS x(position, account_id, amount, memo)
+            "#,
+        )
+            .bind(id)
+            .bind(&positions)
+            .bind(&account_ids)
+            .bind(&amounts)
+            .bind(&memos)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        let schedule = Self::load(&mut tx, t.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("scheduled_journal id={id}") })?;
+
+        tx.commit().await.map_err(map_sqlx)?;
+        Ok(schedule)
+    }
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<ScheduledJournal>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn list(
+        &self,
+        status: Option<ScheduleStatus>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<ScheduledJournal>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        let rows = sqlx::query_as::<_, ScheduledJournalRow>(&format!(
+            r#"
+            SELECT {SCHEDULE_COLUMNS}
+            FROM scheduled_journals
+            WHERE ($1::text IS NULL OR status = $1)
+            ORDER BY next_run_at NULLS LAST, id
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+            .bind(status.map(|s| s.as_code()))
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        let mut schedules = Vec::with_capacity(rows.len());
+        for row in rows {
+            schedules.push(Self::with_lines(&mut conn, row).await?);
+        }
+        Ok(schedules)
+    }
+
+    async fn claim_due(&self, limit: usize, lease: Duration) -> Result<Vec<PublicId>, RepoError> {
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            UPDATE scheduled_journals s
+            SET due_at = now() + make_interval(secs => $2),
+                updated_at = now()
+            WHERE s.id IN (
+                SELECT id
+                FROM scheduled_journals
+                WHERE status = 'ACTIVE' AND due_at <= now()
+                ORDER BY due_at, id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING s.public_id
+            "#,
+        )
+            .bind(limit as i64)
+            .bind(lease.num_seconds() as f64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(ids.into_iter().map(PublicId::new).collect())
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<ScheduledJournal>, RepoError> {
+        Self::load(tx, public_id, true).await
+    }
+
+    async fn save_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        schedule: &ScheduledJournal,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE scheduled_journals
+            SET status = $2,
+                next_occurrence = $3,
+                next_run_at = $4,
+                due_at = $5,
+                posted_count = $6,
+                consecutive_failures = $7,
+                last_error = $8,
+                status_changed_at = $9,
+                status_changed_by = $10,
+                updated_at = now()
+            WHERE id = $1
+            "#,
+        )
+            .bind(schedule.id())
+            .bind(schedule.status().as_code())
+            .bind(to_i32(schedule.next_occurrence(), "next_occurrence")?)
+            .bind(schedule.next_run_at())
+            .bind(schedule.due_at())
+            .bind(to_i32(schedule.posted_count(), "posted_count")?)
+            .bind(to_i32(schedule.consecutive_failures(), "consecutive_failures")?)
+            .bind(schedule.last_error())
+            .bind(schedule.status_changed_at())
+            .bind(schedule.status_changed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn record_run_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        schedule: &ScheduledJournal,
+        journal_id: i64,
+    ) -> Result<(), RepoError> {
+        let scheduled_for = schedule.next_run_at().ok_or_else(|| RepoError::Integrity {
+            message: format!("schedule_id={} has no pending occurrence", schedule.id()),
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO scheduled_journal_runs (schedule_id, occurrence, scheduled_for, journal_tx_id)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+            .bind(schedule.id())
+            .bind(to_i32(schedule.next_occurrence(), "occurrence")?)
+            .bind(scheduled_for)
+            .bind(journal_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn runs(&self, public_id: PublicId) -> Result<Vec<ScheduledJournalRun>, RepoError> {
+        let rows = sqlx::query_as::<_, ScheduledJournalRunRow>(
+            r#"
+            SELECT r.occurrence, r.scheduled_for, r.journal_tx_id, r.posted_at
+            FROM scheduled_journal_runs r
+            JOIN scheduled_journals s ON s.id = r.schedule_id
+            WHERE s.public_id = $1
+            ORDER BY r.occurrence
+            "#,
+        )
+            .bind(public_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(|r| r.to_domain()).collect()
+    }
+}
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::application::contracts::repository::SolvencyReportRepository;
+use crate::domain::aggregate::{
+    NewSolvencyReport, SolvencyBalances, SolvencyReport, HOLDING_ACCOUNT_TYPES, LIABILITY_ACCOUNT_TYPES,
+};
+use crate::domain::entities::AccountType;
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::{SolvencyBalancesRow, SolvencyReportRow};
+
+const REPORT_COLUMNS: &str = r#"
+    id, public_id, asset_id, as_of, trigger, status,
+    user_available, user_locked, treasury_available, treasury_locked, platform_clearing,
+    liabilities, holdings, coverage_bps, requested_by, alerted_at
+"#;
+
+pub struct PgSolvencyReportRepository {
+    pool: PgPool,
+}
+
+impl PgSolvencyReportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SolvencyReportRepository for PgSolvencyReportRepository {
+    async fn balances(&self, asset_id: i16) -> Result<SolvencyBalances, RepoError> {
+        let account_types: Vec<&str> = LIABILITY_ACCOUNT_TYPES
+            .iter()
+            .chain(HOLDING_ACCOUNT_TYPES.iter())
+            .map(|t| t.as_str())
+            .collect();
+        let row = sqlx::query_as::<_, SolvencyBalancesRow>(
+            r#"
+            SELECT
+                COALESCE(SUM(b.balance) FILTER (WHERE a.account_type = $3), 0) AS user_available,
+                COALESCE(SUM(b.balance) FILTER (WHERE a.account_type = $4), 0) AS user_locked,
+                COALESCE(SUM(b.balance) FILTER (WHERE a.account_type = $5), 0) AS treasury_available,
+                COALESCE(SUM(b.balance) FILTER (WHERE a.account_type = $6), 0) AS treasury_locked,
+                COALESCE(SUM(b.balance) FILTER (WHERE a.account_type = $7), 0) AS platform_clearing
+            FROM ledger_accounts a
+            JOIN ledger_account_balances b ON b.account_id = a.id
+            WHERE a.asset_id = $1
+              AND a.account_type = ANY($2::text[])
+            "#,
+        )
+            .bind(asset_id)
+            .bind(&account_types)
+            .bind(AccountType::UserAvailable.as_str())
+            .bind(AccountType::UserLocked.as_str())
+            .bind(AccountType::TreasuryAvailable.as_str())
+            .bind(AccountType::TreasuryLocked.as_str())
+            .bind(AccountType::PlatformClearing.as_str())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.to_domain()
+    }
+
+    async fn active_asset_ids(&self) -> Result<Vec<i16>, RepoError> {
+        sqlx::query_scalar::<_, i16>("SELECT id FROM assets WHERE is_active ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)
+    }
+
+    async fn insert(&self, spec: &NewSolvencyReport) -> Result<SolvencyReport, RepoError> {
+        let b = &spec.balances;
+        let inserted = sqlx::query_as::<_, SolvencyReportRow>(&format!(
+            r#"
+            INSERT INTO solvency_reports (
+                public_id, asset_id, as_of, trigger, status,
+                user_available, user_locked, treasury_available, treasury_locked, platform_clearing,
+                liabilities, holdings, coverage_bps, requested_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (public_id) DO NOTHING
+            RETURNING {REPORT_COLUMNS}
+            "#
+        ))
+            .bind(spec.public_id.value())
+            .bind(spec.asset_id)
+            .bind(spec.as_of)
+            .bind(spec.trigger.as_code())
+            .bind(spec.status.as_code())
+            .bind(i128_to_bigdecimal(b.user_available))
+            .bind(i128_to_bigdecimal(b.user_locked))
+            .bind(i128_to_bigdecimal(b.treasury_available))
+            .bind(i128_to_bigdecimal(b.treasury_locked))
+            .bind(i128_to_bigdecimal(b.platform_clearing))
+            .bind(i128_to_bigdecimal(spec.liabilities_minor))
+            .bind(i128_to_bigdecimal(spec.holdings_minor))
+            .bind(spec.coverage_bps.map(i128_to_bigdecimal))
+            .bind(&spec.requested_by)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        match inserted {
+            Some(row) => row.to_domain(),
+            None => self.find(spec.public_id).await?.ok_or_else(|| RepoError::NotFound {
+                entity: format!("solvency_report public_id={}", spec.public_id.value()),
+            }),
+        }
+    }
+
+    async fn mark_alerted(&self, report_id: i64, at: DateTime<Utc>) -> Result<(), RepoError> {
+        let res = sqlx::query(
+            r#"
+            UPDATE solvency_reports
+            SET alerted_at = $2
+            WHERE id = $1 AND status = 'SHORTFALL' AND alerted_at IS NULL
+            "#,
+        )
+            .bind(report_id)
+            .bind(at)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        if res.rows_affected() != 1 {
+            return Err(RepoError::Conflict {
+                message: format!("solvency report {report_id} is not an unalerted shortfall"),
+            });
+        }
+        Ok(())
+    }
+
+    async fn find(&self, public_id: PublicId) -> Result<Option<SolvencyReport>, RepoError> {
+        let row = sqlx::query_as::<_, SolvencyReportRow>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM solvency_reports WHERE public_id = $1"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn history(&self, asset_id: i16, limit: usize, offset: usize) -> Result<Vec<SolvencyReport>, RepoError> {
+        let rows = sqlx::query_as::<_, SolvencyReportRow>(&format!(
+            r#"
+            SELECT {REPORT_COLUMNS}
+            FROM solvency_reports
+            WHERE asset_id = $1
+            ORDER BY as_of DESC, id DESC
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+            .bind(asset_id)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(SolvencyReportRow::to_domain).collect()
+    }
+}
@@ -1,4 +1,5 @@
 use sqlx::{PgPool, Postgres, Transaction};
+use crate::domain::error::DomainError;
 use crate::domain::repository::RepoError;
 use crate::infrastructure::persistence::error_map::map_sqlx;
 use crate::application::contracts::repository::{UnitOfWork, BoxFut};
@@ -36,4 +37,31 @@ impl UnitOfWork for PgUnitOfWork {
             }
         })
     }
-}
\ No newline at end of file
+
+    fn with_domain_tx<'u, T: Send + 'u>(
+        &'u self,
+        f: impl for<'a> FnOnce(&'a mut Transaction<'_, Postgres>) -> BoxFut<'a, Result<Result<T, DomainError>, RepoError>>
+        + Send
+        + 'u,
+    ) -> BoxFut<'u, Result<Result<T, DomainError>, RepoError>> {
+        Box::pin(async move {
+            let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+            let res = f(&mut tx).await;
+
+            match res {
+                Ok(Ok(v)) => {
+                    tx.commit().await.map_err(map_sqlx)?;
+                    Ok(Ok(v))
+                }
+                Ok(Err(e)) => {
+                    tx.rollback().await.map_err(map_sqlx)?;
+                    Ok(Err(e))
+                }
+                Err(e) => {
+                    tx.rollback().await.map_err(map_sqlx)?;
+                    Err(e)
+                }
+            }
+        })
+    }
+}
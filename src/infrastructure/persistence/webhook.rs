@@ -0,0 +1,324 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::application::contracts::repository::WebhookRepository;
+use crate::domain::aggregate::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookAttempt, WebhookDelivery, WebhookSubscription,
+};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::models::{WebhookAttemptRow, WebhookDeliveryRow, WebhookSubscriptionRow};
+
+const SUBSCRIPTION_COLUMNS: &str =
+    "id, public_id, owner_id, url, secret, event_types, active, created_by, created_at, updated_at";
+
+const DELIVERY_COLUMNS: &str = "id, public_id, subscription_id, event_id, event_type, payload, status, \
+     attempts, failures, next_attempt_at, last_error, delivered_at, created_at";
+
+const ATTEMPT_COLUMNS: &str = "delivery_id, attempt, attempted_at, status_code, error";
+
+pub struct PgWebhookRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn delivery_row(&self, public_id: Uuid) -> Result<Option<WebhookDeliveryRow>, RepoError> {
+        sqlx::query_as::<_, WebhookDeliveryRow>(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries WHERE public_id = $1"
+        ))
+            .bind(public_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)
+    }
+}
+
+fn count(value: u32) -> i32 {
+    i32::try_from(value).unwrap_or(i32::MAX)
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn insert_subscription(&self, spec: &NewWebhookSubscription) -> Result<WebhookSubscription, RepoError> {
+        let event_types: Vec<&str> = spec.event_types.iter().map(|t| t.as_code()).collect();
+        let inserted = sqlx::query_as::<_, WebhookSubscriptionRow>(&format!(
+            r#"
+            INSERT INTO webhook_subscriptions
+                (public_id, owner_id, url, secret, event_types, active, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, TRUE, $6, $7, $7)
+            ON CONFLICT (public_id) DO NOTHING
+            RETURNING {SUBSCRIPTION_COLUMNS}
+            "#
+        ))
+            .bind(spec.public_id.value())
+            .bind(spec.owner_id)
+            .bind(&spec.url)
+            .bind(&spec.secret)
+            .bind(&event_types)
+            .bind(&spec.created_by)
+            .bind(spec.created_at)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        match inserted {
+            Some(row) => row.to_domain(),
+            None => self.find_subscription(spec.public_id).await?.ok_or_else(|| RepoError::NotFound {
+                entity: format!("webhook_subscription public_id={}", spec.public_id.value()),
+            }),
+        }
+    }
+
+    async fn find_subscription(&self, public_id: PublicId) -> Result<Option<WebhookSubscription>, RepoError> {
+        let row = sqlx::query_as::<_, WebhookSubscriptionRow>(&format!(
+            "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE public_id = $1"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn subscription_by_id(&self, id: i64) -> Result<Option<WebhookSubscription>, RepoError> {
+        let row = sqlx::query_as::<_, WebhookSubscriptionRow>(&format!(
+            "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE id = $1"
+        ))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn subscriptions_for_owner(&self, owner_id: Uuid) -> Result<Vec<WebhookSubscription>, RepoError> {
+        let rows = sqlx::query_as::<_, WebhookSubscriptionRow>(&format!(
+            "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE owner_id = $1 ORDER BY id"
+        ))
+            .bind(owner_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WebhookSubscriptionRow::to_domain).collect()
+    }
+
+    async fn active_subscriptions(&self, owner_ids: &[Uuid]) -> Result<Vec<WebhookSubscription>, RepoError> {
+        if owner_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rows = sqlx::query_as::<_, WebhookSubscriptionRow>(&format!(
+            "SELECT {SUBSCRIPTION_COLUMNS} FROM webhook_subscriptions WHERE active AND owner_id = ANY($1) ORDER BY id"
+        ))
+            .bind(owner_ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WebhookSubscriptionRow::to_domain).collect()
+    }
+
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), RepoError> {
+        let updated = sqlx::query(
+            "UPDATE webhook_subscriptions SET active = $2, updated_at = $3 WHERE id = $1",
+        )
+            .bind(subscription.id())
+            .bind(subscription.is_active())
+            .bind(subscription.updated_at())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepoError::NotFound { entity: format!("webhook_subscription id={}", subscription.id()) });
+        }
+        Ok(())
+    }
+
+    async fn enqueue(&self, deliveries: &[NewWebhookDelivery]) -> Result<usize, RepoError> {
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+        let public_ids: Vec<Uuid> = deliveries.iter().map(|d| d.public_id.value()).collect();
+        let subscription_ids: Vec<i64> = deliveries.iter().map(|d| d.subscription_id).collect();
+        let event_ids: Vec<&str> = deliveries.iter().map(|d| d.event_id.as_str()).collect();
+        let event_types: Vec<&str> = deliveries.iter().map(|d| d.event_type.as_code()).collect();
+        let payloads: Vec<&str> = deliveries.iter().map(|d| d.payload.as_str()).collect();
+        let created_at: Vec<_> = deliveries.iter().map(|d| d.created_at).collect();
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries
+                (public_id, subscription_id, event_id, event_type, payload, status,
+                 next_attempt_at, created_at, updated_at)
+            SELECT p, s, e, t, b, 'PENDING', c, c, c
+            FROM UNNEST($1::uuid[], $2::bigint[], $3::text[], $4::text[], $5::text[], $6::timestamptz[])
+                AS x(p, s, e, t, b, c)
+            ON CONFLICT (subscription_id, event_id) DO NOTHING
+            "#,
+        )
+            .bind(&public_ids)
+            .bind(&subscription_ids)
+            .bind(&event_ids)
+            .bind(&event_types)
+            .bind(&payloads)
+            .bind(&created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(inserted.rows_affected() as usize)
+    }
+
+    async fn claim_due(&self, limit: usize, lease: Duration) -> Result<Vec<WebhookDelivery>, RepoError> {
+        let rows = sqlx::query_as::<_, WebhookDeliveryRow>(&format!(
+            r#"
+            UPDATE webhook_deliveries d
+            SET next_attempt_at = now() + make_interval(secs => $2),
+                updated_at = now()
+            WHERE d.id IN (
+                SELECT id
+                FROM webhook_deliveries
+                WHERE status = 'PENDING' AND next_attempt_at <= now()
+                ORDER BY next_attempt_at, id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING {DELIVERY_COLUMNS}
+            "#
+        ))
+            .bind(limit as i64)
+            .bind(lease.num_seconds() as f64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WebhookDeliveryRow::to_domain).collect()
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: Option<&WebhookAttempt>,
+    ) -> Result<(), RepoError> {
+        let state = delivery.state();
+        // the attempt count the row had when this worker claimed it
+        let claimed_attempts = attempt.map_or(state.attempts, |a| a.attempt.saturating_sub(1));
+
+        let mut tx = self.pool.begin().await.map_err(map_sqlx)?;
+        let updated = sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $3, attempts = $4, failures = $5, next_attempt_at = $6,
+                last_error = $7, delivered_at = $8, updated_at = now()
+            WHERE id = $1 AND status = 'PENDING' AND attempts = $2
+            "#,
+        )
+            .bind(delivery.id())
+            .bind(count(claimed_attempts))
+            .bind(state.status.as_code())
+            .bind(count(state.attempts))
+            .bind(count(state.failures))
+            .bind(state.next_attempt_at)
+            .bind(&state.last_error)
+            .bind(state.delivered_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepoError::Conflict {
+                message: format!("webhook delivery {} was already updated by another worker", delivery.public_id().value()),
+            });
+        }
+
+        if let Some(attempt) = attempt {
+            sqlx::query(
+                r#"
+                INSERT INTO webhook_delivery_attempts (delivery_id, attempt, attempted_at, status_code, error)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+                .bind(delivery.id())
+                .bind(count(attempt.attempt))
+                .bind(attempt.attempted_at)
+                .bind(attempt.status_code.map(i32::from))
+                .bind(&attempt.error)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx)
+    }
+
+    async fn find_delivery(&self, public_id: PublicId) -> Result<Option<WebhookDelivery>, RepoError> {
+        self.delivery_row(public_id.value()).await?.map(|r| r.to_domain()).transpose()
+    }
+
+    async fn attempts(&self, delivery_id: i64) -> Result<Vec<WebhookAttempt>, RepoError> {
+        let rows = sqlx::query_as::<_, WebhookAttemptRow>(&format!(
+            "SELECT {ATTEMPT_COLUMNS} FROM webhook_delivery_attempts WHERE delivery_id = $1 ORDER BY attempt"
+        ))
+            .bind(delivery_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WebhookAttemptRow::to_domain).collect()
+    }
+
+    async fn dead_letters(&self, limit: usize, offset: usize) -> Result<Vec<WebhookDelivery>, RepoError> {
+        let rows = sqlx::query_as::<_, WebhookDeliveryRow>(&format!(
+            r#"
+            SELECT {DELIVERY_COLUMNS}
+            FROM webhook_deliveries
+            WHERE status = 'DEAD_LETTERED'
+            ORDER BY updated_at DESC, id DESC
+            LIMIT $1 OFFSET $2
+            "#
+        ))
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WebhookDeliveryRow::to_domain).collect()
+    }
+
+    async fn requeue(&self, delivery: &WebhookDelivery) -> Result<(), RepoError> {
+        let state = delivery.state();
+        let updated = sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2, failures = $3, next_attempt_at = $4, last_error = $5, updated_at = now()
+            WHERE id = $1 AND status = 'DEAD_LETTERED'
+            "#,
+        )
+            .bind(delivery.id())
+            .bind(state.status.as_code())
+            .bind(count(state.failures))
+            .bind(state.next_attempt_at)
+            .bind(&state.last_error)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepoError::Conflict {
+                message: format!("webhook delivery {} is no longer dead-lettered", delivery.public_id().value()),
+            });
+        }
+        Ok(())
+    }
+}
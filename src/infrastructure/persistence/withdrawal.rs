@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+use crate::application::contracts::repository::WithdrawalRepository;
+use crate::domain::aggregate::{NewWithdrawal, Withdrawal, WithdrawalStatus};
+use crate::domain::repository::RepoError;
+use crate::domain::value_objects::PublicId;
+use crate::infrastructure::persistence::error_map::map_sqlx;
+use crate::infrastructure::persistence::mappers::i128_to_bigdecimal;
+use crate::infrastructure::persistence::models::WithdrawalRow;
+
+const WITHDRAWAL_COLUMNS: &str = "id, public_id, owner_id, asset_id, amount, destination, status, rail_reference, \
+     failure_reason, created_by, status_changed_at, status_changed_by";
+
+pub struct PgWithdrawalRepository {
+    pool: PgPool,
+}
+
+impl PgWithdrawalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load(
+        conn: &mut PgConnection,
+        public_id: PublicId,
+        for_update: bool,
+    ) -> Result<Option<Withdrawal>, RepoError> {
+        let lock = if for_update { "FOR UPDATE" } else { "" };
+        let row = sqlx::query_as::<_, WithdrawalRow>(&format!(
+            "SELECT {WITHDRAWAL_COLUMNS} FROM withdrawals WHERE public_id = $1 {lock}"
+        ))
+            .bind(public_id.value())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        row.as_ref().map(WithdrawalRow::to_domain).transpose()
+    }
+}
+
+#[async_trait]
+impl WithdrawalRepository for PgWithdrawalRepository {
+    async fn insert(&self, spec: &NewWithdrawal) -> Result<Withdrawal, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO withdrawals (public_id, owner_id, asset_id, amount, destination, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (public_id) DO NOTHING
+            "#,
+        )
+            .bind(spec.public_id.value())
+            .bind(spec.owner_id)
+            .bind(spec.asset_id)
+            .bind(i128_to_bigdecimal(spec.amount_minor))
+            .bind(&spec.destination)
+            .bind(&spec.created_by)
+            .execute(&mut *conn)
+            .await
+            .map_err(map_sqlx)?;
+
+        Self::load(&mut conn, spec.public_id, false)
+            .await?
+            .ok_or_else(|| RepoError::NotFound { entity: format!("withdrawal public_id={}", spec.public_id.value()) })
+    }
+
+    async fn find_by_public_id(&self, public_id: PublicId) -> Result<Option<Withdrawal>, RepoError> {
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx)?;
+        Self::load(&mut conn, public_id, false).await
+    }
+
+    async fn lock_by_public_id_tx(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        public_id: PublicId,
+    ) -> Result<Option<Withdrawal>, RepoError> {
+        Self::load(tx, public_id, true).await
+    }
+
+    async fn save_tx(&self, tx: &mut Transaction<'_, Postgres>, withdrawal: &Withdrawal) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+            UPDATE withdrawals
+            SET status = $2,
+                rail_reference = $3,
+                failure_reason = $4,
+                status_changed_at = $5,
+                status_changed_by = $6,
+                updated_at = now()
+            WHERE id = $1
+            "#,
+        )
+            .bind(withdrawal.id())
+            .bind(withdrawal.status().as_code())
+            .bind(withdrawal.rail_reference())
+            .bind(withdrawal.failure_reason())
+            .bind(withdrawal.status_changed_at())
+            .bind(withdrawal.status_changed_by())
+            .execute(&mut **tx)
+            .await
+            .map_err(map_sqlx)?;
+
+        Ok(())
+    }
+
+    async fn list_by_status(&self, status: WithdrawalStatus, limit: usize) -> Result<Vec<Withdrawal>, RepoError> {
+        let rows = sqlx::query_as::<_, WithdrawalRow>(&format!(
+            r#"
+            SELECT {WITHDRAWAL_COLUMNS}
+            FROM withdrawals
+            WHERE status = $1
+            ORDER BY updated_at, id
+            LIMIT $2
+            "#
+        ))
+            .bind(status.as_code())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx)?;
+
+        rows.iter().map(WithdrawalRow::to_domain).collect()
+    }
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+
+use crate::application::contracts::{WebhookRequest, WebhookTransport, WebhookTransportError};
+use crate::infrastructure::InfraError;
+
+pub const WEBHOOK_EVENT_ID_HEADER: &str = "X-Sirara-Event-Id";
+pub const WEBHOOK_EVENT_TYPE_HEADER: &str = "X-Sirara-Event-Type";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Sirara-Timestamp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Sirara-Signature";
+
+/// Posts deliveries as JSON with the event id, type, timestamp and signature in headers.
+/// Redirects are not followed: a partner endpoint answers where it was registered.
+pub struct HttpWebhookTransport {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookTransport {
+    /// A delivery that gets no answer within `timeout` counts as a failed attempt.
+    pub fn new(timeout: Duration) -> Result<Self, InfraError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookTransport for HttpWebhookTransport {
+    async fn post(&self, request: &WebhookRequest) -> Result<u16, WebhookTransportError> {
+        let response = self
+            .client
+            .post(&request.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_ID_HEADER, &request.event_id)
+            .header(WEBHOOK_EVENT_TYPE_HEADER, &request.event_type)
+            .header(WEBHOOK_TIMESTAMP_HEADER, request.timestamp.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, &request.signature)
+            .body(request.body.clone())
+            .send()
+            .await
+            .map_err(|e| WebhookTransportError::Unreachable { message: e.to_string() })?;
+
+        // the body is not read: only the status decides the attempt
+        Ok(response.status().as_u16())
+    }
+}
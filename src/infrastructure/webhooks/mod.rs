@@ -0,0 +1,5 @@
+mod http;
+pub use http::{
+    HttpWebhookTransport, WEBHOOK_EVENT_ID_HEADER, WEBHOOK_EVENT_TYPE_HEADER, WEBHOOK_SIGNATURE_HEADER,
+    WEBHOOK_TIMESTAMP_HEADER,
+};
@@ -1,10 +1,18 @@
 use anyhow::Context;
 
-use crate::utils::configuration::{database};
+use crate::utils::configuration::{database, deposits, fx, holds, maker_checker, pending_journals, scheduler, value_dating, withdrawals};
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database: database::DatabaseConfig,
+    pub maker_checker: maker_checker::MakerCheckerConfig,
+    pub value_dating: value_dating::ValueDatingConfig,
+    pub scheduler: scheduler::SchedulerConfig,
+    pub pending_journals: pending_journals::PendingJournalsConfig,
+    pub holds: holds::HoldsConfig,
+    pub withdrawals: withdrawals::WithdrawalsConfig,
+    pub deposits: deposits::DepositsConfig,
+    pub fx: fx::FxConfig,
 }
 
 impl Config {
@@ -13,6 +21,14 @@ impl Config {
         let toml = load_toml()?;
         Ok(Self {
             database: database::load(&toml)?,
+            maker_checker: maker_checker::load(&toml)?,
+            value_dating: value_dating::load(&toml)?,
+            scheduler: scheduler::load(&toml)?,
+            pending_journals: pending_journals::load(&toml)?,
+            holds: holds::load(&toml)?,
+            withdrawals: withdrawals::load(&toml)?,
+            deposits: deposits::load(&toml)?,
+            fx: fx::load(&toml)?,
         })
     }
 }
@@ -20,6 +36,22 @@ impl Config {
 #[derive(Debug, serde::Deserialize, Clone)]
 pub(crate) struct TomlConfig {
     pub db: database::DatabaseToml,
+    #[serde(default)]
+    pub maker_checker: maker_checker::MakerCheckerToml,
+    #[serde(default)]
+    pub value_dating: value_dating::ValueDatingToml,
+    #[serde(default)]
+    pub scheduler: scheduler::SchedulerToml,
+    #[serde(default)]
+    pub pending_journals: pending_journals::PendingJournalsToml,
+    #[serde(default)]
+    pub holds: holds::HoldsToml,
+    #[serde(default)]
+    pub withdrawals: withdrawals::WithdrawalsToml,
+    #[serde(default)]
+    pub deposits: deposits::DepositsToml,
+    #[serde(default)]
+    pub fx: fx::FxToml,
 }
 
 fn load_toml() -> anyhow::Result<TomlConfig> {
@@ -0,0 +1,48 @@
+use crate::domain::aggregate::{ConfirmationThreshold, DepositPolicy, DepositSource};
+
+#[derive(Debug, Clone, Default)]
+pub struct DepositsConfig {
+    pub thresholds: Vec<ConfirmationThreshold>,
+}
+
+impl DepositsConfig {
+    pub fn deposit_policy(&self) -> DepositPolicy {
+        DepositPolicy::new(self.thresholds.clone())
+    }
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+pub(crate) struct DepositsToml {
+    #[serde(default)]
+    pub thresholds: Vec<ConfirmationThresholdToml>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct ConfirmationThresholdToml {
+    pub asset_id: i16,
+    pub confirmations: u32,
+    #[serde(default = "default_source")]
+    pub source: String,
+}
+
+fn default_source() -> String {
+    DepositSource::PlatformClearing.as_code().to_string()
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<DepositsConfig> {
+    let mut thresholds: Vec<ConfirmationThreshold> = Vec::with_capacity(toml.deposits.thresholds.len());
+    for t in &toml.deposits.thresholds {
+        if thresholds.iter().any(|existing| existing.asset_id == t.asset_id) {
+            anyhow::bail!("deposits threshold for asset_id={} is defined more than once", t.asset_id);
+        }
+        let source = DepositSource::from_code(&t.source)
+            .map_err(|e| anyhow::anyhow!("deposits threshold for asset_id={}: {e}", t.asset_id))?;
+        thresholds.push(ConfirmationThreshold {
+            asset_id: t.asset_id,
+            required_confirmations: t.confirmations,
+            source,
+        });
+    }
+
+    Ok(DepositsConfig { thresholds })
+}
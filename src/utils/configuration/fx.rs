@@ -0,0 +1,89 @@
+use crate::domain::aggregate::{FxLiquidity, FxPolicy, PairSpread};
+
+#[derive(Debug, Clone)]
+pub struct FxConfig {
+    pub quote_ttl: chrono::Duration,
+    pub default_spread_bps: u32,
+    pub spreads: Vec<PairSpread>,
+    pub liquidity: FxLiquidity,
+}
+
+impl FxConfig {
+    pub fn fx_policy(&self) -> FxPolicy {
+        FxPolicy::new(self.quote_ttl, self.default_spread_bps, self.spreads.clone(), self.liquidity)
+    }
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct FxToml {
+    #[serde(default = "default_quote_ttl_secs")]
+    pub quote_ttl_secs: u64,
+    #[serde(default = "default_spread_bps")]
+    pub default_spread_bps: u32,
+    #[serde(default)]
+    pub spreads: Vec<PairSpreadToml>,
+    #[serde(default = "default_liquidity")]
+    pub liquidity: String,
+}
+
+impl Default for FxToml {
+    fn default() -> Self {
+        Self {
+            quote_ttl_secs: default_quote_ttl_secs(),
+            default_spread_bps: default_spread_bps(),
+            spreads: Vec::new(),
+            liquidity: default_liquidity(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct PairSpreadToml {
+    pub base_asset_id: i16,
+    pub quote_asset_id: i16,
+    pub spread_bps: u32,
+}
+
+fn default_quote_ttl_secs() -> u64 {
+    30
+}
+
+fn default_spread_bps() -> u32 {
+    50
+}
+
+fn default_liquidity() -> String {
+    FxLiquidity::Treasury.as_code().to_string()
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<FxConfig> {
+    let t = &toml.fx;
+    if t.quote_ttl_secs == 0 {
+        anyhow::bail!("fx.quote_ttl_secs must be at least 1");
+    }
+    let check_bps = |bps: u32, what: &str| {
+        if bps >= 10_000 {
+            anyhow::bail!("{what} must be below 10000 bps");
+        }
+        Ok(())
+    };
+    check_bps(t.default_spread_bps, "fx.default_spread_bps")?;
+
+    let mut spreads: Vec<PairSpread> = Vec::with_capacity(t.spreads.len());
+    for s in &t.spreads {
+        if spreads.iter().any(|e| e.base_asset_id == s.base_asset_id && e.quote_asset_id == s.quote_asset_id) {
+            anyhow::bail!("fx spread for {}/{} is defined more than once", s.base_asset_id, s.quote_asset_id);
+        }
+        check_bps(s.spread_bps, &format!("fx spread for {}/{}", s.base_asset_id, s.quote_asset_id))?;
+        spreads.push(PairSpread {
+            base_asset_id: s.base_asset_id,
+            quote_asset_id: s.quote_asset_id,
+            spread_bps: s.spread_bps,
+        });
+    }
+
+    let liquidity = FxLiquidity::from_code(&t.liquidity).map_err(|e| anyhow::anyhow!("fx.liquidity: {e}"))?;
+    let quote_ttl = chrono::Duration::seconds(i64::try_from(t.quote_ttl_secs)?);
+
+    Ok(FxConfig { quote_ttl, default_spread_bps: t.default_spread_bps, spreads, liquidity })
+}
@@ -0,0 +1,41 @@
+#[derive(Debug, Clone)]
+pub struct HoldsConfig {
+    pub poll_interval: std::time::Duration,
+    pub batch_size: usize,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct HoldsToml {
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+}
+
+impl Default for HoldsToml {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: default_poll_interval_secs(),
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+fn default_poll_interval_secs() -> u64 {
+    30
+}
+
+fn default_batch_size() -> usize {
+    100
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<HoldsConfig> {
+    let t = &toml.holds;
+    if t.batch_size == 0 {
+        anyhow::bail!("holds.batch_size must be at least 1");
+    }
+    Ok(HoldsConfig {
+        poll_interval: std::time::Duration::from_secs(t.poll_interval_secs.max(1)),
+        batch_size: t.batch_size,
+    })
+}
@@ -0,0 +1,41 @@
+use crate::domain::aggregate::{ApprovalPolicy, ApprovalThreshold};
+
+#[derive(Debug, Clone, Default)]
+pub struct MakerCheckerConfig {
+    pub thresholds: Vec<ApprovalThreshold>,
+}
+
+impl MakerCheckerConfig {
+    pub fn approval_policy(&self) -> ApprovalPolicy {
+        ApprovalPolicy::new(self.thresholds.clone())
+    }
+}
+
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+pub(crate) struct MakerCheckerToml {
+    #[serde(default)]
+    pub thresholds: Vec<ApprovalThresholdToml>,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct ApprovalThresholdToml {
+    pub asset_id: i16,
+    pub min_amount_minor: i64,
+    pub required_approvals: u16,
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<MakerCheckerConfig> {
+    let mut thresholds = Vec::with_capacity(toml.maker_checker.thresholds.len());
+    for t in &toml.maker_checker.thresholds {
+        if t.required_approvals == 0 {
+            anyhow::bail!("maker_checker threshold for asset_id={} requires at least 1 approval", t.asset_id);
+        }
+        thresholds.push(ApprovalThreshold {
+            asset_id: t.asset_id,
+            min_amount_minor: t.min_amount_minor as i128,
+            required_approvals: t.required_approvals,
+        });
+    }
+
+    Ok(MakerCheckerConfig { thresholds })
+}
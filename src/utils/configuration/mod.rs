@@ -1,4 +1,28 @@
 mod config;
 
 mod database;
-pub use database::DatabaseConfig;
\ No newline at end of file
+pub use database::DatabaseConfig;
+
+mod maker_checker;
+pub use maker_checker::MakerCheckerConfig;
+
+mod value_dating;
+pub use value_dating::ValueDatingConfig;
+
+mod scheduler;
+pub use scheduler::SchedulerConfig;
+
+mod pending_journals;
+pub use pending_journals::PendingJournalsConfig;
+
+mod holds;
+pub use holds::HoldsConfig;
+
+mod withdrawals;
+pub use withdrawals::WithdrawalsConfig;
+
+mod deposits;
+pub use deposits::DepositsConfig;
+
+mod fx;
+pub use fx::FxConfig;
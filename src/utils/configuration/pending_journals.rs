@@ -0,0 +1,55 @@
+use chrono::Duration;
+
+#[derive(Debug, Clone)]
+pub struct PendingJournalsConfig {
+    pub max_window: Duration,
+    pub poll_interval: std::time::Duration,
+    pub batch_size: usize,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct PendingJournalsToml {
+    #[serde(default = "default_max_window_hours")]
+    pub max_window_hours: u32,
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+}
+
+impl Default for PendingJournalsToml {
+    fn default() -> Self {
+        Self {
+            max_window_hours: default_max_window_hours(),
+            poll_interval_secs: default_poll_interval_secs(),
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+fn default_max_window_hours() -> u32 {
+    168
+}
+
+fn default_poll_interval_secs() -> u64 {
+    10
+}
+
+fn default_batch_size() -> usize {
+    100
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<PendingJournalsConfig> {
+    let t = &toml.pending_journals;
+    if t.max_window_hours == 0 {
+        anyhow::bail!("pending_journals.max_window_hours must be at least 1");
+    }
+    if t.batch_size == 0 {
+        anyhow::bail!("pending_journals.batch_size must be at least 1");
+    }
+    Ok(PendingJournalsConfig {
+        max_window: Duration::hours(t.max_window_hours.into()),
+        poll_interval: std::time::Duration::from_secs(t.poll_interval_secs.max(1)),
+        batch_size: t.batch_size,
+    })
+}
@@ -0,0 +1,84 @@
+use chrono::Duration;
+
+use crate::domain::aggregate::RetryPolicy;
+
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    pub poll_interval: std::time::Duration,
+    pub batch_size: usize,
+    pub lease: Duration,
+    pub retry: RetryPolicy,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct SchedulerToml {
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    #[serde(default = "default_lease_secs")]
+    pub lease_secs: u32,
+    #[serde(default = "default_retry_base_secs")]
+    pub retry_base_secs: u32,
+    #[serde(default = "default_retry_max_secs")]
+    pub retry_max_secs: u32,
+    #[serde(default = "default_max_failures")]
+    pub max_failures: u32,
+}
+
+impl Default for SchedulerToml {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: default_poll_interval_secs(),
+            batch_size: default_batch_size(),
+            lease_secs: default_lease_secs(),
+            retry_base_secs: default_retry_base_secs(),
+            retry_max_secs: default_retry_max_secs(),
+            max_failures: default_max_failures(),
+        }
+    }
+}
+
+fn default_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_batch_size() -> usize {
+    50
+}
+
+fn default_lease_secs() -> u32 {
+    300
+}
+
+fn default_retry_base_secs() -> u32 {
+    60
+}
+
+fn default_retry_max_secs() -> u32 {
+    3600
+}
+
+fn default_max_failures() -> u32 {
+    10
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<SchedulerConfig> {
+    let t = &toml.scheduler;
+    if t.batch_size == 0 {
+        anyhow::bail!("scheduler.batch_size must be at least 1");
+    }
+    if t.retry_max_secs < t.retry_base_secs {
+        anyhow::bail!("scheduler.retry_max_secs must not be below retry_base_secs");
+    }
+    Ok(SchedulerConfig {
+        poll_interval: std::time::Duration::from_secs(t.poll_interval_secs.max(1)),
+        batch_size: t.batch_size,
+        lease: Duration::seconds(t.lease_secs.max(1).into()),
+        retry: RetryPolicy::new(
+            Duration::seconds(t.retry_base_secs.into()),
+            Duration::seconds(t.retry_max_secs.into()),
+            t.max_failures,
+        ),
+    })
+}
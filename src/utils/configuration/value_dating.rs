@@ -0,0 +1,43 @@
+use chrono::Duration;
+
+use crate::domain::services::ValueDatingPolicy;
+
+#[derive(Debug, Clone, Default)]
+pub struct ValueDatingConfig {
+    pub policy: ValueDatingPolicy,
+}
+
+#[derive(Debug, serde::Deserialize, Clone)]
+pub(crate) struct ValueDatingToml {
+    #[serde(default = "default_max_backdate_days")]
+    pub max_backdate_days: u32,
+    #[serde(default = "default_max_forward_minutes")]
+    pub max_forward_minutes: u32,
+}
+
+impl Default for ValueDatingToml {
+    fn default() -> Self {
+        Self {
+            max_backdate_days: default_max_backdate_days(),
+            max_forward_minutes: default_max_forward_minutes(),
+        }
+    }
+}
+
+fn default_max_backdate_days() -> u32 {
+    31
+}
+
+fn default_max_forward_minutes() -> u32 {
+    5
+}
+
+pub(crate) fn load(toml: &crate::utils::configuration::config::TomlConfig) -> anyhow::Result<ValueDatingConfig> {
+    let t = &toml.value_dating;
+    Ok(ValueDatingConfig {
+        policy: ValueDatingPolicy::new(
+            Duration::days(t.max_backdate_days.into()),
+            Duration::minutes(t.max_forward_minutes.into()),
+        ),
+    })
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::JournalDraft;
+use sirara_core::domain::entities::{
+    AccountStatus, AccountStatusChange, AccountStatusTimeline, AccountType, LedgerAccount, OwnerType,
+    PostingDirection, StatusReasonCode,
+};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+
+fn user_account(id: i64, status: AccountStatus) -> LedgerAccount {
+    LedgerAccount::new(
+        id,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::User,
+        Some(Uuid::new_v4()),
+        AccountType::UserAvailable,
+        1,
+        true,
+    )
+    .with_status(status)
+}
+
+fn transfer(from: &LedgerAccount, to: &LedgerAccount) -> Result<(), DomainError> {
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new(format!("test:{}", Uuid::new_v4())).unwrap(),
+        "test",
+        None,
+    )
+    .unwrap();
+    draft.add_line(from.id(), Money::credit(500).unwrap());
+    draft.add_line(to.id(), Money::debit(500).unwrap());
+
+    let by_id: HashMap<i64, &LedgerAccount> = [(from.id(), from), (to.id(), to)].into_iter().collect();
+    draft.validate_with_accounts(&by_id).map(|_| ())
+}
+
+#[test]
+fn status_is_enforced_per_posting_direction() {
+    let active = user_account(1, AccountStatus::Active);
+
+    for (status, can_send, can_receive) in [
+        (AccountStatus::Frozen, false, true),
+        (AccountStatus::CreditOnly, false, true),
+        (AccountStatus::DebitOnly, true, false),
+        (AccountStatus::Closed, false, false),
+    ] {
+        let acct = user_account(2, status);
+        assert_eq!(transfer(&acct, &active).is_ok(), can_send, "{status:?} sending");
+        assert_eq!(transfer(&active, &acct).is_ok(), can_receive, "{status:?} receiving");
+    }
+}
+
+#[test]
+fn frozen_user_account_takes_deposits_but_not_withdrawals() {
+    let frozen = user_account(2, AccountStatus::Frozen);
+    let clearing = LedgerAccount::new(
+        3,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::Platform,
+        None,
+        AccountType::PlatformClearing,
+        1,
+        true,
+    );
+    let by_id: HashMap<i64, &LedgerAccount> =
+        [(frozen.id(), &frozen), (clearing.id(), &clearing)].into_iter().collect();
+    let draft = |ref_type: ExternalRefType, user_line: Money, clearing_line: Money| {
+        let mut draft = JournalDraft::new(
+            PublicId::new(Uuid::new_v4()),
+            ref_type,
+            ExternalRef::new(format!("test:{}", Uuid::new_v4())).unwrap(),
+            "test",
+            None,
+        )
+        .unwrap();
+        draft.add_line(frozen.id(), user_line);
+        draft.add_line(clearing.id(), clearing_line);
+        draft
+    };
+
+    // a deposit debits the user's balance up
+    let deposit = draft(ExternalRefType::Deposit, Money::debit(500).unwrap(), Money::credit(500).unwrap());
+    assert!(deposit.validate_with_accounts(&by_id).is_ok());
+
+    let withdrawal = draft(ExternalRefType::Settlement, Money::credit(500).unwrap(), Money::debit(500).unwrap());
+    assert!(matches!(
+        withdrawal.validate_with_accounts(&by_id),
+        Err(DomainError::AccountStatusBlocksPosting { account_id: 2, ref direction, .. }) if direction == "OUTFLOW"
+    ));
+}
+
+#[test]
+fn transitions_follow_the_state_machine() {
+    let now = Utc::now();
+    let mut acct = user_account(1, AccountStatus::Active);
+
+    let change = acct
+        .change_status(AccountStatus::Frozen, StatusReasonCode::FraudSuspected, None, "compliance:ana", 100, now)
+        .unwrap();
+    assert_eq!((change.from, change.to), (AccountStatus::Active, AccountStatus::Frozen));
+    assert_eq!(change.actor, "compliance:ana");
+
+    // frozen accounts must be unfrozen before anything else
+    assert!(matches!(
+        acct.change_status(AccountStatus::DebitOnly, StatusReasonCode::Offboarding, None, "ops", 100, now),
+        Err(DomainError::AccountStatusTransitionNotAllowed { .. })
+    ));
+    acct.change_status(AccountStatus::Active, StatusReasonCode::ReviewCleared, None, "compliance:ana", 100, now)
+        .unwrap();
+
+    assert_eq!(
+        acct.change_status(AccountStatus::DebitOnly, StatusReasonCode::Other, Some(" ".into()), "ops", 100, now),
+        Err(DomainError::StatusNoteRequired)
+    );
+    acct.change_status(AccountStatus::DebitOnly, StatusReasonCode::Offboarding, None, "ops", 100, now).unwrap();
+}
+
+#[test]
+fn closing_requires_zero_balance_and_is_terminal() {
+    let now = Utc::now();
+    let mut acct = user_account(7, AccountStatus::DebitOnly);
+
+    assert_eq!(
+        acct.change_status(AccountStatus::Closed, StatusReasonCode::CustomerRequest, None, "ops", 1, now),
+        Err(DomainError::AccountCloseNonZeroBalance { account_id: 7, balance_minor: 1 })
+    );
+    acct.change_status(AccountStatus::Closed, StatusReasonCode::CustomerRequest, None, "ops", 0, now).unwrap();
+    assert!(!acct.is_active());
+
+    for next in [AccountStatus::Active, AccountStatus::Frozen, AccountStatus::CreditOnly] {
+        assert!(acct.change_status(next, StatusReasonCode::Other, Some("reopen".into()), "ops", 0, now).is_err());
+    }
+}
+
+#[test]
+fn timeline_answers_point_in_time_questions() {
+    let t0 = Utc::now() - Duration::days(10);
+    let mut acct = user_account(3, AccountStatus::Active);
+
+    let frozen = acct
+        .change_status(AccountStatus::Frozen, StatusReasonCode::LegalOrder, None, "legal", 0, t0 + Duration::days(2))
+        .unwrap();
+    let unfrozen = acct
+        .change_status(AccountStatus::Active, StatusReasonCode::ReviewCleared, None, "legal", 0, t0 + Duration::days(5))
+        .unwrap();
+    let deactivated = acct
+        .set_active(false, StatusReasonCode::Dormancy, None, "ops", t0 + Duration::days(8))
+        .unwrap();
+    assert_eq!((deactivated.from, deactivated.to), (AccountStatus::Active, AccountStatus::Active));
+    assert!(acct.set_active(false, StatusReasonCode::Dormancy, None, "ops", t0).is_err());
+
+    let opened = AccountStatusChange {
+        account_id: 3,
+        from: AccountStatus::Active,
+        to: AccountStatus::Active,
+        was_active: true,
+        is_active: true,
+        reason: StatusReasonCode::AccountOpened,
+        note: None,
+        actor: "system".into(),
+        at: t0,
+    };
+    let timeline = AccountStatusTimeline::new(3, vec![deactivated, unfrozen, frozen, opened]);
+
+    assert_eq!(timeline.state_at(t0 - Duration::days(1)), None);
+    assert!(timeline.was_active_at(t0 + Duration::days(1)));
+    assert_eq!(timeline.state_at(t0 + Duration::days(3)), Some((AccountStatus::Frozen, true)));
+    assert!(timeline.ensure_permits_at(t0 + Duration::days(3), PostingDirection::Inflow).is_ok());
+    assert!(timeline.ensure_permits_at(t0 + Duration::days(3), PostingDirection::Outflow).is_err());
+    assert!(timeline.ensure_permits_at(t0 + Duration::days(6), PostingDirection::Outflow).is_ok());
+    assert!(!timeline.was_active_at(t0 + Duration::days(9)));
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{CORRECTS_PERIOD_KEY, JournalDraft};
+use sirara_core::domain::entities::{AccountingPeriod, PeriodStatus};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, PublicId};
+
+fn stored(status: PeriodStatus) -> AccountingPeriod {
+    let spec = AccountingPeriod::monthly(1, 2026, 3).unwrap();
+    AccountingPeriod::restore(7, spec.asset_id, spec.name, spec.starts_at, spec.ends_at, status, None, None)
+}
+
+#[test]
+fn monthly_periods_cover_the_calendar_month() {
+    let dec = AccountingPeriod::monthly(1, 2025, 12).unwrap();
+    assert_eq!(dec.name, "2025-12");
+    assert_eq!(dec.starts_at, Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap());
+    assert_eq!(dec.ends_at, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+
+    assert!(matches!(AccountingPeriod::monthly(1, 2026, 13), Err(DomainError::PeriodInvalid { .. })));
+
+    let at = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+    assert!(AccountingPeriod::define(1, " ", at, at + chrono::Duration::days(1)).is_err());
+    assert!(AccountingPeriod::define(1, "q1", at, at).is_err());
+
+    let march = stored(PeriodStatus::Open);
+    assert!(march.contains(Utc.with_ymd_and_hms(2026, 3, 31, 23, 59, 59).unwrap()));
+    assert!(!march.contains(Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap()));
+}
+
+#[test]
+fn soft_closed_periods_only_accept_adjustments() {
+    let open = stored(PeriodStatus::Open);
+    assert!(open.ensure_accepts(ExternalRefType::TransferIntent).is_ok());
+
+    let soft = stored(PeriodStatus::SoftClosed);
+    assert!(soft.ensure_accepts(ExternalRefType::PeriodAdjustment).is_ok());
+    assert!(matches!(
+        soft.ensure_accepts(ExternalRefType::TransferIntent),
+        Err(DomainError::PeriodSoftClosed { .. })
+    ));
+
+    let closed = stored(PeriodStatus::Closed);
+    assert!(matches!(
+        closed.ensure_accepts(ExternalRefType::PeriodAdjustment),
+        Err(DomainError::PeriodClosed { .. })
+    ));
+}
+
+#[test]
+fn closing_is_final() {
+    let now = Utc::now();
+    let mut p = stored(PeriodStatus::Open);
+    p.transition(PeriodStatus::SoftClosed, "controller", now).unwrap();
+    p.transition(PeriodStatus::Open, "controller", now).unwrap();
+    assert!(matches!(p.transition(PeriodStatus::SoftClosed, " ", now), Err(DomainError::CreatedByEmpty)));
+
+    p.transition(PeriodStatus::Closed, "controller", now).unwrap();
+    assert_eq!(p.status_changed_by(), Some("controller"));
+    for to in [PeriodStatus::Open, PeriodStatus::SoftClosed] {
+        assert!(matches!(
+            p.transition(to, "controller", now),
+            Err(DomainError::PeriodTransitionNotAllowed { .. })
+        ));
+    }
+}
+
+#[test]
+fn adjusting_journals_name_the_corrected_period() {
+    let mut d = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::PeriodAdjustment,
+        ExternalRef::new(format!("adj:{}", Uuid::new_v4())).unwrap(),
+        "controller",
+        None,
+    )
+    .unwrap();
+    assert!(matches!(d.ensure_adjustment_target(), Err(DomainError::AdjustingJournalPeriodMissing { .. })));
+
+    d.metadata = JournalMetadata::new(BTreeMap::from([(CORRECTS_PERIOD_KEY.to_string(), "7".to_string())])).unwrap();
+    assert!(d.ensure_adjustment_target().is_ok());
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::server::Request;
+use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
+
+use sirara_core::application::contracts::BalanceChange;
+use sirara_core::infrastructure::notifications::{
+    serve_balance_socket, BalanceFanout, BalanceSocketAuthorizer, SubscriptionError,
+};
+
+/// Bearer tokens issued to wallet owners.
+struct TokenAuthorizer(HashMap<String, Uuid>);
+
+impl BalanceSocketAuthorizer for TokenAuthorizer {
+    fn owner(&self, req: &Request) -> Result<Uuid, String> {
+        req.headers()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .and_then(|token| self.0.get(token).copied())
+            .ok_or_else(|| "a valid bearer token is required".to_string())
+    }
+}
+
+fn authorizer(token: &str, owner: Uuid) -> Arc<dyn BalanceSocketAuthorizer> {
+    Arc::new(TokenAuthorizer(HashMap::from([(token.to_string(), owner)])))
+}
+
+async fn connect(
+    url: String,
+    token: Option<&str>,
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    tokio_tungstenite::tungstenite::Error,
+> {
+    let mut req = url.into_client_request()?;
+    if let Some(token) = token {
+        req.headers_mut().insert("authorization", format!("Bearer {token}").parse().unwrap());
+    }
+    Ok(tokio_tungstenite::connect_async(req).await?.0)
+}
+
+fn change(account_id: i64, owner_id: Option<Uuid>, balance_minor: i128) -> BalanceChange {
+    BalanceChange {
+        account_id,
+        owner_type: if owner_id.is_some() { "USER" } else { "PLATFORM" }.to_string(),
+        owner_id: owner_id.map(|o| o.to_string()),
+        asset_id: 1,
+        balance_minor,
+        journal_id: 7,
+    }
+}
+
+#[tokio::test]
+async fn subscribers_only_see_their_owners_changes() {
+    let fanout = BalanceFanout::new(16);
+    let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut all = fanout.subscribe(None);
+    let mut alices = fanout.subscribe(Some(alice));
+
+    assert_eq!(fanout.publish(change(1, Some(bob), 500)), 2);
+    assert_eq!(fanout.publish(change(2, None, -500)), 2);
+    assert_eq!(fanout.publish(change(3, Some(alice), 900)), 2);
+
+    assert_eq!(alices.recv().await.unwrap().account_id, 3);
+    let seen: Vec<i64> = [all.recv().await, all.recv().await, all.recv().await]
+        .into_iter()
+        .map(|c| c.unwrap().account_id)
+        .collect();
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn payloads_round_trip_and_slow_subscribers_lag() {
+    let fanout = BalanceFanout::new(2);
+    let owner = Uuid::new_v4();
+    let mut sub = fanout.subscribe(Some(owner));
+
+    // i128 balances survive the JSON payload
+    let big = change(9, Some(owner), i128::MAX);
+    let payload = serde_json::to_string(&big).unwrap();
+    assert_eq!(fanout.publish_payload(&payload).unwrap(), 1);
+    assert_eq!(sub.recv().await.unwrap(), big);
+    assert!(fanout.publish_payload("{\"account_id\":\"x\"}").is_err());
+
+    for i in 0..4 {
+        fanout.publish(change(i, Some(owner), i as i128));
+    }
+    assert_eq!(sub.recv().await, Err(SubscriptionError::Lagged { missed: 2 }));
+    assert_eq!(sub.recv().await.unwrap().account_id, 2);
+}
+
+#[tokio::test]
+async fn websocket_pushes_the_owners_balances() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let fanout = BalanceFanout::new(16);
+    let (stop, shutdown) = watch::channel(false);
+    let owner = Uuid::new_v4();
+    let server = tokio::spawn(serve_balance_socket(listener, fanout.clone(), authorizer("t0k3n", owner), shutdown));
+
+    // the owner comes from the token, not from anything the client puts in the query
+    let mut ws = connect(format!("ws://{addr}/balances?owner_id={}", Uuid::new_v4()), Some("t0k3n"))
+        .await
+        .unwrap();
+
+    // the subscription starts once the handshake is done
+    while fanout.subscriber_count() == 0 {
+        tokio::task::yield_now().await;
+    }
+    fanout.publish(change(1, Some(Uuid::new_v4()), 100));
+    fanout.publish(change(2, Some(owner), 250));
+
+    let Some(Ok(Message::Text(text))) = ws.next().await else { panic!("expected a text message") };
+    let pushed: BalanceChange = serde_json::from_str(&text).unwrap();
+    assert_eq!((pushed.account_id, pushed.balance_minor), (2, 250));
+
+    stop.send(true).unwrap();
+    assert!(matches!(ws.next().await, Some(Ok(Message::Close(_)))));
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn websocket_requires_an_authorized_owner() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (_stop, shutdown) = watch::channel(false);
+    let owner = Uuid::new_v4();
+    tokio::spawn(serve_balance_socket(listener, BalanceFanout::new(4), authorizer("t0k3n", owner), shutdown));
+
+    for (path, token) in [
+        (format!("/balances?owner_id={owner}"), None),
+        ("/balances".to_string(), Some("forged")),
+        ("/accounts".to_string(), Some("t0k3n")),
+    ] {
+        assert!(connect(format!("ws://{addr}{path}"), token).await.is_err(), "{path} {token:?}");
+    }
+    assert!(connect(format!("ws://{addr}/balances"), Some("t0k3n")).await.is_ok());
+}
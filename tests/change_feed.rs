@@ -0,0 +1,45 @@
+use chrono::{Duration, Utc};
+
+use sirara_core::domain::aggregate::FeedCursor;
+use sirara_core::domain::error::DomainError;
+
+#[test]
+fn consumer_names_are_normalized_and_validated() {
+    let c = FeedCursor::new("  Search-Index.v2 ", 0, Utc::now()).unwrap();
+    assert_eq!(c.consumer(), "search-index.v2");
+    assert_eq!(c.position(), 0);
+
+    for bad in ["", "   ", "analytics/eu", "billing feed", &"x".repeat(65)] {
+        assert!(matches!(FeedCursor::new(bad, 0, Utc::now()), Err(DomainError::InvalidFeedConsumer { .. })));
+    }
+    assert!(matches!(FeedCursor::new("analytics", -1, Utc::now()), Err(DomainError::FeedCursorInvalid { .. })));
+}
+
+#[test]
+fn cursor_only_moves_forward() {
+    let start = Utc::now();
+    let mut c = FeedCursor::new("analytics", 10, start).unwrap();
+
+    let later = start + Duration::seconds(5);
+    assert!(c.advance(25, later).unwrap());
+    assert_eq!(c.position(), 25);
+    assert_eq!(c.updated_at(), later);
+    assert_eq!(c.created_at(), start);
+
+    // a repeated acknowledgement changes nothing
+    assert!(!c.advance(25, later + Duration::seconds(1)).unwrap());
+    assert_eq!(c.updated_at(), later);
+
+    // a stale acknowledgement would replay journals
+    assert!(matches!(c.advance(24, later), Err(DomainError::FeedCursorInvalid { .. })));
+    assert_eq!(c.position(), 25);
+}
+
+#[test]
+fn restored_cursor_resumes_where_it_stopped() {
+    let at = Utc::now();
+    let mut c = FeedCursor::restore("notifications".to_string(), 42, at, at);
+    assert_eq!(c.position(), 42);
+    assert!(c.advance(43, at).unwrap());
+    assert!(matches!(c.advance(42, at), Err(DomainError::FeedCursorInvalid { .. })));
+}
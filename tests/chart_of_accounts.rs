@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+
+use sirara_core::domain::entities::{AccountType, ChartOfAccounts, CoaNode, GlClass, NormalBalance};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::{FinancialStatements, NodeFlows};
+
+const NGN: i16 = 1;
+
+fn node(id: i64, code: &str, class: GlClass, parent_id: Option<i64>) -> CoaNode {
+    CoaNode {
+        id,
+        code: code.to_string(),
+        name: format!("node {code}"),
+        gl_class: class,
+        normal_balance: class.default_normal_balance(),
+        parent_id,
+    }
+}
+
+fn chart() -> ChartOfAccounts {
+    ChartOfAccounts::new(vec![
+        node(1, "1000", GlClass::Asset, None),
+        node(11, "1100", GlClass::Asset, Some(1)),
+        node(2, "2000", GlClass::Liability, None),
+        node(21, "2100", GlClass::Liability, Some(2)),
+        node(211, "2110", GlClass::Liability, Some(21)),
+        node(212, "2120", GlClass::Liability, Some(21)),
+        node(3, "3000", GlClass::Equity, None),
+        node(31, "3110", GlClass::Equity, Some(3)),
+        node(4, "4000", GlClass::Revenue, None),
+        node(41, "4100", GlClass::Revenue, Some(4)),
+        node(5, "5000", GlClass::Expense, None),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn chart_rejects_cycles_and_mixed_classes() {
+    let mixed = ChartOfAccounts::new(vec![
+        node(1, "1000", GlClass::Asset, None),
+        node(2, "2000", GlClass::Liability, Some(1)),
+    ]);
+    assert!(matches!(mixed, Err(DomainError::ChartOfAccountsInvalid { .. })));
+
+    let cycle = ChartOfAccounts::new(vec![
+        node(1, "1000", GlClass::Asset, None),
+        node(2, "1100", GlClass::Asset, Some(3)),
+        node(3, "1200", GlClass::Asset, Some(2)),
+    ]);
+    assert!(matches!(cycle, Err(DomainError::ChartOfAccountsInvalid { .. })));
+}
+
+#[test]
+fn rollup_sums_descendants_in_normal_balance_presentation() {
+    // user funds: 900 available + 100 locked, held against 1_000 in clearing
+    let ledger = HashMap::from([(211, 900), (212, 100), (11, -1_000)]);
+    let rolled = chart().rollup(&ledger).unwrap();
+    let by_code: HashMap<&str, (usize, i128)> =
+        rolled.iter().map(|n| (n.code.as_str(), (n.depth, n.total_minor))).collect();
+
+    assert_eq!(by_code["2000"], (0, 1_000));
+    assert_eq!(by_code["2100"], (1, 1_000));
+    assert_eq!(by_code["2110"], (2, 900));
+    assert_eq!(by_code["1000"], (0, 1_000));
+
+    let codes: Vec<&str> = rolled.iter().map(|n| n.code.as_str()).collect();
+    assert_eq!(&codes[..6], &["1000", "1100", "2000", "2100", "2110", "2120"]);
+}
+
+#[test]
+fn statements_show_user_funds_as_liabilities_and_balance() {
+    let chart = chart();
+
+    // deposits 1_000 into clearing, treasury seeds 500, users paid 20 in fees
+    let balances = HashMap::from([(11, -1_500), (211, 980), (31, 500), (41, 20)]);
+    let sheet = FinancialStatements::balance_sheet(&chart, NGN, Utc::now(), &balances).unwrap();
+    assert_eq!(sheet.total_assets, 1_500);
+    assert_eq!(sheet.total_liabilities, 980);
+    assert_eq!(sheet.total_equity, 500);
+    assert_eq!(sheet.current_earnings, 20);
+    assert!(sheet.is_balanced());
+
+    let to = Utc::now();
+    let from = to - Duration::days(30);
+    let movements = HashMap::from([(41, 20), (211, -20)]);
+    let income = FinancialStatements::income_statement(&chart, NGN, from, to, &movements).unwrap();
+    assert_eq!((income.total_revenue, income.total_expenses, income.net_income), (20, 0, 20));
+    assert_eq!(income.revenue.iter().map(|n| n.code.as_str()).collect::<Vec<_>>(), vec!["4000", "4100"]);
+}
+
+#[test]
+fn contra_nodes_reduce_their_parent() {
+    let mut nodes = vec![node(1, "1000", GlClass::Asset, None), node(11, "1100", GlClass::Asset, Some(1))];
+    nodes.push(CoaNode { normal_balance: NormalBalance::Credit, ..node(12, "1190", GlClass::Asset, Some(1)) });
+    let chart = ChartOfAccounts::new(nodes).unwrap();
+
+    let rolled = chart.rollup(&HashMap::from([(11, -1_000), (12, 150)])).unwrap();
+    let totals: HashMap<&str, i128> = rolled.iter().map(|n| (n.code.as_str(), n.total_minor)).collect();
+    assert_eq!(totals["1190"], 150);
+    assert_eq!(totals["1000"], 850);
+}
+
+#[test]
+fn movement_summary_reconciles_opening_flows_and_closing() {
+    let chart = chart();
+    let to = Utc::now();
+    let from = to - Duration::days(1);
+
+    // users held 600; during the day 500 was deposited and 120 withdrawn
+    let opening = HashMap::from([(11, -600), (211, 600)]);
+    let flows = HashMap::from([
+        (11, NodeFlows { inflow_minor: 120, outflow_minor: -500 }),
+        (211, NodeFlows { inflow_minor: 500, outflow_minor: -120 }),
+    ]);
+    let summary = FinancialStatements::movement_summary(&chart, NGN, from, to, &opening, &flows).unwrap();
+    let by_code: HashMap<&str, (i128, i128, i128, i128)> = summary
+        .nodes
+        .iter()
+        .map(|n| (n.code.as_str(), (n.opening_minor, n.increase_minor, n.decrease_minor, n.closing_minor)))
+        .collect();
+
+    assert_eq!(by_code["1100"], (600, 500, 120, 980));
+    assert_eq!(by_code["2000"], (600, 500, 120, 980));
+    assert_eq!(by_code["3000"], (0, 0, 0, 0));
+}
+
+#[test]
+fn user_buckets_report_as_liabilities() {
+    assert_eq!(AccountType::UserAvailable.gl_class(), GlClass::Liability);
+    assert_eq!(AccountType::UserLocked.gl_class(), GlClass::Liability);
+    assert_eq!(AccountType::PlatformClearing.gl_class(), GlClass::Asset);
+    assert_eq!(AccountType::PlatformRevenue.gl_class(), GlClass::Revenue);
+}
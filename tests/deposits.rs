@@ -0,0 +1,149 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    ConfirmationThreshold, DepositIntent, DepositPolicy, DepositSource, DepositStatus, DEPOSIT_ID_KEY, DEPOSIT_TX_KEY,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRefType, PublicId};
+
+const USDT: i16 = 3;
+
+fn policy() -> DepositPolicy {
+    DepositPolicy::new(vec![ConfirmationThreshold {
+        asset_id: USDT,
+        required_confirmations: 12,
+        source: DepositSource::PlatformClearing,
+    }])
+}
+
+fn pending(owner: Uuid, confirmations: u32) -> DepositIntent {
+    let spec = DepositIntent::detect(
+        PublicId::new(Uuid::new_v4()),
+        owner,
+        USDT,
+        250_000_000,
+        "0xabc:1",
+        confirmations,
+        &policy(),
+        "chain-watcher",
+    )
+    .unwrap();
+    DepositIntent::restore(1, spec, confirmations, DepositStatus::Pending, None, None, None)
+}
+
+fn accounts(owner: Uuid) -> (LedgerAccount, LedgerAccount) {
+    let clearing = LedgerAccount::new(
+        10,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::Platform,
+        None,
+        AccountType::PlatformClearing,
+        USDT,
+        true,
+    );
+    let available = LedgerAccount::new(
+        11,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::User,
+        Some(owner),
+        AccountType::UserAvailable,
+        USDT,
+        true,
+    );
+    (clearing, available)
+}
+
+#[test]
+fn detection_takes_the_assets_threshold_and_source() {
+    let d = pending(Uuid::new_v4(), 2);
+    assert_eq!(d.required_confirmations(), 12);
+    assert_eq!(d.source(), DepositSource::PlatformClearing);
+    assert!(!d.is_creditable());
+
+    assert!(matches!(
+        DepositIntent::detect(PublicId::new(Uuid::new_v4()), Uuid::new_v4(), 1, 100, "tx", 0, &policy(), "w"),
+        Err(DomainError::DepositAssetNotConfigured { asset_id: 1 })
+    ));
+    assert!(matches!(
+        DepositIntent::detect(PublicId::new(Uuid::new_v4()), Uuid::new_v4(), USDT, 0, "tx", 0, &policy(), "w"),
+        Err(DomainError::DepositInvalid { .. })
+    ));
+    assert!(matches!(
+        DepositIntent::detect(PublicId::new(Uuid::new_v4()), Uuid::new_v4(), USDT, 100, "  ", 0, &policy(), "w"),
+        Err(DomainError::DepositInvalid { .. })
+    ));
+}
+
+#[test]
+fn confirmations_reach_the_threshold_and_may_go_back_after_a_reorg() {
+    let mut d = pending(Uuid::new_v4(), 0);
+    let now = Utc::now();
+
+    assert!(d.record_confirmations(11, now));
+    assert!(!d.is_creditable());
+    assert!(!d.record_confirmations(11, now));
+
+    assert!(d.record_confirmations(12, now));
+    assert!(d.is_creditable());
+
+    assert!(d.record_confirmations(3, now));
+    assert!(!d.is_creditable());
+    assert!(matches!(d.mark_credited(99, now), Err(DomainError::DepositInvalid { .. })));
+}
+
+#[test]
+fn credit_moves_funds_from_the_source_to_user_available() {
+    let owner = Uuid::new_v4();
+    let d = pending(owner, 12);
+    let (clearing, available) = accounts(owner);
+
+    let draft = d.credit_draft(&clearing, &available, "chain-watcher").unwrap();
+    assert_eq!(draft.external_ref_type, ExternalRefType::Deposit);
+    assert_eq!(draft.external_ref.as_str(), format!("deposit:{}:credit", d.public_id().value()));
+    assert_eq!(draft.metadata.get(DEPOSIT_ID_KEY), Some(d.public_id().value().to_string().as_str()));
+    assert_eq!(draft.metadata.get(DEPOSIT_TX_KEY), Some("0xabc:1"));
+
+    let lines: Vec<(i64, i128)> = draft.lines().iter().map(|l| (l.account_id, l.amount.minor())).collect();
+    assert_eq!(lines, vec![(10, -250_000_000), (11, 250_000_000)]);
+
+    // someone else's account, or the wrong source bucket, is refused
+    let (_, stranger) = accounts(Uuid::new_v4());
+    assert!(matches!(d.credit_draft(&clearing, &stranger, "w"), Err(DomainError::DepositInvalid { .. })));
+    assert!(matches!(d.credit_draft(&available, &available, "w"), Err(DomainError::DepositInvalid { .. })));
+}
+
+#[test]
+fn credited_deposits_ignore_further_updates() {
+    let mut d = pending(Uuid::new_v4(), 12);
+    let now = Utc::now();
+
+    d.mark_credited(42, now).unwrap();
+    assert_eq!(d.status(), DepositStatus::Credited);
+    assert_eq!(d.journal_id(), Some(42));
+    assert!(!d.is_creditable());
+
+    assert!(!d.record_confirmations(1, now));
+    assert_eq!(d.confirmations(), 12);
+    assert!(d.mark_credited(43, now).is_err());
+}
+
+#[test]
+fn repeated_detection_matches_only_the_same_transfer() {
+    let owner = Uuid::new_v4();
+    let existing = pending(owner, 1);
+    let policy = policy();
+
+    let replay =
+        DepositIntent::detect(PublicId::new(Uuid::new_v4()), owner, USDT, 250_000_000, "0xabc:1", 5, &policy, "w")
+            .unwrap();
+    assert!(replay.matches(&existing));
+
+    let other_amount =
+        DepositIntent::detect(PublicId::new(Uuid::new_v4()), owner, USDT, 1, "0xabc:1", 5, &policy, "w").unwrap();
+    assert!(!other_amount.matches(&existing));
+
+    assert_eq!(DepositSource::from_code("TREASURY").unwrap().account_type(), AccountType::TreasuryAvailable);
+    assert!(matches!(DepositSource::from_code("HOT_WALLET"), Err(DomainError::InvalidDepositSource { .. })));
+}
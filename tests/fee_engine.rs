@@ -0,0 +1,151 @@
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::JournalDraft;
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::{
+    FeeCalculation, FeeContext, FeeEngine, FeeRounding, FeeRule, FeeSchedule, FeeTier,
+};
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+
+const NGN: i16 = 1;
+const PAYER: i64 = 10;
+const RECEIVER: i64 = 11;
+const REVENUE: i64 = 99;
+
+fn rule(id: i64, calculation: FeeCalculation) -> FeeRule {
+    FeeRule {
+        id,
+        asset_id: NGN,
+        posting_kind: ExternalRefType::TransferIntent,
+        calculation,
+        min_fee_minor: None,
+        max_fee_minor: None,
+        rounding: FeeRounding::HalfEven,
+    }
+}
+
+fn transfer_draft(amount: i128) -> JournalDraft {
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new(format!("test:{}", Uuid::new_v4())).unwrap(),
+        "test",
+        None,
+    )
+    .unwrap();
+    draft.add_line(PAYER, Money::credit(amount).unwrap());
+    draft.add_line(RECEIVER, Money::debit(amount).unwrap());
+    draft
+}
+
+fn ctx(base: i128) -> FeeContext {
+    FeeContext {
+        asset_id: NGN,
+        payer_account_id: PAYER,
+        revenue_account_id: REVENUE,
+        base_amount_minor: base,
+    }
+}
+
+#[test]
+fn percentage_rounding_is_deterministic_per_mode() {
+    // 1_005 * 50bps = 5.025 minor units
+    let mut r = rule(1, FeeCalculation::Percentage { rate_bps: 50 });
+    let cases = [
+        (FeeRounding::Down, 5),
+        (FeeRounding::Up, 6),
+        (FeeRounding::HalfUp, 5),
+        (FeeRounding::HalfEven, 5),
+    ];
+    for (mode, expected) in cases {
+        r.rounding = mode;
+        assert_eq!(r.compute(1_005).unwrap(), expected, "{mode:?}");
+    }
+
+    // exact halves: 100 * 50bps = 0.5, 300 * 50bps = 1.5
+    r.rounding = FeeRounding::HalfEven;
+    assert_eq!(r.compute(100).unwrap(), 0);
+    r.rounding = FeeRounding::HalfUp;
+    assert_eq!(r.compute(100).unwrap(), 1);
+    r.rounding = FeeRounding::HalfEven;
+    assert_eq!(r.compute(300).unwrap(), 2);
+}
+
+#[test]
+fn tiered_fee_uses_bracket_containing_base_and_clamps() {
+    let mut r = rule(
+        2,
+        FeeCalculation::Tiered {
+            tiers: vec![
+                FeeTier { up_to_minor: Some(10_000), rate_bps: 100, flat_minor: 0 },
+                FeeTier { up_to_minor: Some(100_000), rate_bps: 50, flat_minor: 10 },
+                FeeTier { up_to_minor: None, rate_bps: 25, flat_minor: 0 },
+            ],
+        },
+    );
+    r.min_fee_minor = Some(20);
+    r.max_fee_minor = Some(1_000);
+
+    assert_eq!(r.compute(1_000).unwrap(), 20); // 10 -> min
+    assert_eq!(r.compute(10_000).unwrap(), 100);
+    assert_eq!(r.compute(50_000).unwrap(), 260);
+    assert_eq!(r.compute(10_000_000).unwrap(), 1_000); // 25_000 -> max
+}
+
+#[test]
+fn invalid_rules_are_rejected_by_schedule() {
+    let mut r = rule(3, FeeCalculation::Flat { amount_minor: 10 });
+    r.min_fee_minor = Some(50);
+    r.max_fee_minor = Some(5);
+    assert!(matches!(FeeSchedule::new(vec![r]), Err(DomainError::FeeRuleInvalid { rule_id: 3, .. })));
+
+    let r = rule(
+        4,
+        FeeCalculation::Tiered {
+            tiers: vec![
+                FeeTier { up_to_minor: None, rate_bps: 10, flat_minor: 0 },
+                FeeTier { up_to_minor: Some(10), rate_bps: 10, flat_minor: 0 },
+            ],
+        },
+    );
+    assert!(matches!(FeeSchedule::new(vec![r]), Err(DomainError::FeeRuleInvalid { rule_id: 4, .. })));
+}
+
+#[test]
+fn apply_appends_balanced_fee_lines_and_records_rule_ids() {
+    let schedule = FeeSchedule::new(vec![
+        rule(7, FeeCalculation::Percentage { rate_bps: 150 }),
+        rule(5, FeeCalculation::Flat { amount_minor: 25 }),
+        FeeRule { asset_id: 2, ..rule(6, FeeCalculation::Flat { amount_minor: 1 }) },
+    ])
+    .unwrap();
+
+    let mut draft = transfer_draft(10_000);
+    let applied = FeeEngine::apply(&schedule, &mut draft, &ctx(10_000)).unwrap();
+
+    assert_eq!(applied.iter().map(|a| (a.rule_id, a.amount_minor)).collect::<Vec<_>>(), vec![(5, 25), (7, 150)]);
+    assert_eq!(draft.fee_rule_ids(), &[5, 7]);
+    assert!(draft.ensure_balanced().is_ok());
+
+    let revenue: i128 = draft.lines().iter().filter(|l| l.account_id == REVENUE).map(|l| l.amount.minor()).sum();
+    let payer: i128 = draft.lines().iter().filter(|l| l.account_id == PAYER).map(|l| l.amount.minor()).sum();
+    assert_eq!(revenue, 175);
+    assert_eq!(payer, -10_175);
+}
+
+#[test]
+fn apply_skips_zero_fees_and_rejects_payer_as_revenue() {
+    let schedule = FeeSchedule::new(vec![rule(1, FeeCalculation::Percentage { rate_bps: 1 })]).unwrap();
+
+    let mut draft = transfer_draft(100);
+    let applied = FeeEngine::apply(&schedule, &mut draft, &ctx(100)).unwrap();
+    assert!(applied.is_empty());
+    assert!(draft.fee_rule_ids().is_empty());
+    assert_eq!(draft.lines().len(), 2);
+
+    let bad = FeeContext { revenue_account_id: PAYER, ..ctx(100) };
+    assert_eq!(
+        FeeEngine::apply(&schedule, &mut draft, &bad).unwrap_err(),
+        DomainError::FeePayerIsRevenueAccount { account_id: PAYER }
+    );
+}
@@ -0,0 +1,151 @@
+use chrono::{Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    FxLiquidity, FxPolicy, FxQuote, FxQuoteStatus, FxRate, PairSpread, FX_APPLIED_RATE_KEY, FX_LEG_KEY, FX_RATE_KEY,
+    FX_SPREAD_KEY,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{Asset, AssetCode, ExchangeRate, ExternalRefType, PublicId};
+
+const USD: i16 = 1;
+const NGN: i16 = 2;
+
+fn usd() -> Asset {
+    Asset::new(USD, AssetCode::new("USD").unwrap(), 2, true)
+}
+
+fn ngn() -> Asset {
+    Asset::new(NGN, AssetCode::new("NGN").unwrap(), 2, true)
+}
+
+fn policy() -> FxPolicy {
+    FxPolicy::new(
+        Duration::seconds(30),
+        50,
+        vec![PairSpread { base_asset_id: USD, quote_asset_id: NGN, spread_bps: 100 }],
+        FxLiquidity::Treasury,
+    )
+}
+
+fn usd_ngn(rate: &str, valid_to: Option<chrono::DateTime<Utc>>) -> FxRate {
+    let from = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let spec = FxRate::define(USD, NGN, ExchangeRate::parse(rate).unwrap(), from, valid_to, "feed", "ops").unwrap();
+    FxRate::restore(7, spec)
+}
+
+fn account(id: i64, owner_type: OwnerType, owner: Option<Uuid>, account_type: AccountType, asset_id: i16) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), owner_type, owner, account_type, asset_id, true)
+}
+
+#[test]
+fn rates_parse_exactly_and_convert_rounding_down() {
+    let rate = ExchangeRate::parse("1523.450").unwrap();
+    assert_eq!((rate.mantissa(), rate.scale()), (152_345, 2));
+    assert_eq!(rate.to_string(), "1523.45");
+    assert_eq!(ExchangeRate::parse("0.000125").unwrap().to_string(), "0.000125");
+
+    // 10.00 USD at 1523.45 is 15234.50 NGN
+    assert_eq!(rate.convert(1_000, 2, 2).unwrap(), 1_523_450);
+    // 0.01 BTC (8 decimals) at 0.5 into a 2-decimal asset
+    assert_eq!(ExchangeRate::parse("0.5").unwrap().convert(1_000_000, 8, 2).unwrap(), 0);
+    assert_eq!(ExchangeRate::parse("3").unwrap().convert(1_000_000, 8, 2).unwrap(), 3);
+
+    for bad in ["", "0", "-1", "1e5", "1.2.3", "0.0000000000000000001"] {
+        assert!(matches!(ExchangeRate::parse(bad), Err(DomainError::FxRateInvalid { .. })), "{bad}");
+    }
+}
+
+#[test]
+fn spread_is_taken_off_the_mid_rate() {
+    let rate = ExchangeRate::parse("1500").unwrap();
+    assert_eq!(rate.less_spread(100).unwrap().to_string(), "1485");
+    assert_eq!(rate.less_spread(0).unwrap(), rate);
+    assert!(rate.less_spread(10_000).is_err());
+
+    let policy = policy();
+    assert_eq!(policy.spread_bps(USD, NGN), 100);
+    assert_eq!(policy.spread_bps(NGN, USD), 50);
+}
+
+#[test]
+fn quote_locks_the_applied_rate_until_the_ttl_or_the_rate_ends() {
+    let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let owner = Uuid::new_v4();
+
+    let spec = FxQuote::issue(PublicId::new(Uuid::new_v4()), owner, &usd_ngn("1500", None), &usd(), &ngn(), 1_000, &policy(), "api", now)
+        .unwrap();
+    assert_eq!(spec.applied_rate.to_string(), "1485");
+    assert_eq!(spec.to_amount_minor, 1_485_000);
+    assert_eq!(spec.expires_at, now + Duration::seconds(30));
+    assert_eq!(spec.liquidity, FxLiquidity::Treasury);
+
+    let ending = now + Duration::seconds(10);
+    let short = FxQuote::issue(
+        PublicId::new(Uuid::new_v4()),
+        owner,
+        &usd_ngn("1500", Some(ending)),
+        &usd(),
+        &ngn(),
+        1_000,
+        &policy(),
+        "api",
+        now,
+    )
+    .unwrap();
+    assert_eq!(short.expires_at, ending);
+
+    let expires_at = spec.expires_at;
+    let quote = FxQuote::restore(1, spec, FxQuoteStatus::Open, None, None, None, None);
+    assert_eq!(quote.status_at(now), FxQuoteStatus::Open);
+    assert_eq!(quote.status_at(expires_at), FxQuoteStatus::Expired);
+    assert!(matches!(quote.ensure_executable(expires_at), Err(DomainError::FxQuoteExpired { .. })));
+
+    assert!(matches!(
+        FxQuote::issue(PublicId::new(Uuid::new_v4()), owner, &usd_ngn("1500", None), &ngn(), &usd(), 1_000, &policy(), "api", now),
+        Err(DomainError::FxQuoteInvalid { .. })
+    ));
+}
+
+#[test]
+fn conversion_posts_two_legs_each_balanced_in_its_own_asset() {
+    let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let owner = Uuid::new_v4();
+    let spec = FxQuote::issue(PublicId::new(Uuid::new_v4()), owner, &usd_ngn("1500", None), &usd(), &ngn(), 1_000, &policy(), "api", now)
+        .unwrap();
+    let mut quote = FxQuote::restore(1, spec, FxQuoteStatus::Open, None, None, None, None);
+
+    let user_usd = account(1, OwnerType::User, Some(owner), AccountType::UserAvailable, USD);
+    let pool_usd = account(2, OwnerType::Treasury, None, AccountType::TreasuryAvailable, USD);
+    let pool_ngn = account(3, OwnerType::Treasury, None, AccountType::TreasuryAvailable, NGN);
+    let user_ngn = account(4, OwnerType::User, Some(owner), AccountType::UserAvailable, NGN);
+
+    let (sell, buy) = quote.conversion_drafts(&user_usd, &pool_usd, &pool_ngn, &user_ngn, "api").unwrap();
+    let lines = |d: &sirara_core::domain::aggregate::JournalDraft| {
+        d.lines().iter().map(|l| (l.account_id, l.amount.minor())).collect::<Vec<_>>()
+    };
+    assert_eq!(lines(&sell), vec![(1, -1_000), (2, 1_000)]);
+    assert_eq!(lines(&buy), vec![(3, -1_485_000), (4, 1_485_000)]);
+
+    let (sell_ref, buy_ref) = quote.leg_refs().unwrap();
+    assert_eq!((&sell.external_ref, &buy.external_ref), (&sell_ref, &buy_ref));
+    for (draft, leg) in [(&sell, "sell"), (&buy, "buy")] {
+        assert_eq!(draft.external_ref_type, ExternalRefType::FxConversion);
+        assert_eq!(draft.metadata.get(FX_LEG_KEY), Some(leg));
+        assert_eq!(draft.metadata.get(FX_RATE_KEY), Some("1500"));
+        assert_eq!(draft.metadata.get(FX_SPREAD_KEY), Some("100"));
+        assert_eq!(draft.metadata.get(FX_APPLIED_RATE_KEY), Some("1485"));
+    }
+
+    // the pool must match the quote's liquidity
+    let inventory = account(5, OwnerType::Platform, None, AccountType::InventoryAvailable, USD);
+    assert!(matches!(
+        quote.conversion_drafts(&user_usd, &inventory, &pool_ngn, &user_ngn, "api"),
+        Err(DomainError::FxQuoteInvalid { .. })
+    ));
+
+    quote.mark_executed(10, 11, "api", now).unwrap();
+    assert_eq!(quote.status_at(now + Duration::hours(1)), FxQuoteStatus::Executed);
+    assert!(matches!(quote.ensure_executable(now), Err(DomainError::FxQuoteNotOpen { .. })));
+}
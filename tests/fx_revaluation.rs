@@ -0,0 +1,156 @@
+use chrono::{Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    FxRate, FxRevaluation, FxRevaluationStatus, JournalDraft, RevaluationLine, RevaluationPosition,
+    FX_CLOSING_RATE_KEY, FX_OPENING_RATE_KEY, FX_REVALUED_ASSET_KEY,
+};
+use sirara_core::domain::entities::{AccountType, AccountingPeriod, LedgerAccount, OwnerType, PeriodStatus};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{Asset, AssetCode, ExchangeRate, ExternalRefType, PublicId};
+
+const NGN: i16 = 1;
+const USD: i16 = 2;
+const BTC: i16 = 3;
+const EUR: i16 = 4;
+
+fn ngn() -> Asset {
+    Asset::new(NGN, AssetCode::new("NGN").unwrap(), 2, true)
+}
+
+fn rate(id: i64, base: i16, value: &str) -> FxRate {
+    let from = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    FxRate::restore(id, FxRate::define(base, NGN, ExchangeRate::parse(value).unwrap(), from, None, "feed", "ops").unwrap())
+}
+
+fn march(asset_id: i16, status: PeriodStatus) -> AccountingPeriod {
+    let spec = AccountingPeriod::monthly(asset_id, 2026, 3).unwrap();
+    AccountingPeriod::restore(9, spec.asset_id, spec.name, spec.starts_at, spec.ends_at, status, None, None)
+}
+
+fn account(id: i64, owner_type: OwnerType, account_type: AccountType, asset_id: i16) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), owner_type, None, account_type, asset_id, true)
+}
+
+fn revaluation_accounts() -> (LedgerAccount, LedgerAccount) {
+    (
+        account(50, OwnerType::Treasury, AccountType::TreasuryRevaluation, NGN),
+        account(51, OwnerType::Platform, AccountType::PlatformUnrealisedFx, NGN),
+    )
+}
+
+fn usd_line(balance_minor: i128) -> RevaluationLine {
+    let position = RevaluationPosition { asset_id: USD, decimals: 2, balance_minor };
+    RevaluationLine::value(&position, &ngn(), &rate(1, USD, "1500"), &rate(2, USD, "1520.5")).unwrap()
+}
+
+fn lines(d: &JournalDraft) -> Vec<(i64, i128)> {
+    d.lines().iter().map(|l| (l.account_id, l.amount.minor())).collect()
+}
+
+#[test]
+fn holdings_are_valued_at_both_rates_and_symmetrically() {
+    // 1 000.00 USD: 1 500 000.00 NGN at the open, 1 520 500.00 NGN at the close
+    let long = usd_line(100_000);
+    assert_eq!((long.opening_value_minor, long.closing_value_minor), (150_000_000, 152_050_000));
+    assert_eq!(long.gain_minor, 2_050_000);
+    assert_eq!((long.opening_rate_id, long.closing_rate_id), (1, 2));
+
+    let short = usd_line(-100_000);
+    assert_eq!(short.gain_minor, -2_050_000);
+
+    // a rate for another asset, or the reporting asset itself, is refused
+    let position = RevaluationPosition { asset_id: BTC, decimals: 8, balance_minor: 1 };
+    assert!(matches!(
+        RevaluationLine::value(&position, &ngn(), &rate(1, USD, "1500"), &rate(2, USD, "1500")),
+        Err(DomainError::FxRevaluationInvalid { .. })
+    ));
+    let own = RevaluationPosition { asset_id: NGN, decimals: 2, balance_minor: 1 };
+    assert!(RevaluationLine::value(&own, &ngn(), &rate(1, USD, "1500"), &rate(2, USD, "1500")).is_err());
+}
+
+#[test]
+fn runs_cover_an_ended_period_of_the_reporting_asset() {
+    let period = march(NGN, PeriodStatus::Open);
+    let after = period.ends_at() + Duration::hours(1);
+    let id = PublicId::new(Uuid::new_v4());
+
+    let spec = FxRevaluation::prepare(id, &ngn(), &period, vec![usd_line(100_000)], "ops", after).unwrap();
+    assert_eq!(spec.valued_at, period.ends_at());
+    assert_eq!(spec.total_gain_minor(), 2_050_000);
+
+    let invalid = |r: Result<_, DomainError>| matches!(r, Err(DomainError::FxRevaluationInvalid { .. }));
+    assert!(invalid(FxRevaluation::prepare(id, &ngn(), &march(USD, PeriodStatus::Open), vec![], "ops", after)));
+    assert!(invalid(FxRevaluation::prepare(id, &ngn(), &period, vec![], "ops", period.ends_at() - Duration::seconds(1))));
+    assert!(invalid(FxRevaluation::prepare(id, &ngn(), &period, vec![usd_line(1), usd_line(2)], "ops", after)));
+
+    // the closing revaluation may still land once the period is soft-closed
+    assert!(march(NGN, PeriodStatus::SoftClosed).ensure_accepts(ExternalRefType::FxRevaluation).is_ok());
+    assert!(march(NGN, PeriodStatus::Closed).ensure_accepts(ExternalRefType::FxRevaluation).is_err());
+}
+
+#[test]
+fn gains_and_losses_post_against_the_reserve_on_the_last_instant_of_the_period() {
+    let period = march(NGN, PeriodStatus::Open);
+    let after = period.ends_at() + Duration::hours(1);
+    let id = PublicId::new(Uuid::new_v4());
+    let btc = RevaluationLine::value(
+        &RevaluationPosition { asset_id: BTC, decimals: 8, balance_minor: 50_000_000 },
+        &ngn(),
+        &rate(3, BTC, "100000000"),
+        &rate(4, BTC, "90000000"),
+    )
+    .unwrap();
+    let flat = RevaluationLine::value(
+        &RevaluationPosition { asset_id: EUR, decimals: 2, balance_minor: 10_000 },
+        &ngn(),
+        &rate(5, EUR, "1600"),
+        &rate(6, EUR, "1600"),
+    )
+    .unwrap();
+    let spec = FxRevaluation::prepare(id, &ngn(), &period, vec![usd_line(100_000), btc, flat], "ops", after).unwrap();
+
+    let (reserve, unrealised) = revaluation_accounts();
+    let drafts = spec.journal_drafts(&reserve, &unrealised, "ops").unwrap();
+    assert_eq!(drafts.len(), 2, "an unchanged holding posts nothing");
+
+    let (asset, gain) = &drafts[0];
+    assert_eq!(*asset, USD);
+    assert_eq!(lines(gain), vec![(50, 2_050_000), (51, -2_050_000)]);
+    assert_eq!(gain.external_ref_type, ExternalRefType::FxRevaluation);
+    assert_eq!(gain.external_ref, FxRevaluation::journal_ref(id, USD, false).unwrap());
+    assert_eq!(gain.effective_at, Some(period.ends_at() - Duration::microseconds(1)));
+    assert_eq!(gain.metadata.get(FX_REVALUED_ASSET_KEY), Some("2"));
+    assert_eq!(gain.metadata.get(FX_OPENING_RATE_KEY), Some("1500"));
+    assert_eq!(gain.metadata.get(FX_CLOSING_RATE_KEY), Some("1520.5"));
+
+    // 0.5 BTC fell from 50 000 000.00 to 45 000 000.00 NGN
+    assert_eq!(lines(&drafts[1].1), vec![(50, -500_000_000), (51, 500_000_000)]);
+
+    let pool = account(52, OwnerType::Treasury, AccountType::TreasuryAvailable, NGN);
+    assert!(matches!(spec.journal_drafts(&pool, &unrealised, "ops"), Err(DomainError::FxRevaluationInvalid { .. })));
+}
+
+#[test]
+fn reversal_mirrors_the_posted_journals_once() {
+    let period = march(NGN, PeriodStatus::Open);
+    let id = PublicId::new(Uuid::new_v4());
+    let mut spec =
+        FxRevaluation::prepare(id, &ngn(), &period, vec![usd_line(100_000)], "ops", period.ends_at()).unwrap();
+    spec.record_journal(USD, 70);
+    let mut run = FxRevaluation::restore(1, spec, FxRevaluationStatus::Posted, period.ends_at(), None, None);
+
+    let (reserve, unrealised) = revaluation_accounts();
+    let drafts = run.reversal_drafts(&reserve, &unrealised, "ops").unwrap();
+    assert_eq!(drafts.len(), 1);
+    let (_, reversal) = &drafts[0];
+    assert_eq!(lines(reversal), vec![(50, -2_050_000), (51, 2_050_000)]);
+    assert_eq!(reversal.external_ref, FxRevaluation::journal_ref(id, USD, true).unwrap());
+    assert_eq!(reversal.effective_at, Some(period.ends_at() - Duration::microseconds(1)));
+
+    run.mark_reversed(&[(USD, 71)], "ops", period.ends_at() + Duration::days(1)).unwrap();
+    assert_eq!(run.status(), FxRevaluationStatus::Reversed);
+    assert_eq!(run.lines()[0].reversal_journal_id, Some(71));
+    assert!(run.reversal_drafts(&reserve, &unrealised, "ops").is_err());
+    assert!(run.mark_reversed(&[], "ops", Utc::now()).is_err());
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    Hold, HoldAction, HoldStatus, JournalDraft, NewHold, EXPIRY_ACTOR, HOLD_ID_KEY,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::LedgerPostingService;
+use sirara_core::domain::value_objects::{ExternalRefType, PublicId};
+
+const NGN: i16 = 1;
+
+fn user_account(id: i64, owner: Uuid, account_type: AccountType) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), OwnerType::User, Some(owner), account_type, NGN, true)
+}
+
+fn placed(amount: i128) -> (NewHold, [LedgerAccount; 3]) {
+    let owner = Uuid::new_v4();
+    let accounts = [
+        user_account(1, owner, AccountType::UserAvailable),
+        user_account(2, owner, AccountType::UserLocked),
+        LedgerAccount::new(
+            3,
+            PublicId::new(Uuid::new_v4()),
+            OwnerType::Platform,
+            None,
+            AccountType::PlatformClearing,
+            NGN,
+            true,
+        ),
+    ];
+    let now = Utc::now();
+    let spec = Hold::place(
+        PublicId::new(Uuid::new_v4()),
+        &accounts[0],
+        &accounts[1],
+        amount,
+        Some(now + Duration::hours(1)),
+        Some("card hold".into()),
+        "cards",
+        now,
+    )
+    .unwrap();
+    (spec, accounts)
+}
+
+fn stored(spec: NewHold) -> Hold {
+    Hold::restore(1, spec, 0, 0, HoldStatus::Active, 1, None, None)
+}
+
+fn minor_of(draft: &JournalDraft, account_id: i64) -> i128 {
+    draft.lines().iter().filter(|l| l.account_id == account_id).map(|l| l.amount.minor()).sum()
+}
+
+/// Runs a hold draft through ledger validation and the posting policy.
+fn passes_policy(draft: JournalDraft, accounts: &[LedgerAccount]) -> bool {
+    let by_id: HashMap<i64, &LedgerAccount> = accounts.iter().map(|a| (a.id(), a)).collect();
+    let validated = draft.validate_with_accounts(&by_id).unwrap();
+    LedgerPostingService::validate(validated, &by_id).is_ok()
+}
+
+#[test]
+fn place_requires_the_same_users_buckets() {
+    let owner = Uuid::new_v4();
+    let available = user_account(1, owner, AccountType::UserAvailable);
+    let locked = user_account(2, owner, AccountType::UserLocked);
+    let other = user_account(3, Uuid::new_v4(), AccountType::UserLocked);
+    let now = Utc::now();
+    let id = PublicId::new(Uuid::new_v4());
+
+    assert!(matches!(
+        Hold::place(id, &available, &other, 100, None, None, "cards", now),
+        Err(DomainError::HoldMustBeSameUser { .. })
+    ));
+    assert!(matches!(
+        Hold::place(id, &locked, &available, 100, None, None, "cards", now),
+        Err(DomainError::HoldInvalid { .. })
+    ));
+    assert!(matches!(
+        Hold::place(id, &available, &locked, 0, None, None, "cards", now),
+        Err(DomainError::HoldInvalid { .. })
+    ));
+    assert!(matches!(
+        Hold::place(id, &available, &locked, 100, Some(now), None, "cards", now),
+        Err(DomainError::HoldInvalid { .. })
+    ));
+}
+
+#[test]
+fn place_journal_moves_available_to_locked_and_names_the_hold() {
+    let (spec, accounts) = placed(1_000);
+    let draft = spec.place_draft().unwrap();
+
+    assert_eq!(draft.external_ref_type, ExternalRefType::Hold);
+    assert_eq!(draft.external_ref.as_str(), format!("hold:{}:place", spec.public_id.value()));
+    assert_eq!(draft.metadata.get(HOLD_ID_KEY), Some(spec.public_id.value().to_string().as_str()));
+    assert_eq!(minor_of(&draft, 1), -1_000);
+    assert_eq!(minor_of(&draft, 2), 1_000);
+    assert!(passes_policy(draft, &accounts));
+}
+
+#[test]
+fn partial_captures_then_release_of_the_remainder() {
+    let (spec, accounts) = placed(1_000);
+    let mut hold = stored(spec);
+    let now = Utc::now();
+
+    let (draft, amount) = hold.capture_draft(Some(300), 3, "cards:capture").unwrap();
+    assert_eq!(amount, 300);
+    assert_eq!(draft.external_ref.as_str(), format!("hold:{}:capture:1", hold.public_id().value()));
+    assert_eq!(minor_of(&draft, 2), -300);
+    assert_eq!(minor_of(&draft, 3), 300);
+    assert!(passes_policy(draft, &accounts));
+    hold.record_capture(300, "cards:capture", now).unwrap();
+    assert_eq!((hold.status(), hold.remaining_minor(), hold.journal_count()), (HoldStatus::Active, 700, 2));
+
+    assert!(matches!(
+        hold.capture_draft(Some(701), 3, "cards:capture"),
+        Err(DomainError::HoldCaptureAmountInvalid { .. })
+    ));
+    assert!(matches!(hold.capture_draft(None, 2, "cards:capture"), Err(DomainError::HoldInvalid { .. })));
+
+    let release = hold.release_draft(HoldAction::Release, "cards").unwrap();
+    assert_eq!(minor_of(&release, 2), -700);
+    assert_eq!(minor_of(&release, 1), 700);
+    assert!(passes_policy(release, &accounts));
+    hold.record_release("cards", now).unwrap();
+    assert_eq!(hold.status(), HoldStatus::Released);
+    assert_eq!((hold.captured_minor(), hold.released_minor(), hold.remaining_minor()), (300, 700, 0));
+    assert!(matches!(hold.ensure_active(now), Err(DomainError::HoldNotActive { .. })));
+}
+
+#[test]
+fn full_capture_completes_the_hold() {
+    let (spec, _) = placed(500);
+    let mut hold = stored(spec);
+
+    let (_, amount) = hold.capture_draft(None, 3, "cards:capture").unwrap();
+    assert_eq!(amount, 500);
+    hold.record_capture(amount, "cards:capture", Utc::now()).unwrap();
+    assert_eq!(hold.status(), HoldStatus::Captured);
+    assert!(matches!(hold.release_draft(HoldAction::Release, "cards"), Err(DomainError::HoldNotActive { .. })));
+}
+
+#[test]
+fn expiry_releases_only_after_the_deadline() {
+    let (spec, _) = placed(500);
+    let mut hold = stored(spec);
+    let now = Utc::now();
+
+    assert!(matches!(hold.expire(now), Err(DomainError::HoldInvalid { .. })));
+    let later = hold.expires_at().unwrap() + Duration::seconds(1);
+    assert!(matches!(hold.ensure_active(later), Err(DomainError::HoldExpired { .. })));
+    assert!(matches!(hold.record_capture(100, "cards", later), Err(DomainError::HoldExpired { .. })));
+
+    let draft = hold.release_draft(HoldAction::Expire, EXPIRY_ACTOR).unwrap();
+    assert_eq!(draft.external_ref.as_str(), format!("hold:{}:expire", hold.public_id().value()));
+    hold.expire(later).unwrap();
+    assert_eq!(hold.status(), HoldStatus::Expired);
+    assert_eq!(hold.status_changed_by(), Some(EXPIRY_ACTOR));
+    assert_eq!(hold.released_minor(), 500);
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{JournalLine, PostedJournal};
+use sirara_core::domain::services::{
+    journal_hash, ChainBreakKind, ChainHead, ChainLink, ChainVerifier, GENESIS_HASH,
+};
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId};
+
+fn journal(db_id: i64, amount: i128) -> PostedJournal {
+    let at = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    PostedJournal {
+        db_id,
+        sequence: db_id,
+        public_id: PublicId::new(Uuid::new_v4()),
+        external_ref_type: ExternalRefType::TransferIntent,
+        external_ref: ExternalRef::new(format!("test:{db_id}")).unwrap(),
+        description: None,
+        created_by: "checkout".to_string(),
+        metadata: JournalMetadata::new(BTreeMap::from([("order_id".to_string(), format!("ord_{db_id}"))])).unwrap(),
+        tags: vec![JournalTag::new("retail").unwrap(), JournalTag::new("card").unwrap()],
+        effective_at: at,
+        posted_at: at,
+        multi_asset: false,
+        asset_id: Some(1),
+        lines: vec![
+            JournalLine { account_id: 10, asset_id: 1, amount: Money::credit(amount).unwrap(), memo: None },
+            JournalLine { account_id: 20, asset_id: 1, amount: Money::debit(amount).unwrap(), memo: None },
+        ],
+        fee_rule_ids: vec![],
+    }
+}
+
+/// Links journals the way postings do, returning the links and the resulting head.
+fn chain(journals: &[PostedJournal]) -> (Vec<ChainLink>, ChainHead) {
+    let mut prev = GENESIS_HASH;
+    let links: Vec<ChainLink> = journals
+        .iter()
+        .enumerate()
+        .map(|(i, j)| {
+            let entry_hash = journal_hash(&prev, j);
+            let link = ChainLink { position: i as i64 + 1, journal_id: j.db_id, prev_hash: prev, entry_hash };
+            prev = entry_hash;
+            link
+        })
+        .collect();
+    let head = ChainHead { position: links.len() as i64, entry_hash: prev };
+    (links, head)
+}
+
+fn verify(links: &[ChainLink], journals: &[Option<&PostedJournal>], head: &ChainHead) -> Option<(i64, ChainBreakKind)> {
+    let mut v = ChainVerifier::new();
+    for (link, j) in links.iter().zip(journals) {
+        if !v.check(link, *j) {
+            break;
+        }
+    }
+    v.finish(head).first_break.map(|b| (b.position, b.kind))
+}
+
+#[test]
+fn hash_covers_content_but_not_row_order() {
+    let j = journal(1, 500);
+    let h = journal_hash(&GENESIS_HASH, &j);
+
+    let mut reordered = j.clone();
+    reordered.lines.reverse();
+    reordered.tags.reverse();
+    assert_eq!(journal_hash(&GENESIS_HASH, &reordered), h);
+
+    let mut edited = j.clone();
+    edited.lines[1].memo = Some("refund".into());
+    assert_ne!(journal_hash(&GENESIS_HASH, &edited), h);
+
+    let mut renumbered = j.clone();
+    renumbered.sequence += 1;
+    assert_ne!(journal_hash(&GENESIS_HASH, &renumbered), h);
+
+    // the same journal hashes differently at another place in the chain
+    assert_ne!(journal_hash(&[1; 32], &j), h);
+}
+
+#[test]
+fn intact_chain_verifies_to_its_head() {
+    let journals: Vec<_> = (1..=5).map(|i| journal(i, i as i128 * 100)).collect();
+    let (links, head) = chain(&journals);
+    let refs: Vec<_> = journals.iter().map(Some).collect();
+    assert_eq!(verify(&links, &refs, &head), None);
+
+    let mut v = ChainVerifier::new();
+    assert!(links.iter().zip(&journals).all(|(l, j)| v.check(l, Some(j))));
+    let result = v.finish(&head);
+    assert!(result.is_intact());
+    assert_eq!(result.checked, 5);
+
+    // an empty chain is intact at the genesis head
+    let empty = ChainVerifier::new().finish(&ChainHead { position: 0, entry_hash: GENESIS_HASH });
+    assert!(empty.is_intact());
+}
+
+#[test]
+fn edited_journal_is_the_first_broken_link() {
+    let mut journals: Vec<_> = (1..=5).map(|i| journal(i, 100)).collect();
+    let (links, head) = chain(&journals);
+
+    // a DBA rewrites an amount in the third journal
+    journals[2].lines[0].amount = Money::credit(99).unwrap();
+    journals[2].lines[1].amount = Money::debit(99).unwrap();
+    let refs: Vec<_> = journals.iter().map(Some).collect();
+    assert_eq!(verify(&links, &refs, &head), Some((3, ChainBreakKind::ContentMismatch)));
+
+    // re-hashing that link to cover up does not match the next link's back-pointer
+    let mut covered = links.clone();
+    covered[2].entry_hash = journal_hash(&covered[2].prev_hash, &journals[2]);
+    assert_eq!(verify(&covered, &refs, &head), Some((4, ChainBreakKind::PrevHashMismatch)));
+}
+
+#[test]
+fn removed_links_and_journals_are_reported() {
+    let journals: Vec<_> = (1..=4).map(|i| journal(i, 100)).collect();
+    let (links, head) = chain(&journals);
+    let refs: Vec<_> = journals.iter().map(Some).collect();
+
+    let mut gapped = links.clone();
+    gapped.remove(1);
+    let gapped_refs = [refs[0], refs[2], refs[3]];
+    assert_eq!(
+        verify(&gapped, &gapped_refs, &head),
+        Some((3, ChainBreakKind::Gap { expected_position: 2 }))
+    );
+
+    let missing = [refs[0], None, refs[2], refs[3]];
+    assert_eq!(verify(&links, &missing, &head), Some((2, ChainBreakKind::MissingJournal)));
+
+    // cutting the tail leaves the head pointing past the last link
+    assert_eq!(verify(&links[..3], &refs[..3], &head), Some((4, ChainBreakKind::HeadMismatch)));
+}
@@ -0,0 +1,99 @@
+use std::collections::{BTreeMap, HashMap};
+
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::JournalDraft;
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{
+    ExternalRef, ExternalRefType, JournalMetadata, JournalTag, Money, PublicId,
+};
+
+fn account(id: i64) -> LedgerAccount {
+    LedgerAccount::new(
+        id,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::User,
+        Some(Uuid::new_v4()),
+        AccountType::UserAvailable,
+        1,
+        true,
+    )
+}
+
+fn draft() -> JournalDraft {
+    JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new(format!("test:{}", Uuid::new_v4())).unwrap(),
+        "checkout",
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn metadata_keys_and_tags_are_validated() {
+    let ok = JournalMetadata::new(BTreeMap::from([("order_id".to_string(), "ord_42".to_string())])).unwrap();
+    assert_eq!(ok.get("order_id"), Some("ord_42"));
+
+    for bad in ["", "Order Id", "order/id"] {
+        let m = BTreeMap::from([(bad.to_string(), "x".to_string())]);
+        assert!(matches!(JournalMetadata::new(m), Err(DomainError::JournalMetadataInvalid { .. })), "{bad:?}");
+    }
+
+    assert_eq!(JournalTag::new("  Campaign:Black-Friday ").unwrap().as_str(), "campaign:black-friday");
+    assert!(matches!(JournalTag::new("two words"), Err(DomainError::JournalTagInvalid { .. })));
+}
+
+#[test]
+fn metadata_tags_and_memos_survive_validation() {
+    let (a, b) = (account(1), account(2));
+    let by_id: HashMap<i64, &LedgerAccount> = [(1, &a), (2, &b)].into_iter().collect();
+
+    let mut d = draft();
+    d.metadata = JournalMetadata::new(BTreeMap::from([("order_id".to_string(), "ord_42".to_string())])).unwrap();
+    d.add_tag(JournalTag::new("checkout").unwrap());
+    d.add_tag(JournalTag::new("CHECKOUT").unwrap());
+    d.add_line_with_memo(1, Money::credit(300).unwrap(), Some("item 1".into()));
+    d.add_line_with_memo(1, Money::credit(200).unwrap(), Some("item 2".into()));
+    d.add_line_with_memo(2, Money::debit(500).unwrap(), Some("  ".into()));
+
+    let v = d.validate_with_accounts(&by_id).unwrap();
+    assert_eq!(v.metadata.get("order_id"), Some("ord_42"));
+    assert_eq!(v.tags.len(), 1);
+
+    let memos: HashMap<i64, Option<&str>> = v.lines.iter().map(|l| (l.account_id, l.memo.as_deref())).collect();
+    assert_eq!(memos[&1], Some("item 1; item 2"));
+    assert_eq!(memos[&2], None);
+}
+
+#[test]
+fn overlong_memo_is_rejected() {
+    let (a, b) = (account(1), account(2));
+    let by_id: HashMap<i64, &LedgerAccount> = [(1, &a), (2, &b)].into_iter().collect();
+
+    let mut d = draft();
+    d.add_line_with_memo(1, Money::credit(10).unwrap(), Some("x".repeat(501)));
+    d.add_line(2, Money::debit(10).unwrap());
+    assert!(matches!(
+        d.validate_with_accounts(&by_id),
+        Err(DomainError::JournalLineMemoTooLong { max: 500 })
+    ));
+}
+
+#[test]
+fn merged_memos_must_fit_too() {
+    let (a, b) = (account(1), account(2));
+    let by_id: HashMap<i64, &LedgerAccount> = [(1, &a), (2, &b)].into_iter().collect();
+
+    // each memo fits, but lines on one account are merged with their memos joined
+    let mut d = draft();
+    d.add_line_with_memo(1, Money::credit(10).unwrap(), Some("a".repeat(300)));
+    d.add_line_with_memo(1, Money::credit(20).unwrap(), Some("b".repeat(300)));
+    d.add_line(2, Money::debit(30).unwrap());
+    assert!(matches!(
+        d.validate_with_accounts(&by_id),
+        Err(DomainError::JournalLineMemoTooLong { max: 500 })
+    ));
+}
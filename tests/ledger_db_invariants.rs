@@ -9,6 +9,8 @@ use uuid::Uuid;
 use sirara_core::application::contracts::repository::LedgerRepositoryTx;
 use sirara_core::domain::aggregate::JournalDraft;
 use sirara_core::domain::entities::LedgerAccount;
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::repository::RepoError;
 use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
 use sirara_core::infrastructure::persistence::ledger::PgLedgerRepository;
 use sirara_core::infrastructure::persistence::models::LedgerAccountRow;
@@ -215,7 +217,7 @@ async fn load_accounts_by_ids(
 ) -> anyhow::Result<HashMap<i64, LedgerAccount>> {
     let rows = sqlx::query_as::<_, LedgerAccountRow>(
         r#"
-        SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active
+        SELECT id, public_id, owner_type, owner_id, account_type, asset_id, is_active, status
         FROM ledger_accounts
         WHERE id = ANY($1)
         "#,
@@ -454,7 +456,7 @@ async fn projection_matches_truth_after_repo_posting() -> anyhow::Result<()> {
     let repo = PgLedgerRepository::new(pool.clone());
     repo.insert_posting_atomic_tx(&mut tx, posting)
         .await
-        .context("repo posting failed")?;
+        .context("repo posting failed")??;
 
     let drift1 = fetch_drift(&mut tx, a1).await?;
     let drift2 = fetch_drift(&mut tx, a2).await?;
@@ -480,7 +482,7 @@ async fn balances_updated_after_repo_posting() -> anyhow::Result<()> {
     let posting = make_validated_posting(a1, a2, &accounts, None)?;
 
     let repo = PgLedgerRepository::new(pool.clone());
-    repo.insert_posting_atomic_tx(&mut tx, posting).await?;
+    repo.insert_posting_atomic_tx(&mut tx, posting).await??;
 
     let b1 = fetch_balance_i128(&mut tx, a1).await?;
     let b2 = fetch_balance_i128(&mut tx, a2).await?;
@@ -510,8 +512,8 @@ async fn idempotency_same_external_ref_does_not_double_apply() -> anyhow::Result
 
     let repo = PgLedgerRepository::new(pool.clone());
 
-    repo.insert_posting_atomic_tx(&mut tx, p1).await?;
-    repo.insert_posting_atomic_tx(&mut tx, p2).await?; 
+    repo.insert_posting_atomic_tx(&mut tx, p1).await??;
+    repo.insert_posting_atomic_tx(&mut tx, p2).await??; 
 
     let b1 = fetch_balance_i128(&mut tx, a1).await?;
     let b2 = fetch_balance_i128(&mut tx, a2).await?;
@@ -526,3 +528,184 @@ async fn idempotency_same_external_ref_does_not_double_apply() -> anyhow::Result
     finish_tx(tx).await?;
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn ledger_sequence_is_gapless_and_stable_on_replay() -> anyhow::Result<()> {
+    let pool = pool().await;
+    reset_db_if_requested(&pool).await?;
+    let mut tx = begin_tx(&pool).await;
+
+    seed_minimal(&mut tx).await?;
+    let a1 = seeded_id(&mut tx, "user_avail").await?;
+    let a2 = seeded_id(&mut tx, "plat_clear").await?;
+
+    let accounts = load_accounts_by_ids(&mut tx, &[a1, a2]).await?;
+
+    let same_ext = ExternalRef::new(format!("test:{}", Uuid::new_v4()))?;
+    let p1 = make_validated_posting(a1, a2, &accounts, Some(same_ext.clone()))?;
+    let p2 = make_validated_posting(a1, a2, &accounts, None)?;
+    let replay = make_validated_posting(a1, a2, &accounts, Some(same_ext))?;
+
+    let repo = PgLedgerRepository::new(pool.clone());
+
+    let first = repo.insert_posting_atomic_tx(&mut tx, p1).await??;
+    let second = repo.insert_posting_atomic_tx(&mut tx, p2).await??;
+    assert_eq!(second.sequence, first.sequence + 1);
+
+    // the replay returns the original journal without taking a new number
+    let replayed = repo.insert_posting_atomic_tx(&mut tx, replay).await??;
+    assert_eq!(replayed.sequence, first.sequence);
+
+    let last_seq: i64 = sqlx::query_scalar("select last_seq from ledger_sequence where id")
+        .fetch_one(&mut *tx)
+        .await?;
+    assert_eq!(last_seq, second.sequence);
+
+    finish_tx(tx).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn closed_period_rejects_posting_as_domain_error() -> anyhow::Result<()> {
+    let pool = pool().await;
+    reset_db_if_requested(&pool).await?;
+    let mut tx = begin_tx(&pool).await;
+
+    seed_minimal(&mut tx).await?;
+    let a1 = seeded_id(&mut tx, "user_avail").await?;
+    let a2 = seeded_id(&mut tx, "plat_clear").await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO accounting_periods (asset_id, name, starts_at, ends_at, status, status_changed_at, status_changed_by)
+        SELECT asset_id, 'closed-now', now() - interval '1 day', now() + interval '1 day', 'CLOSED', now(), 'test'
+        FROM ledger_accounts WHERE id = $1
+        "#,
+    )
+    .bind(a1)
+    .execute(&mut *tx)
+    .await?;
+
+    let accounts = load_accounts_by_ids(&mut tx, &[a1, a2]).await?;
+    let ext = ExternalRef::new(format!("test:{}", Uuid::new_v4()))?;
+    let posting = make_validated_posting(a1, a2, &accounts, Some(ext.clone()))?;
+
+    let repo = PgLedgerRepository::new(pool.clone());
+    let rejected = repo.insert_posting_atomic_tx(&mut tx, posting).await?;
+    assert!(matches!(rejected, Err(DomainError::PeriodClosed { ref period }) if period == "closed-now"));
+
+    // the header written before the period check is rolled back with the posting
+    let headers: i64 = sqlx::query_scalar("select count(*) from journal_transactions where external_ref = $1")
+        .bind(ext.as_str())
+        .fetch_one(&mut *tx)
+        .await?;
+    assert_eq!(headers, 0);
+
+    finish_tx(tx).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn postings_wait_for_the_chain_backfill() -> anyhow::Result<()> {
+    let pool = pool().await;
+    reset_db_if_requested(&pool).await?;
+    let mut tx = begin_tx(&pool).await;
+
+    seed_minimal(&mut tx).await?;
+    let a1 = seeded_id(&mut tx, "user_avail").await?;
+    let a2 = seeded_id(&mut tx, "plat_clear").await?;
+
+    // as left by the migration when journals predate the chain
+    sqlx::query("UPDATE journal_chain_head SET backfilled = FALSE WHERE id").execute(&mut *tx).await?;
+
+    let accounts = load_accounts_by_ids(&mut tx, &[a1, a2]).await?;
+    let posting = make_validated_posting(a1, a2, &accounts, None)?;
+    let repo = PgLedgerRepository::new(pool.clone());
+    assert!(matches!(
+        repo.insert_posting_atomic_tx(&mut tx, posting).await,
+        Err(RepoError::Conflict { ref message }) if message.contains("backfill")
+    ));
+
+    finish_tx(tx).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn multi_posting_tx_does_not_deadlock_with_a_concurrent_posting() -> anyhow::Result<()> {
+    let pool = pool().await;
+    reset_db_if_requested(&pool).await?;
+
+    // the concurrent posting runs on its own connection, so the seed must be committed
+    let mut seed = begin_tx(&pool).await;
+    seed_minimal(&mut seed).await?;
+    let ngn_user = seeded_id(&mut seed, "user_avail").await?;
+    let ngn_clear = seeded_id(&mut seed, "plat_clear").await?;
+    let usdt_clear = seeded_id(&mut seed, "plat_clear_usdt").await?;
+    let usdt_user: i64 = sqlx::query_scalar::<_, i64>(
+        r#"
+        insert into ledger_accounts(public_id, owner_type, owner_id, account_type, asset_id, is_active)
+        select $1, 'USER', $2, 'USER_AVAILABLE', asset_id, true from ledger_accounts where id = $3
+        returning id
+        "#,
+    )
+        .bind(Uuid::new_v4())
+        .bind(Uuid::new_v4())
+        .bind(usdt_clear)
+        .fetch_one(seed.as_mut())
+        .await?;
+    let accounts = load_accounts_by_ids(&mut seed, &[ngn_user, ngn_clear, usdt_user, usdt_clear]).await?;
+    seed.commit().await?;
+
+    let repo = PgLedgerRepository::new(pool.clone());
+
+    // shaped like an FX execute: the sell leg posts first, the buy leg later in the same tx
+    let mut fx = pool.begin().await?;
+    let sell = repo
+        .insert_posting_atomic_tx(&mut fx, make_validated_posting(ngn_user, ngn_clear, &accounts, None)?)
+        .await??;
+
+    // a plain transfer on the buy leg's accounts, started while the sell leg is uncommitted
+    let transfer = make_validated_posting(usdt_user, usdt_clear, &accounts, None)?;
+    let concurrent = {
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            let repo = PgLedgerRepository::new(pool.clone());
+            let mut tx = pool.begin().await?;
+            let posted = repo.insert_posting_atomic_tx(&mut tx, transfer).await??;
+            tx.commit().await?;
+            anyhow::Ok(posted)
+        })
+    };
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+    let buy = repo
+        .insert_posting_atomic_tx(&mut fx, make_validated_posting(usdt_user, usdt_clear, &accounts, None)?)
+        .await
+        .context("buy leg")??;
+    fx.commit().await?;
+    let transfer = concurrent.await?.context("concurrent transfer")?;
+
+    assert_eq!(buy.sequence, sell.sequence + 1);
+    assert_eq!(transfer.sequence, buy.sequence + 1);
+
+    // the hash chain links them in the same order
+    let positions: Vec<i64> = sqlx::query_scalar(
+        "select position from journal_chain where journal_tx_id = any($1) order by position",
+    )
+        .bind(vec![sell.db_id, buy.db_id, transfer.db_id])
+        .fetch_all(&pool)
+        .await?;
+    let chained: Vec<i64> = sqlx::query_scalar(
+        "select journal_tx_id from journal_chain where journal_tx_id = any($1) order by position",
+    )
+        .bind(vec![sell.db_id, buy.db_id, transfer.db_id])
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(positions, vec![positions[0], positions[0] + 1, positions[0] + 2]);
+    assert_eq!(chained, vec![sell.db_id, buy.db_id, transfer.db_id]);
+    Ok(())
+}
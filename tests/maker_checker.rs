@@ -0,0 +1,136 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    AdjustmentAction, AdjustmentStatus, ApprovalPolicy, ApprovalThreshold, JournalDraft, ManualAdjustment,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use sirara_core::infrastructure::persistence::models::{ManualAdjustmentLineRow, ManualAdjustmentRow};
+
+const NGN: i16 = 1;
+const MAKER: &str = "ops:maker";
+
+fn account(id: i64, account_type: AccountType) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), OwnerType::Platform, None, account_type, NGN, true)
+}
+
+fn submitted(amount: i128, policy: &ApprovalPolicy) -> ManualAdjustment {
+    let accounts = [account(1, AccountType::TreasuryAvailable), account(2, AccountType::PlatformClearing)];
+    let by_id: HashMap<i64, &LedgerAccount> = accounts.iter().map(|a| (a.id(), a)).collect();
+
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::ManualAdjustment,
+        ExternalRef::new(format!("adj:{}", Uuid::new_v4())).unwrap(),
+        MAKER,
+        Some("correct clearing drift".to_string()),
+    )
+    .unwrap();
+    draft.add_line(1, Money::debit(amount).unwrap());
+    draft.add_line(2, Money::credit(amount).unwrap());
+
+    let validated = draft.clone().validate_with_accounts(&by_id).unwrap();
+    let spec = ManualAdjustment::submit(draft, &validated, policy, Utc::now()).unwrap();
+    assert_eq!(spec.submitted.action, AdjustmentAction::Submitted);
+
+    ManualAdjustment::restore(
+        1,
+        spec.draft,
+        spec.asset_id,
+        spec.gross_amount_minor,
+        AdjustmentStatus::PendingApproval,
+        spec.required_approvals,
+        vec![],
+        None,
+        None,
+    )
+}
+
+fn policy() -> ApprovalPolicy {
+    ApprovalPolicy::new(vec![
+        ApprovalThreshold { asset_id: NGN, min_amount_minor: 1_000_000, required_approvals: 2 },
+        ApprovalThreshold { asset_id: NGN, min_amount_minor: 100_000_000, required_approvals: 3 },
+    ])
+}
+
+#[test]
+fn required_approvals_follow_the_strictest_matching_threshold() {
+    let p = policy();
+    assert_eq!(p.required_approvals(NGN, 999_999), 1);
+    assert_eq!(p.required_approvals(NGN, 1_000_000), 2);
+    assert_eq!(p.required_approvals(NGN, 500_000_000), 3);
+    assert_eq!(p.required_approvals(2, 500_000_000), 1);
+
+    assert_eq!(submitted(2_000_000, &p).required_approvals(), 2);
+}
+
+#[test]
+fn maker_cannot_approve_and_checkers_must_be_distinct() {
+    let mut adj = submitted(2_000_000, &policy());
+    let now = Utc::now();
+
+    assert_eq!(adj.approve(MAKER, now).unwrap_err(), DomainError::ManualAdjustmentSelfApproval);
+    assert!(!adj.approve("ops:checker-1", now).unwrap());
+    assert_eq!(
+        adj.approve("ops:checker-1", now).unwrap_err(),
+        DomainError::ManualAdjustmentAlreadyApproved { approver: "ops:checker-1".to_string() }
+    );
+    assert!(adj.approve("ops:checker-2", now).unwrap());
+
+    adj.mark_posted(42, "ops:checker-2", now).unwrap();
+    assert_eq!(adj.status(), AdjustmentStatus::Posted);
+    assert_eq!(adj.posted_journal_id(), Some(42));
+
+    let actions: Vec<_> = adj.take_audit().into_iter().map(|e| e.action).collect();
+    assert_eq!(actions, vec![AdjustmentAction::Approved, AdjustmentAction::Approved, AdjustmentAction::Posted]);
+}
+
+#[test]
+fn rejected_adjustments_are_final_and_need_a_reason() {
+    let mut adj = submitted(10, &policy());
+    let now = Utc::now();
+
+    assert_eq!(adj.reject("ops:checker-1", "  ", now).unwrap_err(), DomainError::RejectionReasonRequired);
+    adj.reject("ops:checker-1", "wrong account", now).unwrap();
+    assert_eq!(adj.rejection_reason(), Some("wrong account"));
+
+    assert!(matches!(adj.approve("ops:checker-2", now), Err(DomainError::ManualAdjustmentNotPending { .. })));
+    assert!(matches!(adj.mark_posted(1, "ops:checker-2", now), Err(DomainError::ManualAdjustmentNotPending { .. })));
+}
+
+#[test]
+fn stored_adjustments_keep_metadata_tags_and_line_memos() {
+    let row = ManualAdjustmentRow {
+        id: 7,
+        public_id: Uuid::new_v4(),
+        external_ref: "adj:stored".to_string(),
+        description: None,
+        created_by: MAKER.to_string(),
+        asset_id: NGN,
+        gross_amount: BigDecimal::from(500),
+        status: "PENDING_APPROVAL".to_string(),
+        required_approvals: 1,
+        rejection_reason: None,
+        posted_journal_tx_id: None,
+        effective_at: None,
+        metadata: Json(BTreeMap::from([("ticket".to_string(), "OPS-42".to_string())])),
+        tags: vec!["recon".to_string()],
+    };
+    let lines = [
+        ManualAdjustmentLineRow { account_id: 1, amount: BigDecimal::from(500), memo: Some("drift".to_string()) },
+        ManualAdjustmentLineRow { account_id: 2, amount: BigDecimal::from(-500), memo: None },
+    ];
+
+    let adjustment = row.to_domain(&lines, vec![]).unwrap();
+    let draft = adjustment.draft();
+    assert_eq!(draft.metadata.get("ticket"), Some("OPS-42"));
+    assert_eq!(draft.tags().iter().map(|t| t.as_str()).collect::<Vec<_>>(), ["recon"]);
+    assert_eq!(draft.lines()[0].memo.as_deref(), Some("drift"));
+    assert_eq!(draft.lines()[1].memo, None);
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::JournalDraft;
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::LedgerPostingService;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+
+const USDT: i16 = 1;
+const NGN: i16 = 2;
+
+fn user(id: i64, owner: Uuid, account_type: AccountType, asset_id: i16) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), OwnerType::User, Some(owner), account_type, asset_id, true)
+}
+
+fn treasury(id: i64, asset_id: i16) -> LedgerAccount {
+    LedgerAccount::new(
+        id,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::Treasury,
+        None,
+        AccountType::TreasuryAvailable,
+        asset_id,
+        true,
+    )
+}
+
+fn draft(multi_asset: bool) -> JournalDraft {
+    let mut d = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new(format!("swap:{}", Uuid::new_v4())).unwrap(),
+        "desk",
+        None,
+    )
+    .unwrap();
+    d.multi_asset = multi_asset;
+    d
+}
+
+/// A user sells 100 USDT to the treasury for 150 000 NGN.
+fn swap(d: &mut JournalDraft) {
+    d.add_line(1, Money::credit(100).unwrap());
+    d.add_line(2, Money::debit(100).unwrap());
+    d.add_line(3, Money::credit(150_000).unwrap());
+    d.add_line(4, Money::debit(150_000).unwrap());
+}
+
+fn accounts(owner: Uuid) -> Vec<LedgerAccount> {
+    vec![
+        user(1, owner, AccountType::UserAvailable, USDT),
+        treasury(2, USDT),
+        treasury(3, NGN),
+        user(4, owner, AccountType::UserAvailable, NGN),
+    ]
+}
+
+fn by_id(accounts: &[LedgerAccount]) -> HashMap<i64, &LedgerAccount> {
+    accounts.iter().map(|a| (a.id(), a)).collect()
+}
+
+#[test]
+fn flagged_journal_spans_assets_and_records_each_lines_asset() {
+    let accounts = accounts(Uuid::new_v4());
+    let mut d = draft(true);
+    swap(&mut d);
+
+    let validated = d.validate_with_accounts(&by_id(&accounts)).unwrap();
+    assert!(validated.multi_asset);
+    assert_eq!(validated.asset_id, None);
+    assert!(matches!(validated.single_asset_id(), Err(DomainError::CrossAssetPostingNotAllowed)));
+
+    let mut lines: Vec<(i64, i16, i128)> =
+        validated.lines.iter().map(|l| (l.account_id, l.asset_id, l.amount.minor())).collect();
+    lines.sort_unstable();
+    assert_eq!(lines, vec![(1, USDT, -100), (2, USDT, 100), (3, NGN, -150_000), (4, NGN, 150_000)]);
+
+    assert!(LedgerPostingService::validate(validated, &by_id(&accounts)).is_ok());
+}
+
+#[test]
+fn unflagged_journal_stays_single_asset() {
+    let accounts = accounts(Uuid::new_v4());
+    let mut d = draft(false);
+    swap(&mut d);
+    assert!(matches!(d.validate_with_accounts(&by_id(&accounts)), Err(DomainError::CrossAssetPostingNotAllowed)));
+
+    let mut single = draft(false);
+    single.add_line(1, Money::credit(100).unwrap());
+    single.add_line(2, Money::debit(100).unwrap());
+    let validated = single.validate_with_accounts(&by_id(&accounts)).unwrap();
+    assert_eq!(validated.asset_id, Some(USDT));
+    assert_eq!(validated.single_asset_id().unwrap(), USDT);
+    assert!(validated.lines.iter().all(|l| l.asset_id == USDT));
+}
+
+#[test]
+fn each_asset_must_balance_on_its_own() {
+    let accounts = accounts(Uuid::new_v4());
+    // nets to zero overall, but USDT is short 40 and NGN long 40
+    let mut d = draft(true);
+    d.add_line(1, Money::credit(100).unwrap());
+    d.add_line(2, Money::debit(60).unwrap());
+    d.add_line(3, Money::credit(20).unwrap());
+    d.add_line(4, Money::debit(60).unwrap());
+
+    assert!(matches!(
+        d.validate_with_accounts(&by_id(&accounts)),
+        Err(DomainError::JournalNotBalancedForAsset { asset_id: USDT })
+    ));
+}
+
+#[test]
+fn hold_owner_rule_is_applied_per_asset() {
+    let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+    // alice releases a USDT hold while bob receives NGN from her: different assets, no clash
+    let accounts = vec![
+        user(1, alice, AccountType::UserLocked, USDT),
+        user(2, alice, AccountType::UserAvailable, USDT),
+        user(3, alice, AccountType::UserAvailable, NGN),
+        user(4, bob, AccountType::UserAvailable, NGN),
+    ];
+    let mut d = draft(true);
+    d.add_line(1, Money::credit(100).unwrap());
+    d.add_line(2, Money::debit(100).unwrap());
+    d.add_line(3, Money::credit(5_000).unwrap());
+    d.add_line(4, Money::debit(5_000).unwrap());
+
+    let validated = d.validate_with_accounts(&by_id(&accounts)).unwrap();
+    assert!(LedgerPostingService::validate(validated, &by_id(&accounts)).is_ok());
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    AvailableBalance, JournalDraft, NewPendingJournal, PendingJournal, PendingStatus, EXPIRY_ACTOR,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+
+const NGN: i16 = 1;
+
+fn account(id: i64, account_type: AccountType) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), OwnerType::User, Some(Uuid::new_v4()), account_type, NGN, true)
+}
+
+/// Card-style hold: 1_000 out of the user's available bucket towards clearing (and a fee line when `with_fee`).
+fn authorize(with_fee: bool, now: DateTime<Utc>) -> NewPendingJournal {
+    let accounts = [
+        account(1, AccountType::UserAvailable),
+        account(2, AccountType::PlatformClearing),
+        account(3, AccountType::PlatformClearing),
+    ];
+    let by_id: HashMap<i64, &LedgerAccount> = accounts.iter().map(|a| (a.id(), a)).collect();
+
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new(format!("card:{}", Uuid::new_v4())).unwrap(),
+        "cards",
+        Some("card authorization".into()),
+    )
+    .unwrap();
+    if with_fee {
+        draft.add_line(1, Money::credit(1_050).unwrap());
+        draft.add_line(2, Money::debit(1_000).unwrap());
+        draft.add_line(3, Money::debit(50).unwrap());
+    } else {
+        draft.add_line(1, Money::credit(1_000).unwrap());
+        draft.add_line(2, Money::debit(1_000).unwrap());
+    }
+
+    let validated = draft.validate_with_accounts(&by_id).unwrap();
+    PendingJournal::authorize(&validated, now + Duration::hours(1), Duration::hours(24), now).unwrap()
+}
+
+fn stored(spec: NewPendingJournal) -> PendingJournal {
+    PendingJournal::restore(1, spec.draft, spec.asset_id, PendingStatus::Pending, spec.expires_at, None, None, None, None)
+}
+
+fn minor_of(draft: &JournalDraft, account_id: i64) -> i128 {
+    draft.lines().iter().filter(|l| l.account_id == account_id).map(|l| l.amount.minor()).sum()
+}
+
+#[test]
+fn authorize_checks_expiry_window() {
+    let now = Utc::now();
+    let accounts = [account(1, AccountType::UserAvailable), account(2, AccountType::PlatformClearing)];
+    let by_id: HashMap<i64, &LedgerAccount> = accounts.iter().map(|a| (a.id(), a)).collect();
+
+    let mut draft = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ExternalRefType::TransferIntent,
+        ExternalRef::new("card:window").unwrap(),
+        "cards",
+        None,
+    )
+    .unwrap();
+    draft.add_line(1, Money::credit(10).unwrap());
+    draft.add_line(2, Money::debit(10).unwrap());
+    let validated = draft.validate_with_accounts(&by_id).unwrap();
+
+    let window = Duration::hours(24);
+    assert!(matches!(
+        PendingJournal::authorize(&validated, now, window, now),
+        Err(DomainError::PendingJournalInvalid { .. })
+    ));
+    assert!(matches!(
+        PendingJournal::authorize(&validated, now + Duration::hours(25), window, now),
+        Err(DomainError::PendingJournalInvalid { .. })
+    ));
+
+    let mut dated = validated.clone();
+    dated.effective_at = Some(now);
+    assert!(matches!(
+        PendingJournal::authorize(&dated, now + Duration::hours(1), window, now),
+        Err(DomainError::PendingJournalInvalid { .. })
+    ));
+}
+
+#[test]
+fn reservation_and_full_commit_keep_the_reference() {
+    let pending = stored(authorize(false, Utc::now()));
+    assert_eq!(pending.authorized_amount_minor(), 1_000);
+    assert_eq!(pending.reservation(), HashMap::from([(1, -1_000), (2, 1_000)]));
+
+    let draft = pending.commit_draft(None, "cards:capture").unwrap();
+    assert_eq!(draft.public_id, pending.public_id());
+    assert_eq!(draft.external_ref, pending.draft().external_ref);
+    assert_eq!(draft.created_by, "cards:capture");
+    assert_eq!(minor_of(&draft, 1), -1_000);
+    assert_eq!(minor_of(&draft, 2), 1_000);
+}
+
+#[test]
+fn partial_commit_scales_two_line_journals_only() {
+    let pending = stored(authorize(false, Utc::now()));
+    let draft = pending.commit_draft(Some(400), "cards:capture").unwrap();
+    assert_eq!(minor_of(&draft, 1), -400);
+    assert_eq!(minor_of(&draft, 2), 400);
+
+    for bad in [0, -1, 1_001] {
+        assert!(matches!(
+            pending.commit_draft(Some(bad), "cards:capture"),
+            Err(DomainError::PendingCommitAmountInvalid { .. })
+        ));
+    }
+
+    let with_fee = stored(authorize(true, Utc::now()));
+    assert_eq!(with_fee.authorized_amount_minor(), 1_050);
+    assert!(with_fee.commit_draft(None, "cards:capture").is_ok());
+    assert!(matches!(
+        with_fee.commit_draft(Some(500), "cards:capture"),
+        Err(DomainError::PendingCommitAmountInvalid { .. })
+    ));
+}
+
+#[test]
+fn void_expire_and_commit_are_terminal() {
+    let now = Utc::now();
+
+    let mut voided = stored(authorize(false, now));
+    voided.void("cards", now).unwrap();
+    assert_eq!(voided.status(), PendingStatus::Voided);
+    assert!(matches!(voided.void("cards", now), Err(DomainError::PendingJournalNotPending { .. })));
+    assert!(matches!(voided.ensure_open(now), Err(DomainError::PendingJournalNotPending { .. })));
+
+    let mut pending = stored(authorize(false, now));
+    assert!(matches!(pending.expire(now), Err(DomainError::PendingJournalInvalid { .. })));
+    let later = pending.expires_at() + Duration::seconds(1);
+    assert!(matches!(pending.ensure_open(later), Err(DomainError::PendingJournalExpired { .. })));
+    assert!(matches!(
+        pending.mark_committed(9, 1_000, "cards:capture", later),
+        Err(DomainError::PendingJournalExpired { .. })
+    ));
+    pending.expire(later).unwrap();
+    assert_eq!(pending.status(), PendingStatus::Expired);
+    assert_eq!(pending.status_changed_by(), Some(EXPIRY_ACTOR));
+
+    let mut committed = stored(authorize(false, now));
+    committed.mark_committed(9, 400, "cards:capture", now).unwrap();
+    assert_eq!(committed.status(), PendingStatus::Committed);
+    assert_eq!(committed.committed_amount_minor(), Some(400));
+    assert_eq!(committed.posted_journal_id(), Some(9));
+    assert!(matches!(committed.void("cards", now), Err(DomainError::PendingJournalNotPending { .. })));
+}
+
+#[test]
+fn available_balance_nets_pending_amounts() {
+    let b = AvailableBalance {
+        account_id: 1,
+        asset_id: NGN,
+        posted_minor: 5_000,
+        pending_inflow_minor: 300,
+        pending_outflow_minor: 1_200,
+    };
+    assert_eq!(b.available_minor(), 3_800);
+    assert_eq!(b.pending_minor(), 4_100);
+}
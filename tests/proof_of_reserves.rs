@@ -0,0 +1,109 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{OwnerLiability, ReserveSnapshot};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::{
+    hash_owner, verify_inclusion, verify_owner_inclusion, MerkleNode, MerkleSumTree, SiblingSide,
+};
+use sirara_core::domain::value_objects::PublicId;
+
+const USDT: i16 = 1;
+
+fn salt() -> Vec<u8> {
+    vec![7; 32]
+}
+
+fn liabilities(balances: &[i128]) -> Vec<OwnerLiability> {
+    balances.iter().map(|b| OwnerLiability { owner_id: Uuid::new_v4(), balance_minor: *b }).collect()
+}
+
+fn snapshot(liabilities: Vec<OwnerLiability>) -> ReserveSnapshot {
+    let spec = ReserveSnapshot::take(PublicId::new(Uuid::new_v4()), USDT, liabilities, salt(), "ops", Utc::now()).unwrap();
+    ReserveSnapshot::restore(1, spec)
+}
+
+#[test]
+fn root_commits_to_the_total_of_every_leaf() {
+    let owners = liabilities(&[500, 0, 1_250, 75, 3_000]);
+    let snap = snapshot(owners.clone());
+    assert_eq!(snap.total_minor(), 4_825);
+    assert_eq!(snap.leaf_count(), 5);
+
+    // the order owners are read in does not change the commitment
+    let mut reversed = owners;
+    reversed.reverse();
+    let again = ReserveSnapshot::take(PublicId::new(Uuid::new_v4()), USDT, reversed, salt(), "ops", Utc::now()).unwrap();
+    assert_eq!(again.root, snap.root());
+
+    let empty = MerkleSumTree::build(vec![]).unwrap();
+    assert_eq!(empty.root().sum, 0);
+    assert_eq!(empty.proof(0), None);
+}
+
+#[test]
+fn every_owner_gets_a_proof_that_verifies_offline() {
+    // an odd count exercises the carried node
+    let owners = liabilities(&[10, 20, 30, 40, 50, 60, 70]);
+    let snap = snapshot(owners.clone());
+
+    for owner in &owners {
+        let proof = snap.proof_for(owner.owner_id).unwrap().unwrap();
+        assert_eq!(proof.balance_minor, owner.balance_minor);
+        assert_eq!(proof.owner_hash, hash_owner(snap.salt(), owner.owner_id));
+        verify_owner_inclusion(&snap.root(), owner.owner_id, snap.salt(), &proof).unwrap();
+    }
+
+    assert_eq!(snap.proof_for(Uuid::new_v4()).unwrap(), None);
+}
+
+#[test]
+fn tampered_proofs_are_rejected() {
+    let owners = liabilities(&[100, 200, 300, 400]);
+    let snap = snapshot(owners.clone());
+    let alice = owners[0].owner_id;
+    let proof = snap.proof_for(alice).unwrap().unwrap();
+    let invalid = |r: Result<(), DomainError>| matches!(r, Err(DomainError::MerkleProofInvalid { .. }));
+
+    let mut inflated = proof.clone();
+    inflated.balance_minor += 1;
+    assert!(invalid(verify_inclusion(&snap.root(), &inflated)));
+
+    // hiding liabilities by shrinking a sibling's sum changes the root
+    let mut shrunk = proof.clone();
+    shrunk.steps[0].sibling.sum -= 1;
+    assert!(invalid(verify_inclusion(&snap.root(), &shrunk)));
+
+    let mut flipped = proof.clone();
+    flipped.steps[0].side = match flipped.steps[0].side {
+        SiblingSide::Left => SiblingSide::Right,
+        SiblingSide::Right => SiblingSide::Left,
+    };
+    assert!(invalid(verify_inclusion(&snap.root(), &flipped)));
+
+    let understated = MerkleNode { sum: snap.total_minor() - 1, ..snap.root() };
+    assert!(invalid(verify_inclusion(&understated, &proof)));
+
+    // another owner's leaf does not prove alice's balance
+    assert!(invalid(verify_owner_inclusion(&snap.root(), owners[1].owner_id, snap.salt(), &proof)));
+}
+
+#[test]
+fn snapshots_refuse_negative_or_repeated_owners() {
+    let invalid = |owners: Vec<OwnerLiability>| {
+        matches!(
+            ReserveSnapshot::take(PublicId::new(Uuid::new_v4()), USDT, owners, salt(), "ops", Utc::now()),
+            Err(DomainError::ReserveSnapshotInvalid { .. })
+        )
+    };
+    assert!(invalid(liabilities(&[10, -1])));
+
+    let mut repeated = liabilities(&[10, 20]);
+    repeated[1].owner_id = repeated[0].owner_id;
+    assert!(invalid(repeated));
+
+    assert!(matches!(
+        ReserveSnapshot::take(PublicId::new(Uuid::new_v4()), USDT, vec![], vec![1; 4], "ops", Utc::now()),
+        Err(DomainError::ReserveSnapshotInvalid { .. })
+    ));
+}
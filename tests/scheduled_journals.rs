@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{
+    JournalDraft, NewScheduledJournal, Recurrence, RetryPolicy, ScheduleStatus, ScheduledJournal,
+};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use sirara_core::infrastructure::persistence::models::{ScheduledJournalLineRow, ScheduledJournalRow};
+
+fn template(ref_type: ExternalRefType) -> JournalDraft {
+    let mut d = JournalDraft::new(
+        PublicId::new(Uuid::new_v4()),
+        ref_type,
+        ExternalRef::new("sweep:treasury").unwrap(),
+        "ops",
+        Some("nightly sweep".into()),
+    )
+    .unwrap();
+    d.add_line(1, Money::debit(500).unwrap());
+    d.add_line(2, Money::credit(500).unwrap());
+    d
+}
+
+fn stored(spec: NewScheduledJournal) -> ScheduledJournal {
+    ScheduledJournal::restore(
+        1,
+        spec.template,
+        spec.recurrence,
+        spec.starts_at,
+        spec.ends_at,
+        spec.max_occurrences,
+        ScheduleStatus::Active,
+        0,
+        Some(spec.starts_at),
+        Some(spec.starts_at),
+        0,
+        0,
+        None,
+        None,
+        None,
+    )
+}
+
+fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+}
+
+#[test]
+fn monthly_occurrences_clamp_without_drifting() {
+    let jan31 = at(2026, 1, 31);
+    let times: Vec<_> = (0..4).map(|n| Recurrence::Monthly.occurrence_at(jan31, n).unwrap()).collect();
+    assert_eq!(times, vec![jan31, at(2026, 2, 28), at(2026, 3, 31), at(2026, 4, 30)]);
+
+    let hourly = Recurrence::interval(Duration::hours(1)).unwrap();
+    assert_eq!(hourly.occurrence_at(jan31, 3), Some(jan31 + Duration::hours(3)));
+    assert_eq!(Recurrence::Once.occurrence_at(jan31, 1), None);
+
+    assert!(matches!(Recurrence::interval(Duration::seconds(5)), Err(DomainError::InvalidRecurrence { .. })));
+    assert!(matches!(Recurrence::from_parts("WEEKLY", None), Err(DomainError::InvalidRecurrence { .. })));
+}
+
+#[test]
+fn occurrences_get_deterministic_refs_and_value_dates() {
+    let start = at(2026, 3, 1);
+    let spec = ScheduledJournal::create(template(ExternalRefType::Settlement), Recurrence::Monthly, start, None, Some(2))
+        .unwrap();
+    let mut s = stored(spec);
+
+    let first = s.occurrence_draft(PublicId::new(Uuid::new_v4())).unwrap();
+    let retry = s.occurrence_draft(PublicId::new(Uuid::new_v4())).unwrap();
+    assert_eq!(first.external_ref.as_str(), "sweep:treasury:0");
+    assert_eq!(first.external_ref, retry.external_ref);
+    assert_eq!(first.effective_at, Some(start));
+
+    s.record_posted(start);
+    let second = s.occurrence_draft(PublicId::new(Uuid::new_v4())).unwrap();
+    assert_eq!(second.external_ref.as_str(), "sweep:treasury:1");
+    assert_eq!(second.effective_at, Some(at(2026, 4, 1)));
+
+    s.record_posted(at(2026, 4, 1));
+    assert_eq!(s.status(), ScheduleStatus::Completed);
+    assert_eq!(s.posted_count(), 2);
+    assert!(s.next_run_at().is_none());
+    assert!(s.occurrence_draft(PublicId::new(Uuid::new_v4())).is_err());
+}
+
+#[test]
+fn failures_back_off_and_eventually_pause() {
+    let start = at(2026, 3, 1);
+    let every = Recurrence::interval(Duration::days(1)).unwrap();
+    let mut s = stored(ScheduledJournal::create(template(ExternalRefType::Settlement), every, start, None, None).unwrap());
+    let policy = RetryPolicy::new(Duration::minutes(1), Duration::minutes(5), 3);
+
+    s.record_failure("insufficient funds", start, &policy);
+    assert_eq!(s.due_at(), Some(start + Duration::minutes(1)));
+    s.record_failure("insufficient funds", start, &policy);
+    assert_eq!(s.due_at(), Some(start + Duration::minutes(2)));
+    assert_eq!(s.status(), ScheduleStatus::Active);
+
+    s.record_failure("insufficient funds", start, &policy);
+    assert_eq!(s.status(), ScheduleStatus::Paused);
+    assert_eq!(s.last_error(), Some("insufficient funds"));
+    assert_eq!(s.next_occurrence(), 0);
+    assert_eq!(policy.delay(10), Duration::minutes(5));
+}
+
+#[test]
+fn backoff_stays_capped_at_high_failure_counts() {
+    let policy = RetryPolicy::new(Duration::seconds(1), Duration::hours(1), u32::MAX);
+    for failures in [31, 32, 33, 64, 1_000, u32::MAX] {
+        assert_eq!(policy.delay(failures), Duration::hours(1), "{failures} failures");
+    }
+    // no cap reached before the factor itself tops out
+    let uncapped = RetryPolicy::new(Duration::milliseconds(1), Duration::days(365), u32::MAX);
+    assert_eq!(uncapped.delay(32), Duration::milliseconds(1 << 30));
+}
+
+#[test]
+fn pause_resume_and_cancel() {
+    let start = at(2026, 3, 1);
+    let every = Recurrence::interval(Duration::days(1)).unwrap();
+    let mut s = stored(ScheduledJournal::create(template(ExternalRefType::Settlement), every, start, None, None).unwrap());
+
+    s.pause("ops", start).unwrap();
+    assert!(matches!(s.pause("ops", start), Err(DomainError::ScheduleTransitionNotAllowed { .. })));
+
+    // three days missed while paused; skipping lands on the next future occurrence
+    let now = start + Duration::days(3) + Duration::hours(1);
+    s.resume("ops", true, now).unwrap();
+    assert_eq!(s.status(), ScheduleStatus::Active);
+    assert_eq!(s.next_occurrence(), 4);
+    assert_eq!(s.next_run_at(), Some(start + Duration::days(4)));
+    assert_eq!(s.posted_count(), 0);
+
+    s.cancel("ops", now).unwrap();
+    assert_eq!(s.status_changed_by(), Some("ops"));
+    assert!(matches!(s.resume("ops", false, now), Err(DomainError::ScheduleTransitionNotAllowed { .. })));
+}
+
+#[test]
+fn templates_are_checked_up_front() {
+    let start = at(2026, 3, 1);
+    assert!(matches!(
+        ScheduledJournal::create(template(ExternalRefType::ManualAdjustment), Recurrence::Once, start, None, None),
+        Err(DomainError::ScheduleInvalid { .. })
+    ));
+    assert!(matches!(
+        ScheduledJournal::create(template(ExternalRefType::Settlement), Recurrence::Once, start, Some(start), None),
+        Err(DomainError::ScheduleInvalid { .. })
+    ));
+
+    let mut unbalanced = template(ExternalRefType::Settlement);
+    unbalanced.add_line(3, Money::debit(1).unwrap());
+    assert!(matches!(
+        ScheduledJournal::create(unbalanced, Recurrence::Once, start, None, None),
+        Err(DomainError::JournalNotBalanced)
+    ));
+}
+
+#[test]
+fn stored_multi_asset_templates_post_multi_asset_occurrences() {
+    let start = at(2026, 3, 1);
+    let row = ScheduledJournalRow {
+        id: 1,
+        public_id: Uuid::new_v4(),
+        external_ref_type: ExternalRefType::Settlement.as_code().to_string(),
+        external_ref: "sweep:fx".to_string(),
+        description: None,
+        created_by: "ops".to_string(),
+        metadata: Json(BTreeMap::new()),
+        tags: vec![],
+        multi_asset: true,
+        recurrence: "ONCE".to_string(),
+        interval_seconds: None,
+        starts_at: start,
+        ends_at: None,
+        max_occurrences: None,
+        status: "ACTIVE".to_string(),
+        next_occurrence: 0,
+        next_run_at: Some(start),
+        due_at: Some(start),
+        posted_count: 0,
+        consecutive_failures: 0,
+        last_error: None,
+        status_changed_at: None,
+        status_changed_by: None,
+    };
+    let lines = [
+        ScheduledJournalLineRow { account_id: 1, amount: BigDecimal::from(500), memo: None },
+        ScheduledJournalLineRow { account_id: 2, amount: BigDecimal::from(-500), memo: None },
+    ];
+
+    let schedule = row.to_domain(&lines).unwrap();
+    assert!(schedule.template().multi_asset);
+    assert!(schedule.occurrence_draft(PublicId::new(Uuid::new_v4())).unwrap().multi_asset);
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use sirara_core::application::contracts::{SolvencyAlert, SolvencyAlertError, SolvencyAlertSink};
+use sirara_core::domain::aggregate::{
+    ConfirmationThreshold, DepositIntent, DepositPolicy, DepositSource, DepositStatus, JournalDraft, SolvencyBalances,
+    SolvencyReport, SolvencyStatus, SolvencyTrigger,
+};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, Money, PublicId};
+use sirara_core::infrastructure::alerts::LocalAlertRecorder;
+
+const USDT: i16 = 1;
+
+const CLEARING: i64 = 1;
+const TREASURY: i64 = 2;
+const REVENUE: i64 = 3;
+
+/// One asset's ledger: platform pools and users, with balances moved only by postings.
+struct Ledger {
+    accounts: HashMap<i64, LedgerAccount>,
+    balances: HashMap<i64, i128>,
+}
+
+impl Ledger {
+    fn new() -> Self {
+        let mut ledger = Self { accounts: HashMap::new(), balances: HashMap::new() };
+        ledger.open(CLEARING, OwnerType::Platform, None, AccountType::PlatformClearing);
+        ledger.open(TREASURY, OwnerType::Treasury, None, AccountType::TreasuryAvailable);
+        ledger.open(REVENUE, OwnerType::Platform, None, AccountType::PlatformRevenue);
+        ledger
+    }
+
+    fn open(&mut self, id: i64, owner_type: OwnerType, owner_id: Option<Uuid>, account_type: AccountType) {
+        let account = LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), owner_type, owner_id, account_type, USDT, true);
+        self.accounts.insert(id, account);
+    }
+
+    /// Opens a user's available and locked accounts; returns their ids.
+    fn user(&mut self, id: i64) -> (Uuid, i64, i64) {
+        let owner = Uuid::new_v4();
+        self.open(id, OwnerType::User, Some(owner), AccountType::UserAvailable);
+        self.open(id + 1, OwnerType::User, Some(owner), AccountType::UserLocked);
+        (owner, id, id + 1)
+    }
+
+    fn post(&mut self, draft: JournalDraft) {
+        let refs: HashMap<i64, &LedgerAccount> = self.accounts.iter().map(|(id, a)| (*id, a)).collect();
+        let journal = draft.validate_with_accounts(&refs).unwrap();
+        for line in journal.lines {
+            *self.balances.entry(line.account_id).or_insert(0) += line.amount.minor();
+        }
+    }
+
+    /// A deposit with its confirmations in, credited from platform clearing as the deposit flow posts it.
+    fn deposit(&mut self, owner: Uuid, available: i64, amount_minor: i128) {
+        let policy = DepositPolicy::new(vec![ConfirmationThreshold {
+            asset_id: USDT,
+            required_confirmations: 1,
+            source: DepositSource::PlatformClearing,
+        }]);
+        let spec = DepositIntent::detect(
+            PublicId::new(Uuid::new_v4()),
+            owner,
+            USDT,
+            amount_minor,
+            format!("0x{}", Uuid::new_v4().simple()),
+            1,
+            &policy,
+            "chain-watcher",
+        )
+        .unwrap();
+        let intent = DepositIntent::restore(1, spec, 1, DepositStatus::Pending, None, None, None);
+        let draft = intent.credit_draft(&self.accounts[&CLEARING], &self.accounts[&available], "deposits").unwrap();
+        self.post(draft);
+    }
+
+    /// Moves `amount_minor` from `from` to `to`.
+    fn transfer(&mut self, from: i64, to: i64, amount_minor: i128) {
+        let mut draft = JournalDraft::new(
+            PublicId::new(Uuid::new_v4()),
+            ExternalRefType::TransferIntent,
+            ExternalRef::new(format!("test:{}", Uuid::new_v4())).unwrap(),
+            "test",
+            None,
+        )
+        .unwrap();
+        draft.add_line(from, Money::credit(amount_minor).unwrap());
+        draft.add_line(to, Money::debit(amount_minor).unwrap());
+        self.post(draft);
+    }
+
+    fn solvency(&self) -> SolvencyBalances {
+        let mut b = SolvencyBalances::default();
+        for (id, balance) in &self.balances {
+            match self.accounts[id].account_type() {
+                AccountType::UserAvailable => b.user_available += balance,
+                AccountType::UserLocked => b.user_locked += balance,
+                AccountType::TreasuryAvailable => b.treasury_available += balance,
+                AccountType::TreasuryLocked => b.treasury_locked += balance,
+                AccountType::PlatformClearing => b.platform_clearing += balance,
+                _ => {}
+            }
+        }
+        b
+    }
+
+    fn report(&self) -> SolvencyReport {
+        let spec = SolvencyReport::assess(
+            PublicId::new(Uuid::new_v4()),
+            USDT,
+            self.solvency(),
+            SolvencyTrigger::OnDemand,
+            "ops",
+            Utc::now(),
+        )
+        .unwrap();
+        SolvencyReport::restore(1, spec, None)
+    }
+}
+
+fn alert(r: &SolvencyReport) -> SolvencyAlert {
+    SolvencyAlert {
+        report_id: r.public_id(),
+        asset_id: r.asset_id(),
+        as_of: r.as_of(),
+        liabilities_minor: r.liabilities_minor(),
+        holdings_minor: r.holdings_minor(),
+        shortfall_minor: -r.surplus_minor(),
+        coverage_bps: r.coverage_bps(),
+    }
+}
+
+#[test]
+fn a_single_deposit_is_fully_covered() {
+    let mut ledger = Ledger::new();
+    let (owner, available, _) = ledger.user(10);
+    ledger.deposit(owner, available, 100);
+
+    // the pool is credited as the money arrives
+    assert_eq!(ledger.solvency().platform_clearing, -100);
+    let r = ledger.report();
+    assert_eq!(r.liabilities_minor(), 100);
+    assert_eq!(r.holdings_minor(), 100);
+    assert_eq!(r.coverage_bps(), Some(10_000));
+    assert_eq!(r.status(), SolvencyStatus::Covered);
+    assert!(!r.needs_alert());
+}
+
+#[test]
+fn covered_asset_reports_its_coverage_ratio() {
+    let mut ledger = Ledger::new();
+    let (alice, alice_available, alice_locked) = ledger.user(10);
+    let (bob, bob_available, _) = ledger.user(20);
+    ledger.deposit(alice, alice_available, 7_000);
+    ledger.deposit(bob, bob_available, 1_500);
+    ledger.transfer(alice_available, alice_locked, 1_000);
+    // a fee leaves the users but stays in the pool
+    ledger.transfer(alice_available, REVENUE, 200);
+    // a sweep from clearing to treasury moves holdings between pools
+    ledger.transfer(TREASURY, CLEARING, 3_000);
+
+    let b = ledger.solvency();
+    assert_eq!((b.user_available, b.user_locked), (7_300, 1_000));
+    assert_eq!((b.treasury_available, b.platform_clearing), (-3_000, -5_500));
+
+    let r = ledger.report();
+    assert_eq!(r.liabilities_minor(), 8_300);
+    assert_eq!(r.holdings_minor(), 8_500);
+    assert_eq!(r.surplus_minor(), 200);
+    assert_eq!(r.coverage_bps(), Some(10_240));
+    assert_eq!(r.status(), SolvencyStatus::Covered);
+}
+
+#[test]
+fn shortfall_needs_one_alert() {
+    let mut ledger = Ledger::new();
+    let (owner, available, _) = ledger.user(10);
+    ledger.deposit(owner, available, 3_000);
+    // funds leave the pool without any user's balance going down
+    ledger.transfer(REVENUE, CLEARING, 1_001);
+
+    let mut r = ledger.report();
+    assert_eq!(r.holdings_minor(), 1_999);
+    assert_eq!(r.status(), SolvencyStatus::Shortfall);
+    assert_eq!(r.surplus_minor(), -1_001);
+    // rounded down, so a shortfall never shows as covered
+    assert_eq!(r.coverage_bps(), Some(6_663));
+    assert!(r.needs_alert());
+
+    r.mark_alerted(Utc::now()).unwrap();
+    assert!(!r.needs_alert());
+    assert!(matches!(r.mark_alerted(Utc::now()), Err(DomainError::SolvencyReportInvalid { .. })));
+}
+
+#[test]
+fn nothing_owed_has_no_ratio() {
+    let mut ledger = Ledger::new();
+    // platform capital in treasury, owed to nobody
+    ledger.transfer(TREASURY, REVENUE, 300);
+    let r = ledger.report();
+    assert_eq!(r.holdings_minor(), 300);
+    assert_eq!(r.coverage_bps(), None);
+    assert_eq!(r.status(), SolvencyStatus::Covered);
+
+    assert!(matches!(
+        SolvencyReport::assess(
+            PublicId::new(Uuid::new_v4()),
+            USDT,
+            SolvencyBalances::default(),
+            SolvencyTrigger::Scheduled,
+            " ",
+            Utc::now(),
+        ),
+        Err(DomainError::CreatedByEmpty)
+    ));
+    assert!(matches!(SolvencyTrigger::from_code("HOURLY"), Err(DomainError::InvalidSolvencyTrigger { .. })));
+}
+
+#[tokio::test]
+async fn recorder_keeps_one_alert_per_report() {
+    let sink = LocalAlertRecorder::new();
+    let mut ledger = Ledger::new();
+    let (owner, available, _) = ledger.user(10);
+    ledger.deposit(owner, available, 100);
+    ledger.transfer(REVENUE, CLEARING, 60);
+    let short = ledger.report();
+
+    sink.set_outage(Some("pager down"));
+    assert!(matches!(
+        sink.raise(&alert(&short)).await,
+        Err(SolvencyAlertError::Undelivered { message }) if message == "pager down"
+    ));
+    assert!(sink.alerts().is_empty());
+
+    sink.set_outage(None);
+    sink.raise(&alert(&short)).await.unwrap();
+    sink.raise(&alert(&short)).await.unwrap();
+    let alerts = sink.alerts();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].shortfall_minor, 60);
+    assert_eq!(alerts[0].coverage_bps, Some(4_000));
+}
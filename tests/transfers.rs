@@ -0,0 +1,103 @@
+use uuid::Uuid;
+
+use sirara_core::domain::aggregate::{TransferIntent, RECEIVER_OWNER_KEY, SENDER_OWNER_KEY};
+use sirara_core::domain::entities::{AccountType, LedgerAccount, OwnerType};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::{FeeCalculation, FeeContext, FeeEngine, FeeRounding, FeeRule, FeeSchedule};
+use sirara_core::domain::value_objects::{ExternalRef, ExternalRefType, PublicId};
+
+const NGN: i16 = 1;
+const REVENUE: i64 = 99;
+
+fn available(id: i64, owner: Uuid, asset_id: i16) -> LedgerAccount {
+    LedgerAccount::new(id, PublicId::new(Uuid::new_v4()), OwnerType::User, Some(owner), AccountType::UserAvailable, asset_id, true)
+}
+
+fn key() -> ExternalRef {
+    ExternalRef::new(format!("order-{}", Uuid::new_v4())).unwrap()
+}
+
+#[test]
+fn draft_moves_the_amount_between_the_two_available_accounts() {
+    let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+    let key = key();
+    let intent =
+        TransferIntent::new(key.clone(), &available(1, alice, NGN), &available(2, bob, NGN), 5_000, "api", None).unwrap();
+
+    let draft = intent.draft().unwrap();
+    assert_eq!(draft.external_ref_type, ExternalRefType::TransferIntent);
+    assert_eq!(draft.external_ref, key);
+    assert_eq!(draft.metadata.get(SENDER_OWNER_KEY), Some(alice.to_string().as_str()));
+    assert_eq!(draft.metadata.get(RECEIVER_OWNER_KEY), Some(bob.to_string().as_str()));
+
+    let lines: Vec<(i64, i128)> = draft.lines().iter().map(|l| (l.account_id, l.amount.minor())).collect();
+    assert_eq!(lines, vec![(1, -5_000), (2, 5_000)]);
+}
+
+#[test]
+fn fee_lines_are_charged_to_the_sender_and_keep_the_journal_balanced() {
+    let sender = available(1, Uuid::new_v4(), NGN);
+    let intent = TransferIntent::new(key(), &sender, &available(2, Uuid::new_v4(), NGN), 10_000, "api", None).unwrap();
+    let schedule = FeeSchedule::new(vec![FeeRule {
+        id: 1,
+        asset_id: NGN,
+        posting_kind: ExternalRefType::TransferIntent,
+        calculation: FeeCalculation::Flat { amount_minor: 50 },
+        min_fee_minor: None,
+        max_fee_minor: None,
+        rounding: FeeRounding::HalfEven,
+    }])
+    .unwrap();
+
+    let mut draft = intent.draft().unwrap();
+    FeeEngine::apply(
+        &schedule,
+        &mut draft,
+        &FeeContext {
+            asset_id: NGN,
+            payer_account_id: sender.id(),
+            revenue_account_id: REVENUE,
+            base_amount_minor: intent.amount_minor(),
+        },
+    )
+    .unwrap();
+
+    let net = |id: i64| draft.lines().iter().filter(|l| l.account_id == id).map(|l| l.amount.minor()).sum::<i128>();
+    assert_eq!(net(1), -10_050);
+    assert_eq!(net(2), 10_000);
+    assert_eq!(net(REVENUE), 50);
+    assert_eq!(draft.lines().iter().map(|l| l.amount.minor()).sum::<i128>(), 0);
+}
+
+#[test]
+fn parties_must_be_distinct_users_in_the_same_asset() {
+    let alice = Uuid::new_v4();
+    let sender = available(1, alice, NGN);
+
+    assert!(matches!(
+        TransferIntent::new(key(), &sender, &available(2, alice, NGN), 100, "api", None),
+        Err(DomainError::TransferInvalid { .. })
+    ));
+    assert!(matches!(
+        TransferIntent::new(key(), &sender, &available(2, Uuid::new_v4(), 2), 100, "api", None),
+        Err(DomainError::CrossAssetPostingNotAllowed)
+    ));
+    assert!(matches!(
+        TransferIntent::new(key(), &sender, &available(2, Uuid::new_v4(), NGN), 0, "api", None),
+        Err(DomainError::TransferInvalid { .. })
+    ));
+
+    let locked = LedgerAccount::new(
+        3,
+        PublicId::new(Uuid::new_v4()),
+        OwnerType::User,
+        Some(Uuid::new_v4()),
+        AccountType::UserLocked,
+        NGN,
+        true,
+    );
+    assert!(matches!(
+        TransferIntent::new(key(), &sender, &locked, 100, "api", None),
+        Err(DomainError::TransferInvalid { .. })
+    ));
+}
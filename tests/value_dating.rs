@@ -0,0 +1,31 @@
+use chrono::{Duration, Utc};
+
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::services::{BalanceBasis, ValueDatingPolicy};
+
+#[test]
+fn effective_dates_must_fall_inside_the_window() {
+    let now = Utc::now();
+    let policy = ValueDatingPolicy::new(Duration::days(7), Duration::hours(1));
+
+    assert!(policy.check(None, now).is_ok());
+    assert!(policy.check(Some(now - Duration::days(7)), now).is_ok());
+    assert!(policy.check(Some(now + Duration::hours(1)), now).is_ok());
+
+    assert!(matches!(
+        policy.check(Some(now - Duration::days(7) - Duration::seconds(1)), now),
+        Err(DomainError::EffectiveDateTooEarly { .. })
+    ));
+    assert!(matches!(
+        policy.check(Some(now + Duration::hours(2)), now),
+        Err(DomainError::EffectiveDateTooLate { .. })
+    ));
+}
+
+#[test]
+fn balance_basis_round_trips_codes() {
+    for basis in [BalanceBasis::Posted, BalanceBasis::Effective] {
+        assert_eq!(BalanceBasis::from_code(basis.as_code()).unwrap(), basis);
+    }
+    assert!(matches!(BalanceBasis::from_code("booked"), Err(DomainError::InvalidBalanceBasis { .. })));
+}
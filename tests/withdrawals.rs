@@ -0,0 +1,155 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use sirara_core::application::contracts::{PayoutInstruction, PayoutRail, PayoutRailError, PayoutStatus};
+use sirara_core::domain::aggregate::{Withdrawal, WithdrawalStatus};
+use sirara_core::domain::error::DomainError;
+use sirara_core::domain::value_objects::PublicId;
+use sirara_core::infrastructure::payout::{LocalPayoutSimulator, SimulatedOutcome};
+
+const NGN: i16 = 1;
+
+fn requested() -> Withdrawal {
+    let spec = Withdrawal::request(PublicId::new(Uuid::new_v4()), Uuid::new_v4(), NGN, 5_000, " acct-0042 ", "alice")
+        .unwrap();
+    Withdrawal::restore(1, spec, WithdrawalStatus::Requested, None, None, None, None)
+}
+
+fn instruction(w: &Withdrawal) -> PayoutInstruction {
+    PayoutInstruction {
+        withdrawal_id: w.public_id(),
+        asset_id: w.asset_id(),
+        amount_minor: w.amount_minor(),
+        destination: w.destination().to_string(),
+    }
+}
+
+#[test]
+fn request_validates_amount_and_destination() {
+    let id = PublicId::new(Uuid::new_v4());
+    let owner = Uuid::new_v4();
+
+    let spec = Withdrawal::request(id, owner, NGN, 5_000, " acct-0042 ", "alice").unwrap();
+    assert_eq!(spec.destination, "acct-0042");
+
+    assert!(matches!(
+        Withdrawal::request(id, owner, NGN, 0, "acct", "alice"),
+        Err(DomainError::WithdrawalInvalid { .. })
+    ));
+    assert!(matches!(
+        Withdrawal::request(id, owner, NGN, 10, "   ", "alice"),
+        Err(DomainError::WithdrawalInvalid { .. })
+    ));
+    assert!(matches!(
+        Withdrawal::request(id, owner, NGN, 10, "x".repeat(257), "alice"),
+        Err(DomainError::WithdrawalInvalid { .. })
+    ));
+}
+
+#[test]
+fn lifecycle_moves_forward_and_replays_are_no_ops() {
+    let mut w = requested();
+    let now = Utc::now();
+    assert!(!w.has_hold());
+    assert_eq!(w.hold_id(), w.public_id());
+
+    assert!(w.transition(WithdrawalStatus::Held, "alice", now).unwrap());
+    assert!(w.has_hold());
+    assert!(!w.transition(WithdrawalStatus::Held, "alice", now).unwrap());
+
+    assert!(w.mark_submitted("sim_1", "ops", now).unwrap());
+    assert_eq!(w.rail_reference(), Some("sim_1"));
+    // a replay keeps the first reference
+    assert!(!w.mark_submitted("sim_2", "ops", now).unwrap());
+    assert_eq!(w.rail_reference(), Some("sim_1"));
+    assert!(w.has_hold());
+
+    assert!(w.transition(WithdrawalStatus::Confirmed, "payout_rail", now).unwrap());
+    assert!(w.status().is_final());
+    assert!(!w.has_hold());
+    assert_eq!(w.status_changed_by(), Some("payout_rail"));
+}
+
+#[test]
+fn steps_cannot_be_skipped_and_final_states_stay_final() {
+    let now = Utc::now();
+
+    let mut w = requested();
+    assert!(matches!(
+        w.mark_submitted("sim_1", "ops", now),
+        Err(DomainError::WithdrawalTransitionNotAllowed { .. })
+    ));
+    assert!(matches!(
+        w.transition(WithdrawalStatus::Confirmed, "ops", now),
+        Err(DomainError::WithdrawalTransitionNotAllowed { .. })
+    ));
+
+    w.transition(WithdrawalStatus::Held, "alice", now).unwrap();
+    w.mark_submitted("sim_1", "ops", now).unwrap();
+    w.transition(WithdrawalStatus::Confirmed, "ops", now).unwrap();
+    assert!(matches!(
+        w.mark_failed("late bounce", "ops", now),
+        Err(DomainError::WithdrawalTransitionNotAllowed { .. })
+    ));
+
+    let mut failed = requested();
+    assert!(failed.mark_failed("insufficient funds", "alice", now).unwrap());
+    assert_eq!(failed.failure_reason(), Some("insufficient funds"));
+    assert!(matches!(
+        failed.transition(WithdrawalStatus::Held, "alice", now),
+        Err(DomainError::WithdrawalTransitionNotAllowed { .. })
+    ));
+}
+
+#[test]
+fn status_codes_round_trip() {
+    for status in [
+        WithdrawalStatus::Requested,
+        WithdrawalStatus::Held,
+        WithdrawalStatus::Submitted,
+        WithdrawalStatus::Confirmed,
+        WithdrawalStatus::Failed,
+    ] {
+        assert_eq!(WithdrawalStatus::from_code(status.as_code()).unwrap(), status);
+    }
+    assert!(matches!(WithdrawalStatus::from_code("PAID"), Err(DomainError::InvalidWithdrawalStatus { .. })));
+}
+
+#[tokio::test]
+async fn simulator_is_idempotent_by_withdrawal_id() {
+    let rail = LocalPayoutSimulator::new(SimulatedOutcome::Pending);
+    let w = requested();
+
+    let first = rail.submit(&instruction(&w)).await.unwrap();
+    let again = rail.submit(&instruction(&w)).await.unwrap();
+    assert_eq!(first, again);
+    assert_eq!(rail.submissions(), 1);
+    assert_eq!(rail.status(&first).await.unwrap(), PayoutStatus::Pending);
+
+    rail.confirm(&first).unwrap();
+    assert_eq!(rail.status(&first).await.unwrap(), PayoutStatus::Confirmed);
+    // settled payouts do not change again
+    rail.fail(&first, "bounced").unwrap();
+    assert_eq!(rail.status(&first).await.unwrap(), PayoutStatus::Confirmed);
+}
+
+#[tokio::test]
+async fn simulator_rejects_and_fails_on_request() {
+    let rail = LocalPayoutSimulator::new(SimulatedOutcome::Reject("destination closed".into()));
+    let rejected = requested();
+    assert!(matches!(
+        rail.submit(&instruction(&rejected)).await,
+        Err(PayoutRailError::Rejected { reason }) if reason == "destination closed"
+    ));
+    assert_eq!(rail.submissions(), 0);
+
+    rail.set_outcome(SimulatedOutcome::Fail("bank timeout".into()));
+    let failing = requested();
+    let reference = rail.submit(&instruction(&failing)).await.unwrap();
+    assert_eq!(
+        rail.status(&reference).await.unwrap(),
+        PayoutStatus::Failed { reason: "bank timeout".into() }
+    );
+
+    assert!(matches!(rail.status("sim_unknown").await, Err(PayoutRailError::UnknownReference { .. })));
+}